@@ -1,5 +1,7 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use vox_cryptography::hashes::{BLAKE2b, HashingAlgorithm, MD5, SHA1, SHA256, SHA512};
+use vox_cryptography::hashes::{
+    BLAKE2b, BLAKE2s, BLAKE2sp, HashingAlgorithm, BLAKE3, MD5, RIPEMD160, SHA1, SHA256, SHA512, SM3,
+};
 
 fn sha256_benchmark(c: &mut Criterion) {
     c.bench_function("sha256 - empty", |b| {
@@ -102,13 +104,111 @@ fn blake2b_benchmark(c: &mut Criterion) {
     );
 }
 
+fn blake2s_benchmark(c: &mut Criterion) {
+    c.bench_function("blake2s - empty", |b| {
+        b.iter(|| BLAKE2s::hash(black_box(&[])))
+    });
+
+    c.bench_function(
+        "blake2s - 'The quick brown fox jumped over the lazy dog.'",
+        |b| b.iter(|| BLAKE2s::hash(black_box(b"The quick brown fox jumped over the lazy dog."))),
+    );
+
+    let input_str = "The quick brown fox jumped over the lazy dog.\n".repeat(100_000);
+    let input = input_str.as_bytes();
+
+    c.bench_function(
+        "blake2s - 'The quick brown fox jumped over the lazy dog.' * 100,000",
+        |b| b.iter(|| BLAKE2s::hash(black_box(input))),
+    );
+}
+
+fn blake2sp_benchmark(c: &mut Criterion) {
+    c.bench_function("blake2sp - empty", |b| {
+        b.iter(|| BLAKE2sp::hash(black_box(&[])))
+    });
+
+    c.bench_function(
+        "blake2sp - 'The quick brown fox jumped over the lazy dog.'",
+        |b| b.iter(|| BLAKE2sp::hash(black_box(b"The quick brown fox jumped over the lazy dog."))),
+    );
+
+    let input_str = "The quick brown fox jumped over the lazy dog.\n".repeat(100_000);
+    let input = input_str.as_bytes();
+
+    c.bench_function(
+        "blake2sp - 'The quick brown fox jumped over the lazy dog.' * 100,000",
+        |b| b.iter(|| BLAKE2sp::hash(black_box(input))),
+    );
+}
+
+fn blake3_benchmark(c: &mut Criterion) {
+    c.bench_function("blake3 - empty", |b| {
+        b.iter(|| BLAKE3::hash(black_box(&[])))
+    });
+
+    c.bench_function(
+        "blake3 - 'The quick brown fox jumped over the lazy dog.'",
+        |b| b.iter(|| BLAKE3::hash(black_box(b"The quick brown fox jumped over the lazy dog."))),
+    );
+
+    let input_str = "The quick brown fox jumped over the lazy dog.\n".repeat(100_000);
+    let input = input_str.as_bytes();
+
+    c.bench_function(
+        "blake3 - 'The quick brown fox jumped over the lazy dog.' * 100,000",
+        |b| b.iter(|| BLAKE3::hash(black_box(input))),
+    );
+}
+
+fn ripemd160_benchmark(c: &mut Criterion) {
+    c.bench_function("ripemd160 - empty", |b| {
+        b.iter(|| RIPEMD160::hash(black_box(&[])))
+    });
+
+    c.bench_function(
+        "ripemd160 - 'The quick brown fox jumped over the lazy dog.'",
+        |b| b.iter(|| RIPEMD160::hash(black_box(b"The quick brown fox jumped over the lazy dog."))),
+    );
+
+    let input_str = "The quick brown fox jumped over the lazy dog.\n".repeat(100_000);
+    let input = input_str.as_bytes();
+
+    c.bench_function(
+        "ripemd160 - 'The quick brown fox jumped over the lazy dog.' * 100,000",
+        |b| b.iter(|| RIPEMD160::hash(black_box(input))),
+    );
+}
+
+fn sm3_benchmark(c: &mut Criterion) {
+    c.bench_function("sm3 - empty", |b| b.iter(|| SM3::hash(black_box(&[]))));
+
+    c.bench_function(
+        "sm3 - 'The quick brown fox jumped over the lazy dog.'",
+        |b| b.iter(|| SM3::hash(black_box(b"The quick brown fox jumped over the lazy dog."))),
+    );
+
+    let input_str = "The quick brown fox jumped over the lazy dog.\n".repeat(100_000);
+    let input = input_str.as_bytes();
+
+    c.bench_function(
+        "sm3 - 'The quick brown fox jumped over the lazy dog.' * 100,000",
+        |b| b.iter(|| SM3::hash(black_box(input))),
+    );
+}
+
 criterion_group!(
     hashes,
     sha256_benchmark,
     sha512_benchmark,
     sha1_benchmark,
     md5_benchmark,
-    blake2b_benchmark
+    blake2b_benchmark,
+    blake2s_benchmark,
+    blake2sp_benchmark,
+    blake3_benchmark,
+    ripemd160_benchmark,
+    sm3_benchmark
 );
 
 criterion_main!(hashes);
@@ -1,7 +1,15 @@
 import json
+import subprocess
 
 dict = json.load(open("test_cases.json", "r"))
 
+
+# The generated files are committed as formatted by rustfmt, which leaves the long test vector
+# lines as they are written here.
+def format_rust(path):
+    subprocess.run(["rustfmt", "--edition", "2021", path], check=True)
+
+
 # Create blowfish tests
 with open("tests/raw_blowfish.rs", "w") as raw_blowfish_file:
     i = 1
@@ -42,3 +50,214 @@ with open("tests/raw_blowfish.rs", "w") as raw_blowfish_file:
         raw_blowfish_file.write(assert_statement_decrypt)
 
         i += 1
+
+format_rust("tests/raw_blowfish.rs")
+
+# Create BLAKE2 known answer tests, the input is the bytes 0, 1, 2, ... of each length from 0 to 255
+# and the key is the bytes 0, 1, 2, ... of the maximum key length.
+blake2_algorithms = [("blake2b", "BLAKE2b", 64), ("blake2s", "BLAKE2s", 32),
+                     ("blake2bp", "BLAKE2bp", 64), ("blake2sp", "BLAKE2sp", 32)]
+
+with open("tests/blake2_kat.rs", "w") as blake2_kat_file:
+    blake2_kat_file.write(
+        "use vox_cryptography::hashes::{{{}, Hasher, HashingAlgorithm}};\nuse pretty_assertions::assert_eq;\n\n".format(
+            ", ".join(name for (_, name, _) in blake2_algorithms)))
+
+    blake2_kat_file.write(
+        "fn kat_input(len: usize) -> Vec<u8> {\n\t(0..len).map(|i| i as u8).collect()\n}\n\n")
+
+    for (key, name, key_len) in blake2_algorithms:
+        for variant in ["unkeyed", "keyed"]:
+            cases = dict["kat"][key][variant]
+
+            blake2_kat_file.write("const {}_{}: [&str; {}] = [\n".format(
+                key.upper(), variant.upper(), len(cases)))
+
+            for case in cases:
+                blake2_kat_file.write("\t\"{}\",\n".format(case))
+
+            blake2_kat_file.write("];\n\n")
+
+        blake2_kat_file.write("#[test]\nfn {}_unkeyed_kat() {{\n".format(key))
+        blake2_kat_file.write(
+            "\tfor (len, expected) in {}_UNKEYED.iter().enumerate() {{\n".format(key.upper()))
+        blake2_kat_file.write(
+            "\t\tassert_eq!(hex::encode({}::hash(&kat_input(len))), *expected);\n\t}}\n}}\n\n".format(name))
+
+        blake2_kat_file.write("#[test]\nfn {}_keyed_kat() {{\n".format(key))
+        blake2_kat_file.write(
+            "\tlet key = kat_input({});\n\n".format(key_len))
+        blake2_kat_file.write(
+            "\tfor (len, expected) in {}_KEYED.iter().enumerate() {{\n".format(key.upper()))
+        blake2_kat_file.write(
+            "\t\tlet mut hasher = Hasher::new({}::builder().with_key_slice(&key).build());\n".format(name))
+        blake2_kat_file.write(
+            "\t\thasher.update(&kat_input(len));\n\n")
+        blake2_kat_file.write(
+            "\t\tassert_eq!(hex::encode(hasher.finalize()), *expected);\n\t}\n}\n\n")
+
+format_rust("tests/blake2_kat.rs")
+
+# Create BLAKE3 test vectors, the input is the bytes 0, 1, ..., 250, 0, 1, ... of each length and
+# every output is extended to 131 bytes.
+with open("tests/blake3_kat.rs", "w") as blake3_kat_file:
+    blake3_kat = dict["kat"]["blake3"]
+
+    blake3_kat_file.write(
+        "use vox_cryptography::hashes::{Hasher, BLAKE3};\nuse pretty_assertions::assert_eq;\n\n")
+
+    blake3_kat_file.write(
+        "const KEY: &[u8; 32] = b\"{}\";\nconst CONTEXT: &str = \"{}\";\n\n".format(blake3_kat["key"], blake3_kat["context_string"]))
+
+    blake3_kat_file.write(
+        "fn kat_input(len: usize) -> Vec<u8> {\n\t(0..len).map(|i| (i % 251) as u8).collect()\n}\n\n")
+
+    blake3_kat_file.write(
+        "fn extended_output(hasher: Hasher<BLAKE3>) -> String {\n\tlet mut output = [0u8; 131];\n\thasher.finalize_xof().fill(&mut output);\n\n\thex::encode(output)\n}\n\n")
+
+    blake3_kat_file.write(
+        "const CASES: [(usize, &str, &str, &str); {}] = [\n".format(len(blake3_kat["cases"])))
+
+    for case in blake3_kat["cases"]:
+        blake3_kat_file.write("\t({}, \"{}\", \"{}\", \"{}\"),\n".format(
+            case["input_len"], case["hash"], case["keyed_hash"], case["derive_key"]))
+
+    blake3_kat_file.write("];\n\n")
+
+    for (index, mode, constructor) in [(1, "hash", "BLAKE3::default()"), (2, "keyed_hash", "BLAKE3::new_keyed(KEY)"), (3, "derive_key", "BLAKE3::new_derive_key(CONTEXT)")]:
+        blake3_kat_file.write("#[test]\nfn blake3_{}_kat() {{\n".format(mode))
+        blake3_kat_file.write("\tfor case in CASES {\n")
+        blake3_kat_file.write(
+            "\t\tlet mut hasher = Hasher::new({});\n".format(constructor))
+        blake3_kat_file.write("\t\thasher.update(&kat_input(case.0));\n\n")
+        blake3_kat_file.write(
+            "\t\tassert_eq!(hex::encode(hasher.digest_so_far()), case.{}[..64]);\n".format(index))
+        blake3_kat_file.write(
+            "\t\tassert_eq!(extended_output(hasher), case.{});\n".format(index))
+        blake3_kat_file.write("\t}\n}\n\n")
+
+    blake3_kat_file.write("#[test]\nfn blake3_parallel_kat() {\n")
+    blake3_kat_file.write("\tfor case in CASES {\n")
+    blake3_kat_file.write(
+        "\t\tlet input = kat_input(case.0);\n\n")
+    blake3_kat_file.write(
+        "\t\tassert_eq!(hex::encode(BLAKE3::default().hash_parallel(&input)), case.1[..64]);\n")
+    blake3_kat_file.write(
+        "\t\tassert_eq!(hex::encode(BLAKE3::keyed_hash(KEY, &input)), case.2[..64]);\n")
+    blake3_kat_file.write(
+        "\t\tassert_eq!(hex::encode(BLAKE3::derive_key(CONTEXT, &input)), case.3[..64]);\n")
+    blake3_kat_file.write("\t}\n}\n\n")
+
+    # Inputs longer than a chunk given to `update` in pieces that don't line up with the 1024 byte
+    # chunks, so the chaining values are merged across calls.
+    blake3_kat_file.write("#[test]\nfn blake3_incremental_kat() {\n")
+    blake3_kat_file.write(
+        "\tfor case in CASES.iter().filter(|case| case.0 > 1024) {\n")
+    blake3_kat_file.write("\t\tlet input = kat_input(case.0);\n\n")
+    blake3_kat_file.write("\t\tfor piece_len in [7, 1000, 1025, 4097] {\n")
+    blake3_kat_file.write(
+        "\t\t\tlet mut hasher = Hasher::new(BLAKE3::default());\n")
+    blake3_kat_file.write(
+        "\t\t\tlet mut keyed_hasher = Hasher::new(BLAKE3::new_keyed(KEY));\n\n")
+    blake3_kat_file.write("\t\t\tfor piece in input.chunks(piece_len) {\n")
+    blake3_kat_file.write("\t\t\t\thasher.update(piece);\n")
+    blake3_kat_file.write("\t\t\t\tkeyed_hasher.update(piece);\n\t\t\t}\n\n")
+    blake3_kat_file.write(
+        "\t\t\tassert_eq!(extended_output(hasher), case.1);\n")
+    blake3_kat_file.write(
+        "\t\t\tassert_eq!(extended_output(keyed_hasher), case.2);\n")
+    blake3_kat_file.write("\t\t}\n\t}\n}\n")
+
+format_rust("tests/blake3_kat.rs")
+
+# Create bignum tests, all of the numbers are hexadecimal without leading zeros.
+with open("tests/bignum.rs", "w") as bignum_file:
+    bignum = dict["bignum"]
+
+    bignum_file.write(
+        "use vox_cryptography::bignum::{BigUint, Montgomery, U4096};\nuse pretty_assertions::assert_eq;\n\n")
+
+    bignum_file.write(
+        "fn big(hex: &str) -> BigUint {\n\tlet padded = if hex.len() % 2 == 1 { format!(\"0{}\", hex) } else { hex.to_string() };\n\n\tBigUint::from_be_bytes(&hex::decode(padded).unwrap())\n}\n\n")
+
+    arithmetic_fields = ["a", "b", "sum", "difference",
+                         "product", "quotient", "remainder", "gcd"]
+
+    bignum_file.write("struct ArithmeticCase {\n")
+    for field in arithmetic_fields:
+        bignum_file.write("\t{}: &'static str,\n".format(field))
+    bignum_file.write("}\n\n")
+
+    bignum_file.write(
+        "const ARITHMETIC: [ArithmeticCase; {}] = [\n".format(len(bignum["arithmetic"])))
+
+    for case in bignum["arithmetic"]:
+        bignum_file.write("\tArithmeticCase {\n")
+        for field in arithmetic_fields:
+            bignum_file.write("\t\t{}: \"{}\",\n".format(field, case[field]))
+        bignum_file.write("\t},\n")
+
+    bignum_file.write("];\n\n")
+
+    bignum_file.write(
+        "// (base, exponent, modulus, base^exponent mod modulus, base^-1 mod modulus)\nconst MODULAR: [(&str, &str, &str, &str, Option<&str>); {}] = [\n".format(len(bignum["modular"])))
+
+    for case in bignum["modular"]:
+        inverse = "None" if case["inverse"] is None else "Some(\"{}\")".format(
+            case["inverse"])
+        bignum_file.write("\t(\"{}\", \"{}\", \"{}\", \"{}\", {}),\n".format(
+            case["base"], case["exponent"], case["modulus"], case["result"], inverse))
+
+    bignum_file.write("];\n\n")
+
+    bignum_file.write("#[test]\nfn bignum_arithmetic() {\n")
+    bignum_file.write(
+        "\tfor case in ARITHMETIC {\n")
+    bignum_file.write("\t\tlet (a, b) = (big(case.a), big(case.b));\n\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", &a + &b), case.sum);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", &a - &b), case.difference);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", &a * &b), case.product);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", &a / &b), case.quotient);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", &a % &b), case.remainder);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", a.gcd(&b)), case.gcd);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(BigUint::from_be_bytes(&a.to_be_bytes()), a);\n\t}\n}\n\n")
+
+    bignum_file.write("#[test]\nfn bignum_fixed_width_arithmetic() {\n")
+    bignum_file.write(
+        "\tfor case in ARITHMETIC {\n")
+    bignum_file.write(
+        "\t\tlet (Ok(a), Ok(b)) = (U4096::try_from(&big(case.a)), U4096::try_from(&big(case.b))) else {\n\t\t\tcontinue;\n\t\t};\n\n")
+    bignum_file.write(
+        "\t\tlet (low, high) = a.widening_mul(&b);\n\t\tlet product_bits = &(&BigUint::from(&high) << U4096::BITS) + &BigUint::from(&low);\n\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", product_bits), case.product);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", BigUint::from(&a.wrapping_sub(&b))), case.difference);\n\n")
+    bignum_file.write(
+        "\t\tif let Some(s) = a.checked_add(&b) {\n\t\t\tassert_eq!(format!(\"{:x}\", BigUint::from(&s)), case.sum);\n\t\t}\n\t}\n}\n\n")
+
+    bignum_file.write("#[test]\nfn bignum_modular() {\n")
+    bignum_file.write(
+        "\tfor (base, exponent, modulus, result, inverse) in MODULAR {\n")
+    bignum_file.write(
+        "\t\tlet (base, exponent, modulus) = (big(base), big(exponent), big(modulus));\n\n")
+    bignum_file.write(
+        "\t\tassert_eq!(format!(\"{:x}\", base.modpow(&exponent, &modulus)), result);\n")
+    bignum_file.write(
+        "\t\tassert_eq!(base.modinv(&modulus).map(|i| format!(\"{:x}\", i)).as_deref(), inverse);\n\n")
+    bignum_file.write("\t\tif modulus.is_odd() {\n")
+    bignum_file.write(
+        "\t\t\tlet montgomery = Montgomery::new(&modulus).unwrap();\n\n")
+    bignum_file.write(
+        "\t\t\tassert_eq!(format!(\"{:x}\", montgomery.pow(&base, &exponent)), result);\n")
+    bignum_file.write(
+        "\t\t\tassert_eq!(format!(\"{:x}\", montgomery.pow_vartime(&base, &exponent)), result);\n\t\t}\n\t}\n}\n")
+
+format_rust("tests/bignum.rs")
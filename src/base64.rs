@@ -0,0 +1,208 @@
+//! The standard base64 alphabet of RFC 4648 section 4, with and without padding.
+//!
+//! Characters are converted with arithmetic rather than table lookups, so encoding and decoding
+//! secrets such as PEM private keys takes time that depends only on the length of the input.
+//! Decoding is strict: anything outside the alphabet, including whitespace, misplaced padding and
+//! non zero trailing bits is rejected with `VCryptoError::InvalidEncoding`.
+
+use crate::error::VCryptoError;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// Encodes `input` with padding.
+pub fn encode(input: &[u8]) -> String {
+    let mut output = encode_no_pad(input);
+
+    while !output.len().is_multiple_of(4) {
+        output.push('=');
+    }
+
+    return output;
+}
+
+/// Encodes `input` without padding, as the PHC string format and passlib do.
+pub fn encode_no_pad(input: &[u8]) -> String {
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+
+    for chunk in input.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = (group[0] as u32) << 16 | (group[1] as u32) << 8 | group[2] as u32;
+
+        for i in 0..=chunk.len() {
+            output.push(encode_char((bits >> (18 - 6 * i)) as u8 & 0x3f) as char);
+        }
+    }
+
+    return output;
+}
+
+/// Decodes padded base64, whose length has to be a multiple of 4.
+pub fn decode(input: &str) -> Result<Vec<u8>, VCryptoError> {
+    let bytes = input.as_bytes();
+
+    if !bytes.len().is_multiple_of(4) {
+        return Err(VCryptoError::InvalidEncoding);
+    }
+
+    let padding = bytes.iter().rev().take_while(|&&c| c == b'=').count();
+
+    if padding > 2 {
+        return Err(VCryptoError::InvalidEncoding);
+    }
+
+    return decode_no_pad_bytes(&bytes[..bytes.len() - padding]);
+}
+
+/// Decodes base64 without padding.
+pub fn decode_no_pad(input: &str) -> Result<Vec<u8>, VCryptoError> {
+    return decode_no_pad_bytes(input.as_bytes());
+}
+
+fn decode_no_pad_bytes(input: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+    // A single character left over would only hold 6 bits
+    if input.len() % 4 == 1 {
+        return Err(VCryptoError::InvalidEncoding);
+    }
+
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut invalid = 0u8;
+
+    for chunk in input.chunks(4) {
+        let mut bits = 0u32;
+
+        for (i, &c) in chunk.iter().enumerate() {
+            let (value, valid) = decode_char(c);
+            invalid |= !valid & 1;
+            bits |= (value as u32) << (18 - 6 * i);
+        }
+
+        let bytes = bits.to_be_bytes();
+        let len = chunk.len() - 1;
+        output.extend_from_slice(&bytes[1..1 + len]);
+
+        // The bits of the last character beyond the decoded bytes have to be zero
+        invalid |= (bytes[1 + len..].iter().fold(0, |acc, &byte| acc | byte) != 0) as u8;
+    }
+
+    if invalid != 0 {
+        return Err(VCryptoError::InvalidEncoding);
+    }
+
+    return Ok(output);
+}
+
+/// Maps a 6 bit value to its character without branching on the value.
+fn encode_char(value: u8) -> u8 {
+    let value = value as i16;
+
+    // Each mask is all ones when value is at least the start of a range, and the offsets move
+    // from one range of characters to the next: 'A', 'a', '0', '+' and '/'
+    let ge = |bound: i16| ((bound - 1 - value) >> 8) as u8;
+    let mut c = value as u8 + b'A';
+    c = c.wrapping_add(ge(26) & 6);
+    c = c.wrapping_add(ge(52) & 75u8.wrapping_neg());
+    c = c.wrapping_add(ge(62) & 15u8.wrapping_neg());
+    c = c.wrapping_add(ge(63) & 3);
+
+    return c;
+}
+
+/// Maps a character to its 6 bit value and whether it is in the alphabet, without branching on
+/// the character.
+fn decode_char(c: u8) -> (u8, u8) {
+    let c = c as i16;
+
+    // All ones when low <= c <= high
+    let range = |low: i16, high: i16| (((low - 1 - c) & (c - high - 1)) >> 8) as u8;
+
+    let upper = range(b'A' as i16, b'Z' as i16);
+    let lower = range(b'a' as i16, b'z' as i16);
+    let digit = range(b'0' as i16, b'9' as i16);
+    let plus = range(b'+' as i16, b'+' as i16);
+    let slash = range(b'/' as i16, b'/' as i16);
+
+    let value = (upper & (c - b'A' as i16) as u8)
+        | (lower & (c - b'a' as i16 + 26) as u8)
+        | (digit & (c - b'0' as i16 + 52) as u8)
+        | (plus & 62)
+        | (slash & 63);
+
+    return (value, upper | lower | digit | plus | slash);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_rfc4648() {
+        // RFC 4648 section 10
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            assert_eq!(encode(input.as_bytes()), expected);
+            assert_eq!(decode(expected).unwrap(), input.as_bytes());
+            assert_eq!(
+                encode_no_pad(input.as_bytes()),
+                expected.trim_end_matches('=')
+            );
+            assert_eq!(
+                decode_no_pad(expected.trim_end_matches('=')).unwrap(),
+                input.as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_alphabet() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let encoded = encode(&bytes);
+
+        assert!(encoded.starts_with("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8gISIjJCUmJygpKiss"));
+        assert!(encoded.ends_with("+fr7/P3+/w=="));
+        assert_eq!(decode(&encoded).unwrap(), bytes);
+
+        let all = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let decoded = decode(all).unwrap();
+        assert_eq!(encode(&decoded), all);
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in [
+            "Zg=",
+            "Zg",
+            "Z===",
+            "Zm9v=",
+            "Zg==Zg==",
+            "Zh==",
+            "Zm9=",
+            "Zm 9",
+            "Zm9\n",
+            "Zm-v",
+            "Zm_v",
+            "Zm9\u{e9}",
+            "====",
+        ] {
+            assert_eq!(decode(input).unwrap_err(), VCryptoError::InvalidEncoding);
+        }
+
+        for input in ["Z", "Zg=", "Zh", "Zm9"] {
+            assert_eq!(
+                decode_no_pad(input).unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use super::limbs::{self, Limb, LIMB_BITS, LIMB_BYTES};
+use super::montgomery::Montgomery;
+use crate::error::VCryptoError;
+use crate::random::RandomSource;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// An arbitrarily large unsigned integer.
+///
+/// Arithmetic on `BigUint` takes time that depends on the values involved, secret values should
+/// only be combined through [`Montgomery`].
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // Little endian limbs without any most significant zero limbs
+    limbs: Vec<Limb>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        return Self { limbs: Vec::new() };
+    }
+
+    pub fn one() -> Self {
+        return Self::from(1u64);
+    }
+
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        let mut limbs = vec![0; bytes.len().div_ceil(LIMB_BYTES)];
+
+        for (i, b) in bytes.iter().rev().enumerate() {
+            limbs[i / LIMB_BYTES] |= (*b as Limb) << (8 * (i % LIMB_BYTES));
+        }
+
+        return Self::from_limbs(limbs);
+    }
+
+    /// Returns the big endian bytes without leading zeros, zero is encoded as a single zero byte.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        return self
+            .to_be_bytes_padded(self.bits().div_ceil(8).max(1))
+            .expect("the length fits the number");
+    }
+
+    /// Returns the big endian bytes left padded with zeros to `len` bytes, as the I2OSP primitive
+    /// of RFC 8017 does.
+    pub fn to_be_bytes_padded(&self, len: usize) -> Result<Vec<u8>, VCryptoError> {
+        if self.bits().div_ceil(8) > len {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut bytes = vec![0u8; len];
+
+        for (i, b) in bytes.iter_mut().rev().enumerate() {
+            let limb = self.limbs.get(i / LIMB_BYTES).copied().unwrap_or(0);
+            *b = (limb >> (8 * (i % LIMB_BYTES))) as u8;
+        }
+
+        return Ok(bytes);
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.limbs.is_empty();
+    }
+
+    pub fn is_one(&self) -> bool {
+        return self.limbs == [1];
+    }
+
+    pub fn is_odd(&self) -> bool {
+        return self.limbs.first().is_some_and(|l| l & 1 == 1);
+    }
+
+    pub fn is_even(&self) -> bool {
+        return !self.is_odd();
+    }
+
+    /// Returns the number of significant bits.
+    pub fn bits(&self) -> usize {
+        return limbs::bits(&self.limbs);
+    }
+
+    /// Returns the bit at `index`, counting from the least significant bit.
+    pub fn bit(&self, index: usize) -> bool {
+        return self
+            .limbs
+            .get(index / LIMB_BITS)
+            .is_some_and(|l| (l >> (index % LIMB_BITS)) & 1 == 1);
+    }
+
+    /// Returns `self - rhs`, or `None` if `rhs` is larger.
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+
+        let mut limbs = self.limbs.clone();
+        limbs::sub_assign(&mut limbs, &rhs.limbs);
+
+        return Some(Self::from_limbs(limbs));
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`.
+    ///
+    /// # Panics
+    /// If `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+
+        if *self < *rhs {
+            return (Self::zero(), self.clone());
+        }
+
+        if rhs.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_limb(rhs.limbs[0]);
+
+            return (quotient, Self::from(remainder));
+        }
+
+        return self.div_rem_knuth(rhs);
+    }
+
+    /// Returns `self^exponent mod modulus`, in time that does not depend on the value of
+    /// `exponent` when `modulus` is odd.
+    ///
+    /// # Panics
+    /// If `modulus` is zero.
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(!modulus.is_zero(), "attempt to use a zero modulus");
+
+        if modulus.is_odd() {
+            if modulus.is_one() {
+                return Self::zero();
+            }
+
+            return Montgomery::new(modulus)
+                .expect("the modulus is odd")
+                .pow(self, exponent);
+        }
+
+        // Square and multiply for even moduli, which are never used with secret exponents
+        let base = self % modulus;
+        let mut result = Self::one() % modulus;
+
+        for i in (0..exponent.bits()).rev() {
+            result = &(&result * &result) % modulus;
+
+            if exponent.bit(i) {
+                result = &(&result * &base) % modulus;
+            }
+        }
+
+        return result;
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+
+        while !b.is_zero() {
+            let r = &a % &b;
+            a = b;
+            b = r;
+        }
+
+        return a;
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, or `None` if they are not coprime. This
+    /// takes time that depends on the values involved.
+    pub fn modinv(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() || modulus.is_one() {
+            return None;
+        }
+
+        // The extended Euclidean algorithm, keeping the coefficients of self reduced modulo
+        // modulus so that they stay positive
+        let mut r0 = modulus.clone();
+        let mut r1 = self % modulus;
+        let mut t0 = Self::zero();
+        let mut t1 = Self::one();
+
+        while !r1.is_zero() {
+            let (q, r2) = r0.div_rem(&r1);
+            let qt = &(&q * &t1) % modulus;
+            let t2 = (&t0 + &(modulus - &qt)) % modulus;
+
+            r0 = r1;
+            r1 = r2;
+            t0 = t1;
+            t1 = t2;
+        }
+
+        if !r0.is_one() {
+            return None;
+        }
+
+        return Some(t0);
+    }
+
+    /// Returns a uniformly random number below `bound`, found by rejection sampling. Returns
+    /// `VCryptoError::InvalidInput` if `bound` is zero.
+    pub fn random_below(bound: &Self, rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        if bound.is_zero() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let bits = bound.bits();
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+
+        loop {
+            rng.fill_bytes(&mut bytes)?;
+
+            // Clear the bits above the bound so at least half of the candidates are accepted
+            if !bits.is_multiple_of(8) {
+                bytes[0] &= (1 << (bits % 8)) - 1;
+            }
+
+            let candidate = Self::from_be_bytes(&bytes);
+
+            if &candidate < bound {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    pub(crate) fn from_limbs(mut limbs: Vec<Limb>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        return Self { limbs };
+    }
+
+    pub(crate) fn limbs(&self) -> &[Limb] {
+        return &self.limbs;
+    }
+
+    /// Returns the limbs zero extended to `len` limbs, `len` must be large enough to hold them.
+    pub(crate) fn limbs_padded(&self, len: usize) -> Vec<Limb> {
+        let mut limbs = self.limbs.clone();
+        limbs.resize(len, 0);
+
+        return limbs;
+    }
+
+    /// Returns `self mod divisor` without allocating, `divisor` must not be zero.
+    pub(crate) fn rem_limb(&self, divisor: Limb) -> Limb {
+        let mut remainder: u128 = 0;
+
+        for limb in self.limbs.iter().rev() {
+            remainder = ((remainder << LIMB_BITS) | *limb as u128) % divisor as u128;
+        }
+
+        return remainder as Limb;
+    }
+
+    fn div_rem_limb(&self, divisor: Limb) -> (Self, Limb) {
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder: u128 = 0;
+
+        for i in (0..self.limbs.len()).rev() {
+            let current = (remainder << LIMB_BITS) | self.limbs[i] as u128;
+            quotient[i] = (current / divisor as u128) as Limb;
+            remainder = current % divisor as u128;
+        }
+
+        return (Self::from_limbs(quotient), remainder as Limb);
+    }
+
+    /// Long division from Knuth's The Art of Computer Programming, volume 2, algorithm D, for
+    /// divisors of at least two limbs.
+    fn div_rem_knuth(&self, rhs: &Self) -> (Self, Self) {
+        let n = rhs.limbs.len();
+        let m = self.limbs.len() - n;
+        let shift = rhs.limbs[n - 1].leading_zeros() as usize;
+
+        // Normalize so the most significant bit of the divisor is set
+        let mut v = rhs.limbs.clone();
+        limbs::shl_small(&mut v, shift);
+
+        let mut u = self.limbs.clone();
+        let overflow = limbs::shl_small(&mut u, shift);
+        u.push(overflow);
+
+        let mut quotient = vec![0; m + 1];
+        let base: u128 = 1 << LIMB_BITS;
+
+        for j in (0..=m).rev() {
+            let numerator = ((u[j + n] as u128) << LIMB_BITS) | u[j + n - 1] as u128;
+            let mut q_hat = numerator / v[n - 1] as u128;
+            let mut r_hat = numerator % v[n - 1] as u128;
+
+            while q_hat >= base
+                || q_hat * v[n - 2] as u128 > ((r_hat << LIMB_BITS) | u[j + n - 2] as u128)
+            {
+                q_hat -= 1;
+                r_hat += v[n - 1] as u128;
+
+                if r_hat >= base {
+                    break;
+                }
+            }
+
+            // Multiply and subtract
+            let mut carry = 0;
+            let mut borrow = 0;
+
+            for i in 0..n {
+                let product = q_hat * v[i] as u128 + carry;
+                carry = product >> LIMB_BITS;
+                (u[i + j], borrow) = limbs::sbb(u[i + j], product as Limb, borrow);
+            }
+
+            (u[j + n], borrow) = limbs::sbb(u[j + n], carry as Limb, borrow);
+            quotient[j] = q_hat as Limb;
+
+            // The estimate was one too large, add the divisor back
+            if borrow != 0 {
+                quotient[j] -= 1;
+
+                let carry = limbs::add_assign(&mut u[j..j + n], &v);
+                u[j + n] = u[j + n].wrapping_add(carry);
+            }
+        }
+
+        u.truncate(n);
+        limbs::shr_small(&mut u, shift);
+
+        return (Self::from_limbs(quotient), Self::from_limbs(u));
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        return Self::from_limbs(vec![value]);
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return limbs::cmp(&self.limbs, &other.limbs);
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = self.limbs_padded(self.limbs.len().max(rhs.limbs.len()) + 1);
+        limbs::add_assign(&mut limbs, &rhs.limbs);
+
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// # Panics
+    /// If `rhs` is larger than `self`.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        return self
+            .checked_sub(rhs)
+            .expect("attempt to subtract with overflow");
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    // The product has as many limbs as both of the factors together
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = vec![0; self.limbs.len() + rhs.limbs.len()];
+        limbs::mul_into(&mut limbs, &self.limbs, &rhs.limbs);
+
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl Div for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, rhs: &BigUint) -> BigUint {
+        return self.div_rem(rhs).0;
+    }
+}
+
+impl Rem for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        return self.div_rem(rhs).1;
+    }
+}
+
+macro_rules! forward_owned_ops {
+    ($($trait:ident $method:ident),*) => {
+        $(
+            impl $trait for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: BigUint) -> BigUint {
+                    return (&self).$method(&rhs);
+                }
+            }
+
+            impl $trait<&BigUint> for BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: &BigUint) -> BigUint {
+                    return (&self).$method(rhs);
+                }
+            }
+
+            impl $trait<BigUint> for &BigUint {
+                type Output = BigUint;
+
+                fn $method(self, rhs: BigUint) -> BigUint {
+                    return self.$method(&rhs);
+                }
+            }
+        )*
+    };
+}
+
+forward_owned_ops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        let mut limbs = vec![0; shift / LIMB_BITS];
+        limbs.extend_from_slice(&self.limbs);
+        limbs.push(0);
+
+        let len = limbs.len();
+        limbs::shl_small(&mut limbs[shift / LIMB_BITS..len], shift % LIMB_BITS);
+
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        if shift / LIMB_BITS >= self.limbs.len() {
+            return BigUint::zero();
+        }
+
+        let mut limbs = self.limbs[shift / LIMB_BITS..].to_vec();
+        limbs::shr_small(&mut limbs, shift % LIMB_BITS);
+
+        return BigUint::from_limbs(limbs);
+    }
+}
+
+impl Shl<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shl(self, shift: usize) -> BigUint {
+        return &self << shift;
+    }
+}
+
+impl Shr<usize> for BigUint {
+    type Output = BigUint;
+
+    fn shr(self, shift: usize) -> BigUint {
+        return &self >> shift;
+    }
+}
+
+impl fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+
+        match limbs.next() {
+            Some(limb) => write!(f, "{:x}", limb)?,
+            None => write!(f, "0")?,
+        }
+
+        for limb in limbs {
+            write!(f, "{:016x}", limb)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "BigUint(0x{:x})", self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    fn big(hex: &str) -> BigUint {
+        return BigUint::from_be_bytes(&hex::decode(hex).unwrap());
+    }
+
+    #[test]
+    fn test_be_bytes() {
+        assert_eq!(BigUint::from_be_bytes(&[]), BigUint::zero());
+        assert_eq!(BigUint::from_be_bytes(&[0, 0, 1]), BigUint::one());
+        assert_eq!(BigUint::zero().to_be_bytes(), [0]);
+        assert_eq!(
+            big("000102030405060708090a").to_be_bytes(),
+            hex::decode("0102030405060708090a").unwrap()
+        );
+        assert_eq!(big("0102").to_be_bytes_padded(4), Ok(vec![0, 0, 1, 2]));
+        assert_eq!(
+            big("010203").to_be_bytes_padded(2),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        let x = big("0123456789abcdef0123456789abcdef");
+
+        assert_eq!(format!("{:x}", &x << 4), "123456789abcdef0123456789abcdef0");
+        assert_eq!(
+            format!("{:x}", &x << 68),
+            "123456789abcdef0123456789abcdef00000000000000000"
+        );
+        assert_eq!(format!("{:x}", &x >> 72), "123456789abcd");
+        assert_eq!(&x >> 200, BigUint::zero());
+    }
+
+    #[test]
+    fn test_div_rem_add_back() {
+        // A case where the quotient estimate of algorithm D is one too large and the divisor has
+        // to be added back
+        let u = big("7fffffffffffffff800000000000000000000000000000000000000000000000");
+        let v = big("800000000000000000000000000000000000000000000001");
+        let (q, r) = u.div_rem(&v);
+
+        assert_eq!(format!("{:x}", q), "fffffffffffffffe");
+        assert_eq!(
+            format!("{:x}", r),
+            "7fffffffffffffffffffffffffffffff0000000000000002"
+        );
+    }
+
+    #[test]
+    fn test_modinv() {
+        assert_eq!(
+            BigUint::from(3u64).modinv(&BigUint::from(11u64)),
+            Some(BigUint::from(4u64))
+        );
+        assert_eq!(BigUint::from(6u64).modinv(&BigUint::from(9u64)), None);
+        assert_eq!(BigUint::zero().modinv(&BigUint::from(9u64)), None);
+    }
+
+    #[test]
+    fn test_modpow_even_modulus() {
+        assert_eq!(
+            BigUint::from(3u64).modpow(&BigUint::from(200u64), &BigUint::from(1000u64)),
+            BigUint::from(1u64)
+        );
+        assert_eq!(
+            BigUint::from(3u64).modpow(&BigUint::zero(), &BigUint::one()),
+            BigUint::zero()
+        );
+    }
+
+    #[test]
+    fn test_random_below() {
+        let mut rng = HmacDrbg::<SHA256>::new(&[0u8; 32], &[], &[]);
+        let bound = BigUint::from(1000u64);
+
+        for _ in 0..100 {
+            assert!(BigUint::random_below(&bound, &mut rng).unwrap() < bound);
+        }
+
+        assert_eq!(
+            BigUint::random_below(&BigUint::one(), &mut rng).unwrap(),
+            BigUint::zero()
+        );
+        assert!(BigUint::random_below(&BigUint::zero(), &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_rem_limb() {
+        let n = BigUint::from_be_bytes(&[0xff; 40]);
+
+        assert_eq!(
+            BigUint::from(n.rem_limb(1000003)),
+            &n % &BigUint::from(1000003u64)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! Arithmetic on little endian slices of 64 bit limbs, shared by the fixed width and heap backed
+//! integers.
+
+use core::cmp::Ordering;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+
+pub(crate) type Limb = u64;
+
+pub(crate) const LIMB_BITS: usize = 64;
+pub(crate) const LIMB_BYTES: usize = 8;
+
+/// Returns `a + b + carry` and the carry out.
+#[inline(always)]
+pub(crate) fn adc(a: Limb, b: Limb, carry: Limb) -> (Limb, Limb) {
+    let t = a as u128 + b as u128 + carry as u128;
+
+    return (t as Limb, (t >> LIMB_BITS) as Limb);
+}
+
+/// Returns `a - b - borrow` and the borrow out, which is 0 or 1.
+#[inline(always)]
+pub(crate) fn sbb(a: Limb, b: Limb, borrow: Limb) -> (Limb, Limb) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+
+    return (t as Limb, (t >> 127) as Limb);
+}
+
+/// Returns `acc + a * b + carry` and the carry out.
+#[inline(always)]
+pub(crate) fn mac(acc: Limb, a: Limb, b: Limb, carry: Limb) -> (Limb, Limb) {
+    let t = acc as u128 + a as u128 * b as u128 + carry as u128;
+
+    return (t as Limb, (t >> LIMB_BITS) as Limb);
+}
+
+/// Adds `b` to `a`, which must be at least as long, returning the carry out.
+pub(crate) fn add_assign(a: &mut [Limb], b: &[Limb]) -> Limb {
+    let mut carry = 0;
+
+    for i in 0..a.len() {
+        (a[i], carry) = adc(a[i], b.get(i).copied().unwrap_or(0), carry);
+    }
+
+    return carry;
+}
+
+/// Subtracts `b` from `a`, which must be at least as long, returning the borrow out.
+pub(crate) fn sub_assign(a: &mut [Limb], b: &[Limb]) -> Limb {
+    let mut borrow = 0;
+
+    for i in 0..a.len() {
+        (a[i], borrow) = sbb(a[i], b.get(i).copied().unwrap_or(0), borrow);
+    }
+
+    return borrow;
+}
+
+/// Writes the product of `a` and `b` to `output`, which must hold at least
+/// `a.len() + b.len()` limbs.
+pub(crate) fn mul_into(output: &mut [Limb], a: &[Limb], b: &[Limb]) {
+    output.fill(0);
+
+    for (i, &b_limb) in b.iter().enumerate() {
+        let mut carry = 0;
+
+        for (j, &a_limb) in a.iter().enumerate() {
+            (output[i + j], carry) = mac(output[i + j], a_limb, b_limb, carry);
+        }
+
+        output[i + a.len()] = carry;
+    }
+}
+
+/// Compares two numbers, which may be of different lengths, in variable time.
+pub(crate) fn cmp(a: &[Limb], b: &[Limb]) -> Ordering {
+    for i in (0..a.len().max(b.len())).rev() {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+
+        if x != y {
+            return x.cmp(&y);
+        }
+    }
+
+    return Ordering::Equal;
+}
+
+/// Copies `source` into `destination` if `choice` is 1 and leaves it unchanged if `choice` is 0,
+/// without branching on `choice`.
+pub(crate) fn conditional_assign(destination: &mut [Limb], source: &[Limb], choice: Limb) {
+    let mask = choice.wrapping_neg();
+
+    for (d, s) in destination.iter_mut().zip(source.iter()) {
+        *d ^= (*d ^ *s) & mask;
+    }
+}
+
+/// Returns 1 if `a` and `b` are equal and 0 otherwise, without branching on their values.
+pub(crate) fn ct_eq(a: &[Limb], b: &[Limb]) -> Limb {
+    let mut difference = 0;
+
+    for (x, y) in a.iter().zip(b.iter()) {
+        difference |= x ^ y;
+    }
+
+    return 1 ^ ((difference | difference.wrapping_neg()) >> (LIMB_BITS - 1));
+}
+
+/// Shifts `a` left by `shift` bits, which must be less than 64, returning the bits shifted out.
+pub(crate) fn shl_small(a: &mut [Limb], shift: usize) -> Limb {
+    if shift == 0 {
+        return 0;
+    }
+
+    let mut carry = 0;
+
+    for limb in a.iter_mut() {
+        let next = *limb >> (LIMB_BITS - shift);
+        *limb = (*limb << shift) | carry;
+        carry = next;
+    }
+
+    return carry;
+}
+
+/// Shifts `a` right by `shift` bits, which must be less than 64.
+pub(crate) fn shr_small(a: &mut [Limb], shift: usize) {
+    if shift == 0 {
+        return;
+    }
+
+    for i in 0..a.len() {
+        let high = a.get(i + 1).map_or(0, |next| next << (LIMB_BITS - shift));
+        a[i] = (a[i] >> shift) | high;
+    }
+}
+
+/// Returns the number of significant bits in `a`.
+pub(crate) fn bits(a: &[Limb]) -> usize {
+    for i in (0..a.len()).rev() {
+        if a[i] != 0 {
+            return (i + 1) * LIMB_BITS - a[i].leading_zeros() as usize;
+        }
+    }
+
+    return 0;
+}
+
+/// Computes `-m0^-1 mod 2^64` for an odd `m0`, as used by Montgomery reduction.
+pub(crate) const fn montgomery_m0_inv(m0: Limb) -> Limb {
+    // Each Newton iteration doubles the number of correct bits, m0 is its own inverse mod 8
+    let mut inv = m0;
+    let mut i = 0;
+
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inv)));
+        i += 1;
+    }
+
+    return inv.wrapping_neg();
+}
+
+/// Writes `a * b * R^-1 mod m` to `output`, where `R = 2^(64 * m.len())`. All of the slices must
+/// be the length of `m`, `a` and `b` must be less than `m` and `m` must be odd. The time taken
+/// depends only on the length of `m`.
+pub(crate) fn montgomery_mul(
+    output: &mut [Limb],
+    a: &[Limb],
+    b: &[Limb],
+    m: &[Limb],
+    m0_inv: Limb,
+) {
+    let n = m.len();
+    let mut t = vec![0 as Limb; n + 2];
+
+    // Coarsely integrated operand scanning
+    for i in 0..n {
+        let mut carry = 0;
+
+        for j in 0..n {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+        }
+
+        (t[n], t[n + 1]) = adc(t[n], carry, 0);
+
+        let u = t[0].wrapping_mul(m0_inv);
+        let (_, mut carry) = mac(t[0], u, m[0], 0);
+
+        for j in 1..n {
+            (t[j - 1], carry) = mac(t[j], u, m[j], carry);
+        }
+
+        (t[n - 1], carry) = adc(t[n], carry, 0);
+        t[n] = t[n + 1] + carry;
+    }
+
+    // The result is less than 2m, subtract m unless that would underflow
+    output.copy_from_slice(&t[..n]);
+    let borrow = sub_assign(output, m);
+    let keep_t = (t[n] ^ 1) & borrow;
+
+    conditional_assign(output, &t[..n], keep_t);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_montgomery_m0_inv() {
+        for m0 in [1u64, 3, 0xffffffff00000001, 0xfffffffffffffc2f, u64::MAX] {
+            assert_eq!(m0.wrapping_mul(montgomery_m0_inv(m0)), u64::MAX);
+        }
+    }
+
+    #[test]
+    fn test_montgomery_mul() {
+        // With m = 2^64 - 59 and R = 2^64, R = 59 mod m so 59 * 59 * R^-1 = 59
+        let m = [0xffffffffffffffc5];
+        let mut output = [0];
+
+        montgomery_mul(&mut output, &[59], &[59], &m, montgomery_m0_inv(m[0]));
+
+        assert_eq!(output, [59]);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert_eq!(ct_eq(&[1, 2, 3], &[1, 2, 3]), 1);
+        assert_eq!(ct_eq(&[1, 2, 3], &[1, 2, 4]), 0);
+        assert_eq!(ct_eq(&[0, 0], &[0, 1 << 63]), 0);
+    }
+}
@@ -0,0 +1,11 @@
+//! Unsigned integers for public key cryptography: fixed width integers, heap backed integers of
+//! any size and Montgomery arithmetic modulo odd numbers.
+
+mod biguint;
+pub(crate) mod limbs;
+mod montgomery;
+mod uint;
+
+pub use biguint::*;
+pub use montgomery::*;
+pub use uint::*;
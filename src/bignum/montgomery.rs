@@ -0,0 +1,179 @@
+use super::limbs::{self, Limb, LIMB_BITS};
+use super::BigUint;
+use crate::error::VCryptoError;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The window size, in bits, of the exponentiation.
+const WINDOW_BITS: usize = 4;
+
+/// Arithmetic modulo an odd number using Montgomery multiplication.
+///
+/// Multiplication and `pow` take time that depends only on the size of the modulus and exponent,
+/// not their values, so they are suitable for secret exponents. Reducing inputs that are larger
+/// than the modulus uses variable time division.
+#[derive(Clone)]
+pub struct Montgomery {
+    modulus: BigUint,
+    m: Vec<Limb>,
+    m0_inv: Limb,
+    // R^2 mod m and R mod m, where R = 2^(64 * m.len())
+    r2: Vec<Limb>,
+    one: Vec<Limb>,
+}
+
+impl Montgomery {
+    /// Returns `VCryptoError::InvalidInput` if `modulus` is even or less than 3.
+    pub fn new(modulus: &BigUint) -> Result<Self, VCryptoError> {
+        if modulus.is_even() || modulus.bits() < 2 {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let n = modulus.limbs().len();
+        let r = &BigUint::one() << (n * LIMB_BITS);
+
+        return Ok(Self {
+            modulus: modulus.clone(),
+            m: modulus.limbs().to_vec(),
+            m0_inv: limbs::montgomery_m0_inv(modulus.limbs()[0]),
+            r2: (&(&r * &r) % modulus).limbs_padded(n),
+            one: (&r % modulus).limbs_padded(n),
+        });
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        return &self.modulus;
+    }
+
+    /// Returns `a * b mod m`.
+    pub fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        let a = self.to_montgomery(a);
+        let b = self.to_montgomery(b);
+
+        return self.out_of_montgomery(&self.montgomery_mul(&a, &b));
+    }
+
+    /// Returns `base^exponent mod m`, using a fixed window so the sequence of operations depends
+    /// only on the number of limbs in `exponent`, which is at least the number in the modulus.
+    pub fn pow(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let n = self.m.len();
+        let base = self.to_montgomery(base);
+
+        let mut table = vec![self.one.clone(), base.clone()];
+
+        for i in 2..1 << WINDOW_BITS {
+            let next = self.montgomery_mul(&table[i - 1], &base);
+            table.push(next);
+        }
+
+        let exponent = exponent.limbs_padded(exponent.limbs().len().max(n));
+        let mut result = self.one.clone();
+        let mut selected = vec![0; n];
+
+        for i in (0..exponent.len() * LIMB_BITS / WINDOW_BITS).rev() {
+            for _ in 0..WINDOW_BITS {
+                result = self.montgomery_mul(&result, &result);
+            }
+
+            let bit = i * WINDOW_BITS;
+            let window =
+                (exponent[bit / LIMB_BITS] >> (bit % LIMB_BITS)) & ((1 << WINDOW_BITS) - 1);
+
+            // Read every entry so the memory access pattern does not depend on the window
+            for (j, entry) in table.iter().enumerate() {
+                let difference = j as Limb ^ window;
+                let equal = 1 ^ ((difference | difference.wrapping_neg()) >> (LIMB_BITS - 1));
+
+                limbs::conditional_assign(&mut selected, entry, equal);
+            }
+
+            result = self.montgomery_mul(&result, &selected);
+        }
+
+        return self.out_of_montgomery(&result);
+    }
+
+    /// Returns `base^exponent mod m` using square and multiply, which is faster for short public
+    /// exponents but leaks the exponent through its timing.
+    pub fn pow_vartime(&self, base: &BigUint, exponent: &BigUint) -> BigUint {
+        let base = self.to_montgomery(base);
+        let mut result = self.one.clone();
+
+        for i in (0..exponent.bits()).rev() {
+            result = self.montgomery_mul(&result, &result);
+
+            if exponent.bit(i) {
+                result = self.montgomery_mul(&result, &base);
+            }
+        }
+
+        return self.out_of_montgomery(&result);
+    }
+
+    fn to_montgomery(&self, a: &BigUint) -> Vec<Limb> {
+        let reduced = if a < &self.modulus {
+            a.limbs_padded(self.m.len())
+        } else {
+            (a % &self.modulus).limbs_padded(self.m.len())
+        };
+
+        return self.montgomery_mul(&reduced, &self.r2);
+    }
+
+    fn out_of_montgomery(&self, a: &[Limb]) -> BigUint {
+        let mut one = vec![0; self.m.len()];
+        one[0] = 1;
+
+        return BigUint::from_limbs(self.montgomery_mul(a, &one));
+    }
+
+    fn montgomery_mul(&self, a: &[Limb], b: &[Limb]) -> Vec<Limb> {
+        let mut output = vec![0; self.m.len()];
+        limbs::montgomery_mul(&mut output, a, b, &self.m, self.m0_inv);
+
+        return output;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_montgomery_invalid_modulus() {
+        assert!(Montgomery::new(&BigUint::from(10u64)).is_err());
+        assert!(Montgomery::new(&BigUint::one()).is_err());
+        assert!(Montgomery::new(&BigUint::zero()).is_err());
+    }
+
+    #[test]
+    fn test_montgomery_pow() {
+        // Fermat's little theorem with the Mersenne prime 2^127 - 1
+        let p = &(&BigUint::one() << 127) - &BigUint::one();
+        let montgomery = Montgomery::new(&p).unwrap();
+        let a = BigUint::from(0x123456789abcdefu64);
+
+        assert_eq!(montgomery.pow(&a, &(&p - &BigUint::one())), BigUint::one());
+        assert_eq!(montgomery.pow_vartime(&a, &p), a);
+        assert_eq!(montgomery.pow(&a, &BigUint::zero()), BigUint::one());
+        assert_eq!(montgomery.pow(&p, &BigUint::from(5u64)), BigUint::zero());
+    }
+
+    #[test]
+    fn test_montgomery_mul() {
+        let m = BigUint::from(1_000_000_007u64);
+        let montgomery = Montgomery::new(&m).unwrap();
+
+        assert_eq!(
+            montgomery.mul(
+                &BigUint::from(123_456_789u64),
+                &BigUint::from(987_654_321u64)
+            ),
+            BigUint::from(259_106_859u64)
+        );
+    }
+}
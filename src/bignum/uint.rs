@@ -0,0 +1,385 @@
+use super::limbs::{self, Limb, LIMB_BITS, LIMB_BYTES};
+use super::BigUint;
+use crate::error::VCryptoError;
+use core::cmp::Ordering;
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// An unsigned integer of `LIMBS` 64 bit limbs.
+///
+/// Addition, subtraction, multiplication and the modular addition and subtraction take time that
+/// depends only on `LIMBS`, division and comparisons do not.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uint<const LIMBS: usize> {
+    // Little endian limbs
+    limbs: [Limb; LIMBS],
+}
+
+pub type U256 = Uint<4>;
+pub type U384 = Uint<6>;
+pub type U512 = Uint<8>;
+pub type U1024 = Uint<16>;
+pub type U2048 = Uint<32>;
+pub type U4096 = Uint<64>;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    pub const BITS: usize = LIMBS * LIMB_BITS;
+    pub const BYTES: usize = LIMBS * LIMB_BYTES;
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+    pub const MAX: Self = Self {
+        limbs: [Limb::MAX; LIMBS],
+    };
+
+    pub const fn from_u64(value: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value;
+
+        return Self { limbs };
+    }
+
+    /// Parses a big endian number, which may be shorter than `Self::BYTES`. Returns
+    /// `VCryptoError::InvalidInput` if the value does not fit.
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let mut limbs = [0; LIMBS];
+
+        for (i, b) in bytes.iter().rev().enumerate() {
+            if i >= Self::BYTES {
+                if *b != 0 {
+                    return Err(VCryptoError::InvalidInput);
+                }
+
+                continue;
+            }
+
+            limbs[i / LIMB_BYTES] |= (*b as Limb) << (8 * (i % LIMB_BYTES));
+        }
+
+        return Ok(Self { limbs });
+    }
+
+    /// Writes the number in big endian to `output`, which must be `Self::BYTES` long.
+    ///
+    /// # Panics
+    /// If `output` is not `Self::BYTES` long.
+    pub fn write_be_bytes(&self, output: &mut [u8]) {
+        assert_eq!(
+            output.len(),
+            Self::BYTES,
+            "the output must be Self::BYTES long"
+        );
+
+        for (i, b) in output.iter_mut().rev().enumerate() {
+            *b = (self.limbs[i / LIMB_BYTES] >> (8 * (i % LIMB_BYTES))) as u8;
+        }
+    }
+
+    /// Returns the number as `Self::BYTES` big endian bytes.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0; Self::BYTES];
+        self.write_be_bytes(&mut bytes);
+
+        return bytes;
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return limbs::ct_eq(&self.limbs, &Self::ZERO.limbs) == 1;
+    }
+
+    pub fn is_odd(&self) -> bool {
+        return LIMBS > 0 && self.limbs[0] & 1 == 1;
+    }
+
+    pub fn bits(&self) -> usize {
+        return limbs::bits(&self.limbs);
+    }
+
+    /// Returns the bit at `index`, counting from the least significant bit.
+    pub fn bit(&self, index: usize) -> bool {
+        return index < Self::BITS
+            && (self.limbs[index / LIMB_BITS] >> (index % LIMB_BITS)) & 1 == 1;
+    }
+
+    /// Compares the values without branching on them.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        return limbs::ct_eq(&self.limbs, &other.limbs) == 1;
+    }
+
+    /// Replaces `self` with `other` if `choice` is true, without branching on `choice`.
+    pub fn conditional_assign(&mut self, other: &Self, choice: bool) {
+        limbs::conditional_assign(&mut self.limbs, &other.limbs, choice as Limb);
+    }
+
+    pub fn overflowing_add(&self, rhs: &Self) -> (Self, bool) {
+        let mut result = *self;
+        let carry = limbs::add_assign(&mut result.limbs, &rhs.limbs);
+
+        return (result, carry == 1);
+    }
+
+    pub fn overflowing_sub(&self, rhs: &Self) -> (Self, bool) {
+        let mut result = *self;
+        let borrow = limbs::sub_assign(&mut result.limbs, &rhs.limbs);
+
+        return (result, borrow == 1);
+    }
+
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        return self.overflowing_add(rhs).0;
+    }
+
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        return self.overflowing_sub(rhs).0;
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_add(rhs);
+
+        return (!overflow).then_some(result);
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let (result, overflow) = self.overflowing_sub(rhs);
+
+        return (!overflow).then_some(result);
+    }
+
+    /// Returns the low and high halves of the full product.
+    pub fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+        let mut product = vec![0; 2 * LIMBS];
+        limbs::mul_into(&mut product, &self.limbs, &rhs.limbs);
+
+        let mut low = Self::ZERO;
+        let mut high = Self::ZERO;
+        low.limbs.copy_from_slice(&product[..LIMBS]);
+        high.limbs.copy_from_slice(&product[LIMBS..]);
+
+        return (low, high);
+    }
+
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        return self.widening_mul(rhs).0;
+    }
+
+    /// Returns the quotient and remainder of `self / rhs`.
+    ///
+    /// # Panics
+    /// If `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = BigUint::from(self).div_rem(&BigUint::from(rhs));
+
+        return (
+            Self::try_from(&quotient).expect("the quotient is at most self"),
+            Self::try_from(&remainder).expect("the remainder is less than rhs"),
+        );
+    }
+
+    /// Returns `(self + rhs) mod modulus`, `self` and `rhs` must already be less than `modulus`.
+    pub fn add_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let (sum, carry) = self.overflowing_add(rhs);
+        let (reduced, borrow) = sum.overflowing_sub(modulus);
+
+        // Keep the unreduced sum only if it did not overflow and was less than the modulus
+        let mut result = reduced;
+        result.conditional_assign(&sum, !carry & borrow);
+
+        return result;
+    }
+
+    /// Returns `(self - rhs) mod modulus`, `self` and `rhs` must already be less than `modulus`.
+    pub fn sub_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let (difference, borrow) = self.overflowing_sub(rhs);
+        let mut result = difference;
+        result.conditional_assign(&difference.wrapping_add(modulus), borrow);
+
+        return result;
+    }
+
+    /// Returns `self * rhs mod modulus`.
+    ///
+    /// # Panics
+    /// If `modulus` is zero.
+    pub fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let (low, high) = self.widening_mul(rhs);
+        let product = &(&BigUint::from(&high) << Self::BITS) + &BigUint::from(&low);
+
+        return Self::try_from(&(&product % &BigUint::from(modulus)))
+            .expect("the remainder is less than the modulus");
+    }
+
+    /// Returns `self^exponent mod modulus`, see [`BigUint::modpow`].
+    pub fn pow_mod(&self, exponent: &Self, modulus: &Self) -> Self {
+        let result = BigUint::from(self).modpow(&BigUint::from(exponent), &BigUint::from(modulus));
+
+        return Self::try_from(&result).expect("the result is less than the modulus");
+    }
+
+    pub fn shl(&self, shift: usize) -> Self {
+        let mut result = Self::ZERO;
+        let limb_shift = shift / LIMB_BITS;
+
+        if limb_shift >= LIMBS {
+            return result;
+        }
+
+        result.limbs[limb_shift..].copy_from_slice(&self.limbs[..LIMBS - limb_shift]);
+        limbs::shl_small(&mut result.limbs, shift % LIMB_BITS);
+
+        return result;
+    }
+
+    pub fn shr(&self, shift: usize) -> Self {
+        let mut result = Self::ZERO;
+        let limb_shift = shift / LIMB_BITS;
+
+        if limb_shift >= LIMBS {
+            return result;
+        }
+
+        result.limbs[..LIMBS - limb_shift].copy_from_slice(&self.limbs[limb_shift..]);
+        limbs::shr_small(&mut result.limbs, shift % LIMB_BITS);
+
+        return result;
+    }
+}
+
+impl<const LIMBS: usize> Default for Uint<LIMBS> {
+    fn default() -> Self {
+        return Self::ZERO;
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for Uint<LIMBS> {
+    fn from(value: u64) -> Self {
+        return Self::from_u64(value);
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BigUint {
+    fn from(value: &Uint<LIMBS>) -> Self {
+        return BigUint::from_limbs(value.limbs.to_vec());
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigUint> for Uint<LIMBS> {
+    type Error = VCryptoError;
+
+    fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+        if value.limbs().len() > LIMBS {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut result = Self::ZERO;
+        result.limbs[..value.limbs().len()].copy_from_slice(value.limbs());
+
+        return Ok(result);
+    }
+}
+
+impl<const LIMBS: usize> Ord for Uint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return limbs::cmp(&self.limbs, &other.limbs);
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for Uint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<const LIMBS: usize> fmt::LowerHex for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for limb in self.limbs.iter().rev() {
+            write!(f, "{:016x}", limb)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for Uint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "Uint(0x{:x})", self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_uint_be_bytes() {
+        let x = U256::from_be_bytes(&[1, 2, 3]).unwrap();
+
+        assert_eq!(x, U256::from_u64(0x010203));
+        assert_eq!(&x.to_be_bytes()[29..], [1, 2, 3]);
+        assert_eq!(U256::from_be_bytes(&[0; 40]), Ok(U256::ZERO));
+        assert_eq!(
+            U256::from_be_bytes(&[1; 33]),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_uint_overflow() {
+        assert_eq!(
+            U256::MAX.overflowing_add(&U256::from_u64(1)),
+            (U256::ZERO, true)
+        );
+        assert_eq!(
+            U256::ZERO.overflowing_sub(&U256::from_u64(1)),
+            (U256::MAX, true)
+        );
+        assert_eq!(U256::MAX.checked_add(&U256::from_u64(1)), None);
+        assert_eq!(
+            U256::MAX.widening_mul(&U256::MAX),
+            (
+                U256::from_u64(1),
+                U256::MAX.wrapping_sub(&U256::from_u64(1))
+            )
+        );
+    }
+
+    #[test]
+    fn test_uint_modular() {
+        // The P-256 field prime
+        let p = U256::from_be_bytes(
+            &hex::decode("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+                .unwrap(),
+        )
+        .unwrap();
+        let p_minus_1 = p.wrapping_sub(&U256::from_u64(1));
+
+        assert_eq!(p_minus_1.add_mod(&U256::from_u64(2), &p), U256::from_u64(1));
+        assert_eq!(U256::from_u64(1).sub_mod(&U256::from_u64(2), &p), p_minus_1);
+        assert_eq!(p_minus_1.mul_mod(&p_minus_1, &p), U256::from_u64(1));
+        assert_eq!(U256::from_u64(3).pow_mod(&p_minus_1, &p), U256::from_u64(1));
+    }
+
+    #[test]
+    fn test_uint_shifts() {
+        let x = U256::from_u64(0x8000000000000001);
+
+        assert_eq!(x.shl(1).shr(1), x);
+        assert_eq!(
+            format!("{:x}", x.shl(192)),
+            format!(
+                "{:x}",
+                U256::from_be_bytes(
+                    &hex::decode(
+                        "8000000000000001000000000000000000000000000000000000000000000000"
+                    )
+                    .unwrap()
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(x.shl(256), U256::ZERO);
+        assert_eq!(x.shr(64), U256::ZERO);
+    }
+}
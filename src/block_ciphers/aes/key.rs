@@ -1,4 +1,5 @@
 use super::constants::{ROUND_CONSTANTS, S_BOX};
+use crate::error::VCryptoError;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum AESVariant {
@@ -57,6 +58,32 @@ impl AESKey {
         return Self(AESKeyInner::AES256(Self::expand_key(key)));
     }
 
+    /// Creates a key from its bytes, choosing the variant from the length: 16, 24 or 32 bytes.
+    pub fn from_bytes(key: &[u8]) -> Result<Self, VCryptoError> {
+        let mut words = [0u32; AESVariant::AES256.words_required()];
+
+        for (word, chunk) in words.iter_mut().zip(key.chunks(4)) {
+            let mut bytes = [0u8; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            *word = u32::from_be_bytes(bytes);
+        }
+
+        return match key.len() {
+            16 => Ok(Self::new_aes128(words[..4].try_into().unwrap())),
+            24 => Ok(Self::new_aes192(words[..6].try_into().unwrap())),
+            32 => Ok(Self::new_aes256(words)),
+            len if len < 16 => Err(VCryptoError::InvalidKeyLengthSmaller {
+                key_length: len,
+                min: 16,
+            }),
+            len if len > 32 => Err(VCryptoError::InvalidKeyLengthLarger {
+                key_length: len,
+                max: 32,
+            }),
+            _ => Err(VCryptoError::InvalidKey),
+        };
+    }
+
     pub fn variant(&self) -> AESVariant {
         return match self.0 {
             AESKeyInner::AES128(_) => AESVariant::AES128,
@@ -132,6 +159,46 @@ mod tests {
         assert_eq!(AESKey::expand_key(key), expected);
     }
 
+    #[test]
+    fn test_from_bytes() {
+        let key = hex::decode("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+        assert_eq!(
+            AESKey::from_bytes(&key),
+            Ok(AESKey::new_aes128([
+                0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c
+            ]))
+        );
+
+        let key: Vec<u8> = (0..32).collect();
+        assert_eq!(
+            AESKey::from_bytes(&key[..24]).unwrap().variant(),
+            AESVariant::AES192
+        );
+        assert_eq!(
+            AESKey::from_bytes(&key).unwrap().variant(),
+            AESVariant::AES256
+        );
+
+        assert_eq!(
+            AESKey::from_bytes(&key[..15]),
+            Err(VCryptoError::InvalidKeyLengthSmaller {
+                key_length: 15,
+                min: 16
+            })
+        );
+        assert_eq!(
+            AESKey::from_bytes(&key[..20]),
+            Err(VCryptoError::InvalidKey)
+        );
+        assert_eq!(
+            AESKey::from_bytes(&[0; 33]),
+            Err(VCryptoError::InvalidKeyLengthLarger {
+                key_length: 33,
+                max: 32
+            })
+        );
+    }
+
     #[test]
     fn test_key_expansion_other() {
         let key: [u32; 4] = [0x12fb34fa, 0x56f978f8, 0x9af7abf6, 0xbcf5cdf4];
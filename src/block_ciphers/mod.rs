@@ -1,9 +1,7 @@
 pub mod aes;
 mod block_cipher;
 pub mod blowfish;
-#[cfg(any(feature = "alloc", feature = "std"))]
 pub mod modes;
-#[cfg(any(feature = "alloc", feature = "std"))]
 pub mod padding;
 pub mod twofish;
 
@@ -0,0 +1,11 @@
+use crate::error::VCryptoError;
+
+pub trait BlockMode {
+    const BLOCK_SIZE: usize;
+
+    /// Encrypts every block in `data` in place, `data` must be a multiple of `BLOCK_SIZE`.
+    fn encrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError>;
+
+    /// Decrypts every block in `data` in place, `data` must be a multiple of `BLOCK_SIZE`.
+    fn decrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError>;
+}
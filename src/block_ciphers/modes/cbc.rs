@@ -0,0 +1,411 @@
+use super::BlockMode;
+use crate::block_ciphers::padding::Padding;
+use crate::block_ciphers::BlockCipher;
+use crate::error::VCryptoError;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Cipher block chaining, NIST SP 800-38A section 6.2. The chaining value starts as the IV and
+/// carries over between calls, so a message can be processed in several pieces as long as each of
+/// them is a multiple of the block size. `update` and `finish` accept pieces of any length.
+pub struct CBC<'a, C: BlockCipher<'a>> {
+    key: C::Key,
+    chaining_value: C::Block,
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    unprocessed_data: C::Block,
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    unprocessed_data_len: usize,
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    encrypted_data: Vec<u8>,
+}
+
+impl<'a, C: BlockCipher<'a>> CBC<'a, C> {
+    pub fn new(key: C::Key, iv: C::Block) -> Self {
+        return Self {
+            key,
+            chaining_value: iv,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            unprocessed_data: C::empty_block(),
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            unprocessed_data_len: 0,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            encrypted_data: Vec::new(),
+        };
+    }
+
+    /// Pads the message in `buffer[..msg_len]` and encrypts it in place, returning the ciphertext.
+    /// `buffer` must be large enough to hold the padded message.
+    pub fn encrypt_padded<'b, P: Padding>(
+        &mut self,
+        buffer: &'b mut [u8],
+        msg_len: usize,
+    ) -> Result<&'b [u8], VCryptoError> {
+        let padded_len = P::pad(buffer, msg_len, C::BLOCK_SIZE)?;
+
+        self.encrypt_blocks(&mut buffer[..padded_len])?;
+
+        return Ok(&buffer[..padded_len]);
+    }
+
+    /// Decrypts `buffer` in place and returns the plaintext with the padding removed.
+    pub fn decrypt_padded<'b, P: Padding>(
+        &mut self,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], VCryptoError> {
+        self.decrypt_blocks(buffer)?;
+
+        let msg_len = P::unpad(buffer, C::BLOCK_SIZE)?;
+
+        return Ok(&buffer[..msg_len]);
+    }
+
+    /// Encrypts every block in `data` in place, `data` must be a multiple of the block size.
+    pub fn encrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        if !data.len().is_multiple_of(C::BLOCK_SIZE) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        for chunk in data.chunks_exact_mut(C::BLOCK_SIZE) {
+            self.encrypt_block(chunk);
+            chunk.copy_from_slice(self.chaining_value.as_ref());
+        }
+
+        return Ok(());
+    }
+
+    /// Encrypts one block, leaving the ciphertext as the chaining value.
+    fn encrypt_block(&mut self, plaintext: &[u8]) {
+        let mut block = self.chaining_value;
+
+        for (a, b) in block.as_mut().iter_mut().zip(plaintext.iter()) {
+            *a ^= *b;
+        }
+
+        self.chaining_value = C::new(self.key, block).encrypt();
+    }
+
+    /// Decrypts every block in `data` in place, `data` must be a multiple of the block size.
+    pub fn decrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        if !data.len().is_multiple_of(C::BLOCK_SIZE) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        for chunk in data.chunks_exact_mut(C::BLOCK_SIZE) {
+            let mut block = C::empty_block();
+            block.as_mut().copy_from_slice(chunk);
+
+            let mut plaintext = C::new(self.key, block).decrypt();
+
+            for (a, b) in plaintext
+                .as_mut()
+                .iter_mut()
+                .zip(self.chaining_value.as_ref().iter())
+            {
+                *a ^= *b;
+            }
+
+            chunk.copy_from_slice(plaintext.as_ref());
+            self.chaining_value = block;
+        }
+
+        return Ok(());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn encrypt<P: Padding>(mut self, input: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let mut encrypted_data = input.to_vec();
+        encrypted_data.resize(P::padded_len(input.len(), C::BLOCK_SIZE), 0);
+
+        let len = self
+            .encrypt_padded::<P>(&mut encrypted_data, input.len())?
+            .len();
+
+        encrypted_data.truncate(len);
+
+        return Ok(encrypted_data);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn decrypt<P: Padding>(mut self, input: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let mut decrypted_data = input.to_vec();
+        let msg_len = self.decrypt_padded::<P>(&mut decrypted_data)?.len();
+
+        decrypted_data.truncate(msg_len);
+
+        return Ok(decrypted_data);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn update(&mut self, mut data: &[u8]) {
+        // The last full block stays buffered until more data arrives, `finish` writes it.
+        while self.unprocessed_data_len + data.len() > C::BLOCK_SIZE {
+            let amount_processed = self.fill_buffer(data);
+            data = &data[amount_processed..];
+            self.unprocessed_data_len = 0;
+
+            let block = self.unprocessed_data;
+            self.encrypt_block(block.as_ref());
+            self.encrypted_data
+                .extend_from_slice(self.chaining_value.as_ref());
+        }
+
+        self.unprocessed_data.as_mut()
+            [self.unprocessed_data_len..self.unprocessed_data_len + data.len()]
+            .copy_from_slice(data);
+
+        self.unprocessed_data_len += data.len();
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn fill_buffer(&mut self, data: &[u8]) -> usize {
+        let amount = C::BLOCK_SIZE - self.unprocessed_data_len;
+
+        self.unprocessed_data.as_mut()[self.unprocessed_data_len..]
+            .copy_from_slice(&data[0..amount]);
+
+        return amount;
+    }
+
+    /// Pads the data given to `update` and returns the whole ciphertext.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn finish<P: Padding>(mut self) -> Result<Vec<u8>, VCryptoError> {
+        // A full block is left unprocessed by `update`, it has to be written before the padding.
+        if self.unprocessed_data_len == C::BLOCK_SIZE {
+            let block = self.unprocessed_data;
+            self.encrypt_block(block.as_ref());
+            self.encrypted_data
+                .extend_from_slice(self.chaining_value.as_ref());
+            self.unprocessed_data_len = 0;
+        }
+
+        let mut last_block = self.unprocessed_data;
+        P::pad(
+            last_block.as_mut(),
+            self.unprocessed_data_len,
+            C::BLOCK_SIZE,
+        )?;
+
+        self.encrypt_block(last_block.as_ref());
+        self.encrypted_data
+            .extend_from_slice(self.chaining_value.as_ref());
+
+        return Ok(self.encrypted_data);
+    }
+}
+
+impl<'a, C: BlockCipher<'a>> BlockMode for CBC<'a, C> {
+    const BLOCK_SIZE: usize = C::BLOCK_SIZE;
+
+    fn encrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        return CBC::encrypt_blocks(self, data);
+    }
+
+    fn decrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        return CBC::decrypt_blocks(self, data);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, C: BlockCipher<'a>> io::Write for CBC<'a, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::block_ciphers::aes::{AESKey, AES};
+    use crate::block_ciphers::padding::PKCS7;
+
+    use pretty_assertions::assert_eq;
+
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+                             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    const IV: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+
+    /*
+     * Following test cases are sourced from NIST SP 800-38A appendix F.2
+     */
+
+    #[test]
+    fn test_cbc_aes128_nist() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let expected = "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+                        73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7";
+
+        let mut data = hex::decode(PLAINTEXT).unwrap();
+        CBC::<AES>::new(key, IV).encrypt_blocks(&mut data).unwrap();
+        assert_eq!(hex::encode(&data), expected);
+
+        CBC::<AES>::new(key, IV).decrypt_blocks(&mut data).unwrap();
+        assert_eq!(hex::encode(&data), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_cbc_aes256_nist() {
+        let key = AESKey::new_aes256([
+            0x603deb10, 0x15ca71be, 0x2b73aef0, 0x857d7781, 0x1f352c07, 0x3b6108d7, 0x2d9810a3,
+            0x0914dff4,
+        ]);
+        let expected = "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d\
+                        39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b";
+
+        // The chaining value carries over between calls
+        let mut data = hex::decode(PLAINTEXT).unwrap();
+        let mut cipher = CBC::<AES>::new(key, IV);
+        cipher.encrypt_blocks(&mut data[..16]).unwrap();
+        cipher.encrypt_blocks(&mut data[16..]).unwrap();
+        assert_eq!(hex::encode(&data), expected);
+
+        let mut cipher = CBC::<AES>::new(key, IV);
+        cipher.decrypt_blocks(&mut data[..48]).unwrap();
+        cipher.decrypt_blocks(&mut data[48..]).unwrap();
+        assert_eq!(hex::encode(&data), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_cbc_padded() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+
+        for len in [0, 1, 15, 16, 17, 40] {
+            let message = vec![0x5a; len];
+            let encrypted = CBC::<AES>::new(key, IV).encrypt::<PKCS7>(&message).unwrap();
+
+            assert_eq!(encrypted.len(), (len / 16 + 1) * 16);
+            assert_eq!(
+                CBC::<AES>::new(key, IV)
+                    .decrypt::<PKCS7>(&encrypted)
+                    .unwrap(),
+                message
+            );
+        }
+
+        let mut buffer = [0u8; 32];
+        buffer[..5].copy_from_slice(b"hello");
+        let encrypted = CBC::<AES>::new(key, IV)
+            .encrypt_padded::<PKCS7>(&mut buffer, 5)
+            .unwrap()
+            .to_vec();
+        assert_eq!(encrypted.len(), 16);
+
+        let mut buffer = encrypted.clone();
+        assert_eq!(
+            CBC::<AES>::new(key, IV)
+                .decrypt_padded::<PKCS7>(&mut buffer)
+                .unwrap(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn test_cbc_aes128_in_place() {
+        // The SP 800-38A F.2.1 message padded in a stack buffer without allocating, the padding
+        // block was computed with `openssl enc -aes-128-cbc`
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let mut buffer = [0u8; 80];
+        hex::decode_to_slice(PLAINTEXT, &mut buffer[..64]).unwrap();
+
+        let encrypted = CBC::<AES>::new(key, IV)
+            .encrypt_padded::<PKCS7>(&mut buffer, 64)
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(&encrypted[..64]),
+            "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+             73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7"
+        );
+        assert_eq!(
+            hex::encode(&encrypted[64..]),
+            "8cb82807230e1321d3fae00d18cc2012"
+        );
+
+        assert_eq!(
+            hex::encode(
+                CBC::<AES>::new(key, IV)
+                    .decrypt_padded::<PKCS7>(&mut buffer)
+                    .unwrap()
+            ),
+            PLAINTEXT
+        );
+
+        // No room for the padding block
+        assert_eq!(
+            CBC::<AES>::new(key, IV).encrypt_padded::<PKCS7>(&mut buffer[..64], 64),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_cbc_unaligned_updates() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let message: Vec<u8> = (0..100).collect();
+
+        for len in [0, 16, 30, 64, 100] {
+            let expected = CBC::<AES>::new(key, IV)
+                .encrypt::<PKCS7>(&message[..len])
+                .unwrap();
+
+            for chunk_size in [1, 7, 10, 15, 16, 17, 33] {
+                let mut cipher = CBC::<AES>::new(key, IV);
+
+                for chunk in message[..len].chunks(chunk_size) {
+                    cipher.update(chunk);
+                }
+
+                assert_eq!(cipher.finish::<PKCS7>().unwrap(), expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cbc_write() {
+        use std::io::Write;
+
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let plaintext = hex::decode(PLAINTEXT).unwrap();
+        let mut cipher = CBC::<AES>::new(key, IV);
+
+        for chunk in plaintext.chunks(10) {
+            cipher.write_all(chunk).unwrap();
+        }
+
+        // SP 800-38A F.2.1 followed by the padding block from `test_cbc_aes128_in_place`
+        assert_eq!(
+            hex::encode(cipher.finish::<PKCS7>().unwrap()),
+            "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b2\
+             73bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7\
+             8cb82807230e1321d3fae00d18cc2012"
+        );
+    }
+
+    #[test]
+    fn test_cbc_invalid_length() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let mut data = [0u8; 17];
+
+        assert_eq!(
+            CBC::<AES>::new(key, IV).encrypt_blocks(&mut data),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            CBC::<AES>::new(key, IV).decrypt_blocks(&mut data),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+}
@@ -1,14 +1,20 @@
+use super::BlockMode;
 use crate::block_ciphers::padding::Padding;
 use crate::block_ciphers::BlockCipher;
 use crate::error::VCryptoError;
 
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
 
 pub struct ECB<'a, C: BlockCipher<'a>> {
     key: C::Key,
+    #[cfg(any(feature = "alloc", feature = "std"))]
     unprocessed_data: C::Block,
+    #[cfg(any(feature = "alloc", feature = "std"))]
     unprocessed_data_len: usize,
+    #[cfg(any(feature = "alloc", feature = "std"))]
     encrypted_data: Vec<u8>,
 }
 
@@ -16,91 +22,157 @@ impl<'a, C: BlockCipher<'a>> ECB<'a, C> {
     pub fn new(key: C::Key) -> Self {
         return Self {
             key,
+            #[cfg(any(feature = "alloc", feature = "std"))]
             unprocessed_data: C::empty_block(),
+            #[cfg(any(feature = "alloc", feature = "std"))]
             unprocessed_data_len: 0,
+            #[cfg(any(feature = "alloc", feature = "std"))]
             encrypted_data: Vec::new(),
         };
     }
 
-    pub fn decrypt<P: Padding>(self, input: &[u8]) -> Result<Vec<u8>, VCryptoError> {
-        if input.len() % C::BLOCK_SIZE != 0 {
+    /// Pads the message in `buffer[..msg_len]` and encrypts it in place, returning the ciphertext.
+    /// `buffer` must be large enough to hold the padded message.
+    pub fn encrypt_padded<'b, P: Padding>(
+        &self,
+        buffer: &'b mut [u8],
+        msg_len: usize,
+    ) -> Result<&'b [u8], VCryptoError> {
+        let padded_len = P::pad(buffer, msg_len, C::BLOCK_SIZE)?;
+
+        self.encrypt_blocks(&mut buffer[..padded_len])?;
+
+        return Ok(&buffer[..padded_len]);
+    }
+
+    /// Decrypts `buffer` in place and returns the plaintext with the padding removed.
+    pub fn decrypt_padded<'b, P: Padding>(
+        &self,
+        buffer: &'b mut [u8],
+    ) -> Result<&'b [u8], VCryptoError> {
+        self.decrypt_blocks(buffer)?;
+
+        let msg_len = P::unpad(buffer, C::BLOCK_SIZE)?;
+
+        return Ok(&buffer[..msg_len]);
+    }
+
+    /// Encrypts every block in `data` in place, `data` must be a multiple of the block size.
+    pub fn encrypt_blocks(&self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        if !data.len().is_multiple_of(C::BLOCK_SIZE) {
             return Err(VCryptoError::InvalidInput);
         }
 
-        let mut raw_decrypted_data = Vec::new();
+        for chunk in data.chunks_exact_mut(C::BLOCK_SIZE) {
+            let mut block = C::empty_block();
+            block.as_mut().copy_from_slice(chunk);
 
-        for i in 0..(input.len() / C::BLOCK_SIZE) {
-            let mut c_block = C::empty_block();
-            c_block
-                .as_mut()
-                .copy_from_slice(&input[i * C::BLOCK_SIZE..(i + 1) * C::BLOCK_SIZE]);
+            chunk.copy_from_slice(C::new(self.key, block).encrypt().as_ref());
+        }
 
-            raw_decrypted_data.extend_from_slice(C::new(self.key, c_block).decrypt().as_ref());
+        return Ok(());
+    }
+
+    /// Decrypts every block in `data` in place, `data` must be a multiple of the block size.
+    pub fn decrypt_blocks(&self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        if !data.len().is_multiple_of(C::BLOCK_SIZE) {
+            return Err(VCryptoError::InvalidInput);
         }
 
-        if let Some(padding_amount) = P::validate_padded_block(&raw_decrypted_data) {
-            raw_decrypted_data.truncate(raw_decrypted_data.len().saturating_sub(padding_amount));
+        for chunk in data.chunks_exact_mut(C::BLOCK_SIZE) {
+            let mut block = C::empty_block();
+            block.as_mut().copy_from_slice(chunk);
 
-            return Ok(raw_decrypted_data);
-        } else {
-            return Err(VCryptoError::InvalidPadding)?;
+            chunk.copy_from_slice(C::new(self.key, block).decrypt().as_ref());
         }
+
+        return Ok(());
     }
 
-    pub fn update(&mut self, data: &[u8]) {
-        if data.len() + self.unprocessed_data_len > C::BLOCK_SIZE {
-            let remaining = data.len() + self.unprocessed_data_len;
-            let mut amount_processed = 0;
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn decrypt<P: Padding>(self, input: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let mut decrypted_data = input.to_vec();
+        let msg_len = self.decrypt_padded::<P>(&mut decrypted_data)?.len();
 
-            while (remaining - amount_processed) > C::BLOCK_SIZE {
-                amount_processed += self.fill_buffer(&data[amount_processed..]);
-                self.unprocessed_data_len = 0;
+        decrypted_data.truncate(msg_len);
 
-                let output_block = C::new(self.key, self.unprocessed_data).encrypt();
-                self.encrypted_data.extend_from_slice(output_block.as_ref());
-            }
+        return Ok(decrypted_data);
+    }
 
-            if (remaining - amount_processed) > 0 {
-                self.unprocessed_data.as_mut()[0..(remaining - amount_processed)]
-                    .copy_from_slice(&data[(data.len() - (remaining - amount_processed))..]);
-                self.unprocessed_data_len = remaining - amount_processed;
-            }
-        } else {
-            self.unprocessed_data.as_mut()
-                [self.unprocessed_data_len..self.unprocessed_data_len + data.len()]
-                .copy_from_slice(data);
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn update(&mut self, mut data: &[u8]) {
+        // The last full block stays buffered until more data arrives, `finish` writes it.
+        while self.unprocessed_data_len + data.len() > C::BLOCK_SIZE {
+            let amount_processed = self.fill_buffer(data);
+            data = &data[amount_processed..];
+            self.unprocessed_data_len = 0;
 
-            self.unprocessed_data_len += data.len();
+            let output_block = C::new(self.key, self.unprocessed_data).encrypt();
+            self.encrypted_data.extend_from_slice(output_block.as_ref());
         }
+
+        self.unprocessed_data.as_mut()
+            [self.unprocessed_data_len..self.unprocessed_data_len + data.len()]
+            .copy_from_slice(data);
+
+        self.unprocessed_data_len += data.len();
     }
 
+    #[cfg(any(feature = "alloc", feature = "std"))]
     fn fill_buffer(&mut self, data: &[u8]) -> usize {
+        let amount = C::BLOCK_SIZE - self.unprocessed_data_len;
+
         self.unprocessed_data.as_mut()[self.unprocessed_data_len..]
-            .copy_from_slice(&data[0..(C::BLOCK_SIZE - self.unprocessed_data_len)]);
+            .copy_from_slice(&data[0..amount]);
 
-        return C::BLOCK_SIZE - self.unprocessed_data_len;
+        return amount;
     }
 
-    pub fn finish<P: Padding>(mut self) -> Vec<u8> {
-        let last_blocks = P::pad_block(
-            &self.unprocessed_data.as_ref()[0..self.unprocessed_data_len],
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn finish<P: Padding>(mut self) -> Result<Vec<u8>, VCryptoError> {
+        // A full block is left unprocessed by `update`, it has to be written before the padding.
+        if self.unprocessed_data_len == C::BLOCK_SIZE {
+            self.encrypted_data
+                .extend_from_slice(C::new(self.key, self.unprocessed_data).encrypt().as_ref());
+            self.unprocessed_data_len = 0;
+        }
+
+        let mut last_block = self.unprocessed_data;
+        P::pad(
+            last_block.as_mut(),
+            self.unprocessed_data_len,
             C::BLOCK_SIZE,
-        );
+        )?;
 
-        let mut temp = C::empty_block();
+        self.encrypted_data
+            .extend_from_slice(C::new(self.key, last_block).encrypt().as_ref());
 
-        temp.as_mut().copy_from_slice(&last_blocks.0);
+        return Ok(self.encrypted_data);
+    }
+}
 
-        self.encrypted_data
-            .extend_from_slice(C::new(self.key, temp).encrypt().as_ref());
+impl<'a, C: BlockCipher<'a>> BlockMode for ECB<'a, C> {
+    const BLOCK_SIZE: usize = C::BLOCK_SIZE;
 
-        if let Some(l) = last_blocks.1 {
-            temp.as_mut().copy_from_slice(&l);
-            self.encrypted_data
-                .extend_from_slice(C::new(self.key, temp).encrypt().as_ref());
-        }
+    fn encrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        return ECB::encrypt_blocks(self, data);
+    }
+
+    fn decrypt_blocks(&mut self, data: &mut [u8]) -> Result<(), VCryptoError> {
+        return ECB::decrypt_blocks(self, data);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, C: BlockCipher<'a>> io::Write for ECB<'a, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
 
-        return self.encrypted_data;
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
     }
 }
 
@@ -130,7 +202,7 @@ mod tests {
         cipher.update(&input);
 
         assert_eq!(
-            cipher.finish::<PKCS7>(),
+            cipher.finish::<PKCS7>().unwrap(),
             vec![
                 0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
                 0x60, 0x89, // Padding block
@@ -156,7 +228,7 @@ mod tests {
         let mut encryptor = ECB::<AES>::new(AESKey::new_aes256(key));
 
         encryptor.update(&pt);
-        let encrypted = encryptor.finish::<PKCS7>();
+        let encrypted = encryptor.finish::<PKCS7>().unwrap();
 
         let decryptor = ECB::<AES>::new(AESKey::new_aes256(key));
 
@@ -165,4 +237,91 @@ mod tests {
             pt
         );
     }
+
+    #[test]
+    fn test_ecb_unaligned_updates() {
+        let key: [u32; 4] = [0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c];
+        let input = (0..100u8).collect::<Vec<u8>>();
+
+        let mut expected = ECB::<AES>::new(AESKey::new_aes128(key));
+        expected.update(&input);
+        let expected = expected.finish::<PKCS7>().unwrap();
+
+        for chunk_size in [1, 7, 10, 15, 16, 17, 33] {
+            let mut cipher = ECB::<AES>::new(AESKey::new_aes128(key));
+
+            for chunk in input.chunks(chunk_size) {
+                cipher.update(chunk);
+            }
+
+            assert_eq!(cipher.finish::<PKCS7>().unwrap(), expected);
+        }
+
+        let cipher = ECB::<AES>::new(AESKey::new_aes128(key));
+        let mut buffer = input.clone();
+        buffer.resize(112, 0);
+
+        assert_eq!(
+            cipher.encrypt_padded::<PKCS7>(&mut buffer, 100).unwrap(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ecb_write() {
+        use std::io::Write;
+
+        let key: [u32; 4] = [0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c];
+        let mut cipher = ECB::<AES>::new(AESKey::new_aes128(key));
+
+        cipher.write_all(&[0u8; 10]).unwrap();
+        cipher.write_all(&[0u8; 10]).unwrap();
+        cipher.write_all(&[0u8; 10]).unwrap();
+
+        let mut buffer = [0u8; 32];
+        let expected = ECB::<AES>::new(AESKey::new_aes128(key))
+            .encrypt_padded::<PKCS7>(&mut buffer, 30)
+            .unwrap();
+
+        assert_eq!(cipher.finish::<PKCS7>().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_ecb_aes256_in_place() {
+        let key: [u32; 8] = [
+            0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f, 0x10111213, 0x14151617, 0x18191a1b,
+            0x1c1d1e1f,
+        ];
+
+        let mut buffer = [0u8; 32];
+        hex::decode_to_slice("00112233445566778899aabbccddeeff", &mut buffer[..16]).unwrap();
+
+        let cipher = ECB::<AES>::new(AESKey::new_aes256(key));
+
+        assert_eq!(
+            hex::encode(cipher.encrypt_padded::<PKCS7>(&mut buffer, 16).unwrap()),
+            "8ea2b7ca516745bfeafc49904b4960899f3b7504926f8bd36e3118e903a4cd4a"
+        );
+
+        assert_eq!(
+            hex::encode(cipher.decrypt_padded::<PKCS7>(&mut buffer).unwrap()),
+            "00112233445566778899aabbccddeeff"
+        );
+    }
+
+    #[test]
+    fn test_ecb_in_place_invalid_length() {
+        let key: [u32; 4] = [0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c];
+        let cipher = ECB::<AES>::new(AESKey::new_aes128(key));
+
+        assert_eq!(
+            cipher.encrypt_padded::<PKCS7>(&mut [0u8; 16], 16),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            cipher.decrypt_padded::<PKCS7>(&mut [0u8; 15]),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
 }
@@ -1,4 +1,7 @@
+mod block_mode;
 mod cbc;
 mod ecb;
 
+pub use block_mode::BlockMode;
+pub use cbc::CBC;
 pub use ecb::ECB;
@@ -1,8 +1,13 @@
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+use crate::error::VCryptoError;
 
 pub trait Padding {
-    fn pad_block(input: &[u8], block_size: usize) -> (Vec<u8>, Option<Vec<u8>>);
+    /// Pads the message stored in `buffer[..msg_len]` in place, returning the padded length which
+    /// will be a multiple of `block_size`. The buffer must have room for the padding bytes.
+    fn pad(buffer: &mut [u8], msg_len: usize, block_size: usize) -> Result<usize, VCryptoError>;
 
-    fn validate_padded_block(data: &[u8]) -> Option<usize>;
+    /// Validates the padding at the end of `data` and returns the length of the original message.
+    fn unpad(data: &[u8], block_size: usize) -> Result<usize, VCryptoError>;
+
+    /// The length `msg_len` bytes will occupy once padded.
+    fn padded_len(msg_len: usize, block_size: usize) -> usize;
 }
@@ -1,37 +1,70 @@
 use super::Padding;
+use crate::error::VCryptoError;
 
 pub struct PKCS7;
 
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec;
-#[cfg(all(feature = "alloc", not(feature = "std")))]
-use alloc::vec::Vec;
+impl PKCS7 {
+    /// The padding length is stored in a single byte, so only block sizes of 1 to 255 bytes can be
+    /// padded. Any other size is `VCryptoError::InvalidInput`.
+    fn validate_block_size(block_size: usize) -> Result<(), VCryptoError> {
+        if block_size == 0 || block_size > u8::MAX as usize {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(());
+    }
+}
 
 impl Padding for PKCS7 {
-    fn pad_block(input: &[u8], block_size: usize) -> (Vec<u8>, Option<Vec<u8>>) {
-        let mut a = input.to_vec();
+    fn pad(buffer: &mut [u8], msg_len: usize, block_size: usize) -> Result<usize, VCryptoError> {
+        Self::validate_block_size(block_size)?;
 
-        if input.len() == block_size {
-            return (a, Some(vec![block_size as u8; block_size]));
-        } else {
-            let n = (block_size - input.len()) as u8;
+        // Checked first, as `padded_len` could overflow for a `msg_len` beyond any buffer
+        if msg_len > buffer.len() {
+            return Err(VCryptoError::InvalidInput);
+        }
 
-            for _ in input.len()..block_size {
-                a.push(n);
-            }
+        let padded_len = Self::padded_len(msg_len, block_size);
 
-            return (a, None);
+        if padded_len > buffer.len() {
+            return Err(VCryptoError::InvalidInput);
         }
+
+        let n = (padded_len - msg_len) as u8;
+
+        for b in buffer[msg_len..padded_len].iter_mut() {
+            *b = n;
+        }
+
+        return Ok(padded_len);
     }
 
-    fn validate_padded_block(block: &[u8]) -> Option<usize> {
-        for i in 0..block[block.len() - 1] {
-            if block[block.len() - 1 - i as usize] != block[block.len() - 1] {
-                return None;
-            }
+    fn unpad(data: &[u8], block_size: usize) -> Result<usize, VCryptoError> {
+        Self::validate_block_size(block_size)?;
+
+        if data.is_empty() || !data.len().is_multiple_of(block_size) {
+            return Err(VCryptoError::InvalidInput);
         }
 
-        return Some(block[block.len() - 1] as usize);
+        let last_block = &data[data.len() - block_size..];
+        let n = last_block[block_size - 1];
+        let mut invalid = (n == 0) as u8 | (n as usize > block_size) as u8;
+
+        // Examine every byte of the final block so the time taken doesn't depend on `n`
+        for (i, b) in last_block.iter().rev().enumerate() {
+            let in_padding = ((i as u8) < n) as u8;
+            invalid |= in_padding & ((*b != n) as u8);
+        }
+
+        if invalid != 0 {
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        return Ok(data.len() - n as usize);
+    }
+
+    fn padded_len(msg_len: usize, block_size: usize) -> usize {
+        return msg_len + (block_size - msg_len % block_size);
     }
 }
 
@@ -41,41 +74,113 @@ mod tests {
 
     #[test]
     fn test_pkcs7_47_to_64_bytes() {
-        let input: Vec<u8> = (0..47).into_iter().collect();
-        let mut reference = input.clone();
+        let mut buffer = [0u8; 64];
+        let mut reference = [17u8; 64];
 
-        for _ in 0..17 {
-            reference.push(17);
+        for i in 0..47 {
+            buffer[i] = i as u8;
+            reference[i] = i as u8;
         }
 
-        assert_eq!(PKCS7::pad_block(&input, 64).0, reference);
+        assert_eq!(PKCS7::pad(&mut buffer, 47, 64), Ok(64));
+        assert_eq!(buffer, reference);
     }
 
     #[test]
     fn test_pkcs7_63_to_64_bytes() {
-        let input: Vec<u8> = (0..63).into_iter().collect();
-        let mut reference = input.clone();
+        let mut buffer = [0u8; 64];
+        let mut reference = [1u8; 64];
 
-        for _ in 0..1 {
-            reference.push(1);
+        for i in 0..63 {
+            buffer[i] = i as u8;
+            reference[i] = i as u8;
         }
 
-        assert_eq!(PKCS7::pad_block(&input, 64).0, reference);
+        assert_eq!(PKCS7::pad(&mut buffer, 63, 64), Ok(64));
+        assert_eq!(buffer, reference);
     }
 
     #[test]
     fn test_pkcs7_64_to_128_bytes() {
-        let input: Vec<u8> = (0..64).into_iter().collect();
-        let reference = input.clone();
-        let mut alt = Vec::new();
+        let mut buffer = [0u8; 128];
+        let mut reference = [64u8; 128];
 
-        for _ in 0..64 {
-            alt.push(64);
+        for i in 0..64 {
+            buffer[i] = i as u8;
+            reference[i] = i as u8;
         }
 
-        let out = PKCS7::pad_block(&input, 64);
+        assert_eq!(PKCS7::pad(&mut buffer, 64, 64), Ok(128));
+        assert_eq!(buffer, reference);
+    }
 
-        assert_eq!(out.0, reference);
-        assert_eq!(out.1, Some(alt));
+    #[test]
+    fn test_pkcs7_buffer_too_small() {
+        let mut buffer = [0u8; 64];
+
+        assert_eq!(
+            PKCS7::pad(&mut buffer, 64, 64),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_pkcs7_unpad() {
+        let mut buffer = [0xaau8; 32];
+        buffer[16..].copy_from_slice(&[3u8; 16]);
+
+        assert_eq!(
+            PKCS7::unpad(&buffer[..19], 16),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(PKCS7::unpad(&buffer, 16), Ok(29));
+        assert_eq!(PKCS7::unpad(&[16u8; 16], 16), Ok(0));
+    }
+
+    #[test]
+    fn test_pkcs7_unpad_invalid() {
+        let mut buffer = [3u8; 16];
+        buffer[14] = 2;
+
+        assert_eq!(PKCS7::unpad(&buffer, 16), Err(VCryptoError::InvalidPadding));
+        assert_eq!(
+            PKCS7::unpad(&[0u8; 16], 16),
+            Err(VCryptoError::InvalidPadding)
+        );
+        assert_eq!(
+            PKCS7::unpad(&[17u8; 16], 16),
+            Err(VCryptoError::InvalidPadding)
+        );
+    }
+
+    #[test]
+    fn test_pkcs7_invalid_block_size() {
+        let mut buffer = [0u8; 512];
+
+        for block_size in [0, 256] {
+            assert_eq!(
+                PKCS7::pad(&mut buffer, 0, block_size),
+                Err(VCryptoError::InvalidInput)
+            );
+            assert_eq!(
+                PKCS7::unpad(&buffer, block_size),
+                Err(VCryptoError::InvalidInput)
+            );
+        }
+
+        assert_eq!(PKCS7::pad(&mut buffer, 0, 255), Ok(255));
+        assert_eq!(PKCS7::unpad(&buffer[..255], 255), Ok(0));
+    }
+
+    #[test]
+    fn test_pkcs7_message_longer_than_buffer() {
+        let mut buffer = [0u8; 32];
+
+        for msg_len in [33, usize::MAX - 1, usize::MAX] {
+            assert_eq!(
+                PKCS7::pad(&mut buffer, msg_len, 16),
+                Err(VCryptoError::InvalidInput)
+            );
+        }
     }
 }
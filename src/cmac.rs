@@ -0,0 +1,291 @@
+use crate::block_ciphers::BlockCipher;
+use crate::error::VCryptoError;
+use crate::util::constant_time_eq;
+
+/// CMAC (also known as OMAC1) as specified in NIST SP 800-38B and RFC 4493, over any block cipher
+/// with a 64 or 128 bit block size.
+pub struct Cmac<'a, C: BlockCipher<'a>> {
+    key: C::Key,
+    k1: C::Block,
+    k2: C::Block,
+    state: C::Block,
+    unprocessed_data: C::Block,
+    unprocessed_data_len: usize,
+}
+
+// Derived implementations would require the cipher itself to be `Clone`
+impl<'a, C: BlockCipher<'a>> Clone for Cmac<'a, C> {
+    fn clone(&self) -> Self {
+        return Self {
+            key: self.key,
+            k1: self.k1,
+            k2: self.k2,
+            state: self.state,
+            unprocessed_data: self.unprocessed_data,
+            unprocessed_data_len: self.unprocessed_data_len,
+        };
+    }
+}
+
+impl<'a, C: BlockCipher<'a>> Cmac<'a, C> {
+    /// Returns `VCryptoError::InvalidInput` if the block size of the cipher is neither 8 nor 16
+    /// bytes, the only sizes SP 800-38B defines the subkey constant for.
+    pub fn new(key: C::Key) -> Result<Self, VCryptoError> {
+        // The constant R_b from section 5.3 of SP 800-38B
+        let rb = match C::BLOCK_SIZE {
+            8 => 0x1b,
+            16 => 0x87,
+            _ => return Err(VCryptoError::InvalidInput),
+        };
+
+        let l = C::new(key, C::empty_block()).encrypt();
+        let k1 = Self::double(l, rb);
+        let k2 = Self::double(k1, rb);
+
+        return Ok(Self {
+            key,
+            k1,
+            k2,
+            state: C::empty_block(),
+            unprocessed_data: C::empty_block(),
+            unprocessed_data_len: 0,
+        });
+    }
+
+    /// Returns the two subkeys derived from the cipher key.
+    pub fn subkeys(&self) -> (C::Block, C::Block) {
+        return (self.k1, self.k2);
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut total_processed = 0;
+
+        while total_processed < data.len() {
+            // A full block is only processed once more data arrives, the final block is treated
+            // differently by `finalize`
+            if self.unprocessed_data_len == C::BLOCK_SIZE {
+                let block = self.unprocessed_data;
+                self.process_block(&block);
+                self.unprocessed_data_len = 0;
+            }
+
+            let amount =
+                (C::BLOCK_SIZE - self.unprocessed_data_len).min(data.len() - total_processed);
+
+            self.unprocessed_data.as_mut()
+                [self.unprocessed_data_len..self.unprocessed_data_len + amount]
+                .copy_from_slice(&data[total_processed..total_processed + amount]);
+
+            self.unprocessed_data_len += amount;
+            total_processed += amount;
+        }
+    }
+
+    pub fn finalize(self) -> C::Block {
+        let mut last_block = C::empty_block();
+
+        if self.unprocessed_data_len == C::BLOCK_SIZE {
+            last_block = self.unprocessed_data;
+            xor_in_place(last_block.as_mut(), self.k1.as_ref());
+        } else {
+            last_block.as_mut()[..self.unprocessed_data_len]
+                .copy_from_slice(&self.unprocessed_data.as_ref()[..self.unprocessed_data_len]);
+            last_block.as_mut()[self.unprocessed_data_len] = 0x80;
+
+            xor_in_place(last_block.as_mut(), self.k2.as_ref());
+        }
+
+        let mut cmac = self;
+        cmac.process_block(&last_block);
+
+        return cmac.state;
+    }
+
+    /// Checks `tag` against the MAC of the data provided so far in constant time. Truncated tags
+    /// are accepted, as permitted by SP 800-38B, but should not be shorter than 8 bytes.
+    pub fn verify(self, tag: &[u8]) -> bool {
+        let mac = self.finalize();
+
+        if tag.is_empty() || tag.len() > C::BLOCK_SIZE {
+            return false;
+        }
+
+        return constant_time_eq(&mac.as_ref()[..tag.len()], tag);
+    }
+
+    fn process_block(&mut self, block: &C::Block) {
+        xor_in_place(self.state.as_mut(), block.as_ref());
+
+        self.state = C::new(self.key, self.state).encrypt();
+    }
+
+    /// Multiplies `block` by x in GF(2^n), the block is treated as a big endian number.
+    fn double(block: C::Block, rb: u8) -> C::Block {
+        let input = block.as_ref();
+        let mut output = C::empty_block();
+        let msb_set = input[0] >> 7;
+
+        for (i, b) in output.as_mut().iter_mut().enumerate() {
+            *b = (input[i] << 1) | input.get(i + 1).map_or(0, |next| next >> 7);
+        }
+
+        // Constant time conditional xor with R_b
+        output.as_mut()[C::BLOCK_SIZE - 1] ^= rb & 0u8.wrapping_sub(msb_set);
+
+        return output;
+    }
+}
+
+fn xor_in_place(destination: &mut [u8], source: &[u8]) {
+    for (d, s) in destination.iter_mut().zip(source.iter()) {
+        *d ^= s;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_ciphers::aes::{AESKey, AES};
+    use crate::block_ciphers::blowfish::{Blowfish, BlowfishKey};
+    use pretty_assertions::assert_eq;
+
+    const MESSAGE: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+
+    fn aes_cmac(key: AESKey, msg_len: usize) -> String {
+        let mut cmac = Cmac::<AES>::new(key).unwrap();
+        cmac.update(&hex::decode(MESSAGE).unwrap()[..msg_len]);
+
+        return hex::encode(cmac.finalize());
+    }
+
+    #[test]
+    fn test_cmac_aes128_subkeys() {
+        let cmac = Cmac::<AES>::new(AESKey::new_aes128([
+            0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c,
+        ]))
+        .unwrap();
+        let (k1, k2) = cmac.subkeys();
+
+        assert_eq!(hex::encode(k1), "fbeed618357133667c85e08f7236a8de");
+        assert_eq!(hex::encode(k2), "f7ddac306ae266ccf90bc11ee46d513b");
+    }
+
+    #[test]
+    fn test_cmac_aes128() {
+        // RFC 4493 section 4
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+
+        assert_eq!(aes_cmac(key, 0), "bb1d6929e95937287fa37d129b756746");
+        assert_eq!(aes_cmac(key, 16), "070a16b46b4d4144f79bdd9dd04a287c");
+        assert_eq!(aes_cmac(key, 40), "dfa66747de9ae63030ca32611497c827");
+        assert_eq!(aes_cmac(key, 64), "51f0bebf7e3b9d92fc49741779363cfe");
+    }
+
+    #[test]
+    fn test_cmac_aes192() {
+        // SP 800-38B appendix D.2
+        let key = AESKey::new_aes192([
+            0x8e73b0f7, 0xda0e6452, 0xc810f32b, 0x809079e5, 0x62f8ead2, 0x522c6b7b,
+        ]);
+
+        assert_eq!(aes_cmac(key, 0), "d17ddf46adaacde531cac483de7a9367");
+        assert_eq!(aes_cmac(key, 16), "9e99a7bf31e710900662f65e617c5184");
+        assert_eq!(aes_cmac(key, 40), "8a1de5be2eb31aad089a82e6ee908b0e");
+        assert_eq!(aes_cmac(key, 64), "a1d5df0eed790f794d77589659f39a11");
+    }
+
+    #[test]
+    fn test_cmac_aes256() {
+        // SP 800-38B appendix D.3
+        let key = AESKey::new_aes256([
+            0x603deb10, 0x15ca71be, 0x2b73aef0, 0x857d7781, 0x1f352c07, 0x3b6108d7, 0x2d9810a3,
+            0x0914dff4,
+        ]);
+
+        assert_eq!(aes_cmac(key, 0), "028962f61b7bf89efc6b551f4667d983");
+        assert_eq!(aes_cmac(key, 16), "28a7023f452e8f82bd4bf28d8c37c35c");
+        assert_eq!(aes_cmac(key, 40), "aaf3d8f1de5640c232f5b169b9c911e6");
+        assert_eq!(aes_cmac(key, 64), "e1992190549f6ed5696a2c056c315410");
+    }
+
+    #[test]
+    fn test_cmac_streaming() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let message = hex::decode(MESSAGE).unwrap();
+        let mut cmac = Cmac::<AES>::new(key).unwrap();
+
+        for chunk in message.chunks(7) {
+            cmac.update(chunk);
+        }
+
+        assert_eq!(
+            hex::encode(cmac.finalize()),
+            "51f0bebf7e3b9d92fc49741779363cfe"
+        );
+    }
+
+    #[test]
+    fn test_cmac_verify() {
+        let key = AESKey::new_aes128([0x2b7e1516, 0x28aed2a6, 0xabf71588, 0x09cf4f3c]);
+        let tag = hex::decode("070a16b46b4d4144f79bdd9dd04a287c").unwrap();
+        let mut cmac = Cmac::<AES>::new(key).unwrap();
+        cmac.update(&hex::decode(MESSAGE).unwrap()[..16]);
+
+        assert!(cmac.clone().verify(&tag));
+        assert!(cmac.clone().verify(&tag[..8]));
+        assert!(!cmac.clone().verify(&[0u8; 16]));
+        assert!(!cmac.verify(&[]));
+    }
+
+    #[test]
+    fn test_cmac_blowfish() {
+        let key = hex::decode("0123456789abcdeff0e1d2c3b4a59687").unwrap();
+        let mac = |msg: &[u8]| {
+            let mut cmac = Cmac::<Blowfish>::new(BlowfishKey::new(&key).unwrap()).unwrap();
+            cmac.update(msg);
+
+            hex::encode(cmac.finalize())
+        };
+
+        assert_eq!(mac(b""), "c4384e723f275260");
+        assert_eq!(mac(b"12345678"), "3f04a0ed247b36b0");
+        assert_eq!(
+            mac(b"The quick brown fox jumps over the lazy dog"),
+            "f19f852b39e70d67"
+        );
+    }
+
+    /// A cipher with a 4 byte block, which CMAC has no subkey constant for.
+    struct Xor32(u32, [u8; 4]);
+
+    impl BlockCipher<'_> for Xor32 {
+        type Key = u32;
+        type Block = [u8; 4];
+
+        const BLOCK_SIZE: usize = 4;
+
+        fn empty_block() -> Self::Block {
+            return [0; 4];
+        }
+
+        fn new(key: Self::Key, block: Self::Block) -> Self {
+            return Self(key, block);
+        }
+
+        fn encrypt(self) -> Self::Block {
+            return (u32::from_be_bytes(self.1) ^ self.0).to_be_bytes();
+        }
+
+        fn decrypt(self) -> Self::Block {
+            return self.encrypt();
+        }
+    }
+
+    #[test]
+    fn test_unsupported_block_size() {
+        assert_eq!(
+            Cmac::<Xor32>::new(0x01020304).err(),
+            Some(VCryptoError::InvalidInput)
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use super::{FieldElement, Scalar};
+
+/// The curve constant d = -121665 / 121666.
+const D: FieldElement = FieldElement::from_limbs([
+    0x34dca135978a3,
+    0x1a8283b156ebd,
+    0x5e7a26001c029,
+    0x739c663a03cbb,
+    0x52036cee2b6ff,
+]);
+
+/// 2 * d, which the addition formulas use.
+const D2: FieldElement = FieldElement::from_limbs([
+    0x69b9426b2f159,
+    0x35050762add7a,
+    0x3cf44c0038052,
+    0x6738cc7407977,
+    0x2406d9dc56dff,
+]);
+
+/// The square root 2^((p - 1) / 4) of -1.
+const SQRT_M1: FieldElement = FieldElement::from_limbs([
+    0x61b274a0ea0b0,
+    0x0d5a5fc8f189d,
+    0x7ef5e9cbd0c60,
+    0x78595a6804c9e,
+    0x2b8324804fc1d,
+]);
+
+/// A point on the twisted Edwards curve -x^2 + y^2 = 1 + d x^2 y^2, birationally equivalent to
+/// Curve25519, in extended coordinates (X : Y : Z : T) with x = X / Z, y = Y / Z and xy = T / Z.
+///
+/// The addition formulas from RFC 8032 section 5.1.4 are complete, so adding the identity or a
+/// point to itself needs no special case.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EdwardsPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+    t: FieldElement,
+}
+
+impl EdwardsPoint {
+    pub(crate) const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ONE,
+        t: FieldElement::ZERO,
+    };
+
+    /// The base point B from RFC 8032, with y = 4/5 and a positive x.
+    pub(crate) const BASEPOINT: Self = Self {
+        x: FieldElement::from_limbs([
+            0x62d608f25d51a,
+            0x412a4b4f6592a,
+            0x75b7171a4b31d,
+            0x1ff60527118fe,
+            0x216936d3cd6e5,
+        ]),
+        y: FieldElement::from_limbs([
+            0x6666666666658,
+            0x4cccccccccccc,
+            0x1999999999999,
+            0x3333333333333,
+            0x6666666666666,
+        ]),
+        z: FieldElement::ONE,
+        t: FieldElement::from_limbs([
+            0x68ab3a5b7dda3,
+            0x00eea2a5eadbb,
+            0x2af8df483c27e,
+            0x332b375274732,
+            0x67875f0fd78b7,
+        ]),
+    };
+
+    /// Encodes the point as y in 255 little endian bits, followed by the sign of x.
+    pub(crate) fn compress(&self) -> [u8; 32] {
+        let z_inverse = self.z.invert();
+        let x = self.x.mul(&z_inverse);
+        let mut bytes = self.y.mul(&z_inverse).to_bytes();
+        bytes[31] |= x.is_negative() << 7;
+
+        return bytes;
+    }
+
+    /// Decodes a point following RFC 8032 section 5.1.3. Encodings of y that are not reduced
+    /// modulo p and a negative zero x are rejected, so every point has a single valid encoding.
+    ///
+    /// Encodings are public, so this takes variable time.
+    pub(crate) fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let sign = bytes[31] >> 7;
+        let y = FieldElement::from_bytes(bytes);
+
+        let mut canonical = *bytes;
+        canonical[31] &= 0x7f;
+
+        if y.to_bytes() != canonical {
+            return None;
+        }
+
+        // x^2 = (y^2 - 1) / (d y^2 + 1) = u / v, a candidate root is u v^3 (u v^7)^((p - 5) / 8)
+        let yy = y.square();
+        let u = yy.sub(&FieldElement::ONE);
+        let v = D.mul(&yy).add(&FieldElement::ONE);
+        let v3 = v.square().mul(&v);
+        let v7 = v3.square().mul(&v);
+        let mut x = u.mul(&v3).mul(&u.mul(&v7).pow_p58());
+        let vxx = v.mul(&x.square());
+
+        if vxx == u.negate() {
+            x = x.mul(&SQRT_M1);
+        } else if vxx != u {
+            return None;
+        }
+
+        if x.is_zero() && sign == 1 {
+            return None;
+        }
+
+        if x.is_negative() != sign {
+            x = x.negate();
+        }
+
+        return Some(Self {
+            x,
+            y,
+            z: FieldElement::ONE,
+            t: x.mul(&y),
+        });
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let a = self.y.sub(&self.x).mul(&rhs.y.sub(&rhs.x));
+        let b = self.y.add(&self.x).mul(&rhs.y.add(&rhs.x));
+        let c = self.t.mul(&D2).mul(&rhs.t);
+        let d = self.z.add(&self.z).mul(&rhs.z);
+        let e = b.sub(&a);
+        let f = d.sub(&c);
+        let g = d.add(&c);
+        let h = b.add(&a);
+
+        return Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        };
+    }
+
+    pub(crate) fn double(&self) -> Self {
+        let a = self.x.square();
+        let b = self.y.square();
+        let zz = self.z.square();
+        let c = zz.add(&zz);
+        let h = a.add(&b);
+        let e = h.sub(&self.x.add(&self.y).square());
+        let g = a.sub(&b);
+        let f = c.add(&g);
+
+        return Self {
+            x: e.mul(&f),
+            y: g.mul(&h),
+            z: f.mul(&g),
+            t: e.mul(&h),
+        };
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        return Self {
+            x: self.x.negate(),
+            y: self.y,
+            z: self.z,
+            t: self.t.negate(),
+        };
+    }
+
+    /// Multiplies the point by `scalar` with a fixed window of 4 bits. The table lookups touch
+    /// every entry and the same additions are made whatever the scalar, so it is safe to use
+    /// with secret scalars.
+    pub(crate) fn mul(&self, scalar: &Scalar) -> Self {
+        let mut table = [Self::IDENTITY; 16];
+
+        for i in 1..16 {
+            table[i] = table[i - 1].add(self);
+        }
+
+        let bytes = scalar.to_bytes();
+        let mut result = Self::IDENTITY;
+
+        for i in (0..64).rev() {
+            let nibble = (bytes[i / 2] >> (4 * (i % 2))) & 0x0f;
+            let mut selected = Self::IDENTITY;
+
+            for (j, entry) in table.iter().enumerate() {
+                // 1 if j equals the nibble, 0 otherwise
+                let choice = (((j as u32) ^ (nibble as u32)).wrapping_sub(1) >> 31) as u8;
+                selected.conditional_assign(entry, choice);
+            }
+
+            result = result.double().double().double().double().add(&selected);
+        }
+
+        return result;
+    }
+
+    /// Multiplies the base point by a secret scalar.
+    pub(crate) fn mul_base(scalar: &Scalar) -> Self {
+        return Self::BASEPOINT.mul(scalar);
+    }
+
+    /// Returns the sum of `scalars[i] * points[i]`, sharing the doublings between all the terms.
+    ///
+    /// Additions depend on the scalar bits, so this must only be used with public values, as in
+    /// signature verification.
+    pub(crate) fn vartime_multiscalar_mul(scalars: &[Scalar], points: &[Self]) -> Self {
+        let mut result = Self::IDENTITY;
+
+        // Scalars are below L < 2^253
+        for i in (0..253).rev() {
+            result = result.double();
+
+            for (scalar, point) in scalars.iter().zip(points) {
+                if scalar.bit(i) == 1 {
+                    result = result.add(point);
+                }
+            }
+        }
+
+        return result;
+    }
+
+    pub(crate) fn mul_by_cofactor(&self) -> Self {
+        return self.double().double().double();
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        return self.x.is_zero() && self.y == self.z;
+    }
+
+    /// Checks whether the point is one of the 8 points whose order divides the cofactor.
+    pub(crate) fn is_small_order(&self) -> bool {
+        return self.mul_by_cofactor().is_identity();
+    }
+
+    fn conditional_assign(&mut self, other: &Self, choice: u8) {
+        self.x.conditional_assign(&other.x, choice);
+        self.y.conditional_assign(&other.y, choice);
+        self.z.conditional_assign(&other.z, choice);
+        self.t.conditional_assign(&other.t, choice);
+    }
+}
+
+impl PartialEq for EdwardsPoint {
+    /// Compares the affine coordinates, cross multiplying to avoid inversions.
+    fn eq(&self, other: &Self) -> bool {
+        return self.x.mul(&other.z) == other.x.mul(&self.z)
+            && self.y.mul(&other.z) == other.y.mul(&self.z);
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// The points of order dividing 8: the identity, then multiples of a point of order 8.
+    const TORSION: [&str; 8] = [
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+        "0000000000000000000000000000000000000000000000000000000000000080",
+        "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+        "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa",
+    ];
+
+    fn decode(s: &str) -> [u8; 32] {
+        return hex::decode(s).unwrap().try_into().unwrap();
+    }
+
+    fn small_scalar(n: u8) -> Scalar {
+        let mut bytes = [0u8; 32];
+        bytes[0] = n;
+
+        return Scalar::from_bytes_mod_order(&bytes);
+    }
+
+    #[test]
+    fn test_basepoint() {
+        assert_eq!(
+            hex::encode(EdwardsPoint::BASEPOINT.compress()),
+            "5866666666666666666666666666666666666666666666666666666666666666"
+        );
+        assert_eq!(
+            EdwardsPoint::decompress(&EdwardsPoint::BASEPOINT.compress()),
+            Some(EdwardsPoint::BASEPOINT)
+        );
+        assert!(!EdwardsPoint::BASEPOINT.is_small_order());
+
+        // B has order L, so (L - 1) B = -B
+        let minus_one = Scalar::from_canonical_bytes(&decode(
+            "ecd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010",
+        ))
+        .unwrap();
+        assert_eq!(
+            EdwardsPoint::mul_base(&minus_one),
+            EdwardsPoint::BASEPOINT.negate()
+        );
+    }
+
+    #[test]
+    fn test_group_law() {
+        let b = EdwardsPoint::BASEPOINT;
+        let b2 = b.double();
+
+        assert_eq!(b.add(&b), b2);
+        assert_eq!(b2.add(&b), b.add(&b2));
+        assert_eq!(b.add(&EdwardsPoint::IDENTITY), b);
+        assert!(b.add(&b.negate()).is_identity());
+        assert_eq!(EdwardsPoint::mul_base(&small_scalar(3)), b2.add(&b));
+        assert_eq!(
+            EdwardsPoint::mul_base(&Scalar::default()),
+            EdwardsPoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        let x = Scalar::from_bytes_mod_order(&[0x5a; 32]);
+        let y = Scalar::from_bytes_mod_order(&[0xc3; 32]);
+        let p = EdwardsPoint::mul_base(&small_scalar(7));
+
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul(&[x, y], &[EdwardsPoint::BASEPOINT, p]),
+            EdwardsPoint::mul_base(&x).add(&p.mul(&y))
+        );
+        assert_eq!(
+            EdwardsPoint::vartime_multiscalar_mul(&[], &[]),
+            EdwardsPoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_small_order_points() {
+        let order_8 = EdwardsPoint::decompress(&decode(TORSION[1])).unwrap();
+        let mut point = EdwardsPoint::IDENTITY;
+
+        for encoding in TORSION {
+            assert_eq!(hex::encode(point.compress()), encoding);
+            assert!(EdwardsPoint::decompress(&decode(encoding))
+                .unwrap()
+                .is_small_order());
+            point = point.add(&order_8);
+        }
+
+        assert!(point.is_identity());
+        assert!(!EdwardsPoint::BASEPOINT.add(&order_8).is_small_order());
+    }
+
+    #[test]
+    fn test_non_canonical_encodings() {
+        // y = 3 is on the curve, y = 3 + p is the same value but not reduced
+        let mut y = [0u8; 32];
+        y[0] = 3;
+        assert!(EdwardsPoint::decompress(&y).is_some());
+
+        let mut y_plus_p = [0xff; 32];
+        y_plus_p[0] = 0xf0;
+        y_plus_p[31] = 0x7f;
+        assert_eq!(EdwardsPoint::decompress(&y_plus_p), None);
+
+        // y = 1 + p is the identity, y = 1 with the sign bit set would be a negative zero x
+        let mut identity = decode(TORSION[0]);
+        identity[31] |= 0x80;
+        assert_eq!(EdwardsPoint::decompress(&identity), None);
+
+        let mut one_plus_p = [0xff; 32];
+        one_plus_p[0] = 0xee;
+        one_plus_p[31] = 0x7f;
+        assert_eq!(EdwardsPoint::decompress(&one_plus_p), None);
+
+        // y = 2 gives a non square x^2
+        let mut not_on_curve = [0u8; 32];
+        not_on_curve[0] = 2;
+        assert_eq!(EdwardsPoint::decompress(&not_on_curve), None);
+    }
+}
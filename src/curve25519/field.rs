@@ -0,0 +1,340 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+const MASK: u64 = (1 << 51) - 1;
+
+/// An element of the field of integers modulo p = 2^255 - 19, stored in five 51 bit limbs.
+///
+/// Every operation takes time that depends only on its inputs' sizes, and leaves each limb below
+/// 2^52 so the results can be fed to any other operation.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FieldElement([u64; 5]);
+
+impl FieldElement {
+    pub(crate) const ZERO: Self = Self([0; 5]);
+    pub(crate) const ONE: Self = Self([1, 0, 0, 0, 0]);
+
+    /// Builds an element from limbs that are already below 2^51, for constants.
+    pub(crate) const fn from_limbs(limbs: [u64; 5]) -> Self {
+        return Self(limbs);
+    }
+
+    /// Decodes 32 little endian bytes, ignoring the most significant bit as RFC 7748 requires.
+    /// Values from p to 2^255 - 1 are accepted and reduced.
+    pub(crate) fn from_bytes(bytes: &[u8; 32]) -> Self {
+        let w0 = LittleEndian::read_u64(&bytes[0..8]);
+        let w1 = LittleEndian::read_u64(&bytes[8..16]);
+        let w2 = LittleEndian::read_u64(&bytes[16..24]);
+        let w3 = LittleEndian::read_u64(&bytes[24..32]);
+
+        return Self([
+            w0 & MASK,
+            ((w0 >> 51) | (w1 << 13)) & MASK,
+            ((w1 >> 38) | (w2 << 26)) & MASK,
+            ((w2 >> 25) | (w3 << 39)) & MASK,
+            (w3 >> 12) & MASK,
+        ]);
+    }
+
+    /// Encodes the fully reduced value as 32 little endian bytes.
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
+        let mut h = Self::carry(self.0).0;
+
+        // h is below 2p, subtract p if h + 19 reaches 2^255
+        let mut q = (h[0] + 19) >> 51;
+        q = (h[1] + q) >> 51;
+        q = (h[2] + q) >> 51;
+        q = (h[3] + q) >> 51;
+        q = (h[4] + q) >> 51;
+
+        h[0] += 19 * q;
+        h[1] += h[0] >> 51;
+        h[0] &= MASK;
+        h[2] += h[1] >> 51;
+        h[1] &= MASK;
+        h[3] += h[2] >> 51;
+        h[2] &= MASK;
+        h[4] += h[3] >> 51;
+        h[3] &= MASK;
+        h[4] &= MASK;
+
+        let mut bytes = [0u8; 32];
+        LittleEndian::write_u64(&mut bytes[0..8], h[0] | (h[1] << 51));
+        LittleEndian::write_u64(&mut bytes[8..16], (h[1] >> 13) | (h[2] << 38));
+        LittleEndian::write_u64(&mut bytes[16..24], (h[2] >> 26) | (h[3] << 25));
+        LittleEndian::write_u64(&mut bytes[24..32], (h[3] >> 39) | (h[4] << 12));
+
+        return bytes;
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let a = self.0;
+        let b = rhs.0;
+
+        return Self::carry([
+            a[0] + b[0],
+            a[1] + b[1],
+            a[2] + b[2],
+            a[3] + b[3],
+            a[4] + b[4],
+        ]);
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        let a = self.0;
+        let b = rhs.0;
+
+        // Adding 4p keeps every limb positive, as the limbs of rhs are below 2^52
+        return Self::carry([
+            (a[0] + 0x1fffffffffffb4) - b[0],
+            (a[1] + 0x1ffffffffffffc) - b[1],
+            (a[2] + 0x1ffffffffffffc) - b[2],
+            (a[3] + 0x1ffffffffffffc) - b[3],
+            (a[4] + 0x1ffffffffffffc) - b[4],
+        ]);
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        return Self::ZERO.sub(self);
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        let a = self.0;
+        let b = rhs.0;
+        let m = |x: u64, y: u64| x as u128 * y as u128;
+
+        // Limbs that wrap around past 2^255 are multiplied by 19, as 2^255 = 19 mod p
+        let b1 = b[1] * 19;
+        let b2 = b[2] * 19;
+        let b3 = b[3] * 19;
+        let b4 = b[4] * 19;
+
+        let c0 = m(a[0], b[0]) + m(a[4], b1) + m(a[3], b2) + m(a[2], b3) + m(a[1], b4);
+        let c1 = m(a[1], b[0]) + m(a[0], b[1]) + m(a[4], b2) + m(a[3], b3) + m(a[2], b4);
+        let c2 = m(a[2], b[0]) + m(a[1], b[1]) + m(a[0], b[2]) + m(a[4], b3) + m(a[3], b4);
+        let c3 = m(a[3], b[0]) + m(a[2], b[1]) + m(a[1], b[2]) + m(a[0], b[3]) + m(a[4], b4);
+        let c4 = m(a[4], b[0]) + m(a[3], b[1]) + m(a[2], b[2]) + m(a[1], b[3]) + m(a[0], b[4]);
+
+        return Self::carry_wide([c0, c1, c2, c3, c4]);
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        return self.mul(self);
+    }
+
+    /// Squares `k` times in a row.
+    pub(crate) fn pow2k(&self, k: u32) -> Self {
+        let mut result = *self;
+
+        for _ in 0..k {
+            result = result.square();
+        }
+
+        return result;
+    }
+
+    pub(crate) fn mul_small(&self, rhs: u32) -> Self {
+        let a = self.0;
+        let m = |x: u64| x as u128 * rhs as u128;
+
+        return Self::carry_wide([m(a[0]), m(a[1]), m(a[2]), m(a[3]), m(a[4])]);
+    }
+
+    /// Returns the inverse as `self^(p - 2)`, zero is mapped to zero.
+    pub(crate) fn invert(&self) -> Self {
+        let (z_250_0, z11) = self.pow22501();
+
+        // (2^250 - 1) * 2^5 + 11 = 2^255 - 21 = p - 2
+        return z_250_0.pow2k(5).mul(&z11);
+    }
+
+    /// Returns `self^((p - 5) / 8)`, the exponentiation at the heart of square roots.
+    pub(crate) fn pow_p58(&self) -> Self {
+        let (z_250_0, _) = self.pow22501();
+
+        // (2^250 - 1) * 2^2 + 1 = 2^252 - 3 = (p - 5) / 8
+        return z_250_0.pow2k(2).mul(self);
+    }
+
+    /// Returns `(self^(2^250 - 1), self^11)`, shared by inversion and square roots.
+    pub(crate) fn pow22501(&self) -> (Self, Self) {
+        let z2 = self.square();
+        let z9 = z2.pow2k(2).mul(self);
+        let z11 = z9.mul(&z2);
+        let z_5_0 = z11.square().mul(&z9);
+        let z_10_0 = z_5_0.pow2k(5).mul(&z_5_0);
+        let z_20_0 = z_10_0.pow2k(10).mul(&z_10_0);
+        let z_40_0 = z_20_0.pow2k(20).mul(&z_20_0);
+        let z_50_0 = z_40_0.pow2k(10).mul(&z_10_0);
+        let z_100_0 = z_50_0.pow2k(50).mul(&z_50_0);
+        let z_200_0 = z_100_0.pow2k(100).mul(&z_100_0);
+        let z_250_0 = z_200_0.pow2k(50).mul(&z_50_0);
+
+        return (z_250_0, z11);
+    }
+
+    /// The least significant bit of the canonical encoding, which RFC 8032 calls the sign.
+    pub(crate) fn is_negative(&self) -> u8 {
+        return self.to_bytes()[0] & 1;
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        return crate::util::constant_time_eq(&self.to_bytes(), &[0u8; 32]);
+    }
+
+    /// Replaces `self` with `other` if `choice` is 1 and leaves it alone if it is 0, without
+    /// branching.
+    pub(crate) fn conditional_assign(&mut self, other: &Self, choice: u8) {
+        let mask = 0u64.wrapping_sub(choice as u64);
+
+        for i in 0..5 {
+            self.0[i] ^= mask & (self.0[i] ^ other.0[i]);
+        }
+    }
+
+    /// Swaps `a` and `b` if `swap` is 1 and leaves them alone if it is 0, without branching.
+    pub(crate) fn conditional_swap(a: &mut Self, b: &mut Self, swap: u8) {
+        let mask = 0u64.wrapping_sub(swap as u64);
+
+        for i in 0..5 {
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    fn carry(mut h: [u64; 5]) -> Self {
+        h[1] += h[0] >> 51;
+        h[0] &= MASK;
+        h[2] += h[1] >> 51;
+        h[1] &= MASK;
+        h[3] += h[2] >> 51;
+        h[2] &= MASK;
+        h[4] += h[3] >> 51;
+        h[3] &= MASK;
+        h[0] += 19 * (h[4] >> 51);
+        h[4] &= MASK;
+
+        return Self(h);
+    }
+
+    fn carry_wide(mut c: [u128; 5]) -> Self {
+        c[1] += c[0] >> 51;
+        c[2] += c[1] >> 51;
+        c[3] += c[2] >> 51;
+        c[4] += c[3] >> 51;
+
+        let mut h = [
+            c[0] as u64 & MASK,
+            c[1] as u64 & MASK,
+            c[2] as u64 & MASK,
+            c[3] as u64 & MASK,
+            c[4] as u64 & MASK,
+        ];
+
+        // The carry out of the top limb is below 2^62, so 19 times it still fits in a u128
+        let top = (c[4] >> 51) * 19 + h[0] as u128;
+        h[0] = top as u64 & MASK;
+        h[1] += (top >> 51) as u64;
+
+        return Self(h);
+    }
+}
+
+impl PartialEq for FieldElement {
+    /// Compares the canonical encodings in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        return crate::util::constant_time_eq(&self.to_bytes(), &other.to_bytes());
+    }
+}
+
+impl Eq for FieldElement {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const P: [u8; 32] = [
+        0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ];
+
+    fn small(n: u8) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        bytes[0] = n;
+
+        return FieldElement::from_bytes(&bytes);
+    }
+
+    #[test]
+    fn test_canonical_encoding() {
+        let mut p_plus_one = P;
+        p_plus_one[0] += 1;
+
+        assert_eq!(FieldElement::from_bytes(&P).to_bytes(), [0u8; 32]);
+        assert_eq!(
+            FieldElement::from_bytes(&p_plus_one).to_bytes(),
+            small(1).to_bytes()
+        );
+
+        // The most significant bit is ignored
+        let mut high_bit = small(5).to_bytes();
+        high_bit[31] |= 0x80;
+        assert_eq!(FieldElement::from_bytes(&high_bit), small(5));
+
+        // p - 1 survives a round trip
+        let mut p_minus_one = P;
+        p_minus_one[0] -= 1;
+        assert_eq!(
+            FieldElement::from_bytes(&p_minus_one).to_bytes(),
+            p_minus_one
+        );
+        assert_eq!(FieldElement::ZERO.sub(&small(1)).to_bytes(), p_minus_one);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = FieldElement::from_bytes(&[0xa5; 32]);
+        let b = FieldElement::from_bytes(&[0x3c; 32]);
+
+        assert_eq!(a.add(&b).sub(&b), a);
+        assert_eq!(a.sub(&b).add(&b), a);
+        assert_eq!(a.mul(&b), b.mul(&a));
+        assert_eq!(
+            a.mul_small(121665),
+            a.mul(&FieldElement([121665, 0, 0, 0, 0]))
+        );
+        assert_eq!(a.mul(&a.invert()), FieldElement::ONE);
+        assert_eq!(FieldElement::ZERO.invert(), FieldElement::ZERO);
+        assert_eq!(a.add(&a.negate()), FieldElement::ZERO);
+        assert!(a.sub(&a).is_zero());
+        assert_eq!(small(1).negate().is_negative(), 0);
+        assert_eq!(small(2).negate().is_negative(), 1);
+
+        // (2 * 4^((p - 5) / 8))^2 = 2^((p - 1) / 2), which is -1 as 2 is not a square modulo p
+        let root = small(4).pow_p58().mul(&small(2));
+        assert_eq!(root.square(), small(1).negate());
+
+        // 2^255 - 1 squared, (2^255 - 1)^2 = 18^2 = 324 mod p
+        let max = FieldElement::from_bytes(&[0xff; 32]);
+        assert_eq!(max.square().to_bytes()[..2], [0x44, 0x01]);
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        let mut a = small(1);
+        let mut b = small(2);
+
+        FieldElement::conditional_swap(&mut a, &mut b, 0);
+        assert_eq!((a, b), (small(1), small(2)));
+
+        FieldElement::conditional_swap(&mut a, &mut b, 1);
+        assert_eq!((a, b), (small(2), small(1)));
+
+        a.conditional_assign(&small(3), 0);
+        assert_eq!(a, small(2));
+        a.conditional_assign(&small(3), 1);
+        assert_eq!(a, small(3));
+    }
+}
@@ -0,0 +1,7 @@
+mod edwards;
+mod field;
+mod scalar;
+
+pub(crate) use edwards::EdwardsPoint;
+pub(crate) use field::FieldElement;
+pub(crate) use scalar::Scalar;
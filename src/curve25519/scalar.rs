@@ -0,0 +1,189 @@
+use byteorder::{ByteOrder, LittleEndian};
+
+/// The order of the prime order subgroup, L = 2^252 + 27742317777372353535851937790883648493,
+/// in little endian 64 bit limbs.
+const L: [u64; 4] = [
+    0x5812631a5cf5d3ed,
+    0x14def9dea2f79cd6,
+    0x0000000000000000,
+    0x1000000000000000,
+];
+
+/// An integer modulo the group order L, stored fully reduced in four 64 bit limbs.
+///
+/// Reduction shifts the input in one bit at a time and conditionally subtracts L, so every
+/// operation takes the same time whatever the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Scalar([u64; 4]);
+
+impl Scalar {
+    /// Reduces 32 little endian bytes modulo L.
+    pub(crate) fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(bytes);
+
+        return Self::from_bytes_mod_order_wide(&wide);
+    }
+
+    /// Reduces 64 little endian bytes modulo L, as RFC 8032 does with SHA-512 outputs.
+    pub(crate) fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Self {
+        let mut limbs = [0u64; 8];
+        LittleEndian::read_u64_into(bytes, &mut limbs);
+
+        return Self::reduce(&limbs);
+    }
+
+    /// Decodes 32 little endian bytes, or returns `None` if they are not below L.
+    pub(crate) fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        LittleEndian::read_u64_into(bytes, &mut limbs);
+
+        let (_, borrow) = sub_l(&limbs);
+
+        if borrow == 0 {
+            return None;
+        }
+
+        return Some(Self(limbs));
+    }
+
+    pub(crate) fn to_bytes(self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        LittleEndian::write_u64_into(&self.0, &mut bytes);
+
+        return bytes;
+    }
+
+    /// Returns bit `i`, counting from the least significant.
+    pub(crate) fn bit(&self, i: usize) -> u8 {
+        return ((self.0[i / 64] >> (i % 64)) & 1) as u8;
+    }
+
+    /// Returns `self * rhs + addend`, the computation of S in a signature.
+    pub(crate) fn mul_add(&self, rhs: &Self, addend: &Self) -> Self {
+        let mut wide = [0u64; 8];
+
+        for i in 0..4 {
+            let mut carry = 0u128;
+
+            for j in 0..4 {
+                let t = self.0[i] as u128 * rhs.0[j] as u128 + wide[i + j] as u128 + carry;
+                wide[i + j] = t as u64;
+                carry = t >> 64;
+            }
+
+            wide[i + 4] = carry as u64;
+        }
+
+        // Both factors are below 2^253, so adding a third value below L cannot overflow 512 bits
+        let mut carry = 0u128;
+
+        for (i, limb) in wide.iter_mut().enumerate() {
+            let t = *limb as u128 + *addend.0.get(i).unwrap_or(&0) as u128 + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+
+        return Self::reduce(&wide);
+    }
+
+    fn reduce(wide: &[u64; 8]) -> Self {
+        let mut r = [0u64; 4];
+
+        for i in (0..512).rev() {
+            // r is below L < 2^253, so doubling it and adding a bit fits in 256 bits
+            let bit = (wide[i / 64] >> (i % 64)) & 1;
+            r[3] = (r[3] << 1) | (r[2] >> 63);
+            r[2] = (r[2] << 1) | (r[1] >> 63);
+            r[1] = (r[1] << 1) | (r[0] >> 63);
+            r[0] = (r[0] << 1) | bit;
+
+            let (difference, borrow) = sub_l(&r);
+            let keep = 0u64.wrapping_sub(borrow);
+
+            for j in 0..4 {
+                r[j] = (keep & r[j]) | (!keep & difference[j]);
+            }
+        }
+
+        return Self(r);
+    }
+}
+
+/// Returns `value - L` and the final borrow, which is 1 if `value` is below L.
+fn sub_l(value: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut difference = [0u64; 4];
+    let mut borrow = 0u64;
+
+    for i in 0..4 {
+        let (d, b1) = value[i].overflowing_sub(L[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        difference[i] = d;
+        borrow = (b1 | b2) as u64;
+    }
+
+    return (difference, borrow);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn scalar(s: &str) -> Scalar {
+        return Scalar::from_canonical_bytes(&hex::decode(s).unwrap().try_into().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_reduction() {
+        let wide = hex::decode(
+            "5818bcb570e1fe7ef4b9351d6f15e58a017dda875bdada7759f62601207f9cd9\
+             48774d33b8f3c2a08617120a422ed85c17bcadbb2929ceea66c545900ef73e67",
+        )
+        .unwrap();
+
+        assert_eq!(
+            hex::encode(Scalar::from_bytes_mod_order_wide(&wide.try_into().unwrap()).to_bytes()),
+            "3ad1c20215fe4904c2a226fb6daab4328a6849c40a5dbb0000273798c322c70e"
+        );
+
+        let mut l = [0u8; 32];
+        LittleEndian::write_u64_into(&L, &mut l);
+        assert_eq!(Scalar::from_bytes_mod_order(&l), Scalar::default());
+        assert_eq!(
+            Scalar::from_bytes_mod_order(&[0xff; 32]).to_bytes()[31],
+            0x0f
+        );
+    }
+
+    #[test]
+    fn test_canonical_bytes() {
+        let mut l = [0u8; 32];
+        LittleEndian::write_u64_into(&L, &mut l);
+        assert_eq!(Scalar::from_canonical_bytes(&l), None);
+
+        l[0] -= 1;
+        assert_eq!(Scalar::from_canonical_bytes(&l).unwrap().to_bytes(), l);
+        assert_eq!(Scalar::from_canonical_bytes(&[0xff; 32]), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = scalar("c5d1675ae40d1d844e7bbf9106de3a4ec8530fb1903cc4db02258717921a4801");
+        let y = scalar("be917dc4ac85eb5ffdcec9016dd1c5c7c0682390412a8cf79b37d0b11148b00a");
+        let z = scalar("594e519ae499312b29433b7dd8a97ff068defcba9755b6d5d00e84c524d67b06");
+
+        assert_eq!(
+            hex::encode(x.mul_add(&y, &z).to_bytes()),
+            "bfd91264acbfe1ee52b9db396c540e199fb70a990da530771250189ba49a7a06"
+        );
+        assert_eq!(x.mul_add(&y, &z), y.mul_add(&x, &z));
+
+        // (L - 1) x + x = 0
+        let minus_one = scalar("ecd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010");
+        assert_eq!(minus_one.mul_add(&x, &x), Scalar::default());
+        assert_eq!(minus_one.bit(0), 0);
+        assert_eq!(minus_one.bit(2), 1);
+        assert_eq!(minus_one.bit(252), 1);
+    }
+}
@@ -0,0 +1,203 @@
+use super::oid::ObjectIdentifier;
+use super::{Reader, Tag, Writer};
+use crate::error::VCryptoError;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The encoding of NULL, the parameters of RSA and of most hashes in PKCS#1.
+pub const NULL_PARAMETERS: &[u8] = &[0x05, 0x00];
+
+/// The X.509 AlgorithmIdentifier, an algorithm identifier followed by optional parameters whose
+/// type depends on the algorithm.
+///
+/// ```text
+/// AlgorithmIdentifier ::= SEQUENCE {
+///     algorithm   OBJECT IDENTIFIER,
+///     parameters  ANY DEFINED BY algorithm OPTIONAL }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlgorithmIdentifier<'a> {
+    oid: ObjectIdentifier,
+    parameters: Option<&'a [u8]>,
+}
+
+impl<'a> AlgorithmIdentifier<'a> {
+    /// Creates an identifier without parameters.
+    pub fn new(oid: ObjectIdentifier) -> Self {
+        return Self {
+            oid,
+            parameters: None,
+        };
+    }
+
+    /// Creates an identifier with parameters given as a complete DER element.
+    pub fn with_parameters(oid: ObjectIdentifier, parameters: &'a [u8]) -> Self {
+        return Self {
+            oid,
+            parameters: Some(parameters),
+        };
+    }
+
+    /// Decodes the whole of `data` as an AlgorithmIdentifier.
+    pub fn from_der(data: &'a [u8]) -> Result<Self, VCryptoError> {
+        let mut reader = Reader::new(data);
+        let identifier = Self::decode(&mut reader)?;
+        reader.finish()?;
+
+        return Ok(identifier);
+    }
+
+    /// Reads an AlgorithmIdentifier from `reader`. The parameters can be any single element.
+    pub fn decode(reader: &mut Reader<'a>) -> Result<Self, VCryptoError> {
+        let mut sequence = reader.read_sequence()?;
+        let oid = sequence.read_oid()?;
+
+        let parameters = if sequence.is_empty() {
+            None
+        } else {
+            Some(sequence.read_raw()?)
+        };
+
+        sequence.finish()?;
+
+        return Ok(Self { oid, parameters });
+    }
+
+    pub fn encode(&self, writer: &mut Writer) {
+        writer.write_sequence(|writer| {
+            writer.write_oid(&self.oid);
+
+            if let Some(parameters) = self.parameters {
+                writer.write_raw(parameters);
+            }
+        });
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut writer = Writer::new();
+        self.encode(&mut writer);
+
+        return writer.into_bytes();
+    }
+
+    pub fn oid(&self) -> ObjectIdentifier {
+        return self.oid;
+    }
+
+    /// Returns the parameters as a complete DER element.
+    pub fn parameters(&self) -> Option<&'a [u8]> {
+        return self.parameters;
+    }
+
+    /// Returns a reader over the parameters, to decode them.
+    pub fn parameters_reader(&self) -> Reader<'a> {
+        return Reader::new(self.parameters.unwrap_or(&[]));
+    }
+
+    /// Checks that there are no parameters, or that they are NULL, which specifications such as
+    /// RFC 5754 require readers to treat the same. Returns `VCryptoError::InvalidInput`
+    /// otherwise.
+    pub fn expect_no_parameters(&self) -> Result<(), VCryptoError> {
+        return match self.parameters {
+            None => Ok(()),
+            Some(parameters) if parameters == NULL_PARAMETERS => Ok(()),
+            Some(_) => Err(VCryptoError::InvalidInput),
+        };
+    }
+
+    /// Reads the parameters as a single element with the given tag and returns its contents.
+    /// Returns `VCryptoError::InvalidInput` if they are absent or of another type.
+    pub fn parameters_with_tag(&self, tag: Tag) -> Result<&'a [u8], VCryptoError> {
+        let parameters = self.parameters.ok_or(VCryptoError::InvalidInput)?;
+
+        return Reader::read_single(parameters, tag);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::oid;
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_subject_public_key_info() {
+        // SubjectPublicKeyInfo structures exported by OpenSSL
+        for (spki, algorithm, parameters) in [
+            (
+                "3059301306072a8648ce3d020106082a8648ce3d03010703420004af251162b6e9ee34c99a2bac7c\
+                 b4a26c788322a93322fb1a0895e32fdc614dc1a3f9f2608a21255fbf8c3384645f088446fc94df5d\
+                 0410a818b979179c282414",
+                oid::EC_PUBLIC_KEY,
+                "06082a8648ce3d030107",
+            ),
+            (
+                "305c300d06092a864886f70d0101010500034b00304802410098a6f5351162d9be5576c4ba41e978\
+                 a83914221922ca369b32c92a49ba87739fdfd74a35e2bb6319f63aa4f4742064920a0ed8f2e295ec\
+                 ecaf69eabd9bb211f30203010001",
+                oid::RSA_ENCRYPTION,
+                "0500",
+            ),
+        ] {
+            let spki = hex::decode(spki).unwrap();
+            let mut reader = Reader::new(&spki);
+            let mut sequence = reader.read_sequence().unwrap();
+            reader.finish().unwrap();
+
+            let identifier = AlgorithmIdentifier::decode(&mut sequence).unwrap();
+            let key = sequence.read_bit_string().unwrap().as_bytes().unwrap();
+            sequence.finish().unwrap();
+
+            assert_eq!(identifier.oid(), algorithm);
+            assert_eq!(hex::encode(identifier.parameters().unwrap()), parameters);
+
+            let mut writer = Writer::new();
+            writer.write_sequence(|writer| {
+                identifier.encode(writer);
+                writer.write_bit_string(key);
+            });
+            assert_eq!(writer.into_bytes(), spki);
+        }
+    }
+
+    #[test]
+    fn test_parameters() {
+        let identifier = AlgorithmIdentifier::new(oid::SHA256);
+        let der = identifier.to_der();
+
+        assert_eq!(hex::encode(&der), "300b0609608648016503040201");
+        assert_eq!(AlgorithmIdentifier::from_der(&der).unwrap(), identifier);
+        assert_eq!(identifier.expect_no_parameters(), Ok(()));
+        assert!(identifier.parameters_reader().is_empty());
+
+        let identifier = AlgorithmIdentifier::with_parameters(oid::SHA256, NULL_PARAMETERS);
+        assert_eq!(identifier.expect_no_parameters(), Ok(()));
+        assert_eq!(
+            identifier.parameters_with_tag(Tag::OCTET_STRING),
+            Err(VCryptoError::InvalidInput)
+        );
+
+        let identifier =
+            AlgorithmIdentifier::with_parameters(oid::AES_128_CBC, &[0x04, 0x01, 0xaa]);
+        assert_eq!(
+            identifier.expect_no_parameters(),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            identifier.parameters_with_tag(Tag::OCTET_STRING),
+            Ok(&[0xaa][..])
+        );
+
+        // Trailing data inside and after the sequence
+        for der in [
+            "300f060960864801650304020105000500",
+            "300b06096086480165030402010500",
+        ] {
+            assert_eq!(
+                AlgorithmIdentifier::from_der(&hex::decode(der).unwrap()).unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! A strict DER reader and writer for the types keys and signatures are made of: INTEGER,
+//! BIT STRING, OCTET STRING, OBJECT IDENTIFIER, NULL, SEQUENCE and context specific tags, with
+//! the X.509 AlgorithmIdentifier and a registry of object identifiers.
+
+mod algorithm_identifier;
+pub mod oid;
+mod reader;
+mod writer;
+
+pub use algorithm_identifier::*;
+pub use oid::ObjectIdentifier;
+pub use reader::*;
+pub use writer::*;
+
+use core::fmt;
+
+/// A single byte identifier octet: class, constructed bit and a tag number up to 30.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tag(u8);
+
+impl Tag {
+    pub const INTEGER: Tag = Tag(0x02);
+    pub const BIT_STRING: Tag = Tag(0x03);
+    pub const OCTET_STRING: Tag = Tag(0x04);
+    pub const NULL: Tag = Tag(0x05);
+    pub const OBJECT_IDENTIFIER: Tag = Tag(0x06);
+    pub const SEQUENCE: Tag = Tag(0x30);
+    pub const SET: Tag = Tag(0x31);
+
+    /// The constructed context specific tag `[number]`, used for explicit tagging and for
+    /// implicitly tagged constructed types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `number` is above 30.
+    pub const fn context(number: u8) -> Self {
+        assert!(number < 0x1f, "tag number too large");
+
+        return Tag(0xa0 | number);
+    }
+
+    /// The primitive context specific tag `[number]`, used for implicitly tagged primitive
+    /// types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `number` is above 30.
+    pub const fn context_primitive(number: u8) -> Self {
+        assert!(number < 0x1f, "tag number too large");
+
+        return Tag(0x80 | number);
+    }
+
+    /// Returns the identifier octet.
+    pub const fn value(&self) -> u8 {
+        return self.0;
+    }
+}
+
+impl fmt::Debug for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "Tag(0x{:02x})", self.0);
+    }
+}
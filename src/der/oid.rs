@@ -0,0 +1,479 @@
+use crate::error::VCryptoError;
+use crate::hashes::Algorithm;
+use core::fmt;
+use core::str::FromStr;
+
+/// The longest encoding accepted for an object identifier, well above any used in practice.
+pub const MAX_OID_LENGTH: usize = 32;
+
+/// An ASN.1 OBJECT IDENTIFIER, stored as the contents of its DER encoding.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectIdentifier {
+    bytes: [u8; MAX_OID_LENGTH],
+    len: usize,
+}
+
+/// Iterates over the arcs of an object identifier.
+struct Arcs<'a> {
+    bytes: &'a [u8],
+    started: bool,
+    second: Option<u64>,
+}
+
+impl ObjectIdentifier {
+    /// Encodes an object identifier from its arcs, for use in constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two arcs, the first is above 2, the second is above 39
+    /// while the first is 0 or 1, or the encoding is longer than `MAX_OID_LENGTH`.
+    pub const fn new(arcs: &[u64]) -> Self {
+        return match Self::encode(arcs) {
+            Some(oid) => oid,
+            None => panic!("invalid object identifier"),
+        };
+    }
+
+    /// Encodes an object identifier from its arcs. Returns `VCryptoError::InvalidInput` in the
+    /// cases where `new` panics.
+    pub fn from_arcs(arcs: &[u64]) -> Result<Self, VCryptoError> {
+        return Self::encode(arcs).ok_or(VCryptoError::InvalidInput);
+    }
+
+    /// Checks and wraps the contents of a DER encoded OBJECT IDENTIFIER. Returns
+    /// `VCryptoError::InvalidEncoding` if they are empty, too long, or a subidentifier is not
+    /// minimally encoded, is truncated or does not fit in 64 bits.
+    pub fn from_der_contents(contents: &[u8]) -> Result<Self, VCryptoError> {
+        if contents.is_empty() || contents.len() > MAX_OID_LENGTH {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        let mut start = true;
+        let mut value: u64 = 0;
+
+        for &byte in contents {
+            if (start && byte == 0x80) || value > u64::MAX >> 7 {
+                return Err(VCryptoError::InvalidEncoding);
+            }
+
+            value = value << 7 | (byte & 0x7f) as u64;
+            start = byte & 0x80 == 0;
+
+            if start {
+                value = 0;
+            }
+        }
+
+        if !start {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        let mut bytes = [0; MAX_OID_LENGTH];
+        bytes[..contents.len()].copy_from_slice(contents);
+
+        return Ok(Self {
+            bytes,
+            len: contents.len(),
+        });
+    }
+
+    pub fn as_der_contents(&self) -> &[u8] {
+        return &self.bytes[..self.len];
+    }
+
+    /// Returns the name of the identifier in the registry of this module, which follows the
+    /// short names of OpenSSL.
+    pub fn name(&self) -> Option<&'static str> {
+        return REGISTRY
+            .iter()
+            .find(|(oid, _)| oid == self)
+            .map(|(_, name)| *name);
+    }
+
+    /// Looks up an identifier of the registry by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        return REGISTRY
+            .iter()
+            .find(|(_, registered)| *registered == name)
+            .map(|(oid, _)| *oid);
+    }
+
+    const fn encode(arcs: &[u64]) -> Option<Self> {
+        if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] > 39) {
+            return None;
+        }
+
+        if arcs[1] > u64::MAX - 80 {
+            return None;
+        }
+
+        let mut bytes = [0; MAX_OID_LENGTH];
+        let mut len = 0;
+        let mut i = 1;
+
+        while i < arcs.len() {
+            let value = if i == 1 {
+                arcs[0] * 40 + arcs[1]
+            } else {
+                arcs[i]
+            };
+
+            let mut groups = 1;
+            while groups < 10 && value >> (7 * groups) != 0 {
+                groups += 1;
+            }
+
+            if len + groups > MAX_OID_LENGTH {
+                return None;
+            }
+
+            let mut group = groups;
+            while group > 0 {
+                group -= 1;
+
+                let continuation = if group == 0 { 0 } else { 0x80 };
+                bytes[len] = ((value >> (7 * group)) & 0x7f) as u8 | continuation;
+                len += 1;
+            }
+
+            i += 1;
+        }
+
+        return Some(Self { bytes, len });
+    }
+
+    fn arcs(&self) -> Arcs<'_> {
+        return Arcs {
+            bytes: self.as_der_contents(),
+            started: false,
+            second: None,
+        };
+    }
+}
+
+impl Iterator for Arcs<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if let Some(second) = self.second.take() {
+            return Some(second);
+        }
+
+        let started = self.started;
+        let end = self.bytes.iter().position(|byte| byte & 0x80 == 0)?;
+        let (subidentifier, rest) = self.bytes.split_at(end + 1);
+        let value = subidentifier
+            .iter()
+            .fold(0, |value, byte| value << 7 | (byte & 0x7f) as u64);
+
+        self.bytes = rest;
+        self.started = true;
+
+        if started {
+            return Some(value);
+        }
+
+        // The first subidentifier packs the first two arcs as 40 * first + second
+        let first_arc = (value / 40).min(2);
+        self.second = Some(value - 40 * first_arc);
+
+        return Some(first_arc);
+    }
+}
+
+impl fmt::Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl fmt::Debug for ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "ObjectIdentifier({})", self);
+    }
+}
+
+impl FromStr for ObjectIdentifier {
+    type Err = VCryptoError;
+
+    /// Parses the dotted decimal form, such as `2.16.840.1.101.3.4.2.1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Every arc after the first two takes at least one byte
+        let mut arcs = [0; MAX_OID_LENGTH + 1];
+        let mut count = 0;
+
+        for arc in s.split('.') {
+            if count == arcs.len() || arc.is_empty() || !arc.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(VCryptoError::InvalidInput);
+            }
+
+            arcs[count] = arc.parse().map_err(|_| VCryptoError::InvalidInput)?;
+            count += 1;
+        }
+
+        return Self::from_arcs(&arcs[..count]);
+    }
+}
+
+pub const MD5: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 5]);
+pub const SHA1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 14, 3, 2, 26]);
+pub const SHA224: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 4]);
+pub const SHA256: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 1]);
+pub const SHA384: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 2]);
+pub const SHA512: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 3]);
+pub const SHA512_224: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 5]);
+pub const SHA512_256: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 6]);
+pub const SHAKE128: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 11]);
+pub const SHAKE256: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 12]);
+pub const RIPEMD160: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 36, 3, 2, 1]);
+pub const SM3: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 156, 10197, 1, 401]);
+/// BLAKE2b with a 64 byte digest, from RFC 7693.
+pub const BLAKE2B512: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 3, 6, 1, 4, 1, 1722, 12, 2, 1, 16]);
+/// BLAKE2s with a 32 byte digest, from RFC 7693.
+pub const BLAKE2S256: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 3, 6, 1, 4, 1, 1722, 12, 2, 2, 8]);
+
+pub const HMAC_WITH_SHA1: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 7]);
+pub const HMAC_WITH_SHA224: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 8]);
+pub const HMAC_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 9]);
+pub const HMAC_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 10]);
+pub const HMAC_WITH_SHA512: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 11]);
+pub const HMAC_WITH_SHA512_224: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 12]);
+pub const HMAC_WITH_SHA512_256: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 2, 13]);
+pub const KMAC128: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 19]);
+pub const KMAC256: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 2, 20]);
+
+pub const AES_128_ECB: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 1]);
+pub const AES_128_CBC: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 2]);
+pub const AES_192_ECB: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 21]);
+pub const AES_192_CBC: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 22]);
+pub const AES_256_ECB: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 41]);
+pub const AES_256_CBC: ObjectIdentifier = ObjectIdentifier::new(&[2, 16, 840, 1, 101, 3, 4, 1, 42]);
+pub const BF_ECB: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 6, 1, 4, 1, 3029, 1, 1]);
+pub const BF_CBC: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 6, 1, 4, 1, 3029, 1, 2]);
+
+pub const PBKDF2: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 5, 12]);
+pub const PBES2: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 5, 13]);
+
+pub const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 1]);
+pub const RSAES_OAEP: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 7]);
+pub const MGF1: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 8]);
+pub const RSASSA_PSS: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 10]);
+pub const SHA1_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 5]);
+pub const SHA224_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 14]);
+pub const SHA256_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]);
+pub const SHA384_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 12]);
+pub const SHA512_WITH_RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 13]);
+pub const DH_KEY_AGREEMENT: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 3, 1]);
+pub const EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 2, 1]);
+pub const PRIME256V1: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 3, 1, 7]);
+pub const SECP384R1: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 132, 0, 34]);
+pub const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 4, 3, 2]);
+pub const ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new(&[1, 2, 840, 10045, 4, 3, 3]);
+pub const X25519: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 110]);
+pub const ED25519: ObjectIdentifier = ObjectIdentifier::new(&[1, 3, 101, 112]);
+
+/// Every identifier above with its OpenSSL short name. Twofish, BLAKE2bp, BLAKE2sp and BLAKE3
+/// have no registered identifier and so no entry.
+const REGISTRY: [(ObjectIdentifier, &str); 50] = [
+    (MD5, "MD5"),
+    (SHA1, "SHA1"),
+    (SHA224, "SHA224"),
+    (SHA256, "SHA256"),
+    (SHA384, "SHA384"),
+    (SHA512, "SHA512"),
+    (SHA512_224, "SHA512-224"),
+    (SHA512_256, "SHA512-256"),
+    (SHAKE128, "SHAKE128"),
+    (SHAKE256, "SHAKE256"),
+    (RIPEMD160, "RIPEMD160"),
+    (SM3, "SM3"),
+    (BLAKE2B512, "BLAKE2b512"),
+    (BLAKE2S256, "BLAKE2s256"),
+    (HMAC_WITH_SHA1, "hmacWithSHA1"),
+    (HMAC_WITH_SHA224, "hmacWithSHA224"),
+    (HMAC_WITH_SHA256, "hmacWithSHA256"),
+    (HMAC_WITH_SHA384, "hmacWithSHA384"),
+    (HMAC_WITH_SHA512, "hmacWithSHA512"),
+    (HMAC_WITH_SHA512_224, "hmacWithSHA512-224"),
+    (HMAC_WITH_SHA512_256, "hmacWithSHA512-256"),
+    (KMAC128, "KMAC128"),
+    (KMAC256, "KMAC256"),
+    (AES_128_ECB, "AES-128-ECB"),
+    (AES_128_CBC, "AES-128-CBC"),
+    (AES_192_ECB, "AES-192-ECB"),
+    (AES_192_CBC, "AES-192-CBC"),
+    (AES_256_ECB, "AES-256-ECB"),
+    (AES_256_CBC, "AES-256-CBC"),
+    (BF_ECB, "BF-ECB"),
+    (BF_CBC, "BF-CBC"),
+    (PBKDF2, "PBKDF2"),
+    (PBES2, "PBES2"),
+    (RSA_ENCRYPTION, "rsaEncryption"),
+    (RSAES_OAEP, "RSAES-OAEP"),
+    (MGF1, "MGF1"),
+    (RSASSA_PSS, "RSASSA-PSS"),
+    (SHA1_WITH_RSA_ENCRYPTION, "sha1WithRSAEncryption"),
+    (SHA224_WITH_RSA_ENCRYPTION, "sha224WithRSAEncryption"),
+    (SHA256_WITH_RSA_ENCRYPTION, "sha256WithRSAEncryption"),
+    (SHA384_WITH_RSA_ENCRYPTION, "sha384WithRSAEncryption"),
+    (SHA512_WITH_RSA_ENCRYPTION, "sha512WithRSAEncryption"),
+    (DH_KEY_AGREEMENT, "dhKeyAgreement"),
+    (EC_PUBLIC_KEY, "id-ecPublicKey"),
+    (PRIME256V1, "prime256v1"),
+    (SECP384R1, "secp384r1"),
+    (ECDSA_WITH_SHA256, "ecdsa-with-SHA256"),
+    (ECDSA_WITH_SHA384, "ecdsa-with-SHA384"),
+    (X25519, "X25519"),
+    (ED25519, "ED25519"),
+];
+
+/// The hashes of `hashes::Algorithm` that have an identifier.
+const HASHES: [(Algorithm, ObjectIdentifier); 12] = [
+    (Algorithm::MD5, MD5),
+    (Algorithm::SHA1, SHA1),
+    (Algorithm::SHA224, SHA224),
+    (Algorithm::SHA256, SHA256),
+    (Algorithm::SHA384, SHA384),
+    (Algorithm::SHA512, SHA512),
+    (Algorithm::SHA512_224, SHA512_224),
+    (Algorithm::SHA512_256, SHA512_256),
+    (Algorithm::RIPEMD160, RIPEMD160),
+    (Algorithm::SM3, SM3),
+    (Algorithm::BLAKE2b, BLAKE2B512),
+    (Algorithm::BLAKE2s, BLAKE2S256),
+];
+
+/// Returns the identifier of a hash. BLAKE2bp, BLAKE2sp and BLAKE3 have none.
+pub fn hash_oid(algorithm: Algorithm) -> Option<ObjectIdentifier> {
+    return HASHES
+        .iter()
+        .find(|(registered, _)| *registered == algorithm)
+        .map(|(_, oid)| *oid);
+}
+
+/// Returns the hash with the given identifier.
+pub fn hash_algorithm(oid: &ObjectIdentifier) -> Option<Algorithm> {
+    return HASHES
+        .iter()
+        .find(|(_, registered)| registered == oid)
+        .map(|(algorithm, _)| *algorithm);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(
+            SHA256.as_der_contents(),
+            [0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]
+        );
+        assert_eq!(
+            RSA_ENCRYPTION.as_der_contents(),
+            [0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01]
+        );
+        assert_eq!(X25519.as_der_contents(), [0x2b, 0x65, 0x6e]);
+        assert_eq!(
+            ObjectIdentifier::new(&[2, 999, 3]).as_der_contents(),
+            [0x88, 0x37, 0x03]
+        );
+        assert_eq!(
+            ObjectIdentifier::new(&[1, 2, u64::MAX]).as_der_contents(),
+            [0x2a, 0x81, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        for text in [
+            "1.2.840.113549.1.1.1",
+            "2.16.840.1.101.3.4.2.1",
+            "2.999.3",
+            "0.39",
+            "1.2.18446744073709551615",
+        ] {
+            let oid: ObjectIdentifier = text.parse().unwrap();
+
+            assert_eq!(oid.to_string(), text);
+            assert_eq!(
+                ObjectIdentifier::from_der_contents(oid.as_der_contents()).unwrap(),
+                oid
+            );
+        }
+
+        assert_eq!(format!("{:?}", SHA1), "ObjectIdentifier(1.3.14.3.2.26)");
+
+        for text in [
+            "",
+            "1",
+            "3.1",
+            "1.40",
+            "1..2",
+            "1.2.",
+            "1.-2",
+            "1.+2",
+            "1.2.18446744073709551616",
+        ] {
+            assert_eq!(
+                text.parse::<ObjectIdentifier>().unwrap_err(),
+                VCryptoError::InvalidInput
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        for contents in [
+            &[][..],
+            // Leading 0x80 in a subidentifier
+            &[0x2a, 0x80, 0x01],
+            // Truncated subidentifier
+            &[0x2a, 0x86],
+            // Subidentifier above 64 bits
+            &[
+                0x2a, 0x82, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+            ],
+            &[0x2a; MAX_OID_LENGTH + 1],
+        ] {
+            assert_eq!(
+                ObjectIdentifier::from_der_contents(contents).unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        for (oid, name) in REGISTRY {
+            assert_eq!(ObjectIdentifier::from_name(name), Some(oid));
+            assert_eq!(oid.name(), Some(name));
+        }
+
+        assert_eq!(ObjectIdentifier::from_name("sha256"), None);
+        assert_eq!(hash_oid(Algorithm::SHA384), Some(SHA384));
+        assert_eq!(hash_oid(Algorithm::BLAKE3), None);
+        assert_eq!(hash_algorithm(&SM3), Some(Algorithm::SM3));
+        assert_eq!(hash_algorithm(&AES_128_CBC), None);
+        assert_eq!(ObjectIdentifier::from_name("BF-ECB"), Some(BF_ECB));
+        assert_eq!(BF_ECB.to_string(), "1.3.6.1.4.1.3029.1.1");
+    }
+}
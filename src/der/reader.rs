@@ -0,0 +1,460 @@
+use super::oid::ObjectIdentifier;
+use super::Tag;
+use crate::error::VCryptoError;
+
+/// The longest length field accepted, enough for contents of up to 4 GiB.
+const MAX_LENGTH_BYTES: usize = 4;
+
+/// A DER decoder reading elements one after the other from a slice.
+///
+/// Decoding is strict: lengths have to be definite and minimally encoded, integers minimally
+/// encoded, and every other type in its single DER form. Malformed input is reported with
+/// `VCryptoError::InvalidEncoding`, and unexpected tags with `VCryptoError::InvalidInput`;
+/// nothing panics whatever the input.
+#[derive(Clone, Copy, Debug)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+/// The contents of a BIT STRING.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitString<'a> {
+    unused_bits: u8,
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        return Self { data };
+    }
+
+    /// Decodes `data` as a single element with the given tag and returns its contents. Returns
+    /// `VCryptoError::InvalidEncoding` if anything follows it.
+    pub fn read_single(data: &'a [u8], tag: Tag) -> Result<&'a [u8], VCryptoError> {
+        let mut reader = Self::new(data);
+        let contents = reader.read(tag)?;
+        reader.finish()?;
+
+        return Ok(contents);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.data.is_empty();
+    }
+
+    /// Returns the data not read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        return self.data;
+    }
+
+    /// Checks that everything has been read, returns `VCryptoError::InvalidEncoding` otherwise.
+    pub fn finish(self) -> Result<(), VCryptoError> {
+        if !self.data.is_empty() {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        return Ok(());
+    }
+
+    /// Returns the tag of the next element without reading it, or `None` at the end of the data.
+    pub fn peek_tag(&self) -> Option<Tag> {
+        return self.data.first().map(|&tag| Tag(tag));
+    }
+
+    /// Reads the next element, whatever its tag, and returns its tag and contents.
+    pub fn read_any(&mut self) -> Result<(Tag, &'a [u8]), VCryptoError> {
+        let (tag, contents, _) = self.read_element()?;
+
+        return Ok((tag, contents));
+    }
+
+    /// Reads the next element, whatever its tag, and returns its whole encoding.
+    pub fn read_raw(&mut self) -> Result<&'a [u8], VCryptoError> {
+        let (_, _, encoding) = self.read_element()?;
+
+        return Ok(encoding);
+    }
+
+    /// Reads an element with the given tag and returns its contents. Returns
+    /// `VCryptoError::InvalidInput` if the next element has another tag.
+    pub fn read(&mut self, tag: Tag) -> Result<&'a [u8], VCryptoError> {
+        let mut reader = *self;
+        let (next_tag, contents) = reader.read_any()?;
+
+        if next_tag != tag {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        *self = reader;
+
+        return Ok(contents);
+    }
+
+    /// Reads an element if the next one has the given tag.
+    pub fn read_optional(&mut self, tag: Tag) -> Result<Option<&'a [u8]>, VCryptoError> {
+        if self.peek_tag() != Some(tag) {
+            return Ok(None);
+        }
+
+        return self.read(tag).map(Some);
+    }
+
+    /// Reads a SEQUENCE and returns a reader over its elements.
+    pub fn read_sequence(&mut self) -> Result<Reader<'a>, VCryptoError> {
+        return self.read(Tag::SEQUENCE).map(Reader::new);
+    }
+
+    /// Reads an explicitly tagged `[number]` element and returns a reader over its contents.
+    /// Returns `VCryptoError::InvalidInput` if `number` is above 30, which needs more than one
+    /// identifier octet.
+    pub fn read_explicit(&mut self, number: u8) -> Result<Reader<'a>, VCryptoError> {
+        return self.read(explicit_tag(number)?).map(Reader::new);
+    }
+
+    /// Reads an explicitly tagged `[number]` element if there is one, with the same limit on
+    /// `number` as `read_explicit`.
+    pub fn read_optional_explicit(
+        &mut self,
+        number: u8,
+    ) -> Result<Option<Reader<'a>>, VCryptoError> {
+        return Ok(self.read_optional(explicit_tag(number)?)?.map(Reader::new));
+    }
+
+    /// Reads an INTEGER and returns its minimal two's complement encoding.
+    pub fn read_integer(&mut self) -> Result<&'a [u8], VCryptoError> {
+        let contents = self.read(Tag::INTEGER)?;
+
+        match contents {
+            [] => return Err(VCryptoError::InvalidEncoding),
+            [0x00, next, ..] if next & 0x80 == 0 => return Err(VCryptoError::InvalidEncoding),
+            [0xff, next, ..] if next & 0x80 != 0 => return Err(VCryptoError::InvalidEncoding),
+            _ => {}
+        }
+
+        return Ok(contents);
+    }
+
+    /// Reads a non negative INTEGER and returns its big endian magnitude without leading zeros,
+    /// except for zero itself which is a single zero byte. Returns `VCryptoError::InvalidInput`
+    /// if it is negative.
+    pub fn read_unsigned_integer(&mut self) -> Result<&'a [u8], VCryptoError> {
+        let mut reader = *self;
+
+        let magnitude = match reader.read_integer()? {
+            [first, ..] if first & 0x80 != 0 => return Err(VCryptoError::InvalidInput),
+            [0x00, rest @ ..] if !rest.is_empty() => rest,
+            contents => contents,
+        };
+
+        *self = reader;
+
+        return Ok(magnitude);
+    }
+
+    /// Reads a non negative INTEGER that fits in a `u64`. Returns `VCryptoError::InvalidInput`
+    /// if it is negative or too large.
+    pub fn read_u64(&mut self) -> Result<u64, VCryptoError> {
+        let mut reader = *self;
+        let magnitude = reader.read_unsigned_integer()?;
+
+        if magnitude.len() > 8 {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        *self = reader;
+
+        return Ok(magnitude
+            .iter()
+            .fold(0, |value, &byte| value << 8 | byte as u64));
+    }
+
+    /// Reads a BIT STRING, whose unused bits have to be zero.
+    pub fn read_bit_string(&mut self) -> Result<BitString<'a>, VCryptoError> {
+        let contents = self.read(Tag::BIT_STRING)?;
+
+        let (unused_bits, bytes) = match contents {
+            [unused_bits, bytes @ ..] => (*unused_bits, bytes),
+            [] => return Err(VCryptoError::InvalidEncoding),
+        };
+
+        let valid = match bytes.last() {
+            Some(last) => unused_bits < 8 && last & ((1 << unused_bits) - 1) == 0,
+            None => unused_bits == 0,
+        };
+
+        if !valid {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        return Ok(BitString { unused_bits, bytes });
+    }
+
+    pub fn read_octet_string(&mut self) -> Result<&'a [u8], VCryptoError> {
+        return self.read(Tag::OCTET_STRING);
+    }
+
+    pub fn read_null(&mut self) -> Result<(), VCryptoError> {
+        if !self.read(Tag::NULL)?.is_empty() {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        return Ok(());
+    }
+
+    pub fn read_oid(&mut self) -> Result<ObjectIdentifier, VCryptoError> {
+        return ObjectIdentifier::from_der_contents(self.read(Tag::OBJECT_IDENTIFIER)?);
+    }
+
+    /// Returns the tag, the contents and the whole encoding of the next element.
+    fn read_element(&mut self) -> Result<(Tag, &'a [u8], &'a [u8]), VCryptoError> {
+        let (&tag, rest) = self
+            .data
+            .split_first()
+            .ok_or(VCryptoError::InvalidEncoding)?;
+
+        // Tag numbers above 30 take more bytes, nothing read here uses them
+        if tag & 0x1f == 0x1f {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        let (&first, rest) = rest.split_first().ok_or(VCryptoError::InvalidEncoding)?;
+
+        let (length, rest) = if first < 0x80 {
+            (first as usize, rest)
+        } else {
+            let count = (first & 0x7f) as usize;
+
+            // 0x80 is the indefinite length of BER
+            if count == 0 || count > MAX_LENGTH_BYTES || rest.len() < count {
+                return Err(VCryptoError::InvalidEncoding);
+            }
+
+            let (length_bytes, rest) = rest.split_at(count);
+            let length = length_bytes
+                .iter()
+                .fold(0, |length, &byte| length << 8 | byte as usize);
+
+            // The long form is only allowed for lengths that need it, without leading zeros
+            if length < 0x80 || length_bytes[0] == 0 {
+                return Err(VCryptoError::InvalidEncoding);
+            }
+
+            (length, rest)
+        };
+
+        if rest.len() < length {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        let header_len = self.data.len() - rest.len();
+        let (encoding, remaining) = self.data.split_at(header_len + length);
+        self.data = remaining;
+
+        return Ok((Tag(tag), &encoding[header_len..], encoding));
+    }
+}
+
+impl<'a> BitString<'a> {
+    pub fn unused_bits(&self) -> u8 {
+        return self.unused_bits;
+    }
+
+    /// Returns the bytes holding the bits, the last one padded with `unused_bits` zeros.
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        return self.bytes;
+    }
+
+    /// Returns the bytes if the number of bits is a multiple of 8, as for keys and signatures.
+    pub fn as_bytes(&self) -> Option<&'a [u8]> {
+        if self.unused_bits != 0 {
+            return None;
+        }
+
+        return Some(self.bytes);
+    }
+}
+
+fn explicit_tag(number: u8) -> Result<Tag, VCryptoError> {
+    if number > 30 {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    return Ok(Tag::context(number));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_lengths() {
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0xaa; 0x80]);
+        assert_eq!(
+            Reader::read_single(&long, Tag::OCTET_STRING).unwrap(),
+            &[0xaa; 0x80]
+        );
+
+        for data in [
+            // Indefinite length
+            &[0x30, 0x80, 0x00, 0x00][..],
+            // Long form for a short length
+            &[0x04, 0x81, 0x01, 0xaa],
+            // Leading zero in the length
+            &[0x04, 0x82, 0x00, 0x80],
+            // Too many length bytes
+            &[0x04, 0x85, 0x01, 0x00, 0x00, 0x00, 0x00],
+            // Truncated contents and headers
+            &[0x04, 0x02, 0xaa],
+            &[0x04, 0x81],
+            &[0x04],
+            &[],
+            // High tag number form
+            &[0x1f, 0x20, 0x00],
+        ] {
+            assert_eq!(
+                Reader::new(data).read_any().unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+
+        assert_eq!(
+            Reader::read_single(&[0x05, 0x00, 0x05, 0x00], Tag::NULL).unwrap_err(),
+            VCryptoError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        for (data, expected) in [
+            (&[0x02, 0x01, 0x00][..], 0),
+            (&[0x02, 0x01, 0x7f], 0x7f),
+            (&[0x02, 0x02, 0x00, 0x80], 0x80),
+            (&[0x02, 0x03, 0x01, 0x00, 0x01], 0x10001),
+            (
+                &[
+                    0x02, 0x09, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                ],
+                u64::MAX,
+            ),
+        ] {
+            assert_eq!(Reader::new(data).read_u64().unwrap(), expected);
+        }
+
+        assert_eq!(
+            Reader::new(&[0x02, 0x02, 0x00, 0x80])
+                .read_unsigned_integer()
+                .unwrap(),
+            &[0x80]
+        );
+        assert_eq!(
+            Reader::new(&[0x02, 0x02, 0xff, 0x7f])
+                .read_integer()
+                .unwrap(),
+            &[0xff, 0x7f]
+        );
+
+        for data in [
+            &[0x02, 0x00][..],
+            &[0x02, 0x02, 0x00, 0x7f],
+            &[0x02, 0x02, 0xff, 0x80],
+        ] {
+            assert_eq!(
+                Reader::new(data).read_integer().unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+
+        // Negative and too large values
+        for data in [
+            &[0x02, 0x01, 0x80][..],
+            &[
+                0x02, 0x09, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+        ] {
+            let mut reader = Reader::new(data);
+
+            assert_eq!(reader.read_u64().unwrap_err(), VCryptoError::InvalidInput);
+            assert_eq!(reader.remaining(), data);
+        }
+    }
+
+    #[test]
+    fn test_bit_strings() {
+        let bits = Reader::new(&[0x03, 0x03, 0x00, 0x12, 0x34])
+            .read_bit_string()
+            .unwrap();
+        assert_eq!(bits.as_bytes(), Some(&[0x12, 0x34][..]));
+
+        let bits = Reader::new(&[0x03, 0x02, 0x04, 0xf0])
+            .read_bit_string()
+            .unwrap();
+        assert_eq!((bits.unused_bits(), bits.raw_bytes()), (4, &[0xf0][..]));
+        assert_eq!(bits.as_bytes(), None);
+
+        for data in [
+            &[0x03, 0x00][..],
+            &[0x03, 0x01, 0x01],
+            &[0x03, 0x02, 0x08, 0x00],
+            &[0x03, 0x02, 0x04, 0xf8],
+        ] {
+            assert_eq!(
+                Reader::new(data).read_bit_string().unwrap_err(),
+                VCryptoError::InvalidEncoding
+            );
+        }
+    }
+
+    #[test]
+    fn test_structures() {
+        // SEQUENCE { INTEGER 1, [0] { NULL }, OCTET STRING 'ab' }
+        let data = [
+            0x30, 0x0b, 0x02, 0x01, 0x01, 0xa0, 0x02, 0x05, 0x00, 0x04, 0x02, 0x61, 0x62,
+        ];
+        let mut outer = Reader::new(&data);
+        let mut sequence = outer.read_sequence().unwrap();
+        outer.finish().unwrap();
+
+        assert_eq!(sequence.peek_tag(), Some(Tag::INTEGER));
+        assert_eq!(
+            sequence.read_octet_string().unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+        assert_eq!(sequence.read_u64().unwrap(), 1);
+        assert!(sequence.read_optional_explicit(1).unwrap().is_none());
+
+        let mut explicit = sequence.read_optional_explicit(0).unwrap().unwrap();
+        explicit.read_null().unwrap();
+        explicit.finish().unwrap();
+
+        assert_eq!(sequence.read_raw().unwrap(), &[0x04, 0x02, 0x61, 0x62]);
+        assert!(sequence.is_empty());
+        assert_eq!(sequence.peek_tag(), None);
+
+        assert_eq!(
+            Reader::new(&[0x05, 0x01, 0x00]).read_null().unwrap_err(),
+            VCryptoError::InvalidEncoding
+        );
+    }
+
+    #[test]
+    fn test_explicit_tag_numbers() {
+        let data = [0xbe, 0x02, 0x05, 0x00];
+
+        assert!(Reader::new(&data).read_explicit(30).is_ok());
+
+        for number in [31, 0x7f, u8::MAX] {
+            assert_eq!(
+                Reader::new(&data).read_explicit(number).unwrap_err(),
+                VCryptoError::InvalidInput
+            );
+            assert_eq!(
+                Reader::new(&data)
+                    .read_optional_explicit(number)
+                    .unwrap_err(),
+                VCryptoError::InvalidInput
+            );
+        }
+    }
+}
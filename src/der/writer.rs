@@ -0,0 +1,193 @@
+use super::oid::ObjectIdentifier;
+use super::Tag;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A DER encoder appending elements to a buffer. Constructed elements are written by passing a
+/// closure that writes their contents.
+#[derive(Clone, Debug, Default)]
+pub struct Writer {
+    buffer: Vec<u8>,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        return Self { buffer: Vec::new() };
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        return self.buffer;
+    }
+
+    /// Writes an element with the given tag and contents.
+    pub fn write(&mut self, tag: Tag, contents: &[u8]) {
+        self.buffer.push(tag.0);
+
+        if contents.len() < 0x80 {
+            self.buffer.push(contents.len() as u8);
+        } else {
+            let length = (contents.len() as u64).to_be_bytes();
+            let skip = length.iter().take_while(|&&byte| byte == 0).count();
+
+            self.buffer.push(0x80 | (length.len() - skip) as u8);
+            self.buffer.extend_from_slice(&length[skip..]);
+        }
+
+        self.buffer.extend_from_slice(contents);
+    }
+
+    /// Appends an element that is already encoded.
+    pub fn write_raw(&mut self, encoding: &[u8]) {
+        self.buffer.extend_from_slice(encoding);
+    }
+
+    /// Writes a constructed element whose contents are written by `contents`.
+    pub fn write_constructed(&mut self, tag: Tag, contents: impl FnOnce(&mut Writer)) {
+        let mut writer = Writer::new();
+        contents(&mut writer);
+
+        self.write(tag, &writer.buffer);
+    }
+
+    pub fn write_sequence(&mut self, contents: impl FnOnce(&mut Writer)) {
+        self.write_constructed(Tag::SEQUENCE, contents);
+    }
+
+    /// Writes an explicitly tagged `[number]` element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `number` is above 30.
+    pub fn write_explicit(&mut self, number: u8, contents: impl FnOnce(&mut Writer)) {
+        self.write_constructed(Tag::context(number), contents);
+    }
+
+    /// Writes a non negative INTEGER from its big endian magnitude, which may have leading zeros.
+    pub fn write_unsigned_integer(&mut self, magnitude: &[u8]) {
+        let skip = magnitude.iter().take_while(|&&byte| byte == 0).count();
+        let magnitude = &magnitude[skip..];
+
+        match magnitude.first() {
+            Some(first) if first & 0x80 == 0 => self.write(Tag::INTEGER, magnitude),
+            _ => {
+                // Zero, or a value whose top bit would make it negative
+                let mut contents = Vec::with_capacity(magnitude.len() + 1);
+                contents.push(0);
+                contents.extend_from_slice(magnitude);
+
+                self.write(Tag::INTEGER, &contents);
+            }
+        }
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_unsigned_integer(&value.to_be_bytes());
+    }
+
+    /// Writes a BIT STRING holding whole bytes.
+    pub fn write_bit_string(&mut self, bytes: &[u8]) {
+        let mut contents = Vec::with_capacity(bytes.len() + 1);
+        contents.push(0);
+        contents.extend_from_slice(bytes);
+
+        self.write(Tag::BIT_STRING, &contents);
+    }
+
+    pub fn write_octet_string(&mut self, bytes: &[u8]) {
+        self.write(Tag::OCTET_STRING, bytes);
+    }
+
+    pub fn write_null(&mut self) {
+        self.write(Tag::NULL, &[]);
+    }
+
+    pub fn write_oid(&mut self, oid: &ObjectIdentifier) {
+        self.write(Tag::OBJECT_IDENTIFIER, oid.as_der_contents());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Reader;
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_integers() {
+        for (magnitude, expected) in [
+            (&[][..], &[0x02, 0x01, 0x00][..]),
+            (&[0x00, 0x00], &[0x02, 0x01, 0x00]),
+            (&[0x7f], &[0x02, 0x01, 0x7f]),
+            (&[0x00, 0x80], &[0x02, 0x02, 0x00, 0x80]),
+            (&[0x01, 0x00, 0x01], &[0x02, 0x03, 0x01, 0x00, 0x01]),
+        ] {
+            let mut writer = Writer::new();
+            writer.write_unsigned_integer(magnitude);
+            let encoding = writer.into_bytes();
+
+            assert_eq!(encoding, expected);
+
+            let mut reader = Reader::new(&encoding);
+            let decoded = reader.read_unsigned_integer().unwrap();
+            assert_eq!(
+                decoded
+                    .iter()
+                    .skip_while(|&&byte| byte == 0)
+                    .collect::<Vec<_>>(),
+                magnitude
+                    .iter()
+                    .skip_while(|&&byte| byte == 0)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        let mut writer = Writer::new();
+        writer.write_u64(u64::MAX);
+        assert_eq!(
+            writer.into_bytes(),
+            [0x02, 0x09, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_structures() {
+        let mut writer = Writer::new();
+        writer.write_sequence(|writer| {
+            writer.write_u64(1);
+            writer.write_explicit(0, |writer| writer.write_null());
+            writer.write_octet_string(b"ab");
+            writer.write_bit_string(&[0x12]);
+        });
+
+        assert_eq!(
+            writer.into_bytes(),
+            [
+                0x30, 0x0f, 0x02, 0x01, 0x01, 0xa0, 0x02, 0x05, 0x00, 0x04, 0x02, 0x61, 0x62, 0x03,
+                0x02, 0x00, 0x12
+            ]
+        );
+    }
+
+    #[test]
+    fn test_long_lengths() {
+        for (length, header) in [
+            (0x7f, &[0x04, 0x7f][..]),
+            (0x80, &[0x04, 0x81, 0x80]),
+            (0xff, &[0x04, 0x81, 0xff]),
+            (0x100, &[0x04, 0x82, 0x01, 0x00]),
+            (0x10000, &[0x04, 0x83, 0x01, 0x00, 0x00]),
+        ] {
+            let contents = vec![0x5a; length];
+            let mut writer = Writer::new();
+            writer.write_octet_string(&contents);
+            let encoding = writer.into_bytes();
+
+            assert_eq!(&encoding[..header.len()], header);
+            assert_eq!(
+                Reader::read_single(&encoding, Tag::OCTET_STRING).unwrap(),
+                &contents[..]
+            );
+        }
+    }
+}
@@ -0,0 +1,454 @@
+use crate::bignum::{BigUint, Montgomery};
+use crate::error::VCryptoError;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The safe prime groups of RFC 3526 and RFC 7919. They all use the generator 2, which
+/// generates the subgroup of order q = (p - 1) / 2.
+///
+/// The FFDHE groups are the ones to use for new protocols, the MODP groups are there for peers
+/// that only know them, such as IKE and SSH implementations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NamedGroup {
+    Modp1536,
+    Modp2048,
+    Modp3072,
+    Modp4096,
+    Modp6144,
+    Modp8192,
+    Ffdhe2048,
+    Ffdhe3072,
+    Ffdhe4096,
+    Ffdhe6144,
+    Ffdhe8192,
+}
+
+/// The parameters of a named group, with the precomputed constants exponentiation needs.
+#[derive(Clone)]
+pub struct Group {
+    name: NamedGroup,
+    p: BigUint,
+    q: BigUint,
+    g: BigUint,
+    montgomery: Montgomery,
+}
+
+impl NamedGroup {
+    /// Returns the size of the prime in bits.
+    pub fn bits(&self) -> usize {
+        return match self {
+            NamedGroup::Modp1536 => 1536,
+            NamedGroup::Modp2048 | NamedGroup::Ffdhe2048 => 2048,
+            NamedGroup::Modp3072 | NamedGroup::Ffdhe3072 => 3072,
+            NamedGroup::Modp4096 | NamedGroup::Ffdhe4096 => 4096,
+            NamedGroup::Modp6144 | NamedGroup::Ffdhe6144 => 6144,
+            NamedGroup::Modp8192 | NamedGroup::Ffdhe8192 => 8192,
+        };
+    }
+
+    fn prime_hex(&self) -> &'static str {
+        return match self {
+            NamedGroup::Modp1536 => MODP_1536,
+            NamedGroup::Modp2048 => MODP_2048,
+            NamedGroup::Modp3072 => MODP_3072,
+            NamedGroup::Modp4096 => MODP_4096,
+            NamedGroup::Modp6144 => MODP_6144,
+            NamedGroup::Modp8192 => MODP_8192,
+            NamedGroup::Ffdhe2048 => FFDHE2048,
+            NamedGroup::Ffdhe3072 => FFDHE3072,
+            NamedGroup::Ffdhe4096 => FFDHE4096,
+            NamedGroup::Ffdhe6144 => FFDHE6144,
+            NamedGroup::Ffdhe8192 => FFDHE8192,
+        };
+    }
+}
+
+impl Group {
+    pub fn new(name: NamedGroup) -> Self {
+        let p = BigUint::from_be_bytes(&decode_hex(name.prime_hex()));
+        let q = &(&p - &BigUint::one()) >> 1;
+        let montgomery = Montgomery::new(&p).expect("the primes are odd");
+
+        return Self {
+            name,
+            p,
+            q,
+            g: BigUint::from(2u64),
+            montgomery,
+        };
+    }
+
+    pub fn name(&self) -> NamedGroup {
+        return self.name;
+    }
+
+    /// The prime p.
+    pub fn p(&self) -> &BigUint {
+        return &self.p;
+    }
+
+    /// The order q of the subgroup generated by g.
+    pub fn q(&self) -> &BigUint {
+        return &self.q;
+    }
+
+    /// The generator g.
+    pub fn g(&self) -> &BigUint {
+        return &self.g;
+    }
+
+    /// Returns the size of the prime in bytes, which is the length of public values and shared
+    /// secrets.
+    pub fn size(&self) -> usize {
+        return self.p.bits().div_ceil(8);
+    }
+
+    pub(crate) fn montgomery(&self) -> &Montgomery {
+        return &self.montgomery;
+    }
+
+    /// Performs the full public key validation of SP 800-56A section 5.6.2.3.1: `y` has to be
+    /// between 2 and p - 2 and in the subgroup of order q. Returns `VCryptoError::InvalidKey`
+    /// otherwise.
+    pub(crate) fn validate(&self, y: &BigUint) -> Result<(), VCryptoError> {
+        let p_minus_one = &self.p - &BigUint::one();
+
+        if y <= &BigUint::one() || y >= &p_minus_one {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        if !self.montgomery.pow_vartime(y, &self.q).is_one() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(());
+    }
+}
+
+impl From<NamedGroup> for Group {
+    fn from(name: NamedGroup) -> Self {
+        return Self::new(name);
+    }
+}
+
+/// Decodes the uppercase hexadecimal primes below.
+fn decode_hex(hex: &str) -> Vec<u8> {
+    let digit = |c: u8| -> u8 {
+        return match c {
+            b'0'..=b'9' => c - b'0',
+            _ => c - b'A' + 10,
+        };
+    };
+
+    return hex
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| return digit(pair[0]) << 4 | digit(pair[1]))
+        .collect();
+}
+
+// RFC 3526 sections 2 to 7
+const MODP_1536: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA237327FFFFFFFFFFFFFFFF";
+
+const MODP_2048: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+3995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF";
+
+const MODP_3072: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A93AD2CAFFFFFFFFFFFFFFFF";
+
+const MODP_4096: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7\
+88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA2583E9CA2AD44CE8\
+DBBBC2DB04DE8EF92E8EFC141FBECAA6287C59474E6BC05D99B2964FA090C3A2\
+233BA186515BE7ED1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9\
+93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C934063199FFFFFFFFFFFFFFFF";
+
+const MODP_6144: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7\
+88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA2583E9CA2AD44CE8\
+DBBBC2DB04DE8EF92E8EFC141FBECAA6287C59474E6BC05D99B2964FA090C3A2\
+233BA186515BE7ED1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9\
+93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C93402849236C3FAB4D27C7026\
+C1D4DCB2602646DEC9751E763DBA37BDF8FF9406AD9E530EE5DB382F413001AE\
+B06A53ED9027D831179727B0865A8918DA3EDBEBCF9B14ED44CE6CBACED4BB1B\
+DB7F1447E6CC254B332051512BD7AF426FB8F401378CD2BF5983CA01C64B92EC\
+F032EA15D1721D03F482D7CE6E74FEF6D55E702F46980C82B5A84031900B1C9E\
+59E7C97FBEC7E8F323A97A7E36CC88BE0F1D45B7FF585AC54BD407B22B4154AA\
+CC8F6D7EBF48E1D814CC5ED20F8037E0A79715EEF29BE32806A1D58BB7C5DA76\
+F550AA3D8A1FBFF0EB19CCB1A313D55CDA56C9EC2EF29632387FE8D76E3C0468\
+043E8F663F4860EE12BF2D5B0B7474D6E694F91E6DCC4024FFFFFFFFFFFFFFFF";
+
+const MODP_8192: &str = "\
+FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74\
+020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F1437\
+4FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7ED\
+EE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF05\
+98DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB\
+9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3B\
+E39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF695581718\
+3995497CEA956AE515D2261898FA051015728E5A8AAAC42DAD33170D04507A33\
+A85521ABDF1CBA64ECFB850458DBEF0A8AEA71575D060C7DB3970F85A6E1E4C7\
+ABF5AE8CDB0933D71E8C94E04A25619DCEE3D2261AD2EE6BF12FFA06D98A0864\
+D87602733EC86A64521F2B18177B200CBBE117577A615D6C770988C0BAD946E2\
+08E24FA074E5AB3143DB5BFCE0FD108E4B82D120A92108011A723C12A787E6D7\
+88719A10BDBA5B2699C327186AF4E23C1A946834B6150BDA2583E9CA2AD44CE8\
+DBBBC2DB04DE8EF92E8EFC141FBECAA6287C59474E6BC05D99B2964FA090C3A2\
+233BA186515BE7ED1F612970CEE2D7AFB81BDD762170481CD0069127D5B05AA9\
+93B4EA988D8FDDC186FFB7DC90A6C08F4DF435C93402849236C3FAB4D27C7026\
+C1D4DCB2602646DEC9751E763DBA37BDF8FF9406AD9E530EE5DB382F413001AE\
+B06A53ED9027D831179727B0865A8918DA3EDBEBCF9B14ED44CE6CBACED4BB1B\
+DB7F1447E6CC254B332051512BD7AF426FB8F401378CD2BF5983CA01C64B92EC\
+F032EA15D1721D03F482D7CE6E74FEF6D55E702F46980C82B5A84031900B1C9E\
+59E7C97FBEC7E8F323A97A7E36CC88BE0F1D45B7FF585AC54BD407B22B4154AA\
+CC8F6D7EBF48E1D814CC5ED20F8037E0A79715EEF29BE32806A1D58BB7C5DA76\
+F550AA3D8A1FBFF0EB19CCB1A313D55CDA56C9EC2EF29632387FE8D76E3C0468\
+043E8F663F4860EE12BF2D5B0B7474D6E694F91E6DBE115974A3926F12FEE5E4\
+38777CB6A932DF8CD8BEC4D073B931BA3BC832B68D9DD300741FA7BF8AFC47ED\
+2576F6936BA424663AAB639C5AE4F5683423B4742BF1C978238F16CBE39D652D\
+E3FDB8BEFC848AD922222E04A4037C0713EB57A81A23F0C73473FC646CEA306B\
+4BCBC8862F8385DDFA9D4B7FA2C087E879683303ED5BDD3A062B3CF5B3A278A6\
+6D2A13F83F44F82DDF310EE074AB6A364597E899A0255DC164F31CC50846851D\
+F9AB48195DED7EA1B1D510BD7EE74D73FAF36BC31ECFA268359046F4EB879F92\
+4009438B481C6CD7889A002ED5EE382BC9190DA6FC026E479558E4475677E9AA\
+9E3050E2765694DFC81F56E880B96E7160C980DD98EDD3DFFFFFFFFFFFFFFFFF";
+
+// RFC 7919 appendix A
+const FFDHE2048: &str = "\
+FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695\
+A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A\
+D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935\
+984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A\
+BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4\
+AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61\
+9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005\
+C58EF1837D1683B2C6F34A26C1B2EFFA886B423861285C97FFFFFFFFFFFFFFFF";
+
+const FFDHE3072: &str = "\
+FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695\
+A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A\
+D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935\
+984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A\
+BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4\
+AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61\
+9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005\
+C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B\
+BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C\
+AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF\
+5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E\
+0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B66C62E37FFFFFFFFFFFFFFFF";
+
+const FFDHE4096: &str = "\
+FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695\
+A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A\
+D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935\
+984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A\
+BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4\
+AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61\
+9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005\
+C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B\
+BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C\
+AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF\
+5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E\
+0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB\
+7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A\
+7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038\
+092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF\
+8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E655F6AFFFFFFFFFFFFFFFF";
+
+const FFDHE6144: &str = "\
+FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695\
+A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A\
+D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935\
+984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A\
+BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4\
+AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61\
+9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005\
+C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B\
+BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C\
+AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF\
+5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E\
+0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB\
+7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A\
+7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038\
+092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF\
+8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD9020BFD64B645036C7A\
+4E677D2C38532A3A23BA4442CAF53EA63BB454329B7624C8917BDD64B1C0FD4C\
+B38E8C334C701C3ACDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477\
+A52471F7A9A96910B855322EDB6340D8A00EF092350511E30ABEC1FFF9E3A26E\
+7FB29F8C183023C3587E38DA0077D9B4763E4E4B94B2BBC194C6651E77CAF992\
+EEAAC0232A281BF6B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C\
+D72B03746AE77F5E62292C311562A846505DC82DB854338AE49F5235C95B9117\
+8CCF2DD5CACEF403EC9D1810C6272B045B3B71F9DC6B80D63FDD4A8E9ADB1E69\
+62A69526D43161C1A41D570D7938DAD4A40E329CD0E40E65FFFFFFFFFFFFFFFF";
+
+const FFDHE8192: &str = "\
+FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695\
+A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A\
+D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935\
+984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A\
+BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4\
+AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61\
+9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005\
+C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B\
+BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C\
+AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF\
+5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E\
+0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB\
+7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A\
+7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038\
+092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF\
+8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD9020BFD64B645036C7A\
+4E677D2C38532A3A23BA4442CAF53EA63BB454329B7624C8917BDD64B1C0FD4C\
+B38E8C334C701C3ACDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477\
+A52471F7A9A96910B855322EDB6340D8A00EF092350511E30ABEC1FFF9E3A26E\
+7FB29F8C183023C3587E38DA0077D9B4763E4E4B94B2BBC194C6651E77CAF992\
+EEAAC0232A281BF6B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C\
+D72B03746AE77F5E62292C311562A846505DC82DB854338AE49F5235C95B9117\
+8CCF2DD5CACEF403EC9D1810C6272B045B3B71F9DC6B80D63FDD4A8E9ADB1E69\
+62A69526D43161C1A41D570D7938DAD4A40E329CCFF46AAA36AD004CF600C838\
+1E425A31D951AE64FDB23FCEC9509D43687FEB69EDD1CC5E0B8CC3BDF64B10EF\
+86B63142A3AB8829555B2F747C932665CB2C0F1CC01BD70229388839D2AF05E4\
+54504AC78B7582822846C0BA35C35F5C59160CC046FD8251541FC68C9C86B022\
+BB7099876A460E7451A8A93109703FEE1C217E6C3826E52C51AA691E0E423CFC\
+99E9E31650C1217B624816CDAD9A95F9D5B8019488D9C0A0A1FE3075A577E231\
+83F81D4A3F2FA4571EFC8CE0BA8A4FE8B6855DFE72B0A66EDED2FBABFBE58A30\
+FAFABE1C5D71A87E2F741EF8C1FE86FEA6BBFDE530677F0D97D11D49F7A8443D\
+0822E506A9F4614E011E2A94838FF88CD68C8BB7C5C6424CFFFFFFFFFFFFFFFF";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{HashingAlgorithm, SHA256};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_primes() {
+        // SHA-256 of the primes as exported by OpenSSL for each named group
+        for (name, digest) in [
+            (
+                NamedGroup::Modp1536,
+                "64fcc83ec403930bf18393dbc883ccaa1fbb08ac876f77f7aa99748ca945019b",
+            ),
+            (
+                NamedGroup::Modp2048,
+                "d66436f79bbd6b2e38c0ffbd079be904d2641415e2e67140e09448be9a60890e",
+            ),
+            (
+                NamedGroup::Modp3072,
+                "48cf8b092fbce4359d9871abf74f98e25b6163379eaa15cd9087e800c6d1c55c",
+            ),
+            (
+                NamedGroup::Modp4096,
+                "4ee95187682bcb230ad26a95205f6920e84708f6251b3894329b09ec23919e33",
+            ),
+            (
+                NamedGroup::Modp6144,
+                "d1bfe6d0925ce7e4da262b62861514a7755e35831e429f343e7b864848657efd",
+            ),
+            (
+                NamedGroup::Modp8192,
+                "39ab4feab950a3128fb71accb9fc3965d857012e081998a85996e3ea8b3c3bcf",
+            ),
+            (
+                NamedGroup::Ffdhe2048,
+                "9cd3b7f336872f46c09428d1bbc19877a4d440512cda8d1c1cf0cd6e33698966",
+            ),
+            (
+                NamedGroup::Ffdhe3072,
+                "0eaf67db3a839156d5013494a5318a772b5697d270d721f37f092efc69ea5a17",
+            ),
+            (
+                NamedGroup::Ffdhe4096,
+                "4648414224ac881b3d0dc59b466f96d06a558278776807797ecf1f66ff397b3e",
+            ),
+            (
+                NamedGroup::Ffdhe6144,
+                "227ac9066b3ddd9e193670cda2388fa884f65ba0cf98b742d1fe77a6687c79c7",
+            ),
+            (
+                NamedGroup::Ffdhe8192,
+                "770b14efaf6f049929c523113b3fa99a8d11dab1b18af3609590122075d19833",
+            ),
+        ] {
+            let group = Group::new(name);
+
+            assert_eq!(group.p().bits(), name.bits());
+            assert_eq!(group.size() * 8, name.bits());
+            assert_eq!(hex::encode(SHA256::hash(&group.p().to_be_bytes())), digest);
+            assert_eq!(&(group.q() << 1) + &BigUint::one(), *group.p());
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let group = Group::new(NamedGroup::Ffdhe2048);
+        let p_minus_one = group.p() - &BigUint::one();
+
+        assert_eq!(group.validate(group.g()), Ok(()));
+        assert_eq!(group.validate(&BigUint::from(4u64)), Ok(()));
+
+        // 0, 1 and p - 1 are the small subgroup, p and beyond are out of range
+        for y in [
+            BigUint::zero(),
+            BigUint::one(),
+            p_minus_one.clone(),
+            group.p().clone(),
+            group.p() + &BigUint::from(2u64),
+        ] {
+            assert_eq!(group.validate(&y), Err(VCryptoError::InvalidKey));
+        }
+
+        // p = 7 mod 8, so 2 is a square and -2 is not, it is outside the subgroup of order q
+        assert_eq!(
+            group.validate(&(group.p() - &BigUint::from(2u64))),
+            Err(VCryptoError::InvalidKey)
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use super::group::Group;
+use crate::bignum::BigUint;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::hmac::Hmac;
+use crate::kdf::{Kbkdf, KbkdfMode};
+use crate::random::RandomSource;
+use crate::util::zeroize;
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A secret exponent x between 1 and q - 1, with its public value.
+#[derive(Clone)]
+pub struct SecretKey {
+    x: BigUint,
+    public_key: PublicKey,
+}
+
+/// A public value y = g^x mod p, validated to be in the subgroup of order q.
+#[derive(Clone)]
+pub struct PublicKey {
+    group: Group,
+    y: BigUint,
+}
+
+/// The result of a key exchange, `y^x mod p` encoded as big endian bytes as long as p, which is
+/// zeroized when dropped. It is not uniformly random and has to go through a KDF, such as
+/// `derive_key_into`, before being used as a key.
+pub struct SharedSecret(Vec<u8>);
+
+impl SecretKey {
+    /// Generates a key pair, drawing x uniformly between 1 and q - 1.
+    pub fn generate(group: &Group, rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        let x = &BigUint::random_below(&(group.q() - &BigUint::one()), rng)? + &BigUint::one();
+
+        return Ok(Self::from_exponent(group, x));
+    }
+
+    /// Decodes a big endian exponent. Returns `VCryptoError::InvalidKey` unless it is between 1
+    /// and q - 1.
+    pub fn from_be_bytes(group: &Group, bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let x = BigUint::from_be_bytes(bytes);
+
+        if x.is_zero() || &x >= group.q() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(Self::from_exponent(group, x));
+    }
+
+    /// Returns the exponent as big endian bytes as long as p.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        return self
+            .x
+            .to_be_bytes_padded(self.public_key.group.size())
+            .expect("x is less than p");
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        return &self.public_key;
+    }
+
+    /// Computes the shared secret with the exponentiation of SP 800-56A section 5.7.1.1. Returns
+    /// `VCryptoError::InvalidKey` if `public_key` belongs to another group.
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> Result<SharedSecret, VCryptoError> {
+        let group = &self.public_key.group;
+
+        if public_key.group.name() != group.name() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        // Public keys are validated, so y^x is neither 0 nor 1
+        let z = group.montgomery().pow(&public_key.y, &self.x);
+
+        return Ok(SharedSecret(
+            z.to_be_bytes_padded(group.size())
+                .expect("z is less than p"),
+        ));
+    }
+
+    fn from_exponent(group: &Group, x: BigUint) -> Self {
+        let y = group.montgomery().pow(group.g(), &x);
+
+        return Self {
+            x,
+            public_key: PublicKey {
+                group: group.clone(),
+                y,
+            },
+        };
+    }
+}
+
+impl PublicKey {
+    /// Decodes a big endian public value, of any length. Returns `VCryptoError::InvalidKey`
+    /// unless it is between 2 and p - 2 and in the subgroup of order q.
+    pub fn from_be_bytes(group: &Group, bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let y = BigUint::from_be_bytes(bytes);
+        group.validate(&y)?;
+
+        return Ok(Self {
+            group: group.clone(),
+            y,
+        });
+    }
+
+    /// Returns the public value as big endian bytes as long as p, the encoding of TLS and IKE.
+    pub fn to_be_bytes(&self) -> Vec<u8> {
+        return self
+            .y
+            .to_be_bytes_padded(self.group.size())
+            .expect("y is less than p");
+    }
+
+    pub fn group(&self) -> &Group {
+        return &self.group;
+    }
+
+    pub fn y(&self) -> &BigUint {
+        return &self.y;
+    }
+}
+
+impl SharedSecret {
+    pub fn as_bytes(&self) -> &[u8] {
+        return &self.0;
+    }
+
+    /// Fills `output` with the two step KDF of SP 800-56C section 5 over HMAC with `H`: the
+    /// secret is extracted with `salt` as the HMAC key, then expanded with the counter mode KBKDF
+    /// over `fixed_info`. An empty salt is the default salt of the standard.
+    ///
+    /// Returns `VCryptoError::InvalidInput` if `output` is longer than KBKDF can produce.
+    pub fn derive_key_into<H: HashingAlgorithm>(
+        &self,
+        salt: &[u8],
+        fixed_info: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), VCryptoError> {
+        let mut extract = Hmac::<H>::new(salt);
+        extract.update(&self.0);
+        let mut key = extract.finalize();
+
+        let result = Kbkdf::new(Hmac::<H>::new(key.as_ref()), KbkdfMode::Counter)
+            .derive_into(fixed_info, output);
+        zeroize(key.as_mut());
+
+        return result;
+    }
+}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        return self.group.name() == other.group.name() && self.y == other.y;
+    }
+}
+
+impl Eq for PublicKey {}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SecretKey({:?}, ..)", self.public_key.group.name());
+    }
+}
+
+impl fmt::Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "PublicKey({:?}, {:x})", self.group.name(), self.y);
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SharedSecret(..)");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dh::NamedGroup;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_exchange() {
+        // Checked against OpenSSL through pyca/cryptography
+        let group = Group::new(NamedGroup::Ffdhe2048);
+        let alice = SecretKey::from_be_bytes(&group, &[0x11; 32]).unwrap();
+        let bob = SecretKey::from_be_bytes(&group, &[0x22; 32]).unwrap();
+
+        assert_eq!(
+            hex::encode(SHA256::hash(&alice.public_key().to_be_bytes())),
+            "fa569bcaabef1d8f1f54479063612a15752ef5594fc9c4ff99cc74e552549a52"
+        );
+
+        let alice_shared = alice.diffie_hellman(bob.public_key()).unwrap();
+        let bob_shared = bob.diffie_hellman(alice.public_key()).unwrap();
+
+        assert_eq!(alice_shared.as_bytes().len(), 256);
+        assert_eq!(
+            hex::encode(SHA256::hash(alice_shared.as_bytes())),
+            "5a4b3ee2e31ff42c6cb608d5704f824ecf16e46605ccff5acd511373f59a3411"
+        );
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+        assert_eq!(format!("{:?}", alice_shared), "SharedSecret(..)");
+        assert_eq!(format!("{:?}", alice), "SecretKey(Ffdhe2048, ..)");
+
+        let mut key = [0u8; 42];
+        alice_shared
+            .derive_key_into::<SHA256>(b"salt", b"fixed info", &mut key)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "a4aec44be2826ad9445aa874f0c82ff2219bae265def32c4c680576f124814df54746e903b26fa23bb9f"
+        );
+
+        let mut key = [0u8; 32];
+        bob_shared
+            .derive_key_into::<SHA256>(&[], &[], &mut key)
+            .unwrap();
+        assert_eq!(
+            hex::encode(key),
+            "020418f7f9e36a14b7fdbcac4972043bf5dea4e184cb4394c916f4b7632df0e5"
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"dh key generation", &[], &[]);
+        let group = Group::new(NamedGroup::Modp1536);
+        let alice = SecretKey::generate(&group, &mut rng).unwrap();
+        let bob = SecretKey::generate(&group, &mut rng).unwrap();
+
+        assert_ne!(alice.to_be_bytes(), bob.to_be_bytes());
+        assert_eq!(alice.to_be_bytes().len(), 192);
+        assert_eq!(
+            SecretKey::from_be_bytes(&group, &alice.to_be_bytes())
+                .unwrap()
+                .public_key(),
+            alice.public_key()
+        );
+
+        let bob_public = PublicKey::from_be_bytes(&group, &bob.public_key().to_be_bytes()).unwrap();
+        assert_eq!(
+            alice.diffie_hellman(&bob_public).unwrap().as_bytes(),
+            bob.diffie_hellman(alice.public_key()).unwrap().as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_invalid_keys() {
+        let group = Group::new(NamedGroup::Ffdhe2048);
+        let other_group = Group::new(NamedGroup::Modp2048);
+
+        assert_eq!(
+            SecretKey::from_be_bytes(&group, &[0; 32]).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            SecretKey::from_be_bytes(&group, &group.q().to_be_bytes()).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            PublicKey::from_be_bytes(&group, &[1]).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            PublicKey::from_be_bytes(&group, &(group.p() - &BigUint::one()).to_be_bytes())
+                .unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+
+        let alice = SecretKey::from_be_bytes(&group, &[0x11; 32]).unwrap();
+        let mallory = SecretKey::from_be_bytes(&other_group, &[0x22; 32]).unwrap();
+
+        assert_eq!(
+            alice.diffie_hellman(mallory.public_key()).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Finite field Diffie-Hellman over the safe prime groups of RFC 3526 and RFC 7919, with full
+//! validation of public values as in SP 800-56A.
+//!
+//! Exponentiations with secret exponents go through the constant time `Montgomery::pow`.
+
+mod group;
+mod key;
+
+pub use group::{Group, NamedGroup};
+pub use key::{PublicKey, SecretKey, SharedSecret};
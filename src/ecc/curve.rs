@@ -0,0 +1,151 @@
+use super::field::{Modulus, Residue, MAX_LIMBS};
+use crate::hashes::{HashingAlgorithm, SHA256, SHA384};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// A short Weierstrass curve y^2 = x^3 - 3x + b over a prime field p congruent to 3 modulo 4,
+/// whose points form a group of prime order n.
+///
+/// Field elements and scalars are `8 * LIMBS` bytes long, and both p and n must use every bit
+/// of that size. Parameters are given in little endian 64 bit limbs, padded with zeros to six
+/// limbs.
+pub trait Curve: Clone + Copy + Debug + Default + PartialEq + Eq + 'static {
+    /// The hash ECDSA signs messages with, and that RFC 6979 builds its HMAC on.
+    type Hash: HashingAlgorithm;
+
+    const LIMBS: usize;
+    /// The field prime p.
+    const P: [u64; MAX_LIMBS];
+    /// The group order n.
+    const N: [u64; MAX_LIMBS];
+    /// The curve coefficient b.
+    const B: [u64; MAX_LIMBS];
+    /// The x-coordinate of the generator.
+    const GX: [u64; MAX_LIMBS];
+    /// The y-coordinate of the generator.
+    const GY: [u64; MAX_LIMBS];
+}
+
+/// NIST P-256, also known as secp256r1 and prime256v1, from FIPS 186-5 and SP 800-186.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct P256;
+
+/// NIST P-384, also known as secp384r1, from FIPS 186-5 and SP 800-186.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct P384;
+
+impl Curve for P256 {
+    type Hash = SHA256;
+
+    const LIMBS: usize = 4;
+    const P: [u64; MAX_LIMBS] = [
+        0xffffffffffffffff,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+        0,
+        0,
+    ];
+    const N: [u64; MAX_LIMBS] = [
+        0xf3b9cac2fc632551,
+        0xbce6faada7179e84,
+        0xffffffffffffffff,
+        0xffffffff00000000,
+        0,
+        0,
+    ];
+    const B: [u64; MAX_LIMBS] = [
+        0x3bce3c3e27d2604b,
+        0x651d06b0cc53b0f6,
+        0xb3ebbd55769886bc,
+        0x5ac635d8aa3a93e7,
+        0,
+        0,
+    ];
+    const GX: [u64; MAX_LIMBS] = [
+        0xf4a13945d898c296,
+        0x77037d812deb33a0,
+        0xf8bce6e563a440f2,
+        0x6b17d1f2e12c4247,
+        0,
+        0,
+    ];
+    const GY: [u64; MAX_LIMBS] = [
+        0xcbb6406837bf51f5,
+        0x2bce33576b315ece,
+        0x8ee7eb4a7c0f9e16,
+        0x4fe342e2fe1a7f9b,
+        0,
+        0,
+    ];
+}
+
+impl Curve for P384 {
+    type Hash = SHA384;
+
+    const LIMBS: usize = 6;
+    const P: [u64; MAX_LIMBS] = [
+        0x00000000ffffffff,
+        0xffffffff00000000,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+    const N: [u64; MAX_LIMBS] = [
+        0xecec196accc52973,
+        0x581a0db248b0a77a,
+        0xc7634d81f4372ddf,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ];
+    const B: [u64; MAX_LIMBS] = [
+        0x2a85c8edd3ec2aef,
+        0xc656398d8a2ed19d,
+        0x0314088f5013875a,
+        0x181d9c6efe814112,
+        0x988e056be3f82d19,
+        0xb3312fa7e23ee7e4,
+    ];
+    const GX: [u64; MAX_LIMBS] = [
+        0x3a545e3872760ab7,
+        0x5502f25dbf55296c,
+        0x59f741e082542a38,
+        0x6e1d3b628ba79b98,
+        0x8eb1c71ef320ad74,
+        0xaa87ca22be8b0537,
+    ];
+    const GY: [u64; MAX_LIMBS] = [
+        0x7a431d7c90ea0e5f,
+        0x0a60b1ce1d7e819d,
+        0xe9da3113b5f0b8c0,
+        0xf8f41dbd289a147c,
+        0x5d9e98bf9292dc29,
+        0x3617de4a96262c6f,
+    ];
+}
+
+/// The field prime of `C` as a modulus.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FieldModulus<C>(PhantomData<C>);
+
+/// The group order of `C` as a modulus.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct OrderModulus<C>(PhantomData<C>);
+
+impl<C: Curve> Modulus for FieldModulus<C> {
+    const LIMBS: usize = C::LIMBS;
+    const MODULUS: [u64; MAX_LIMBS] = C::P;
+}
+
+impl<C: Curve> Modulus for OrderModulus<C> {
+    const LIMBS: usize = C::LIMBS;
+    const MODULUS: [u64; MAX_LIMBS] = C::N;
+}
+
+/// An element of the field of `C`.
+pub(crate) type FieldElement<C> = Residue<FieldModulus<C>>;
+
+/// An integer modulo the group order of `C`.
+pub(crate) type Scalar<C> = Residue<OrderModulus<C>>;
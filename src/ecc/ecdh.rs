@@ -0,0 +1,90 @@
+use super::curve::Curve;
+use super::key::{PublicKey, SecretKey};
+use crate::util::zeroize;
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The result of a key exchange, the x-coordinate of the shared point as `8 * C::LIMBS` big
+/// endian bytes, which is zeroized when dropped. It should be passed through a KDF before being
+/// used as a key.
+pub struct SharedSecret(Vec<u8>);
+
+impl<C: Curve> SecretKey<C> {
+    /// Performs the ECDH primitive of SEC1 section 3.3.1 with `public_key`.
+    ///
+    /// Public keys are validated when decoded and the curves have a cofactor of one, so the
+    /// shared point is never the point at infinity.
+    pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret {
+        let (x, _) = public_key
+            .point()
+            .mul(self.scalar())
+            .to_affine()
+            .expect("the public key has prime order");
+
+        return SharedSecret(x.to_be_bytes());
+    }
+}
+
+impl SharedSecret {
+    pub fn as_bytes(&self) -> &[u8] {
+        return &self.0;
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SharedSecret(..)");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::{P256, P384};
+    use pretty_assertions::assert_eq;
+
+    fn exchange<C: Curve>(bytes: usize, public_key: &str, expected: &str) {
+        let alice = SecretKey::<C>::from_bytes(&[0x11; 48][..bytes]).unwrap();
+        let bob = SecretKey::<C>::from_bytes(&[0x22; 48][..bytes]).unwrap();
+
+        assert_eq!(
+            hex::encode(bob.public_key().to_sec1_bytes(true)),
+            public_key
+        );
+
+        let alice_shared = alice.diffie_hellman(&bob.public_key());
+        let bob_shared = bob.diffie_hellman(&alice.public_key());
+
+        assert_eq!(hex::encode(alice_shared.as_bytes()), expected);
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+        assert_eq!(format!("{:?}", alice_shared), "SharedSecret(..)");
+    }
+
+    #[test]
+    fn test_p256() {
+        exchange::<P256>(
+            32,
+            "03d65a93977caa3d1b081852ff57a79e465f1660577304baead505dd3a48589cf3",
+            "ccfc261f58193c98ca4ad4a53bbac6f0ee29bc4d48438090446908622ca79af6",
+        );
+    }
+
+    #[test]
+    fn test_p384() {
+        exchange::<P384>(
+            48,
+            "024f2bda7fd2105f8467e21f45223ad58863ffa4c084832d9f6c64ffc47fdd519727ab53cb71f9c40de24b64\
+             acde61f02f",
+            "2ac3da23c114b5b1f3aa200cf3c57bebd1b3b880a0e68066ab5d00dda50dcfe6cd03410292346187a84b1f12\
+             d53569c0",
+        );
+    }
+}
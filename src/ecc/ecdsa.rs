@@ -0,0 +1,291 @@
+use super::curve::{Curve, Scalar};
+use super::key::{PublicKey, SecretKey};
+use super::point::ProjectivePoint;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::random::HmacDrbg;
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// An ECDSA signature, the pair of scalars (r, s), both between 1 and n - 1.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Signature<C: Curve> {
+    r: Scalar<C>,
+    s: Scalar<C>,
+}
+
+impl<C: Curve> Signature<C> {
+    /// Decodes r followed by s, each as `8 * C::LIMBS` big endian bytes, the fixed size format
+    /// of IEEE P1363. Returns `VCryptoError::InvalidInput` if the length is wrong or either
+    /// value is not between 1 and n - 1.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let len = Scalar::<C>::BYTES;
+
+        if bytes.len() != 2 * len {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let r = Scalar::from_be_bytes(&bytes[..len]).ok_or(VCryptoError::InvalidInput)?;
+        let s = Scalar::from_be_bytes(&bytes[len..]).ok_or(VCryptoError::InvalidInput)?;
+
+        if r.is_zero() || s.is_zero() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(Self { r, s });
+    }
+
+    /// Encodes r followed by s, each as `8 * C::LIMBS` big endian bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.r.to_be_bytes();
+        bytes.extend_from_slice(&self.s.to_be_bytes());
+
+        return bytes;
+    }
+}
+
+impl<C: Curve> SecretKey<C> {
+    /// Signs the `C::Hash` digest of `message` with ECDSA, using the deterministic nonce of
+    /// RFC 6979.
+    pub fn sign_ecdsa(&self, message: &[u8]) -> Signature<C> {
+        return self.sign_ecdsa_prehash(C::Hash::hash(message).as_ref());
+    }
+
+    /// Signs a digest computed by the caller, with any hash. Digests longer than the order are
+    /// truncated to its size as FIPS 186-5 requires. The RFC 6979 nonce is still derived with
+    /// HMAC over `C::Hash`.
+    pub fn sign_ecdsa_prehash(&self, digest: &[u8]) -> Signature<C> {
+        let e = bits2int::<C>(digest);
+        let x = self.scalar();
+
+        // RFC 6979 section 3.2 is HMAC_DRBG instantiated with int2octets(x) as the entropy and
+        // bits2octets(h1) as the nonce, drawing candidates until one is valid
+        let mut x_bytes = x.to_be_bytes();
+        let mut drbg = HmacDrbg::<C::Hash>::new(&x_bytes, &e.to_be_bytes(), &[]);
+        crate::util::zeroize(&mut x_bytes);
+
+        let mut k_bytes = vec![0u8; Scalar::<C>::BYTES];
+
+        loop {
+            drbg.generate(&mut k_bytes, &[])
+                .expect("few candidates are ever rejected");
+
+            let mut k = match Scalar::<C>::from_be_bytes(&k_bytes) {
+                Some(k) if !k.is_zero() => k,
+                _ => continue,
+            };
+
+            let (r_x, _) = ProjectivePoint::generator()
+                .mul(&k)
+                .to_affine()
+                .expect("k is not a multiple of n");
+            let r = Scalar::from_be_bytes_reduced(&r_x.to_be_bytes())
+                .expect("field elements and scalars have the same size");
+            let s = k.invert().mul(&e.add(&r.mul(x)));
+            k.zeroize();
+
+            if !r.is_zero() && !s.is_zero() {
+                crate::util::zeroize(&mut k_bytes);
+
+                return Signature { r, s };
+            }
+        }
+    }
+}
+
+impl<C: Curve> PublicKey<C> {
+    /// Verifies an ECDSA signature of the `C::Hash` digest of `message`.
+    pub fn verify_ecdsa(&self, message: &[u8], signature: &Signature<C>) -> bool {
+        return self.verify_ecdsa_prehash(C::Hash::hash(message).as_ref(), signature);
+    }
+
+    /// Verifies an ECDSA signature of a digest computed by the caller.
+    pub fn verify_ecdsa_prehash(&self, digest: &[u8], signature: &Signature<C>) -> bool {
+        let e = bits2int::<C>(digest);
+        let w = signature.s.invert();
+        let u1 = e.mul(&w);
+        let u2 = signature.r.mul(&w);
+
+        let (x, _) = match ProjectivePoint::vartime_double_mul(&u1, &u2, self.point()).to_affine() {
+            Some(point) => point,
+            None => return false,
+        };
+
+        return Scalar::<C>::from_be_bytes_reduced(&x.to_be_bytes()) == Some(signature.r);
+    }
+}
+
+/// Converts a digest to a scalar as bits2int followed by a reduction modulo n, keeping its
+/// leftmost bits when it is longer than n and left padding it with zeros when shorter.
+fn bits2int<C: Curve>(digest: &[u8]) -> Scalar<C> {
+    let len = Scalar::<C>::BYTES;
+    let mut bytes = vec![0u8; len];
+
+    if digest.len() >= len {
+        bytes.copy_from_slice(&digest[..len]);
+    } else {
+        bytes[len - digest.len()..].copy_from_slice(digest);
+    }
+
+    return Scalar::from_be_bytes_reduced(&bytes).expect("the length is right");
+}
+
+impl<C: Curve> fmt::Debug for Signature<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "Signature<{:?}>({:?})", C::default(), self.to_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::{P256, P384};
+    use crate::hashes::{SHA1, SHA512};
+    use pretty_assertions::assert_eq;
+
+    const P256_KEY: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P384_KEY: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8\
+                            96d5724e4c70a825f872c9ea60d2edf5";
+
+    fn key<C: Curve>(s: &str) -> SecretKey<C> {
+        return SecretKey::from_bytes(&hex::decode(s).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_rfc6979_p256() {
+        // RFC 6979 appendix A.2.5, with SHA-256
+        let key = key::<P256>(P256_KEY);
+
+        for (message, expected) in [
+            (
+                &b"sample"[..],
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+                 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+            ),
+            (
+                &b"test"[..],
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+                 019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+            ),
+        ] {
+            let signature = key.sign_ecdsa(message);
+
+            assert_eq!(hex::encode(signature.to_bytes()), expected);
+            assert!(key.public_key().verify_ecdsa(message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_rfc6979_p384() {
+        // RFC 6979 appendix A.2.6, with SHA-384
+        let key = key::<P384>(P384_KEY);
+
+        for (message, expected) in [
+            (
+                &b"sample"[..],
+                "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c\
+                 81a648152e44acf96e36dd1e80fabe46\
+                 99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94f\
+                 a329c145786e679e7b82c71a38628ac8",
+            ),
+            (
+                &b"test"[..],
+                "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb\
+                 0542a7f0812998da8f1dd3ca3cf023db\
+                 ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e0\
+                 6a739f040649a667bf3b828246baa5a5",
+            ),
+        ] {
+            let signature = key.sign_ecdsa(message);
+
+            assert_eq!(hex::encode(signature.to_bytes()), expected);
+            assert!(key.public_key().verify_ecdsa(message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_random_nonce() {
+        // Signed by OpenSSL through pyca/cryptography, with a random nonce
+        let public_key = key::<P256>(P256_KEY).public_key();
+        let signature = Signature::from_bytes(
+            &hex::decode(
+                "e18027e5d2e79bf2f30da4ae08b0eca5ab637752cf7cd226d92f9bdbea13dabf\
+                 c9405e3e76ca1ec1cd9a9c97413c9aa6740def016d6351d5e8aad6c98f8308ae",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert!(public_key.verify_ecdsa(b"interop", &signature));
+        assert!(!public_key.verify_ecdsa(b"interoq", &signature));
+    }
+
+    #[test]
+    fn test_prehash() {
+        let key = key::<P256>(P256_KEY);
+        let public_key = key.public_key();
+
+        // A SHA-512 digest is truncated, a SHA-1 digest is padded
+        for digest in [&SHA512::hash(b"sample")[..], &SHA1::hash(b"sample")[..]] {
+            let signature = key.sign_ecdsa_prehash(digest);
+
+            assert!(public_key.verify_ecdsa_prehash(digest, &signature));
+            assert!(!public_key.verify_ecdsa(b"sample", &signature));
+        }
+    }
+
+    #[test]
+    fn test_invalid_signatures() {
+        let key = key::<P256>(P256_KEY);
+        let public_key = key.public_key();
+        let signature = key.sign_ecdsa(b"sample").to_bytes();
+
+        assert!(!public_key.verify_ecdsa(
+            b"sample",
+            &Signature::from_bytes(&signature).unwrap().swap()
+        ));
+        assert!(!public_key.verify_ecdsa(b"samplf", &Signature::from_bytes(&signature).unwrap()));
+
+        for i in [0, 31, 32, 63] {
+            let mut modified = signature.clone();
+            modified[i] ^= 0x01;
+
+            assert!(!public_key.verify_ecdsa(b"sample", &Signature::from_bytes(&modified).unwrap()));
+        }
+
+        let other_key = SecretKey::<P256>::from_bytes(&[0x01; 32])
+            .unwrap()
+            .public_key();
+        assert!(!other_key.verify_ecdsa(b"sample", &Signature::from_bytes(&signature).unwrap()));
+
+        // r and s have to be between 1 and n - 1
+        let n = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+        for (r, s) in [
+            (n.to_string(), hex::encode(&signature[32..])),
+            (hex::encode(&signature[..32]), "00".repeat(32)),
+        ] {
+            assert_eq!(
+                Signature::<P256>::from_bytes(&hex::decode(r + &s).unwrap()).unwrap_err(),
+                VCryptoError::InvalidInput
+            );
+        }
+
+        assert_eq!(
+            Signature::<P256>::from_bytes(&signature[1..]).unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    impl<C: Curve> Signature<C> {
+        fn swap(self) -> Self {
+            return Self {
+                r: self.s,
+                s: self.r,
+            };
+        }
+    }
+}
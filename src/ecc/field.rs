@@ -0,0 +1,360 @@
+use crate::bignum::limbs::{self, Limb, LIMB_BYTES};
+use core::marker::PhantomData;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The number of limbs residues are stored in, enough for P-384.
+pub(crate) const MAX_LIMBS: usize = 6;
+
+/// An odd modulus of `LIMBS` limbs whose most significant bit is set, with the constants
+/// Montgomery multiplication needs, computed at compile time.
+pub(crate) trait Modulus: Clone + Copy + core::fmt::Debug + 'static {
+    const LIMBS: usize;
+    /// The modulus in little endian limbs, padded with zeros to `MAX_LIMBS`.
+    const MODULUS: [Limb; MAX_LIMBS];
+    const M0_INV: Limb = limbs::montgomery_m0_inv(Self::MODULUS[0]);
+    /// R^2 mod m, where R = 2^(64 * LIMBS), which converts values to Montgomery form.
+    const R2: [Limb; MAX_LIMBS] = r2(&Self::MODULUS, Self::LIMBS);
+}
+
+/// A residue modulo `M`, stored fully reduced in Montgomery form as a R mod m.
+///
+/// Everything but `from_be_bytes`, which only rejects values, takes time that depends only on
+/// the modulus.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Residue<M: Modulus> {
+    limbs: [Limb; MAX_LIMBS],
+    modulus: PhantomData<M>,
+}
+
+impl<M: Modulus> Residue<M> {
+    pub(crate) const ZERO: Self = Self::new([0; MAX_LIMBS]);
+    /// The length of the big endian encoding.
+    pub(crate) const BYTES: usize = M::LIMBS * LIMB_BYTES;
+
+    const fn new(limbs: [Limb; MAX_LIMBS]) -> Self {
+        return Self {
+            limbs,
+            modulus: PhantomData,
+        };
+    }
+
+    pub(crate) fn one() -> Self {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+
+        return Self::from_canonical_limbs(&one);
+    }
+
+    /// Converts a value below the modulus to Montgomery form.
+    pub(crate) fn from_canonical_limbs(value: &[Limb; MAX_LIMBS]) -> Self {
+        return Self::new(montgomery_mul::<M>(value, &M::R2));
+    }
+
+    /// Decodes exactly `BYTES` big endian bytes, or returns `None` if the value is not below the
+    /// modulus.
+    pub(crate) fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let value = parse_be_bytes::<M>(bytes)?;
+        let mut difference = value;
+
+        if limbs::sub_assign(&mut difference[..M::LIMBS], &M::MODULUS[..M::LIMBS]) == 0 {
+            return None;
+        }
+
+        return Some(Self::from_canonical_limbs(&value));
+    }
+
+    /// Decodes exactly `BYTES` big endian bytes and reduces them, which a single subtraction is
+    /// enough for as the modulus has its top bit set.
+    pub(crate) fn from_be_bytes_reduced(bytes: &[u8]) -> Option<Self> {
+        let mut value = parse_be_bytes::<M>(bytes)?;
+        let mut difference = value;
+        let borrow = limbs::sub_assign(&mut difference[..M::LIMBS], &M::MODULUS[..M::LIMBS]);
+        limbs::conditional_assign(&mut value, &difference, borrow ^ 1);
+
+        return Some(Self::from_canonical_limbs(&value));
+    }
+
+    /// Encodes the value as `BYTES` big endian bytes.
+    pub(crate) fn to_be_bytes(self) -> Vec<u8> {
+        let value = self.to_canonical_limbs();
+        let mut bytes = vec![0u8; Self::BYTES];
+
+        for (i, b) in bytes.iter_mut().rev().enumerate() {
+            *b = (value[i / LIMB_BYTES] >> (8 * (i % LIMB_BYTES))) as u8;
+        }
+
+        return bytes;
+    }
+
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let mut sum = self.limbs;
+        let carry = limbs::add_assign(&mut sum[..M::LIMBS], &rhs.limbs[..M::LIMBS]);
+        let mut difference = sum;
+        let borrow = limbs::sub_assign(&mut difference[..M::LIMBS], &M::MODULUS[..M::LIMBS]);
+
+        // Keep the sum only if it did not overflow and is below the modulus
+        limbs::conditional_assign(&mut difference, &sum, (carry ^ 1) & borrow);
+
+        return Self::new(difference);
+    }
+
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        let mut difference = self.limbs;
+        let borrow = limbs::sub_assign(&mut difference[..M::LIMBS], &rhs.limbs[..M::LIMBS]);
+        let mut wrapped = difference;
+        limbs::add_assign(&mut wrapped[..M::LIMBS], &M::MODULUS[..M::LIMBS]);
+        limbs::conditional_assign(&mut difference, &wrapped, borrow);
+
+        return Self::new(difference);
+    }
+
+    pub(crate) fn negate(&self) -> Self {
+        return Self::ZERO.sub(self);
+    }
+
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        return Self::new(montgomery_mul::<M>(&self.limbs, &rhs.limbs));
+    }
+
+    pub(crate) fn square(&self) -> Self {
+        return self.mul(self);
+    }
+
+    /// Raises the value to a public `exponent`, given in little endian limbs. The time taken
+    /// depends on the exponent but not on the value.
+    pub(crate) fn pow_vartime(&self, exponent: &[Limb]) -> Self {
+        let mut result = Self::one();
+
+        for i in (0..limbs::bits(exponent)).rev() {
+            result = result.square();
+
+            if (exponent[i / 64] >> (i % 64)) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+
+        return result;
+    }
+
+    /// Returns the inverse as `self^(m - 2)`, zero is mapped to zero. The modulus must be prime.
+    pub(crate) fn invert(&self) -> Self {
+        let mut exponent = M::MODULUS;
+        limbs::sub_assign(&mut exponent[..M::LIMBS], &[2]);
+
+        return self.pow_vartime(&exponent[..M::LIMBS]);
+    }
+
+    /// Returns a square root as `self^((m + 1) / 4)`, or `None` if there is none. The modulus
+    /// must be a prime congruent to 3 modulo 4.
+    pub(crate) fn sqrt(&self) -> Option<Self> {
+        let mut exponent = M::MODULUS;
+        limbs::add_assign(&mut exponent[..M::LIMBS], &[1]);
+        limbs::shr_small(&mut exponent[..M::LIMBS], 2);
+
+        let root = self.pow_vartime(&exponent[..M::LIMBS]);
+
+        if root.square() != *self {
+            return None;
+        }
+
+        return Some(root);
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        return limbs::ct_eq(&self.limbs, &[0; MAX_LIMBS]) == 1;
+    }
+
+    /// Whether the canonical value is odd.
+    pub(crate) fn is_odd(&self) -> bool {
+        return self.to_canonical_limbs()[0] & 1 == 1;
+    }
+
+    /// Replaces `self` with `other` if `choice` is true, without branching on `choice`.
+    pub(crate) fn conditional_assign(&mut self, other: &Self, choice: bool) {
+        limbs::conditional_assign(&mut self.limbs, &other.limbs, choice as Limb);
+    }
+
+    /// Overwrites the value with zeros in a way the compiler cannot remove.
+    pub(crate) fn zeroize(&mut self) {
+        for limb in self.limbs.iter_mut() {
+            // SAFETY: `limb` is a valid and aligned reference to a single limb
+            unsafe { core::ptr::write_volatile(limb, 0) };
+        }
+
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+
+    fn to_canonical_limbs(self) -> [Limb; MAX_LIMBS] {
+        let mut one = [0; MAX_LIMBS];
+        one[0] = 1;
+
+        return montgomery_mul::<M>(&self.limbs, &one);
+    }
+}
+
+impl<M: Modulus> PartialEq for Residue<M> {
+    /// Compares the values in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        return limbs::ct_eq(&self.limbs, &other.limbs) == 1;
+    }
+}
+
+impl<M: Modulus> Eq for Residue<M> {}
+
+/// Parses exactly `M::LIMBS * 8` big endian bytes into limbs.
+fn parse_be_bytes<M: Modulus>(bytes: &[u8]) -> Option<[Limb; MAX_LIMBS]> {
+    if bytes.len() != M::LIMBS * LIMB_BYTES {
+        return None;
+    }
+
+    let mut value = [0; MAX_LIMBS];
+
+    for (i, b) in bytes.iter().rev().enumerate() {
+        value[i / LIMB_BYTES] |= (*b as Limb) << (8 * (i % LIMB_BYTES));
+    }
+
+    return Some(value);
+}
+
+fn montgomery_mul<M: Modulus>(a: &[Limb; MAX_LIMBS], b: &[Limb; MAX_LIMBS]) -> [Limb; MAX_LIMBS] {
+    let n = M::LIMBS;
+    let mut output = [0; MAX_LIMBS];
+    limbs::montgomery_mul(
+        &mut output[..n],
+        &a[..n],
+        &b[..n],
+        &M::MODULUS[..n],
+        M::M0_INV,
+    );
+
+    return output;
+}
+
+/// Computes 2^(128 * limbs) mod `modulus` by doubling 1 that many times.
+const fn r2(modulus: &[Limb; MAX_LIMBS], limbs: usize) -> [Limb; MAX_LIMBS] {
+    let mut r = [0; MAX_LIMBS];
+    r[0] = 1;
+
+    let mut i = 0;
+
+    while i < 128 * limbs {
+        let mut carry = 0;
+        let mut j = 0;
+
+        while j < limbs {
+            let next = r[j] >> 63;
+            r[j] = (r[j] << 1) | carry;
+            carry = next;
+            j += 1;
+        }
+
+        let mut difference = [0; MAX_LIMBS];
+        let mut borrow = 0;
+        j = 0;
+
+        while j < limbs {
+            let t = (r[j] as u128).wrapping_sub(modulus[j] as u128 + borrow as u128);
+            difference[j] = t as Limb;
+            borrow = (t >> 127) as Limb;
+            j += 1;
+        }
+
+        if carry == 1 || borrow == 0 {
+            r = difference;
+        }
+
+        i += 1;
+    }
+
+    return r;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// The P-256 prime.
+    #[derive(Clone, Copy, Debug)]
+    struct P256Prime;
+
+    impl Modulus for P256Prime {
+        const LIMBS: usize = 4;
+        const MODULUS: [Limb; MAX_LIMBS] = [
+            0xffffffffffffffff,
+            0x00000000ffffffff,
+            0x0000000000000000,
+            0xffffffff00000001,
+            0,
+            0,
+        ];
+    }
+
+    type Fe = Residue<P256Prime>;
+
+    fn fe(s: &str) -> Fe {
+        return Fe::from_be_bytes(&hex::decode(s).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_montgomery_constants() {
+        // R^2 mod p for the P-256 prime
+        assert_eq!(
+            P256Prime::R2,
+            [
+                0x0000000000000003,
+                0xfffffffbffffffff,
+                0xfffffffffffffffe,
+                0x00000004fffffffd,
+                0,
+                0
+            ]
+        );
+        assert_eq!(P256Prime::M0_INV, 1);
+    }
+
+    #[test]
+    fn test_encoding() {
+        let p = "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+        let p_minus_one = "ffffffff00000001000000000000000000000000fffffffffffffffffffffffe";
+
+        assert_eq!(Fe::from_be_bytes(&hex::decode(p).unwrap()), None);
+        assert_eq!(hex::encode(fe(p_minus_one).to_be_bytes()), p_minus_one);
+        assert_eq!(Fe::from_be_bytes(&[0; 31]), None);
+        assert!(Fe::from_be_bytes_reduced(&hex::decode(p).unwrap())
+            .unwrap()
+            .is_zero());
+        assert_eq!(
+            Fe::from_be_bytes_reduced(&[0xff; 32]).unwrap(),
+            fe("00000000fffffffeffffffffffffffffffffffff000000000000000000000000")
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = fe("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        let b = fe("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+
+        assert_eq!(a.add(&b).sub(&b), a);
+        assert_eq!(a.sub(&b).add(&b), a);
+        assert!(a.add(&a.negate()).is_zero());
+        assert_eq!(a.mul(&b), b.mul(&a));
+        assert_eq!(a.mul(&a.invert()), Fe::one());
+        assert!(Fe::ZERO.invert().is_zero());
+        assert_eq!(
+            hex::encode(a.mul(&b).to_be_bytes()),
+            "823cd15f6dd3c71933565064513a6b2bd183e554c6a08622f713ebbbface98be"
+        );
+
+        let root = a.square().sqrt().unwrap();
+        assert!(root == a || root == a.negate());
+
+        // -1 is not a square modulo a prime congruent to 3 modulo 4
+        assert_eq!(Fe::one().negate().sqrt(), None);
+        assert!(!Fe::one().negate().is_odd());
+    }
+}
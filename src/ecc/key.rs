@@ -0,0 +1,211 @@
+use super::curve::{Curve, Scalar};
+use super::point::ProjectivePoint;
+use crate::error::VCryptoError;
+use crate::random::RandomSource;
+use crate::util::zeroize;
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A secret key, a scalar between 1 and n - 1, usable both for ECDSA and ECDH.
+#[derive(Clone)]
+pub struct SecretKey<C: Curve> {
+    scalar: Scalar<C>,
+    public_key: PublicKey<C>,
+}
+
+/// A public key, a point on the curve other than the point at infinity.
+#[derive(Clone, Copy)]
+pub struct PublicKey<C: Curve> {
+    point: ProjectivePoint<C>,
+}
+
+impl<C: Curve> SecretKey<C> {
+    /// Decodes a big endian scalar of exactly `8 * C::LIMBS` bytes. Returns
+    /// `VCryptoError::InvalidKey` if it has another length or is not between 1 and n - 1.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let scalar = Scalar::from_be_bytes(bytes).ok_or(VCryptoError::InvalidKey)?;
+
+        if scalar.is_zero() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(Self::from_scalar(scalar));
+    }
+
+    /// Draws a uniformly random scalar by rejection sampling.
+    pub fn random(rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        let mut bytes = vec![0u8; Scalar::<C>::BYTES];
+
+        loop {
+            rng.fill_bytes(&mut bytes)?;
+
+            if let Some(scalar) = Scalar::from_be_bytes(&bytes) {
+                if !scalar.is_zero() {
+                    zeroize(&mut bytes);
+
+                    return Ok(Self::from_scalar(scalar));
+                }
+            }
+        }
+    }
+
+    /// Returns the scalar as `8 * C::LIMBS` big endian bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return self.scalar.to_be_bytes();
+    }
+
+    pub fn public_key(&self) -> PublicKey<C> {
+        return self.public_key;
+    }
+
+    pub(crate) fn scalar(&self) -> &Scalar<C> {
+        return &self.scalar;
+    }
+
+    fn from_scalar(scalar: Scalar<C>) -> Self {
+        return Self {
+            scalar,
+            public_key: PublicKey {
+                point: ProjectivePoint::generator().mul(&scalar),
+            },
+        };
+    }
+}
+
+impl<C: Curve> PublicKey<C> {
+    /// Decodes a SEC1 compressed or uncompressed point. Returns `VCryptoError::InvalidKey` if
+    /// the encoding is malformed, a coordinate is not reduced, or the point is not on the curve.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let point = ProjectivePoint::from_sec1_bytes(bytes).ok_or(VCryptoError::InvalidKey)?;
+
+        return Ok(Self { point });
+    }
+
+    /// Encodes the key as a SEC1 point, of `1 + 8 * C::LIMBS` bytes when compressed and
+    /// `1 + 16 * C::LIMBS` bytes otherwise.
+    pub fn to_sec1_bytes(&self, compress: bool) -> Vec<u8> {
+        return self
+            .point
+            .to_sec1_bytes(compress)
+            .expect("a public key is never the point at infinity");
+    }
+
+    pub(crate) fn point(&self) -> &ProjectivePoint<C> {
+        return &self.point;
+    }
+}
+
+impl<C: Curve> PartialEq for PublicKey<C> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.point == other.point;
+    }
+}
+
+impl<C: Curve> Eq for PublicKey<C> {}
+
+impl<C: Curve> Drop for SecretKey<C> {
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C: Curve> fmt::Debug for SecretKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SecretKey<{:?}>(..)", C::default());
+    }
+}
+
+impl<C: Curve> fmt::Debug for PublicKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(
+            f,
+            "PublicKey<{:?}>({:?})",
+            C::default(),
+            self.to_sec1_bytes(true)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::{P256, P384};
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_public_key() {
+        // RFC 6979 appendix A.2.5
+        let key = SecretKey::<P256>::from_bytes(
+            &hex::decode("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+                .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            hex::encode(key.public_key().to_sec1_bytes(false)),
+            "04\
+             60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+             7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"
+        );
+        assert_eq!(
+            hex::encode(key.public_key().to_sec1_bytes(true)),
+            "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"
+        );
+        assert_eq!(
+            PublicKey::from_sec1_bytes(&key.public_key().to_sec1_bytes(true)).unwrap(),
+            key.public_key()
+        );
+        assert_eq!(format!("{:?}", key), "SecretKey<P256>(..)");
+    }
+
+    #[test]
+    fn test_secret_key_range() {
+        let n = hex::decode(
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf\
+             581a0db248b0a77aecec196accc52973",
+        )
+        .unwrap();
+        let mut n_minus_one = n.clone();
+        n_minus_one[47] -= 1;
+
+        assert_eq!(
+            SecretKey::<P384>::from_bytes(&n).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            SecretKey::<P384>::from_bytes(&[0; 48]).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            SecretKey::<P384>::from_bytes(&n_minus_one[1..]).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            SecretKey::<P384>::from_bytes(&n_minus_one)
+                .unwrap()
+                .to_bytes(),
+            n_minus_one
+        );
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"ecc random key", &[], &[]);
+        let a = SecretKey::<P256>::random(&mut rng).unwrap();
+        let b = SecretKey::<P256>::random(&mut rng).unwrap();
+
+        assert_ne!(a.to_bytes(), b.to_bytes());
+        assert_eq!(
+            SecretKey::<P256>::from_bytes(&a.to_bytes())
+                .unwrap()
+                .public_key(),
+            a.public_key()
+        );
+    }
+}
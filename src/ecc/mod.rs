@@ -0,0 +1,18 @@
+//! Elliptic curve cryptography over the NIST prime curves P-256 and P-384: ECDSA with the
+//! deterministic nonces of RFC 6979, ECDH, and SEC1 point encoding.
+//!
+//! Field and scalar arithmetic is done in Montgomery form on fixed size limbs and the scalar
+//! multiplication used with secret scalars runs in constant time. Only signature verification,
+//! which handles public values, uses variable time arithmetic.
+
+mod curve;
+mod ecdh;
+mod ecdsa;
+mod field;
+mod key;
+mod point;
+
+pub use curve::{Curve, P256, P384};
+pub use ecdh::SharedSecret;
+pub use ecdsa::Signature;
+pub use key::{PublicKey, SecretKey};
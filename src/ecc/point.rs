@@ -0,0 +1,364 @@
+use super::curve::{Curve, FieldElement, Scalar};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The SEC1 tag of an uncompressed point, followed by both coordinates.
+const SEC1_UNCOMPRESSED: u8 = 0x04;
+/// The SEC1 tags of a compressed point with an even and an odd y-coordinate, followed by the
+/// x-coordinate.
+const SEC1_COMPRESSED_EVEN: u8 = 0x02;
+const SEC1_COMPRESSED_ODD: u8 = 0x03;
+
+/// A point in projective coordinates (X : Y : Z), with x = X / Z and y = Y / Z. The point at
+/// infinity is (0 : 1 : 0).
+///
+/// Addition uses the complete formulas of Renes, Costello and Batina for a = -3, which handle
+/// doubling and the point at infinity without special cases.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ProjectivePoint<C: Curve> {
+    x: FieldElement<C>,
+    y: FieldElement<C>,
+    z: FieldElement<C>,
+}
+
+impl<C: Curve> ProjectivePoint<C> {
+    pub(crate) fn identity() -> Self {
+        return Self {
+            x: FieldElement::ZERO,
+            y: FieldElement::one(),
+            z: FieldElement::ZERO,
+        };
+    }
+
+    pub(crate) fn generator() -> Self {
+        return Self {
+            x: FieldElement::from_canonical_limbs(&C::GX),
+            y: FieldElement::from_canonical_limbs(&C::GY),
+            z: FieldElement::one(),
+        };
+    }
+
+    /// Decodes a SEC1 compressed or uncompressed point, checking that it lies on the curve. The
+    /// point at infinity is rejected, and as the cofactor is 1 every other point on the curve
+    /// generates the whole group.
+    pub(crate) fn from_sec1_bytes(bytes: &[u8]) -> Option<Self> {
+        let len = FieldElement::<C>::BYTES;
+
+        let (x, y) = match bytes.first() {
+            Some(&SEC1_UNCOMPRESSED) if bytes.len() == 1 + 2 * len => {
+                let x = FieldElement::from_be_bytes(&bytes[1..1 + len])?;
+                let y = FieldElement::from_be_bytes(&bytes[1 + len..])?;
+
+                if y.square() != Self::curve_equation(&x) {
+                    return None;
+                }
+
+                (x, y)
+            }
+            Some(&tag @ (SEC1_COMPRESSED_EVEN | SEC1_COMPRESSED_ODD)) if bytes.len() == 1 + len => {
+                let x = FieldElement::from_be_bytes(&bytes[1..])?;
+                let mut y = Self::curve_equation(&x).sqrt()?;
+
+                if y.is_odd() != (tag == SEC1_COMPRESSED_ODD) {
+                    y = y.negate();
+                }
+
+                (x, y)
+            }
+            _ => return None,
+        };
+
+        return Some(Self {
+            x,
+            y,
+            z: FieldElement::one(),
+        });
+    }
+
+    /// Encodes the point in SEC1 format, or returns `None` for the point at infinity.
+    pub(crate) fn to_sec1_bytes(self, compress: bool) -> Option<Vec<u8>> {
+        let (x, y) = self.to_affine()?;
+        let mut bytes = Vec::with_capacity(1 + 2 * FieldElement::<C>::BYTES);
+
+        if compress {
+            bytes.push(match y.is_odd() {
+                true => SEC1_COMPRESSED_ODD,
+                false => SEC1_COMPRESSED_EVEN,
+            });
+            bytes.extend_from_slice(&x.to_be_bytes());
+        } else {
+            bytes.push(SEC1_UNCOMPRESSED);
+            bytes.extend_from_slice(&x.to_be_bytes());
+            bytes.extend_from_slice(&y.to_be_bytes());
+        }
+
+        return Some(bytes);
+    }
+
+    /// Returns the affine coordinates, or `None` for the point at infinity.
+    pub(crate) fn to_affine(self) -> Option<(FieldElement<C>, FieldElement<C>)> {
+        if self.is_identity() {
+            return None;
+        }
+
+        let z_inverse = self.z.invert();
+
+        return Some((self.x.mul(&z_inverse), self.y.mul(&z_inverse)));
+    }
+
+    pub(crate) fn is_identity(&self) -> bool {
+        return self.z.is_zero();
+    }
+
+    /// Algorithm 4 from "Complete addition formulas for prime order elliptic curves".
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        let b = FieldElement::<C>::from_canonical_limbs(&C::B);
+        let (x1, y1, z1) = (&self.x, &self.y, &self.z);
+        let (x2, y2, z2) = (&rhs.x, &rhs.y, &rhs.z);
+
+        let mut t0 = x1.mul(x2);
+        let mut t1 = y1.mul(y2);
+        let mut t2 = z1.mul(z2);
+        let mut t3 = x1.add(y1).mul(&x2.add(y2));
+        let mut t4 = t0.add(&t1);
+        t3 = t3.sub(&t4);
+        t4 = y1.add(z1).mul(&y2.add(z2));
+        let mut x3 = t1.add(&t2);
+        t4 = t4.sub(&x3);
+        x3 = x1.add(z1).mul(&x2.add(z2));
+        let mut y3 = t0.add(&t2);
+        y3 = x3.sub(&y3);
+        let mut z3 = b.mul(&t2);
+        x3 = y3.sub(&z3);
+        z3 = x3.add(&x3);
+        x3 = x3.add(&z3);
+        z3 = t1.sub(&x3);
+        x3 = t1.add(&x3);
+        y3 = b.mul(&y3);
+        t1 = t2.add(&t2);
+        t2 = t1.add(&t2);
+        y3 = y3.sub(&t2);
+        y3 = y3.sub(&t0);
+        t1 = y3.add(&y3);
+        y3 = t1.add(&y3);
+        t1 = t0.add(&t0);
+        t0 = t1.add(&t0);
+        t0 = t0.sub(&t2);
+        t1 = t4.mul(&y3);
+        t2 = t0.mul(&y3);
+        y3 = x3.mul(&z3);
+        y3 = y3.add(&t2);
+        x3 = t3.mul(&x3);
+        x3 = x3.sub(&t1);
+        z3 = t4.mul(&z3);
+        t1 = t3.mul(&t0);
+        z3 = z3.add(&t1);
+
+        return Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        };
+    }
+
+    pub(crate) fn double(&self) -> Self {
+        return self.add(self);
+    }
+
+    /// Multiplies the point by `scalar` with a fixed window of 4 bits. The table lookups touch
+    /// every entry and the same additions are made whatever the scalar, so it is safe to use
+    /// with secret scalars.
+    pub(crate) fn mul(&self, scalar: &Scalar<C>) -> Self {
+        let mut table = [Self::identity(); 16];
+
+        for i in 1..16 {
+            table[i] = table[i - 1].add(self);
+        }
+
+        let mut result = Self::identity();
+
+        for byte in scalar.to_be_bytes() {
+            for nibble in [byte >> 4, byte & 0x0f] {
+                let mut selected = Self::identity();
+
+                for (j, entry) in table.iter().enumerate() {
+                    selected.conditional_assign(entry, j as u8 == nibble);
+                }
+
+                result = result.double().double().double().double().add(&selected);
+            }
+        }
+
+        return result;
+    }
+
+    /// Returns `a * G + b * point` with Shamir's trick. The additions depend on the scalar
+    /// bits, so this must only be used with public values, as in signature verification.
+    pub(crate) fn vartime_double_mul(a: &Scalar<C>, b: &Scalar<C>, point: &Self) -> Self {
+        let generator = Self::generator();
+        let both = generator.add(point);
+        let a_bytes = a.to_be_bytes();
+        let b_bytes = b.to_be_bytes();
+        let mut result = Self::identity();
+
+        for (a_byte, b_byte) in a_bytes.iter().zip(&b_bytes) {
+            for shift in (0..8).rev() {
+                result = result.double();
+
+                match ((a_byte >> shift) & 1, (b_byte >> shift) & 1) {
+                    (1, 1) => result = result.add(&both),
+                    (1, 0) => result = result.add(&generator),
+                    (0, 1) => result = result.add(point),
+                    _ => {}
+                }
+            }
+        }
+
+        return result;
+    }
+
+    /// Returns x^3 - 3x + b, which is y^2 for points on the curve.
+    fn curve_equation(x: &FieldElement<C>) -> FieldElement<C> {
+        let b = FieldElement::<C>::from_canonical_limbs(&C::B);
+        let three_x = x.add(x).add(x);
+
+        return x.square().mul(x).sub(&three_x).add(&b);
+    }
+
+    fn conditional_assign(&mut self, other: &Self, choice: bool) {
+        self.x.conditional_assign(&other.x, choice);
+        self.y.conditional_assign(&other.y, choice);
+        self.z.conditional_assign(&other.z, choice);
+    }
+}
+
+impl<C: Curve> PartialEq for ProjectivePoint<C> {
+    /// Compares the affine coordinates, cross multiplying to avoid inversions.
+    fn eq(&self, other: &Self) -> bool {
+        return self.x.mul(&other.z) == other.x.mul(&self.z)
+            && self.y.mul(&other.z) == other.y.mul(&self.z);
+    }
+}
+
+impl<C: Curve> Eq for ProjectivePoint<C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecc::{P256, P384};
+    use pretty_assertions::assert_eq;
+
+    fn scalar<C: Curve>(s: &str) -> Scalar<C> {
+        return Scalar::from_be_bytes(&hex::decode(s).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_group_law() {
+        let g = ProjectivePoint::<P256>::generator();
+        let identity = ProjectivePoint::identity();
+
+        assert_eq!(g.add(&identity), g);
+        assert_eq!(identity.add(&g), g);
+        assert!(identity.double().is_identity());
+        assert_eq!(g.double().add(&g), g.add(&g.double()));
+
+        // n - 1 times the generator is its negation, which shares the x-coordinate
+        let n_minus_one =
+            scalar::<P256>("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550");
+        let minus_g = g.mul(&n_minus_one);
+        assert_eq!(minus_g.to_affine().unwrap().0, g.to_affine().unwrap().0);
+        assert!(minus_g.add(&g).is_identity());
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let k = scalar::<P256>("0000000000000000000000000000000000000000000000000000000000003039");
+        let expected = "04\
+                        26efcebd0ee9e34a669187e18b3a9122b2f733945b649cc9f9f921e9f9dad812\
+                        90238bde9cc7bb330d150c67704dd25ae7055205744b6f31bf4070745872d0e6";
+        let point = ProjectivePoint::<P256>::generator().mul(&k);
+
+        assert_eq!(hex::encode(point.to_sec1_bytes(false).unwrap()), expected);
+        assert_eq!(
+            ProjectivePoint::vartime_double_mul(&k, &Scalar::ZERO, &point),
+            point
+        );
+        assert_eq!(
+            ProjectivePoint::vartime_double_mul(&Scalar::ZERO, &Scalar::one(), &point),
+            point
+        );
+        assert!(ProjectivePoint::<P256>::generator()
+            .mul(&Scalar::ZERO)
+            .is_identity());
+    }
+
+    #[test]
+    fn test_sec1_round_trip() {
+        let k = scalar::<P384>(
+            "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8\
+             96d5724e4c70a825f872c9ea60d2edf5",
+        );
+        let point = ProjectivePoint::<P384>::generator().mul(&k);
+
+        for compress in [false, true] {
+            let encoded = point.to_sec1_bytes(compress).unwrap();
+
+            assert_eq!(encoded.len(), if compress { 49 } else { 97 });
+            assert_eq!(
+                ProjectivePoint::<P384>::from_sec1_bytes(&encoded),
+                Some(point)
+            );
+        }
+
+        let negated = point.mul(&Scalar::one().negate());
+        assert_ne!(
+            point.to_sec1_bytes(true).unwrap()[0],
+            negated.to_sec1_bytes(true).unwrap()[0]
+        );
+        assert_eq!(
+            ProjectivePoint::<P384>::identity().to_sec1_bytes(true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_invalid_sec1_points() {
+        let g = ProjectivePoint::<P256>::generator();
+        let uncompressed = g.to_sec1_bytes(false).unwrap();
+        let compressed = g.to_sec1_bytes(true).unwrap();
+
+        // Off the curve
+        let mut modified = uncompressed.clone();
+        modified[64] ^= 0x01;
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&modified), None);
+
+        // Wrong tags and lengths, and the SEC1 encoding of the point at infinity
+        let mut hybrid = uncompressed.clone();
+        hybrid[0] = 0x06;
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&hybrid), None);
+        assert_eq!(
+            ProjectivePoint::<P256>::from_sec1_bytes(&uncompressed[..64]),
+            None
+        );
+        assert_eq!(
+            ProjectivePoint::<P256>::from_sec1_bytes(&compressed[..32]),
+            None
+        );
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&[0x00]), None);
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&[]), None);
+
+        // x = p is not reduced, and there is no point with x = 1
+        let mut x_is_p = [0x02; 33];
+        x_is_p[1..].copy_from_slice(
+            &hex::decode("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+                .unwrap(),
+        );
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&x_is_p), None);
+
+        let mut x_is_one = [0u8; 33];
+        x_is_one[0] = 0x03;
+        x_is_one[32] = 0x01;
+        assert_eq!(ProjectivePoint::<P256>::from_sec1_bytes(&x_is_one), None);
+    }
+}
@@ -0,0 +1,814 @@
+use crate::curve25519::{EdwardsPoint, Scalar};
+use crate::error::VCryptoError;
+use crate::hashes::{Hasher, HashingAlgorithm, SHA512};
+use crate::random::RandomSource;
+use crate::util::zeroize;
+use core::fmt;
+use core::hash::Hash;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The prefix of dom2 from RFC 8032 section 5.1, which separates Ed25519ctx and Ed25519ph from
+/// plain Ed25519.
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// The longest context Ed25519ctx and Ed25519ph accept, as its length is encoded in one byte.
+pub const ED25519_MAX_CONTEXT_LENGTH: usize = 255;
+
+/// An Ed25519 secret key, the 32 byte seed from which the signing scalar and the nonce prefix
+/// are derived.
+#[derive(Clone)]
+pub struct SigningKey {
+    seed: [u8; 32],
+    // The clamped scalar followed by the prefix, the two halves of SHA-512(seed)
+    expanded: [u8; 64],
+    verifying_key: VerifyingKey,
+}
+
+/// An Ed25519 public key, kept both as its encoding and as the decoded point.
+#[derive(Clone, Copy)]
+pub struct VerifyingKey {
+    compressed: [u8; 32],
+    point: EdwardsPoint,
+}
+
+/// An Ed25519 signature, the encoding of the point R followed by the scalar S.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Signature([u8; 64]);
+
+/// Which of the three schemes from RFC 8032 a signature belongs to.
+#[derive(Clone, Copy)]
+enum Domain<'a> {
+    Pure,
+    Context(&'a [u8]),
+    Prehashed(&'a [u8]),
+}
+
+impl SigningKey {
+    pub fn from_bytes(seed: &[u8; 32]) -> Self {
+        let mut expanded = SHA512::hash(seed);
+        expanded[0] &= 248;
+        expanded[31] &= 127;
+        expanded[31] |= 64;
+
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&expanded[..32]);
+        let point = EdwardsPoint::mul_base(&Scalar::from_bytes_mod_order(&scalar_bytes));
+        zeroize(&mut scalar_bytes);
+
+        return Self {
+            seed: *seed,
+            expanded,
+            verifying_key: VerifyingKey {
+                compressed: point.compress(),
+                point,
+            },
+        };
+    }
+
+    pub fn generate(rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed)?;
+
+        let key = Self::from_bytes(&seed);
+        zeroize(&mut seed);
+
+        return Ok(key);
+    }
+
+    /// Returns the seed the key was created from.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        return self.seed;
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        return self.verifying_key;
+    }
+
+    /// Signs `message` with plain Ed25519. Signatures are deterministic, the nonce is derived
+    /// from the key and the message.
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        return self.sign_with_domain(Domain::Pure, message);
+    }
+
+    /// Signs `message` with Ed25519ctx, binding the signature to `context`. Returns
+    /// `VCryptoError::InvalidInput` if the context is empty, which RFC 8032 forbids for this
+    /// variant, or longer than 255 bytes.
+    pub fn sign_ctx(&self, message: &[u8], context: &[u8]) -> Result<Signature, VCryptoError> {
+        if context.is_empty() || context.len() > ED25519_MAX_CONTEXT_LENGTH {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(self.sign_with_domain(Domain::Context(context), message));
+    }
+
+    /// Signs with Ed25519ph the message that was fed to `prehashed`, so long messages can be
+    /// streamed. The context may be empty. Returns `VCryptoError::InvalidInput` if it is longer
+    /// than 255 bytes.
+    pub fn sign_prehashed(
+        &self,
+        prehashed: Hasher<SHA512>,
+        context: &[u8],
+    ) -> Result<Signature, VCryptoError> {
+        if context.len() > ED25519_MAX_CONTEXT_LENGTH {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(self.sign_with_domain(Domain::Prehashed(context), &prehashed.finalize()));
+    }
+
+    fn sign_with_domain(&self, domain: Domain, message: &[u8]) -> Signature {
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&self.expanded[..32]);
+        let a = Scalar::from_bytes_mod_order(&scalar_bytes);
+        zeroize(&mut scalar_bytes);
+
+        let mut hasher = domain.hasher();
+        hasher.update(&self.expanded[32..]);
+        hasher.update(message);
+        let r = Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+
+        let r_bytes = EdwardsPoint::mul_base(&r).compress();
+        let k = challenge(domain, &r_bytes, &self.verifying_key.compressed, message);
+        let s = k.mul_add(&a, &r);
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&r_bytes);
+        signature[32..].copy_from_slice(&s.to_bytes());
+
+        return Signature(signature);
+    }
+}
+
+impl VerifyingKey {
+    /// Returns `VCryptoError::InvalidKey` if `bytes` is not the canonical encoding of a curve
+    /// point, or if the point has a small order, as such a key would accept forged signatures.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, VCryptoError> {
+        let point = EdwardsPoint::decompress(bytes).ok_or(VCryptoError::InvalidKey)?;
+
+        if point.is_small_order() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(Self {
+            compressed: *bytes,
+            point,
+        });
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        return &self.compressed;
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        return self.compressed;
+    }
+
+    /// Verifies a plain Ed25519 signature of `message`.
+    ///
+    /// Verification is strict: S has to be below the group order, R has to be canonically
+    /// encoded and not of small order, and the equation [S]B = R + [k]A is checked without
+    /// multiplying by the cofactor. Every message and key therefore has at most one valid
+    /// signature per nonce.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        return self.verify_with_domain(Domain::Pure, message, signature);
+    }
+
+    /// Verifies an Ed25519ctx signature made with `context`.
+    pub fn verify_ctx(&self, message: &[u8], context: &[u8], signature: &Signature) -> bool {
+        if context.is_empty() || context.len() > ED25519_MAX_CONTEXT_LENGTH {
+            return false;
+        }
+
+        return self.verify_with_domain(Domain::Context(context), message, signature);
+    }
+
+    /// Verifies an Ed25519ph signature of the message that was fed to `prehashed`.
+    pub fn verify_prehashed(
+        &self,
+        prehashed: Hasher<SHA512>,
+        context: &[u8],
+        signature: &Signature,
+    ) -> bool {
+        if context.len() > ED25519_MAX_CONTEXT_LENGTH {
+            return false;
+        }
+
+        return self.verify_with_domain(
+            Domain::Prehashed(context),
+            &prehashed.finalize(),
+            signature,
+        );
+    }
+
+    fn verify_with_domain(&self, domain: Domain, message: &[u8], signature: &Signature) -> bool {
+        let (r_bytes, _, s) = match signature.decode() {
+            Some(decoded) => decoded,
+            None => return false,
+        };
+
+        let k = challenge(domain, &r_bytes, &self.compressed, message);
+
+        // [S]B - [k]A has to be R, comparing encodings is enough as R was canonical
+        let check = EdwardsPoint::vartime_multiscalar_mul(
+            &[s, k],
+            &[EdwardsPoint::BASEPOINT, self.point.negate()],
+        );
+
+        return check.compress() == r_bytes;
+    }
+}
+
+impl Signature {
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        return Self(*bytes);
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        return &self.0;
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        return self.0;
+    }
+
+    /// Splits the signature into the encoding of R, R itself and the scalar S, or returns `None`
+    /// if R is not a canonically encoded point of large order or S is not reduced.
+    fn decode(&self) -> Option<([u8; 32], EdwardsPoint, Scalar)> {
+        let mut r_bytes = [0u8; 32];
+        let mut s_bytes = [0u8; 32];
+        r_bytes.copy_from_slice(&self.0[..32]);
+        s_bytes.copy_from_slice(&self.0[32..]);
+
+        let s = Scalar::from_canonical_bytes(&s_bytes)?;
+        let r = EdwardsPoint::decompress(&r_bytes)?;
+
+        if r.is_small_order() {
+            return None;
+        }
+
+        return Some((r_bytes, r, s));
+    }
+}
+
+impl Domain<'_> {
+    /// Starts a SHA-512 hash with dom2(F, C), which is empty for plain Ed25519.
+    fn hasher(self) -> Hasher<SHA512> {
+        let mut hasher = SHA512::hasher();
+
+        let (flag, context) = match self {
+            Domain::Pure => return hasher,
+            Domain::Context(context) => (0u8, context),
+            Domain::Prehashed(context) => (1u8, context),
+        };
+
+        hasher.update(DOM2_PREFIX);
+        hasher.update(&[flag, context.len() as u8]);
+        hasher.update(context);
+
+        return hasher;
+    }
+}
+
+/// Computes k = SHA-512(dom2(F, C) || R || A || M) modulo the group order.
+fn challenge(domain: Domain, r_bytes: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    let mut hasher = domain.hasher();
+    hasher.update(r_bytes);
+    hasher.update(public_key);
+    hasher.update(message);
+
+    return Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+}
+
+/// Verifies plain Ed25519 signatures of `messages[i]` by `verifying_keys[i]` all at once, by
+/// checking a random linear combination of their equations. Returns `Ok(false)` if any of them
+/// is invalid, without telling which, and `VCryptoError::InvalidInput` if the slices have
+/// different lengths.
+///
+/// The combined equation is multiplied by the cofactor, otherwise random coefficients would
+/// make the outcome random for signatures with a small order component. Signatures that pass
+/// [`VerifyingKey::verify`] always pass here, but a deliberately malformed one could pass here
+/// and fail there.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn verify_batch(
+    messages: &[&[u8]],
+    signatures: &[Signature],
+    verifying_keys: &[VerifyingKey],
+    rng: &mut impl RandomSource,
+) -> Result<bool, VCryptoError> {
+    if messages.len() != signatures.len() || messages.len() != verifying_keys.len() {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    let mut scalars = Vec::with_capacity(2 * messages.len() + 1);
+    let mut points = Vec::with_capacity(2 * messages.len() + 1);
+    let mut s_sum = Scalar::default();
+
+    for ((message, signature), verifying_key) in messages.iter().zip(signatures).zip(verifying_keys)
+    {
+        let (r_bytes, r, s) = match signature.decode() {
+            Some(decoded) => decoded,
+            None => return Ok(false),
+        };
+
+        // 128 bit coefficients are enough to make a forgery slip through with negligible odds
+        let mut z_bytes = [0u8; 32];
+        rng.fill_bytes(&mut z_bytes[..16])?;
+        let z = Scalar::from_bytes_mod_order(&z_bytes);
+
+        let k = challenge(Domain::Pure, &r_bytes, &verifying_key.compressed, message);
+
+        s_sum = z.mul_add(&s, &s_sum);
+        scalars.push(z);
+        points.push(r);
+        scalars.push(z.mul_add(&k, &Scalar::default()));
+        points.push(verifying_key.point);
+    }
+
+    // sum(z R) + sum(z k A) - sum(z S) B has to be the identity
+    scalars.push(s_sum);
+    points.push(EdwardsPoint::BASEPOINT.negate());
+
+    return Ok(EdwardsPoint::vartime_multiscalar_mul(&scalars, &points)
+        .mul_by_cofactor()
+        .is_identity());
+}
+
+impl From<[u8; 32]> for SigningKey {
+    fn from(seed: [u8; 32]) -> Self {
+        return Self::from_bytes(&seed);
+    }
+}
+
+impl From<&SigningKey> for VerifyingKey {
+    fn from(signing_key: &SigningKey) -> Self {
+        return signing_key.verifying_key;
+    }
+}
+
+impl TryFrom<[u8; 32]> for VerifyingKey {
+    type Error = VCryptoError;
+
+    fn try_from(bytes: [u8; 32]) -> Result<Self, Self::Error> {
+        return Self::from_bytes(&bytes);
+    }
+}
+
+impl From<[u8; 64]> for Signature {
+    fn from(bytes: [u8; 64]) -> Self {
+        return Self(bytes);
+    }
+}
+
+impl PartialEq for VerifyingKey {
+    fn eq(&self, other: &Self) -> bool {
+        return self.compressed == other.compressed;
+    }
+}
+
+impl Eq for VerifyingKey {}
+
+impl Hash for VerifyingKey {
+    fn hash<S: core::hash::Hasher>(&self, state: &mut S) {
+        self.compressed.hash(state);
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        zeroize(&mut self.seed);
+        zeroize(&mut self.expanded);
+    }
+}
+
+impl fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SigningKey(..)");
+    }
+}
+
+impl fmt::Debug for VerifyingKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "VerifyingKey({:?})", self.compressed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    struct TestVector {
+        seed: &'static str,
+        public_key: &'static str,
+        message: &'static str,
+        signature: &'static str,
+    }
+
+    // RFC 8032 section 7.1
+    const RFC8032_VECTORS: [TestVector; 4] = [
+        TestVector {
+            seed: "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            public_key: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            message: "",
+            signature: "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                        5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        },
+        TestVector {
+            seed: "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            public_key: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            message: "72",
+            signature: "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                        085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        },
+        TestVector {
+            seed: "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            public_key: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            message: "af82",
+            signature: "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+                        18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        },
+        TestVector {
+            seed: "f5e5767cf153319517630f226876b86c8160cc583bc013744c6bf255f5cc0ee5",
+            public_key: "278117fc144c72340f67d0f2316e8386ceffbf2b2428c9c51fef7c597f1d426e",
+            message: "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                      2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            signature: "f43f7b7aa9758bce7bdcc41d3774acb1950b27fff41aab020cd8be565f6428b8\
+                        675a79a6b50708dbe80c10686dc84377affbe4aef5a66c750fa2237cc085b20f",
+        },
+    ];
+
+    fn seed(s: &str) -> [u8; 32] {
+        return hex::decode(s).unwrap().try_into().unwrap();
+    }
+
+    fn signature(s: &str) -> Signature {
+        return Signature::from_bytes(&hex::decode(s).unwrap().try_into().unwrap());
+    }
+
+    fn prehashed(message: &[u8]) -> Hasher<SHA512> {
+        let mut hasher = SHA512::hasher();
+        hasher.update(message);
+
+        return hasher;
+    }
+
+    #[test]
+    fn test_rfc8032_ed25519() {
+        for vector in RFC8032_VECTORS.iter() {
+            let key = SigningKey::from_bytes(&seed(vector.seed));
+            let message = hex::decode(vector.message).unwrap();
+            let signature = key.sign(&message);
+
+            assert_eq!(
+                hex::encode(key.verifying_key().as_bytes()),
+                vector.public_key
+            );
+            assert_eq!(hex::encode(signature.as_bytes()), vector.signature);
+
+            let verifying_key = VerifyingKey::from_bytes(&seed(vector.public_key)).unwrap();
+            assert!(verifying_key.verify(&message, &signature));
+            assert!(!verifying_key.verify(b"another message", &signature));
+        }
+    }
+
+    #[test]
+    fn test_rfc8032_ed25519ctx() {
+        // RFC 8032 section 7.2
+        let key = SigningKey::from_bytes(&seed(
+            "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+        ));
+        let verifying_key = key.verifying_key();
+        let message = hex::decode("f726936d19c800494e3fdaff20b276a8").unwrap();
+
+        assert_eq!(
+            hex::encode(verifying_key.as_bytes()),
+            "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292"
+        );
+
+        for (context, expected) in [
+            (
+                &b"foo"[..],
+                "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a\
+                 8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+            ),
+            (
+                &b"bar"[..],
+                "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3\
+                 216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+            ),
+        ] {
+            let signature = key.sign_ctx(&message, context).unwrap();
+
+            assert_eq!(hex::encode(signature.as_bytes()), expected);
+            assert!(verifying_key.verify_ctx(&message, context, &signature));
+            assert!(!verifying_key.verify_ctx(&message, b"baz", &signature));
+            assert!(!verifying_key.verify(&message, &signature));
+        }
+
+        let signature = key
+            .sign_ctx(
+                &hex::decode("508e9e6882b979fea900f62adceaca35").unwrap(),
+                b"foo",
+            )
+            .unwrap();
+        assert_eq!(
+            hex::encode(signature.as_bytes()),
+            "8b70c1cc8310e1de20ac53ce28ae6e7207f33c3295e03bb5c0732a1d20dc6490\
+             8922a8b052cf99b7c4fe107a5abb5b2c4085ae75890d02df26269d8945f84b0b"
+        );
+    }
+
+    #[test]
+    fn test_rfc8032_ed25519ph() {
+        // RFC 8032 section 7.3
+        let key = SigningKey::from_bytes(&seed(
+            "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+        ));
+        let verifying_key = key.verifying_key();
+        let signature = key.sign_prehashed(prehashed(b"abc"), &[]).unwrap();
+
+        assert_eq!(
+            hex::encode(signature.as_bytes()),
+            "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae41\
+             31f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406"
+        );
+        assert!(verifying_key.verify_prehashed(prehashed(b"abc"), &[], &signature));
+        assert!(!verifying_key.verify_prehashed(prehashed(b"abd"), &[], &signature));
+        assert!(!verifying_key.verify_prehashed(prehashed(b"abc"), b"context", &signature));
+        assert!(!verifying_key.verify(b"abc", &signature));
+    }
+
+    #[test]
+    fn test_context_length() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let verifying_key = key.verifying_key();
+        let longest = [0x61; ED25519_MAX_CONTEXT_LENGTH];
+
+        assert_eq!(
+            key.sign_ctx(b"message", &[]).unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+        assert_eq!(
+            key.sign_ctx(b"message", &[0; 256]).unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+        assert_eq!(
+            key.sign_prehashed(prehashed(b"message"), &[0; 256])
+                .unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+
+        let signature = key.sign_ctx(b"message", &longest).unwrap();
+        assert!(verifying_key.verify_ctx(b"message", &longest, &signature));
+        assert!(!verifying_key.verify_ctx(b"message", &[], &signature));
+    }
+
+    /// The group order L, little endian.
+    const GROUP_ORDER: &str = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+
+    /// Checks [S]B = R + [k]A with S reduced instead of rejected and without looking at the order
+    /// of R or A, which is all a lax verifier does. Every case rejected below passes this, so the
+    /// strict checks are what stops them.
+    fn lax_verify(public_key: &[u8; 32], message: &[u8], signature: &Signature) -> bool {
+        let r_bytes: [u8; 32] = signature.as_bytes()[..32].try_into().unwrap();
+        let s_bytes: [u8; 32] = signature.as_bytes()[32..].try_into().unwrap();
+        let a = EdwardsPoint::decompress(public_key).unwrap();
+        let r = EdwardsPoint::decompress(&r_bytes).unwrap();
+        let k = challenge(Domain::Pure, &r_bytes, public_key, message);
+
+        return EdwardsPoint::mul_base(&Scalar::from_bytes_mod_order(&s_bytes))
+            == r.add(&a.mul(&k));
+    }
+
+    /// Builds R || S with S = r + k a, using the secret scalar a of `key` and any R = [r]B.
+    fn forge(key: &SigningKey, r_bytes: &[u8; 32], r: &Scalar, message: &[u8]) -> Signature {
+        let mut a_bytes = [0u8; 32];
+        a_bytes.copy_from_slice(&key.expanded[..32]);
+        let k = challenge(
+            Domain::Pure,
+            r_bytes,
+            key.verifying_key().as_bytes(),
+            message,
+        );
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(r_bytes);
+        bytes[32..].copy_from_slice(
+            &k.mul_add(&Scalar::from_bytes_mod_order(&a_bytes), r)
+                .to_bytes(),
+        );
+
+        return Signature::from(bytes);
+    }
+
+    #[test]
+    fn test_malleability() {
+        let vector = &RFC8032_VECTORS[0];
+        let public_key = seed(vector.public_key);
+        let verifying_key = VerifyingKey::from_bytes(&public_key).unwrap();
+        let order = seed(GROUP_ORDER);
+
+        // S + L satisfies the equation as well but is not reduced
+        let mut bytes = signature(vector.signature).to_bytes();
+        let mut carry = 0u16;
+        for (s, l) in bytes[32..].iter_mut().zip(order) {
+            carry += *s as u16 + l as u16;
+            *s = carry as u8;
+            carry >>= 8;
+        }
+        let s_plus_l = Signature::from(bytes);
+
+        assert_eq!(
+            hex::encode(s_plus_l.as_bytes()),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+             4c8c7872aa064e049dbb3013fbf29380d25bf5f0595bbe24655141438e7a101b"
+        );
+        assert!(lax_verify(&public_key, b"", &s_plus_l));
+        assert!(!verifying_key.verify(b"", &s_plus_l));
+
+        // S = L and S with the top bit set
+        bytes[32..].copy_from_slice(&order);
+        assert!(!verifying_key.verify(b"", &Signature::from(bytes)));
+        bytes[63] |= 0x80;
+        assert!(!verifying_key.verify(b"", &Signature::from(bytes)));
+    }
+
+    #[test]
+    fn test_small_order_r() {
+        // R is the identity and S = k a, so [S]B = R + [k]A holds without knowing the nonce
+        let key = SigningKey::from_bytes(&seed(RFC8032_VECTORS[0].seed));
+        let identity = EdwardsPoint::IDENTITY.compress();
+        let forged = forge(&key, &identity, &Scalar::default(), b"small order R");
+
+        assert_eq!(
+            hex::encode(forged.as_bytes()),
+            "0100000000000000000000000000000000000000000000000000000000000000\
+             f0a69ddebb802c89e405561d3315c191f2a6a984f8b7999f1f271e28c0a9850d"
+        );
+        assert!(lax_verify(
+            key.verifying_key().as_bytes(),
+            b"small order R",
+            &forged
+        ));
+        assert!(!key.verifying_key().verify(b"small order R", &forged));
+    }
+
+    #[test]
+    fn test_small_order_a() {
+        // With A the identity, R = [r]B and S = r verify for every message
+        let identity = EdwardsPoint::IDENTITY.compress();
+        let r = Scalar::from_bytes_mod_order(&[0x42; 32]);
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&EdwardsPoint::mul_base(&r).compress());
+        bytes[32..].copy_from_slice(&r.to_bytes());
+        let forged = Signature::from(bytes);
+
+        assert!(lax_verify(&identity, b"first message", &forged));
+        assert!(lax_verify(&identity, b"second message", &forged));
+        assert_eq!(
+            VerifyingKey::from_bytes(&identity).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+    }
+
+    #[test]
+    fn test_non_canonical_r() {
+        // The identity encoded with y = 1 + p, S = k a is computed over that encoding
+        let key = SigningKey::from_bytes(&seed(RFC8032_VECTORS[0].seed));
+        let r_bytes = seed("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        let forged = forge(&key, &r_bytes, &Scalar::default(), b"non canonical R");
+
+        assert!(EdwardsPoint::decompress(&r_bytes).is_none());
+        assert!(!key.verifying_key().verify(b"non canonical R", &forged));
+
+        // A large order R encoded with its sign bit flipped is a different point
+        let signature = key.sign(b"message");
+        let mut bytes = signature.to_bytes();
+        bytes[31] ^= 0x80;
+        assert!(!key
+            .verifying_key()
+            .verify(b"message", &Signature::from(bytes)));
+    }
+
+    #[test]
+    fn test_invalid_verifying_keys() {
+        // The 8 points of small order, which verify S = 0 signatures with a small order R for any
+        // message. x = 0 and y = -1 only have one encoding each.
+        for encoding in [
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac037a",
+            "c7176a703d4dd84fba3c0b760d10670f2a2053fa2c39ccc64ec7fd7792ac03fa",
+            "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc05",
+            "26e8958fc2b227b045c3f489f2ef98f0d5dfac05d3c63339b13802886d53fc85",
+        ] {
+            let point = EdwardsPoint::decompress(&seed(encoding)).unwrap();
+
+            assert!(point.is_small_order());
+            assert_eq!(
+                VerifyingKey::from_bytes(&seed(encoding)).unwrap_err(),
+                VCryptoError::InvalidKey
+            );
+        }
+
+        // The 19 field elements below 19 also fit in 255 bits as y + p, with either sign bit
+        let mut encoding = seed("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        for i in 0..19 {
+            encoding[0] = 0xed + i;
+            for sign in [0x00, 0x80] {
+                encoding[31] = 0x7f | sign;
+
+                assert!(EdwardsPoint::decompress(&encoding).is_none());
+                assert_eq!(
+                    VerifyingKey::from_bytes(&encoding).unwrap_err(),
+                    VCryptoError::InvalidKey
+                );
+            }
+        }
+
+        // A negative zero x, and y = 2 which is not on the curve
+        for encoding in [
+            "0100000000000000000000000000000000000000000000000000000000000080",
+            "0200000000000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert!(EdwardsPoint::decompress(&seed(encoding)).is_none());
+            assert_eq!(
+                VerifyingKey::from_bytes(&seed(encoding)).unwrap_err(),
+                VCryptoError::InvalidKey
+            );
+        }
+    }
+
+    #[test]
+    fn test_modified_signatures() {
+        let key = SigningKey::from_bytes(&[0x42; 32]);
+        let verifying_key = key.verifying_key();
+        let signature = key.sign(b"message");
+
+        assert!(verifying_key.verify(b"message", &signature));
+
+        for i in [0, 31, 32, 63] {
+            let mut bytes = signature.to_bytes();
+            bytes[i] ^= 0x01;
+
+            assert!(!verifying_key.verify(b"message", &Signature::from(bytes)));
+        }
+
+        let other_key = SigningKey::from_bytes(&[0x43; 32]).verifying_key();
+        assert!(!other_key.verify(b"message", &signature));
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"ed25519 generate", &[], &[]);
+        let key = SigningKey::generate(&mut rng).unwrap();
+        let restored = SigningKey::from(key.to_bytes());
+
+        assert_eq!(key.verifying_key(), restored.verifying_key());
+        assert_eq!(
+            VerifyingKey::try_from(key.verifying_key().to_bytes()).unwrap(),
+            key.verifying_key()
+        );
+        assert_eq!(format!("{:?}", key), "SigningKey(..)");
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"ed25519 batch", &[], &[]);
+        let messages: Vec<&[u8]> = vec![b"first", b"second", b"third", b""];
+        let keys: Vec<SigningKey> = (0..4)
+            .map(|_| SigningKey::generate(&mut rng).unwrap())
+            .collect();
+        let mut signatures: Vec<Signature> = keys
+            .iter()
+            .zip(&messages)
+            .map(|(key, message)| key.sign(message))
+            .collect();
+        let verifying_keys: Vec<VerifyingKey> =
+            keys.iter().map(SigningKey::verifying_key).collect();
+
+        assert!(verify_batch(&messages, &signatures, &verifying_keys, &mut rng).unwrap());
+        assert!(verify_batch(&[], &[], &[], &mut rng).unwrap());
+
+        // Signatures swapped between two messages
+        signatures.swap(0, 1);
+        assert!(!verify_batch(&messages, &signatures, &verifying_keys, &mut rng).unwrap());
+        signatures.swap(0, 1);
+
+        let mut bytes = signatures[2].to_bytes();
+        bytes[40] ^= 0x01;
+        signatures[2] = Signature::from(bytes);
+        assert!(!verify_batch(&messages, &signatures, &verifying_keys, &mut rng).unwrap());
+
+        assert_eq!(
+            verify_batch(&messages[1..], &signatures, &verifying_keys, &mut rng).unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+}
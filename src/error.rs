@@ -19,6 +19,7 @@ pub enum VCryptoError {
     InvalidKey,
     InvalidPadding,
     InvalidInput,
+    InvalidEncoding,
     InvalidPasswordLength {
         password_length: usize,
         min: usize,
@@ -29,6 +30,9 @@ pub enum VCryptoError {
         min: usize,
         max: usize,
     },
+    UnknownAlgorithm,
+    Cancelled,
+    RandomSourceFailure,
 }
 
 #[cfg(feature = "std")]
@@ -52,6 +56,7 @@ impl Display for VCryptoError {
             VCryptoError::InvalidKey => write!(f, "invalid key"),
             VCryptoError::InvalidPadding => write!(f, "invalid padding"),
             VCryptoError::InvalidInput => write!(f, "invalid input"),
+            VCryptoError::InvalidEncoding => write!(f, "invalid encoding"),
             VCryptoError::InvalidPasswordLength {
                 password_length: _,
                 min: _,
@@ -62,6 +67,9 @@ impl Display for VCryptoError {
                 min: _,
                 max: _,
             } => write!(f, "invalid cost"),
+            VCryptoError::UnknownAlgorithm => write!(f, "unknown algorithm"),
+            VCryptoError::Cancelled => write!(f, "operation cancelled"),
+            VCryptoError::RandomSourceFailure => write!(f, "random source failure"),
         };
     }
 }
@@ -90,6 +98,7 @@ impl Debug for VCryptoError {
             VCryptoError::InvalidKey => write!(f, "invalid key"),
             VCryptoError::InvalidPadding => write!(f, "invalid padding"),
             VCryptoError::InvalidInput => write!(f, "invalid input"),
+            VCryptoError::InvalidEncoding => write!(f, "invalid encoding"),
             VCryptoError::InvalidPasswordLength {
                 password_length,
                 min,
@@ -104,6 +113,11 @@ impl Debug for VCryptoError {
                 "invalid cost parameter ({}), the cost should be between {} and {}",
                 cost, min, max
             ),
+            VCryptoError::UnknownAlgorithm => write!(f, "unknown algorithm"),
+            VCryptoError::Cancelled => write!(f, "operation cancelled"),
+            VCryptoError::RandomSourceFailure => {
+                write!(f, "the random source failed to provide bytes")
+            }
         };
     }
 }
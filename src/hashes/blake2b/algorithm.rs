@@ -1,57 +1,192 @@
 use super::constants::*;
-use crate::hashes::HashingAlgorithm;
-
-use core::cmp::min;
+use crate::hashes::{HashingAlgorithm, VariableOutput};
 
+#[derive(Clone, Copy, Debug)]
 pub struct BLAKE2bBuilder {
     key: Option<[u8; 128]>,
-    key_len: u8,
-    output_len: u8,
+    parameters: BLAKE2bParameters,
+    last_node: bool,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct BLAKE2b {
     hash_state: [u64; 8],
     output_len: u8,
+    // The key block is held back until it is known whether it is also the final block
+    key_block: Option<[u8; 128]>,
     key_provided: bool,
+    last_node: bool,
+}
+
+/// The BLAKE2b parameter block, see section 2.5 of the BLAKE2 specification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct BLAKE2bParameters {
+    pub(crate) digest_length: u8,
+    pub(crate) key_length: u8,
+    pub(crate) fanout: u8,
+    pub(crate) depth: u8,
+    pub(crate) leaf_length: u32,
+    pub(crate) node_offset: u64,
+    pub(crate) node_depth: u8,
+    pub(crate) inner_length: u8,
+    pub(crate) salt: [u8; BLAKE2B_SALT_LEN],
+    pub(crate) personalization: [u8; BLAKE2B_PERSONALIZATION_LEN],
+}
+
+impl BLAKE2bParameters {
+    pub(crate) fn new(digest_length: u8) -> Self {
+        return Self {
+            digest_length,
+            key_length: 0,
+            fanout: 1,
+            depth: 1,
+            leaf_length: 0,
+            node_offset: 0,
+            node_depth: 0,
+            inner_length: 0,
+            salt: [0u8; BLAKE2B_SALT_LEN],
+            personalization: [0u8; BLAKE2B_PERSONALIZATION_LEN],
+        };
+    }
+
+    fn to_words(self) -> [u64; 8] {
+        let mut words = [0u64; 8];
+
+        words[0] = (self.digest_length as u64)
+            | ((self.key_length as u64) << 8)
+            | ((self.fanout as u64) << 16)
+            | ((self.depth as u64) << 24)
+            | ((self.leaf_length as u64) << 32);
+        words[1] = self.node_offset;
+        words[2] = (self.node_depth as u64) | ((self.inner_length as u64) << 8);
+        words[4] = u64::from_le_bytes(self.salt[0..8].try_into().unwrap());
+        words[5] = u64::from_le_bytes(self.salt[8..16].try_into().unwrap());
+        words[6] = u64::from_le_bytes(self.personalization[0..8].try_into().unwrap());
+        words[7] = u64::from_le_bytes(self.personalization[8..16].try_into().unwrap());
+
+        return words;
+    }
 }
 
 impl BLAKE2bBuilder {
     pub fn new() -> Self {
         return Self {
             key: None,
-            key_len: 0,
-            output_len: 0,
+            parameters: BLAKE2bParameters::new(BLAKE2B_OUTPUT_MAX as u8),
+            last_node: false,
         };
     }
 
+    /// Sets the key, turning BLAKE2b into a MAC. Keys may be at most 64 bytes long.
     pub fn with_key_slice(mut self, key: &[u8]) -> Self {
-        let n = min(64, key.len());
+        if key.len() > BLAKE2B_KEY_MAX {
+            panic!("Invalid BLAKE2b key length");
+        }
+
         let mut k = [0u8; 128];
-        k[..n].copy_from_slice(&key[..n]);
+        k[..key.len()].copy_from_slice(key);
 
-        self.key = Some(k);
+        self.key = if key.is_empty() { None } else { Some(k) };
+        self.parameters.key_length = key.len() as u8;
 
         return self;
     }
 
+    /// Sets the digest length in bytes, between 1 and 64.
     pub fn with_output_len(mut self, output_len: u8) -> Self {
-        if output_len < 64 || output_len > 1 {
+        if output_len < 1 || output_len as usize > BLAKE2B_OUTPUT_MAX {
             panic!("Invalid BLAKE2b output length");
         }
 
-        self.output_len = output_len;
+        self.parameters.digest_length = output_len;
 
         return self;
     }
 
-    pub fn build(self) -> BLAKE2b {
-        return BLAKE2b {
-            hash_state: BLAKE2B_IV,
-            output_len: self.output_len,
-            key_provided: self.key.is_some(),
+    /// Sets the salt, shorter salts are padded with zeros.
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        if salt.len() > BLAKE2B_SALT_LEN {
+            panic!("Invalid BLAKE2b salt length");
+        }
+
+        self.parameters.salt = [0u8; BLAKE2B_SALT_LEN];
+        self.parameters.salt[..salt.len()].copy_from_slice(salt);
+
+        return self;
+    }
+
+    /// Sets the personalization string, shorter strings are padded with zeros.
+    pub fn with_personalization(mut self, personalization: &[u8]) -> Self {
+        if personalization.len() > BLAKE2B_PERSONALIZATION_LEN {
+            panic!("Invalid BLAKE2b personalization length");
+        }
+
+        self.parameters.personalization = [0u8; BLAKE2B_PERSONALIZATION_LEN];
+        self.parameters.personalization[..personalization.len()].copy_from_slice(personalization);
+
+        return self;
+    }
+
+    /// Sets the maximum number of children of each node in a tree, 0 means unlimited.
+    pub fn with_fanout(mut self, fanout: u8) -> Self {
+        self.parameters.fanout = fanout;
+
+        return self;
+    }
+
+    /// Sets the maximum depth of a tree, between 1 and 255.
+    pub fn with_max_depth(mut self, depth: u8) -> Self {
+        if depth < 1 {
+            panic!("Invalid BLAKE2b tree depth");
+        }
+
+        self.parameters.depth = depth;
+
+        return self;
+    }
+
+    /// Sets the maximum number of bytes hashed by each leaf, 0 means unlimited.
+    pub fn with_leaf_length(mut self, leaf_length: u32) -> Self {
+        self.parameters.leaf_length = leaf_length;
+
+        return self;
+    }
+
+    /// Sets the offset of this node within its level of the tree, starting at 0.
+    pub fn with_node_offset(mut self, node_offset: u64) -> Self {
+        self.parameters.node_offset = node_offset;
+
+        return self;
+    }
+
+    /// Sets the level of this node in the tree, 0 for leaves.
+    pub fn with_node_depth(mut self, node_depth: u8) -> Self {
+        self.parameters.node_depth = node_depth;
+
+        return self;
+    }
+
+    /// Sets the length in bytes of the digests passed from one level of the tree to the next,
+    /// between 0 and 64.
+    pub fn with_inner_length(mut self, inner_length: u8) -> Self {
+        if inner_length as usize > BLAKE2B_OUTPUT_MAX {
+            panic!("Invalid BLAKE2b inner length");
         }
-        .initialize(self.key, self.key_len as u64);
+
+        self.parameters.inner_length = inner_length;
+
+        return self;
+    }
+
+    /// Marks this node as the last node on its level of the tree.
+    pub fn with_last_node(mut self, last_node: bool) -> Self {
+        self.last_node = last_node;
+
+        return self;
+    }
+
+    pub fn build(self) -> BLAKE2b {
+        return BLAKE2b::from_parameters(self.parameters, self.key, self.last_node);
     }
 }
 
@@ -60,18 +195,55 @@ impl BLAKE2b {
         return BLAKE2bBuilder::new();
     }
 
-    fn initialize(mut self, key: Option<[u8; 128]>, key_len: u64) -> Self {
-        // self.hash_state[0] ^= ((0x0101 as u64) << 4) | (key_len << 2) | (self.output_len as u64);
-        self.hash_state[0] =
-            self.hash_state[0] ^ 0x01010000 ^ (key_len << 8) ^ self.output_len as u64;
+    pub(crate) fn from_parameters(
+        parameters: BLAKE2bParameters,
+        key_block: Option<[u8; 128]>,
+        last_node: bool,
+    ) -> Self {
+        let mut hash_state = BLAKE2B_IV;
 
-        if let Some(k) = key {
-            self.update(&k, 0);
+        for (h, p) in hash_state.iter_mut().zip(parameters.to_words()) {
+            *h ^= p;
         }
 
+        return Self {
+            hash_state,
+            output_len: parameters.digest_length,
+            key_block,
+            key_provided: key_block.is_some(),
+            last_node,
+        };
+    }
+
+    /// Overrides the number of bytes returned by `finalize` without changing the parameter block,
+    /// the leaves of a tree return their full state to the root node.
+    pub(crate) fn with_output_len(mut self, output_len: u8) -> Self {
+        self.output_len = output_len;
+
         return self;
     }
 
+    fn process_key_block(&mut self, final_block: bool) {
+        if let Some(k) = self.key_block.take() {
+            self.compress(&k, 0, final_block);
+        }
+    }
+
+    fn output(&self) -> VariableOutput<BLAKE2B_OUTPUT_MAX> {
+        let mut max_output = [0u8; BLAKE2B_OUTPUT_MAX];
+
+        for (i, b) in self
+            .hash_state
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (i * 8, n.to_le_bytes()))
+        {
+            max_output[i..i + 8].copy_from_slice(&b);
+        }
+
+        return VariableOutput::new(max_output, self.output_len as usize);
+    }
+
     fn compress(&mut self, chunk: &[u8], bytes_processed: u128, final_block: bool) {
         let tbp;
 
@@ -90,6 +262,10 @@ impl BLAKE2b {
 
         if final_block {
             v[14] ^= 0xffff_ffff_ffff_ffff;
+
+            if self.last_node {
+                v[15] ^= 0xffff_ffff_ffff_ffff;
+            }
         }
 
         let mut m = [0u64; 16];
@@ -145,11 +321,11 @@ impl BLAKE2b {
 impl HashingAlgorithm for BLAKE2b {
     type Chunk = [u8; 128];
 
-    type Output = [u8; 64];
+    type Output = VariableOutput<BLAKE2B_OUTPUT_MAX>;
 
     const CHUNK_SIZE: usize = 128;
 
-    const OUTPUT_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = BLAKE2B_OUTPUT_MAX;
 
     const LENGTH_MODULO: u128 = u128::MAX;
 
@@ -157,45 +333,48 @@ impl HashingAlgorithm for BLAKE2b {
         return [0u8; Self::CHUNK_SIZE];
     }
 
+    fn output_len(&self) -> usize {
+        return self.output_len as usize;
+    }
+
     fn update(&mut self, chunk: &[u8], bytes_processed: u128) {
+        self.process_key_block(false);
         self.compress(chunk, bytes_processed, false);
     }
 
     fn finalize(mut self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        if total_bytes_processed == 0 && self.key_block.is_some() {
+            // The key block is the only block
+            self.process_key_block(true);
+
+            return self.output();
+        }
+
+        self.process_key_block(false);
+
         let mut final_chunk = [0u8; 128];
         final_chunk[0..partial_chunk.len()].copy_from_slice(partial_chunk);
 
         self.compress(&final_chunk, total_bytes_processed, true);
 
-        let mut max_output = [0u8; 64];
-
-        for (i, b) in self
-            .hash_state
-            .into_iter()
-            .enumerate()
-            .map(|(i, n)| (i * 8, n.to_le_bytes()))
-        {
-            max_output[i..i + 8].copy_from_slice(&b);
-        }
-
-        return max_output;
+        return self.output();
     }
 }
 
 impl Default for BLAKE2b {
     fn default() -> Self {
-        return Self {
-            hash_state: BLAKE2B_IV,
-            output_len: 64,
-            key_provided: false,
-        }
-        .initialize(None, 0);
+        return Self::from_parameters(
+            BLAKE2bParameters::new(BLAKE2B_OUTPUT_MAX as u8),
+            None,
+            false,
+        );
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hashes::Hasher;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -241,4 +420,76 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_blake2b_256_abc() {
+        let mut hasher = Hasher::new(BLAKE2b::builder().with_output_len(32).build());
+        hasher.update(b"abc");
+
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_output_len_salt_personalization() {
+        let mut hasher = Hasher::new(
+            BLAKE2b::builder()
+                .with_output_len(20)
+                .with_key_slice(b"secret key")
+                .with_salt(b"sixteen byte sal")
+                .with_personalization(b"personalization!")
+                .build(),
+        );
+        hasher.update(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "52114bea6bd6bfe2eb2b1c2a41ee5951d1106b61"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_tree_parameters() {
+        let mut hasher = Hasher::new(
+            BLAKE2b::builder()
+                .with_fanout(2)
+                .with_max_depth(3)
+                .with_leaf_length(4096)
+                .with_node_offset(5)
+                .with_node_depth(1)
+                .with_inner_length(64)
+                .with_last_node(true)
+                .build(),
+        );
+        hasher.update(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "13a1087dbb67ecdf67ee7861801b81f0412bffe328912c65a0c02725409d10bbf56ccbc34cf4d5c6cbfb75d36ca5645be243cb01948a93fb90dccaebd3e981ef"
+        );
+    }
+
+    #[test]
+    fn test_blake2b_valid_output_lengths() {
+        for output_len in 1..=64 {
+            let output =
+                Hasher::new(BLAKE2b::builder().with_output_len(output_len).build()).finalize();
+
+            assert_eq!(output.len(), output_len as usize);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blake2b_invalid_output_len() {
+        BLAKE2b::builder().with_output_len(65);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blake2b_invalid_key_len() {
+        BLAKE2b::builder().with_key_slice(&[0u8; 65]);
+    }
 }
@@ -21,3 +21,8 @@ pub const BLAKE2B_SCHEDULE: [[u64; 16]; 10] = [
     [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
     [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
 ];
+
+pub const BLAKE2B_KEY_MAX: usize = 64;
+pub const BLAKE2B_OUTPUT_MAX: usize = 64;
+pub const BLAKE2B_SALT_LEN: usize = 16;
+pub const BLAKE2B_PERSONALIZATION_LEN: usize = 16;
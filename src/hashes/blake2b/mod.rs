@@ -1,4 +1,6 @@
 mod algorithm;
-mod constants;
+pub(crate) mod constants;
+mod parallel;
 
 pub use algorithm::*;
+pub use parallel::*;
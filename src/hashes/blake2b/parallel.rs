@@ -0,0 +1,226 @@
+use super::algorithm::{BLAKE2b, BLAKE2bParameters};
+use super::constants::*;
+use crate::hashes::{Hasher, HashingAlgorithm, VariableOutput};
+
+const BLAKE2BP_DEGREE: usize = 4;
+const BLAKE2B_BLOCK: usize = 128;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BLAKE2bpBuilder {
+    key: Option<[u8; 128]>,
+    key_len: u8,
+    output_len: u8,
+    salt: [u8; BLAKE2B_SALT_LEN],
+    personalization: [u8; BLAKE2B_PERSONALIZATION_LEN],
+}
+
+/// BLAKE2bp, four BLAKE2b leaves hashing interleaved 128 byte blocks with a BLAKE2b root node
+/// combining their outputs.
+#[derive(Clone)]
+pub struct BLAKE2bp {
+    leaves: [Hasher<BLAKE2b>; BLAKE2BP_DEGREE],
+    root: BLAKE2b,
+}
+
+impl BLAKE2bpBuilder {
+    pub fn new() -> Self {
+        return Self {
+            key: None,
+            key_len: 0,
+            output_len: BLAKE2B_OUTPUT_MAX as u8,
+            salt: [0u8; BLAKE2B_SALT_LEN],
+            personalization: [0u8; BLAKE2B_PERSONALIZATION_LEN],
+        };
+    }
+
+    pub fn with_key_slice(mut self, key: &[u8]) -> Self {
+        if key.len() > BLAKE2B_KEY_MAX {
+            panic!("Invalid BLAKE2bp key length");
+        }
+
+        let mut k = [0u8; 128];
+        k[..key.len()].copy_from_slice(key);
+
+        self.key = if key.is_empty() { None } else { Some(k) };
+        self.key_len = key.len() as u8;
+
+        return self;
+    }
+
+    pub fn with_output_len(mut self, output_len: u8) -> Self {
+        if output_len < 1 || output_len as usize > BLAKE2B_OUTPUT_MAX {
+            panic!("Invalid BLAKE2bp output length");
+        }
+
+        self.output_len = output_len;
+
+        return self;
+    }
+
+    /// Sets the salt used by every node, shorter salts are padded with zeros.
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        if salt.len() > BLAKE2B_SALT_LEN {
+            panic!("Invalid BLAKE2bp salt length");
+        }
+
+        self.salt = [0u8; BLAKE2B_SALT_LEN];
+        self.salt[..salt.len()].copy_from_slice(salt);
+
+        return self;
+    }
+
+    /// Sets the personalization string used by every node, shorter strings are padded with zeros.
+    pub fn with_personalization(mut self, personalization: &[u8]) -> Self {
+        if personalization.len() > BLAKE2B_PERSONALIZATION_LEN {
+            panic!("Invalid BLAKE2bp personalization length");
+        }
+
+        self.personalization = [0u8; BLAKE2B_PERSONALIZATION_LEN];
+        self.personalization[..personalization.len()].copy_from_slice(personalization);
+
+        return self;
+    }
+
+    pub fn build(self) -> BLAKE2bp {
+        let mut parameters = BLAKE2bParameters::new(self.output_len);
+        parameters.key_length = self.key_len;
+        parameters.fanout = BLAKE2BP_DEGREE as u8;
+        parameters.depth = 2;
+        parameters.inner_length = BLAKE2B_OUTPUT_MAX as u8;
+        parameters.salt = self.salt;
+        parameters.personalization = self.personalization;
+
+        let leaves = core::array::from_fn(|i| {
+            let mut leaf_parameters = parameters;
+            leaf_parameters.node_offset = i as u64;
+
+            return Hasher::new(
+                BLAKE2b::from_parameters(leaf_parameters, self.key, i == BLAKE2BP_DEGREE - 1)
+                    .with_output_len(BLAKE2B_OUTPUT_MAX as u8),
+            );
+        });
+
+        let mut root_parameters = parameters;
+        root_parameters.node_depth = 1;
+
+        return BLAKE2bp {
+            leaves,
+            root: BLAKE2b::from_parameters(root_parameters, None, true),
+        };
+    }
+}
+
+impl Default for BLAKE2bpBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl BLAKE2bp {
+    pub fn builder() -> BLAKE2bpBuilder {
+        return BLAKE2bpBuilder::new();
+    }
+
+    fn finalize_root(self) -> VariableOutput<BLAKE2B_OUTPUT_MAX> {
+        let mut root = Hasher::new(self.root);
+
+        for leaf in self.leaves {
+            root.update(leaf.finalize().as_ref());
+        }
+
+        return root.finalize();
+    }
+
+    /// Hashes `input` using a separate thread for each leaf, this should be called on a newly
+    /// built instance.
+    #[cfg(feature = "std")]
+    pub fn hash_parallel(mut self, input: &[u8]) -> VariableOutput<BLAKE2B_OUTPUT_MAX> {
+        std::thread::scope(|s| {
+            for (i, leaf) in self.leaves.iter_mut().enumerate() {
+                s.spawn(move || {
+                    for block in input.chunks(BLAKE2B_BLOCK).skip(i).step_by(BLAKE2BP_DEGREE) {
+                        leaf.update(block);
+                    }
+                });
+            }
+        });
+
+        return self.finalize_root();
+    }
+}
+
+impl HashingAlgorithm for BLAKE2bp {
+    type Chunk = [u8; BLAKE2BP_DEGREE * BLAKE2B_BLOCK];
+
+    type Output = VariableOutput<BLAKE2B_OUTPUT_MAX>;
+
+    const CHUNK_SIZE: usize = BLAKE2BP_DEGREE * BLAKE2B_BLOCK;
+
+    const OUTPUT_SIZE: usize = BLAKE2B_OUTPUT_MAX;
+
+    const LENGTH_MODULO: u128 = u128::MAX;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn output_len(&self) -> usize {
+        return self.root.output_len();
+    }
+
+    fn update(&mut self, chunk: &[u8], _bytes_processed: u128) {
+        for (leaf, block) in self.leaves.iter_mut().zip(chunk.chunks(BLAKE2B_BLOCK)) {
+            leaf.update(block);
+        }
+    }
+
+    fn finalize(mut self, partial_chunk: &[u8], _total_bytes_processed: u128) -> Self::Output {
+        for (leaf, block) in self
+            .leaves
+            .iter_mut()
+            .zip(partial_chunk.chunks(BLAKE2B_BLOCK))
+        {
+            leaf.update(block);
+        }
+
+        return self.finalize_root();
+    }
+}
+
+impl Default for BLAKE2bp {
+    fn default() -> Self {
+        return BLAKE2bpBuilder::new().build();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_blake2bp_empty() {
+        assert_eq!(
+            hex::encode(BLAKE2bp::hash(&[])),
+            "b5ef811a8038f70b628fa8b294daae7492b1ebe343a80eaabbf1f6ae664dd67b9d90b0120791eab81dc96985f28849f6a305186a85501b405114bfa678df9380"
+        );
+    }
+
+    #[test]
+    fn test_blake2bp_streaming_matches_parallel() {
+        let input: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let mut hasher = Hasher::new(BLAKE2bp::builder().with_key_slice(b"key").build());
+
+        for chunk in input.chunks(97) {
+            hasher.update(chunk);
+        }
+
+        assert_eq!(
+            hasher.finalize(),
+            BLAKE2bp::builder()
+                .with_key_slice(b"key")
+                .build()
+                .hash_parallel(&input)
+        );
+    }
+}
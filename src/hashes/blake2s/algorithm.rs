@@ -0,0 +1,383 @@
+use super::constants::*;
+use crate::hashes::blake2b::constants::BLAKE2B_SCHEDULE;
+use crate::hashes::{HashingAlgorithm, VariableOutput};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BLAKE2sBuilder {
+    key: Option<[u8; 64]>,
+    key_len: u8,
+    output_len: u8,
+    salt: [u8; BLAKE2S_SALT_LEN],
+    personalization: [u8; BLAKE2S_PERSONALIZATION_LEN],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BLAKE2s {
+    hash_state: [u32; 8],
+    output_len: u8,
+    // The key block is held back until it is known whether it is also the final block
+    key_block: Option<[u8; 64]>,
+    key_provided: bool,
+    last_node: bool,
+}
+
+/// The BLAKE2s parameter block, see section 2.5 of the BLAKE2 specification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct BLAKE2sParameters {
+    pub(crate) digest_length: u8,
+    pub(crate) key_length: u8,
+    pub(crate) fanout: u8,
+    pub(crate) depth: u8,
+    pub(crate) leaf_length: u32,
+    // Only the lower 48 bits are used
+    pub(crate) node_offset: u64,
+    pub(crate) node_depth: u8,
+    pub(crate) inner_length: u8,
+    pub(crate) salt: [u8; BLAKE2S_SALT_LEN],
+    pub(crate) personalization: [u8; BLAKE2S_PERSONALIZATION_LEN],
+}
+
+impl BLAKE2sParameters {
+    pub(crate) fn new(digest_length: u8) -> Self {
+        return Self {
+            digest_length,
+            key_length: 0,
+            fanout: 1,
+            depth: 1,
+            leaf_length: 0,
+            node_offset: 0,
+            node_depth: 0,
+            inner_length: 0,
+            salt: [0u8; BLAKE2S_SALT_LEN],
+            personalization: [0u8; BLAKE2S_PERSONALIZATION_LEN],
+        };
+    }
+
+    fn to_words(self) -> [u32; 8] {
+        let mut words = [0u32; 8];
+
+        words[0] = (self.digest_length as u32)
+            | ((self.key_length as u32) << 8)
+            | ((self.fanout as u32) << 16)
+            | ((self.depth as u32) << 24);
+        words[1] = self.leaf_length;
+        words[2] = self.node_offset as u32;
+        words[3] = ((self.node_offset >> 32) as u32 & 0xffff)
+            | ((self.node_depth as u32) << 16)
+            | ((self.inner_length as u32) << 24);
+        words[4] = u32::from_le_bytes(self.salt[0..4].try_into().unwrap());
+        words[5] = u32::from_le_bytes(self.salt[4..8].try_into().unwrap());
+        words[6] = u32::from_le_bytes(self.personalization[0..4].try_into().unwrap());
+        words[7] = u32::from_le_bytes(self.personalization[4..8].try_into().unwrap());
+
+        return words;
+    }
+}
+
+impl BLAKE2sBuilder {
+    pub fn new() -> Self {
+        return Self {
+            key: None,
+            key_len: 0,
+            output_len: BLAKE2S_OUTPUT_MAX as u8,
+            salt: [0u8; BLAKE2S_SALT_LEN],
+            personalization: [0u8; BLAKE2S_PERSONALIZATION_LEN],
+        };
+    }
+
+    pub fn with_key_slice(mut self, key: &[u8]) -> Self {
+        if key.len() > BLAKE2S_KEY_MAX {
+            panic!("Invalid BLAKE2s key length");
+        }
+
+        let mut k = [0u8; 64];
+        k[..key.len()].copy_from_slice(key);
+
+        self.key = if key.is_empty() { None } else { Some(k) };
+        self.key_len = key.len() as u8;
+
+        return self;
+    }
+
+    pub fn with_output_len(mut self, output_len: u8) -> Self {
+        if output_len < 1 || output_len as usize > BLAKE2S_OUTPUT_MAX {
+            panic!("Invalid BLAKE2s output length");
+        }
+
+        self.output_len = output_len;
+
+        return self;
+    }
+
+    /// Sets the salt, shorter salts are padded with zeros.
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        if salt.len() > BLAKE2S_SALT_LEN {
+            panic!("Invalid BLAKE2s salt length");
+        }
+
+        self.salt = [0u8; BLAKE2S_SALT_LEN];
+        self.salt[..salt.len()].copy_from_slice(salt);
+
+        return self;
+    }
+
+    /// Sets the personalization string, shorter strings are padded with zeros.
+    pub fn with_personalization(mut self, personalization: &[u8]) -> Self {
+        if personalization.len() > BLAKE2S_PERSONALIZATION_LEN {
+            panic!("Invalid BLAKE2s personalization length");
+        }
+
+        self.personalization = [0u8; BLAKE2S_PERSONALIZATION_LEN];
+        self.personalization[..personalization.len()].copy_from_slice(personalization);
+
+        return self;
+    }
+
+    pub fn build(self) -> BLAKE2s {
+        let mut parameters = BLAKE2sParameters::new(self.output_len);
+        parameters.key_length = self.key_len;
+        parameters.salt = self.salt;
+        parameters.personalization = self.personalization;
+
+        return BLAKE2s::from_parameters(parameters, self.key, false);
+    }
+}
+
+impl Default for BLAKE2sBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl BLAKE2s {
+    pub fn builder() -> BLAKE2sBuilder {
+        return BLAKE2sBuilder::new();
+    }
+
+    pub(crate) fn from_parameters(
+        parameters: BLAKE2sParameters,
+        key_block: Option<[u8; 64]>,
+        last_node: bool,
+    ) -> Self {
+        let mut hash_state = BLAKE2S_IV;
+
+        for (h, p) in hash_state.iter_mut().zip(parameters.to_words()) {
+            *h ^= p;
+        }
+
+        return Self {
+            hash_state,
+            output_len: parameters.digest_length,
+            key_block,
+            key_provided: key_block.is_some(),
+            last_node,
+        };
+    }
+
+    /// Overrides the number of bytes returned by `finalize` without changing the parameter block,
+    /// the leaves of a tree return their full state to the root node.
+    pub(crate) fn with_output_len(mut self, output_len: u8) -> Self {
+        self.output_len = output_len;
+
+        return self;
+    }
+
+    fn process_key_block(&mut self, final_block: bool) {
+        if let Some(k) = self.key_block.take() {
+            self.compress(&k, 0, final_block);
+        }
+    }
+
+    fn output(&self) -> VariableOutput<BLAKE2S_OUTPUT_MAX> {
+        let mut max_output = [0u8; BLAKE2S_OUTPUT_MAX];
+
+        for (i, b) in self
+            .hash_state
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (i * 4, n.to_le_bytes()))
+        {
+            max_output[i..i + 4].copy_from_slice(&b);
+        }
+
+        return VariableOutput::new(max_output, self.output_len as usize);
+    }
+
+    fn compress(&mut self, chunk: &[u8], bytes_processed: u128, final_block: bool) {
+        let tbp = if self.key_provided {
+            bytes_processed + 64
+        } else {
+            bytes_processed
+        };
+
+        let mut v = [0u32; 16];
+        v[0..8].copy_from_slice(&self.hash_state);
+        v[8..].copy_from_slice(&BLAKE2S_IV);
+
+        v[12] ^= (tbp & 0xffff_ffff) as u32;
+        v[13] ^= ((tbp >> 32) & 0xffff_ffff) as u32;
+
+        if final_block {
+            v[14] ^= 0xffff_ffff;
+
+            if self.last_node {
+                v[15] ^= 0xffff_ffff;
+            }
+        }
+
+        let mut m = [0u32; 16];
+
+        for i in 0..16 {
+            m[i] = u32::from_le_bytes(chunk[i * 4..(i + 1) * 4].try_into().unwrap());
+        }
+
+        for i in 0..10 {
+            let s = BLAKE2B_SCHEDULE[i];
+
+            Self::mix(&mut v, 0, 4, 8, 12, m[s[0] as usize], m[s[1] as usize]);
+            Self::mix(&mut v, 1, 5, 9, 13, m[s[2] as usize], m[s[3] as usize]);
+            Self::mix(&mut v, 2, 6, 10, 14, m[s[4] as usize], m[s[5] as usize]);
+            Self::mix(&mut v, 3, 7, 11, 15, m[s[6] as usize], m[s[7] as usize]);
+
+            Self::mix(&mut v, 0, 5, 10, 15, m[s[8] as usize], m[s[9] as usize]);
+            Self::mix(&mut v, 1, 6, 11, 12, m[s[10] as usize], m[s[11] as usize]);
+            Self::mix(&mut v, 2, 7, 8, 13, m[s[12] as usize], m[s[13] as usize]);
+            Self::mix(&mut v, 3, 4, 9, 14, m[s[14] as usize], m[s[15] as usize]);
+        }
+
+        for i in 0..8 {
+            self.hash_state[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    #[inline]
+    fn mix(working: &mut [u32], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+        working[a] = working[a].wrapping_add(working[b]).wrapping_add(x);
+        working[d] = (working[d] ^ working[a]).rotate_right(16);
+
+        working[c] = working[c].wrapping_add(working[d]);
+        working[b] = (working[b] ^ working[c]).rotate_right(12);
+
+        working[a] = working[a].wrapping_add(working[b]).wrapping_add(y);
+        working[d] = (working[d] ^ working[a]).rotate_right(8);
+
+        working[c] = working[c].wrapping_add(working[d]);
+        working[b] = (working[b] ^ working[c]).rotate_right(7);
+    }
+}
+
+impl HashingAlgorithm for BLAKE2s {
+    type Chunk = [u8; 64];
+
+    type Output = VariableOutput<BLAKE2S_OUTPUT_MAX>;
+
+    const CHUNK_SIZE: usize = 64;
+
+    const OUTPUT_SIZE: usize = BLAKE2S_OUTPUT_MAX;
+
+    const LENGTH_MODULO: u128 = 0xffff_ffff_ffff_ffff;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn output_len(&self) -> usize {
+        return self.output_len as usize;
+    }
+
+    fn update(&mut self, chunk: &[u8], bytes_processed: u128) {
+        self.process_key_block(false);
+        self.compress(chunk, bytes_processed, false);
+    }
+
+    fn finalize(mut self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        if total_bytes_processed == 0 && self.key_block.is_some() {
+            // The key block is the only block
+            self.process_key_block(true);
+
+            return self.output();
+        }
+
+        self.process_key_block(false);
+
+        let mut final_chunk = [0u8; 64];
+        final_chunk[0..partial_chunk.len()].copy_from_slice(partial_chunk);
+
+        self.compress(&final_chunk, total_bytes_processed, true);
+
+        return self.output();
+    }
+}
+
+impl Default for BLAKE2s {
+    fn default() -> Self {
+        return Self::from_parameters(
+            BLAKE2sParameters::new(BLAKE2S_OUTPUT_MAX as u8),
+            None,
+            false,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::Hasher;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_blake2s_256_empty() {
+        assert_eq!(
+            hex::encode(BLAKE2s::hash(&[])),
+            "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+        );
+    }
+
+    #[test]
+    fn test_blake2s_256_abc() {
+        // RFC 7693 Appendix B
+        assert_eq!(
+            BLAKE2s::hash(b"abc"),
+            [
+                0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb,
+                0x45, 0x2f, 0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c,
+                0x86, 0x67, 0x59, 0x82
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blake2s_large_input() {
+        let input = b"this test should be longer than one block and a bit longer than 2 blocks. This means it must be 3 or more blocks, how about that! Well this last bit of text is just filling for space :)";
+
+        assert_eq!(
+            hex::encode(BLAKE2s::hash(input)),
+            "4304a92d854f7bd70ae581cb9819e200b4f678ae5543d392d146d66d622f3849"
+        );
+    }
+
+    #[test]
+    fn test_blake2s_output_len_salt_personalization() {
+        let mut hasher = Hasher::new(
+            BLAKE2s::builder()
+                .with_output_len(20)
+                .with_key_slice(b"secret key")
+                .with_salt(b"saltsalt")
+                .with_personalization(b"personal")
+                .build(),
+        );
+
+        hasher.update(b"The quick brown fox jumps over the lazy dog");
+
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "cf93488087f51cfb3db560eac078fb2e853411f8"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_blake2s_invalid_output_len() {
+        BLAKE2s::builder().with_output_len(33);
+    }
+}
@@ -0,0 +1,8 @@
+pub const BLAKE2S_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+pub const BLAKE2S_KEY_MAX: usize = 32;
+pub const BLAKE2S_OUTPUT_MAX: usize = 32;
+pub const BLAKE2S_SALT_LEN: usize = 8;
+pub const BLAKE2S_PERSONALIZATION_LEN: usize = 8;
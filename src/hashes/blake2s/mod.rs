@@ -0,0 +1,6 @@
+mod algorithm;
+mod constants;
+mod parallel;
+
+pub use algorithm::*;
+pub use parallel::*;
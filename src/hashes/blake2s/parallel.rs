@@ -0,0 +1,226 @@
+use super::algorithm::{BLAKE2s, BLAKE2sParameters};
+use super::constants::*;
+use crate::hashes::{Hasher, HashingAlgorithm, VariableOutput};
+
+const BLAKE2SP_DEGREE: usize = 8;
+const BLAKE2S_BLOCK: usize = 64;
+
+#[derive(Clone, Copy, Debug)]
+pub struct BLAKE2spBuilder {
+    key: Option<[u8; 64]>,
+    key_len: u8,
+    output_len: u8,
+    salt: [u8; BLAKE2S_SALT_LEN],
+    personalization: [u8; BLAKE2S_PERSONALIZATION_LEN],
+}
+
+/// BLAKE2sp, eight BLAKE2s leaves hashing interleaved 64 byte blocks with a BLAKE2s root node
+/// combining their outputs.
+#[derive(Clone)]
+pub struct BLAKE2sp {
+    leaves: [Hasher<BLAKE2s>; BLAKE2SP_DEGREE],
+    root: BLAKE2s,
+}
+
+impl BLAKE2spBuilder {
+    pub fn new() -> Self {
+        return Self {
+            key: None,
+            key_len: 0,
+            output_len: BLAKE2S_OUTPUT_MAX as u8,
+            salt: [0u8; BLAKE2S_SALT_LEN],
+            personalization: [0u8; BLAKE2S_PERSONALIZATION_LEN],
+        };
+    }
+
+    pub fn with_key_slice(mut self, key: &[u8]) -> Self {
+        if key.len() > BLAKE2S_KEY_MAX {
+            panic!("Invalid BLAKE2sp key length");
+        }
+
+        let mut k = [0u8; 64];
+        k[..key.len()].copy_from_slice(key);
+
+        self.key = if key.is_empty() { None } else { Some(k) };
+        self.key_len = key.len() as u8;
+
+        return self;
+    }
+
+    pub fn with_output_len(mut self, output_len: u8) -> Self {
+        if output_len < 1 || output_len as usize > BLAKE2S_OUTPUT_MAX {
+            panic!("Invalid BLAKE2sp output length");
+        }
+
+        self.output_len = output_len;
+
+        return self;
+    }
+
+    /// Sets the salt used by every node, shorter salts are padded with zeros.
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        if salt.len() > BLAKE2S_SALT_LEN {
+            panic!("Invalid BLAKE2sp salt length");
+        }
+
+        self.salt = [0u8; BLAKE2S_SALT_LEN];
+        self.salt[..salt.len()].copy_from_slice(salt);
+
+        return self;
+    }
+
+    /// Sets the personalization string used by every node, shorter strings are padded with zeros.
+    pub fn with_personalization(mut self, personalization: &[u8]) -> Self {
+        if personalization.len() > BLAKE2S_PERSONALIZATION_LEN {
+            panic!("Invalid BLAKE2sp personalization length");
+        }
+
+        self.personalization = [0u8; BLAKE2S_PERSONALIZATION_LEN];
+        self.personalization[..personalization.len()].copy_from_slice(personalization);
+
+        return self;
+    }
+
+    pub fn build(self) -> BLAKE2sp {
+        let mut parameters = BLAKE2sParameters::new(self.output_len);
+        parameters.key_length = self.key_len;
+        parameters.fanout = BLAKE2SP_DEGREE as u8;
+        parameters.depth = 2;
+        parameters.inner_length = BLAKE2S_OUTPUT_MAX as u8;
+        parameters.salt = self.salt;
+        parameters.personalization = self.personalization;
+
+        let leaves = core::array::from_fn(|i| {
+            let mut leaf_parameters = parameters;
+            leaf_parameters.node_offset = i as u64;
+
+            return Hasher::new(
+                BLAKE2s::from_parameters(leaf_parameters, self.key, i == BLAKE2SP_DEGREE - 1)
+                    .with_output_len(BLAKE2S_OUTPUT_MAX as u8),
+            );
+        });
+
+        let mut root_parameters = parameters;
+        root_parameters.node_depth = 1;
+
+        return BLAKE2sp {
+            leaves,
+            root: BLAKE2s::from_parameters(root_parameters, None, true),
+        };
+    }
+}
+
+impl Default for BLAKE2spBuilder {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl BLAKE2sp {
+    pub fn builder() -> BLAKE2spBuilder {
+        return BLAKE2spBuilder::new();
+    }
+
+    fn finalize_root(self) -> VariableOutput<BLAKE2S_OUTPUT_MAX> {
+        let mut root = Hasher::new(self.root);
+
+        for leaf in self.leaves {
+            root.update(leaf.finalize().as_ref());
+        }
+
+        return root.finalize();
+    }
+
+    /// Hashes `input` using a separate thread for each leaf, this should be called on a newly
+    /// built instance.
+    #[cfg(feature = "std")]
+    pub fn hash_parallel(mut self, input: &[u8]) -> VariableOutput<BLAKE2S_OUTPUT_MAX> {
+        std::thread::scope(|s| {
+            for (i, leaf) in self.leaves.iter_mut().enumerate() {
+                s.spawn(move || {
+                    for block in input.chunks(BLAKE2S_BLOCK).skip(i).step_by(BLAKE2SP_DEGREE) {
+                        leaf.update(block);
+                    }
+                });
+            }
+        });
+
+        return self.finalize_root();
+    }
+}
+
+impl HashingAlgorithm for BLAKE2sp {
+    type Chunk = [u8; BLAKE2SP_DEGREE * BLAKE2S_BLOCK];
+
+    type Output = VariableOutput<BLAKE2S_OUTPUT_MAX>;
+
+    const CHUNK_SIZE: usize = BLAKE2SP_DEGREE * BLAKE2S_BLOCK;
+
+    const OUTPUT_SIZE: usize = BLAKE2S_OUTPUT_MAX;
+
+    const LENGTH_MODULO: u128 = u128::MAX;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn output_len(&self) -> usize {
+        return self.root.output_len();
+    }
+
+    fn update(&mut self, chunk: &[u8], _bytes_processed: u128) {
+        for (leaf, block) in self.leaves.iter_mut().zip(chunk.chunks(BLAKE2S_BLOCK)) {
+            leaf.update(block);
+        }
+    }
+
+    fn finalize(mut self, partial_chunk: &[u8], _total_bytes_processed: u128) -> Self::Output {
+        for (leaf, block) in self
+            .leaves
+            .iter_mut()
+            .zip(partial_chunk.chunks(BLAKE2S_BLOCK))
+        {
+            leaf.update(block);
+        }
+
+        return self.finalize_root();
+    }
+}
+
+impl Default for BLAKE2sp {
+    fn default() -> Self {
+        return BLAKE2spBuilder::new().build();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_blake2sp_empty() {
+        assert_eq!(
+            hex::encode(BLAKE2sp::hash(&[])),
+            "dd0e891776933f43c7d032b08a917e25741f8aa9a12c12e1cac8801500f2ca4f"
+        );
+    }
+
+    #[test]
+    fn test_blake2sp_streaming_matches_parallel() {
+        let input: Vec<u8> = (0..5000).map(|i| (i % 251) as u8).collect();
+        let mut hasher = Hasher::new(BLAKE2sp::builder().with_key_slice(b"key").build());
+
+        for chunk in input.chunks(97) {
+            hasher.update(chunk);
+        }
+
+        assert_eq!(
+            hasher.finalize(),
+            BLAKE2sp::builder()
+                .with_key_slice(b"key")
+                .build()
+                .hash_parallel(&input)
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use super::constants::*;
+use crate::hashes::{Hasher, HashingAlgorithm};
+
+#[cfg(feature = "std")]
+use std::io;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BLAKE3 {
+    key_words: [u32; 8],
+    flags: u32,
+    // The chaining values of the completed subtrees, at most one for each level of the tree
+    cv_stack: [[u32; 8]; BLAKE3_MAX_DEPTH],
+    cv_stack_len: usize,
+    chunks_processed: u64,
+}
+
+/// Produces an arbitrary amount of output from a BLAKE3 hash by repeatedly compressing the root
+/// node with an incrementing counter.
+#[derive(Clone, Copy, Debug)]
+pub struct BLAKE3OutputReader {
+    node: Node,
+    block_counter: u64,
+    position_within_block: usize,
+}
+
+/// The inputs to a compression that have not been compressed yet, the root node is held back in
+/// this form so that any amount of output can be produced from it.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    input_chaining_value: [u32; 8],
+    block_words: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl BLAKE3 {
+    fn new_internal(key_words: [u32; 8], flags: u32) -> Self {
+        return Self {
+            key_words,
+            flags,
+            cv_stack: [[0u32; 8]; BLAKE3_MAX_DEPTH],
+            cv_stack_len: 0,
+            chunks_processed: 0,
+        };
+    }
+
+    /// Creates an instance for the keyed hash mode, which can be used as a MAC.
+    pub fn new_keyed(key: &[u8; BLAKE3_KEY_LEN]) -> Self {
+        return Self::new_internal(words_from_le_bytes(key), KEYED_HASH);
+    }
+
+    /// Creates an instance for the key derivation mode. The context string should be hardcoded,
+    /// globally unique and application specific, the key material is then provided as the input.
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_hasher = Hasher::new(Self::new_internal(BLAKE3_IV, DERIVE_KEY_CONTEXT));
+        context_hasher.update(context.as_bytes());
+
+        let context_key = context_hasher.finalize();
+
+        return Self::new_internal(words_from_le_bytes(&context_key), DERIVE_KEY_MATERIAL);
+    }
+
+    pub fn keyed_hash(key: &[u8; BLAKE3_KEY_LEN], input: &[u8]) -> [u8; BLAKE3_OUTPUT_LEN] {
+        let mut hasher = Hasher::new(Self::new_keyed(key));
+        hasher.update(input);
+
+        return hasher.finalize();
+    }
+
+    pub fn derive_key(context: &str, key_material: &[u8]) -> [u8; BLAKE3_OUTPUT_LEN] {
+        let mut hasher = Hasher::new(Self::new_derive_key(context));
+        hasher.update(key_material);
+
+        return hasher.finalize();
+    }
+
+    /// Hashes `input` splitting the tree across multiple threads, this should be called on a newly
+    /// created instance.
+    #[cfg(feature = "std")]
+    pub fn hash_parallel(self, input: &[u8]) -> [u8; BLAKE3_OUTPUT_LEN] {
+        let mut output = [0u8; BLAKE3_OUTPUT_LEN];
+        self.hash_parallel_xof(input).fill(&mut output);
+
+        return output;
+    }
+
+    /// The extendable output version of `hash_parallel`.
+    #[cfg(feature = "std")]
+    pub fn hash_parallel_xof(self, input: &[u8]) -> BLAKE3OutputReader {
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+
+        return BLAKE3OutputReader::new(self.subtree_node(input, 0, threads));
+    }
+
+    /// Returns the root node of the subtree covering `input`, the left subtree always contains the
+    /// largest power of 2 number of complete chunks so the result matches the incremental hash.
+    #[cfg(feature = "std")]
+    fn subtree_node(&self, input: &[u8], chunk_counter: u64, threads: usize) -> Node {
+        if input.len() <= BLAKE3_CHUNK_LEN {
+            return self.chunk_node(input, chunk_counter);
+        }
+
+        let full_chunks = (input.len() - 1) / BLAKE3_CHUNK_LEN;
+        let left_chunks = 1usize << (usize::BITS - 1 - full_chunks.leading_zeros());
+        let (left, right) = input.split_at(left_chunks * BLAKE3_CHUNK_LEN);
+        let right_counter = chunk_counter + left_chunks as u64;
+
+        let (left_cv, right_cv) = if threads > 1 && input.len() >= BLAKE3_PARALLEL_MIN_LEN {
+            std::thread::scope(|s| {
+                let left_handle = s.spawn(|| {
+                    self.subtree_node(left, chunk_counter, threads / 2)
+                        .chaining_value()
+                });
+                let right_cv = self
+                    .subtree_node(right, right_counter, threads - threads / 2)
+                    .chaining_value();
+
+                (left_handle.join().unwrap(), right_cv)
+            })
+        } else {
+            (
+                self.subtree_node(left, chunk_counter, 1).chaining_value(),
+                self.subtree_node(right, right_counter, 1).chaining_value(),
+            )
+        };
+
+        return self.parent_node(&left_cv, &right_cv);
+    }
+
+    /// Returns the node for the final block of a chunk of at most `BLAKE3_CHUNK_LEN` bytes.
+    fn chunk_node(&self, chunk: &[u8], chunk_counter: u64) -> Node {
+        let mut chaining_value = self.key_words;
+        let mut flags = self.flags | CHUNK_START;
+        let mut blocks = chunk.chunks(BLAKE3_BLOCK_LEN).peekable();
+
+        while let Some(block) = blocks.next() {
+            if blocks.peek().is_none() {
+                return Node::new(chaining_value, block, chunk_counter, flags | CHUNK_END);
+            }
+
+            chaining_value =
+                Node::new(chaining_value, block, chunk_counter, flags).chaining_value();
+            flags &= !CHUNK_START;
+        }
+
+        // An empty chunk, only possible when the whole input is empty
+        return Node::new(chaining_value, &[], chunk_counter, flags | CHUNK_END);
+    }
+
+    fn parent_node(&self, left_cv: &[u32; 8], right_cv: &[u32; 8]) -> Node {
+        let mut block_words = [0u32; 16];
+        block_words[..8].copy_from_slice(left_cv);
+        block_words[8..].copy_from_slice(right_cv);
+
+        return Node {
+            input_chaining_value: self.key_words,
+            block_words,
+            counter: 0,
+            block_len: BLAKE3_BLOCK_LEN as u32,
+            flags: self.flags | PARENT,
+        };
+    }
+
+    /// Adds the chaining value of a completed chunk, merging every subtree it completes. This is
+    /// only called once more input is known to follow, so the root is never merged early.
+    fn add_chunk_chaining_value(&mut self, mut chaining_value: [u32; 8]) {
+        self.chunks_processed += 1;
+
+        let mut total_chunks = self.chunks_processed;
+
+        while total_chunks & 1 == 0 {
+            self.cv_stack_len -= 1;
+            chaining_value = self
+                .parent_node(&self.cv_stack[self.cv_stack_len], &chaining_value)
+                .chaining_value();
+            total_chunks >>= 1;
+        }
+
+        self.cv_stack[self.cv_stack_len] = chaining_value;
+        self.cv_stack_len += 1;
+    }
+
+    fn root_node(&self, partial_chunk: &[u8]) -> Node {
+        let mut node = self.chunk_node(partial_chunk, self.chunks_processed);
+
+        for chaining_value in self.cv_stack[..self.cv_stack_len].iter().rev() {
+            node = self.parent_node(chaining_value, &node.chaining_value());
+        }
+
+        return node;
+    }
+
+    /// Returns a reader producing an arbitrary amount of output, the first 32 bytes are the same
+    /// as the regular output.
+    pub(crate) fn finalize_xof(
+        self,
+        partial_chunk: &[u8],
+        _total_bytes_processed: u128,
+    ) -> BLAKE3OutputReader {
+        return BLAKE3OutputReader::new(self.root_node(partial_chunk));
+    }
+}
+
+impl Hasher<BLAKE3> {
+    /// Finalizes the hash, returning a reader for the extendable output.
+    pub fn finalize_xof(self) -> BLAKE3OutputReader {
+        return self.finalize_with(BLAKE3::finalize_xof);
+    }
+}
+
+impl HashingAlgorithm for BLAKE3 {
+    type Chunk = [u8; BLAKE3_CHUNK_LEN];
+
+    type Output = [u8; BLAKE3_OUTPUT_LEN];
+
+    const CHUNK_SIZE: usize = BLAKE3_CHUNK_LEN;
+
+    const OUTPUT_SIZE: usize = BLAKE3_OUTPUT_LEN;
+
+    const LENGTH_MODULO: u128 = u128::MAX;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn update(&mut self, chunk: &[u8], _bytes_processed: u128) {
+        let chaining_value = self
+            .chunk_node(chunk, self.chunks_processed)
+            .chaining_value();
+
+        self.add_chunk_chaining_value(chaining_value);
+    }
+
+    fn finalize(self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        let mut output = [0u8; BLAKE3_OUTPUT_LEN];
+        self.finalize_xof(partial_chunk, total_bytes_processed)
+            .fill(&mut output);
+
+        return output;
+    }
+}
+
+impl Default for BLAKE3 {
+    fn default() -> Self {
+        return Self::new_internal(BLAKE3_IV, 0);
+    }
+}
+
+impl Node {
+    fn new(chaining_value: [u32; 8], block: &[u8], counter: u64, flags: u32) -> Self {
+        let mut block_bytes = [0u8; BLAKE3_BLOCK_LEN];
+        block_bytes[..block.len()].copy_from_slice(block);
+
+        return Self {
+            input_chaining_value: chaining_value,
+            block_words: words_from_le_bytes(&block_bytes),
+            counter,
+            block_len: block.len() as u32,
+            flags,
+        };
+    }
+
+    fn chaining_value(&self) -> [u32; 8] {
+        let state = compress(
+            &self.input_chaining_value,
+            &self.block_words,
+            self.counter,
+            self.block_len,
+            self.flags,
+        );
+
+        return state[..8].try_into().unwrap();
+    }
+
+    fn root_output_block(&self, block_counter: u64) -> [u8; BLAKE3_BLOCK_LEN] {
+        let state = compress(
+            &self.input_chaining_value,
+            &self.block_words,
+            block_counter,
+            self.block_len,
+            self.flags | ROOT,
+        );
+        let mut output = [0u8; BLAKE3_BLOCK_LEN];
+
+        for (i, b) in state
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (i * 4, n.to_le_bytes()))
+        {
+            output[i..i + 4].copy_from_slice(&b);
+        }
+
+        return output;
+    }
+}
+
+impl BLAKE3OutputReader {
+    fn new(node: Node) -> Self {
+        return Self {
+            node,
+            block_counter: 0,
+            position_within_block: 0,
+        };
+    }
+
+    /// Fills `output` with the next bytes of the extendable output.
+    pub fn fill(&mut self, output: &mut [u8]) {
+        let mut filled = 0;
+
+        while filled < output.len() {
+            let block = self.node.root_output_block(self.block_counter);
+            let n = (BLAKE3_BLOCK_LEN - self.position_within_block).min(output.len() - filled);
+
+            output[filled..filled + n].copy_from_slice(
+                &block[self.position_within_block..self.position_within_block + n],
+            );
+
+            filled += n;
+            self.position_within_block += n;
+
+            if self.position_within_block == BLAKE3_BLOCK_LEN {
+                self.block_counter += 1;
+                self.position_within_block = 0;
+            }
+        }
+    }
+
+    /// The number of bytes that have been read so far.
+    pub fn position(&self) -> u64 {
+        return self.block_counter * BLAKE3_BLOCK_LEN as u64 + self.position_within_block as u64;
+    }
+}
+
+#[cfg(feature = "std")]
+impl io::Read for BLAKE3OutputReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill(buf);
+
+        return Ok(buf.len());
+    }
+}
+
+#[cfg(feature = "std")]
+const BLAKE3_PARALLEL_MIN_LEN: usize = 128 * BLAKE3_CHUNK_LEN;
+
+fn words_from_le_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0u32; N];
+
+    for (w, b) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *w = u32::from_le_bytes(b.try_into().unwrap());
+    }
+
+    return words;
+}
+
+fn compress(
+    chaining_value: &[u32; 8],
+    block_words: &[u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[..8].copy_from_slice(chaining_value);
+    state[8..12].copy_from_slice(&BLAKE3_IV[..4]);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = block_len;
+    state[15] = flags;
+
+    let mut m = *block_words;
+
+    for round in 0..7 {
+        mix(&mut state, 0, 4, 8, 12, m[0], m[1]);
+        mix(&mut state, 1, 5, 9, 13, m[2], m[3]);
+        mix(&mut state, 2, 6, 10, 14, m[4], m[5]);
+        mix(&mut state, 3, 7, 11, 15, m[6], m[7]);
+
+        mix(&mut state, 0, 5, 10, 15, m[8], m[9]);
+        mix(&mut state, 1, 6, 11, 12, m[10], m[11]);
+        mix(&mut state, 2, 7, 8, 13, m[12], m[13]);
+        mix(&mut state, 3, 4, 9, 14, m[14], m[15]);
+
+        if round < 6 {
+            let mut permuted = [0u32; 16];
+
+            for i in 0..16 {
+                permuted[i] = m[BLAKE3_MSG_PERMUTATION[i]];
+            }
+
+            m = permuted;
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= chaining_value[i];
+    }
+
+    return state;
+}
+
+#[inline]
+fn mix(working: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    working[a] = working[a].wrapping_add(working[b]).wrapping_add(x);
+    working[d] = (working[d] ^ working[a]).rotate_right(16);
+
+    working[c] = working[c].wrapping_add(working[d]);
+    working[b] = (working[b] ^ working[c]).rotate_right(12);
+
+    working[a] = working[a].wrapping_add(working[b]).wrapping_add(y);
+    working[d] = (working[d] ^ working[a]).rotate_right(8);
+
+    working[c] = working[c].wrapping_add(working[d]);
+    working[b] = (working[b] ^ working[c]).rotate_right(7);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_blake3_empty() {
+        assert_eq!(
+            hex::encode(BLAKE3::hash(&[])),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn test_blake3_abc() {
+        assert_eq!(
+            hex::encode(BLAKE3::hash(b"abc")),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn test_blake3_xof_prefix() {
+        let input = b"The quick brown fox jumps over the lazy dog";
+        let mut hasher = BLAKE3::hasher();
+        hasher.update(input);
+
+        let mut reader = hasher.finalize_xof();
+        let mut output = [0u8; 100];
+        reader.fill(&mut output[..10]);
+        reader.fill(&mut output[10..]);
+
+        assert_eq!(reader.position(), 100);
+        assert_eq!(output[..32], BLAKE3::hash(input));
+    }
+
+    #[test]
+    fn test_blake3_parallel_matches_incremental() {
+        let input: Vec<u8> = (0..1_000_003).map(|i| (i % 251) as u8).collect();
+        let mut hasher = BLAKE3::hasher();
+
+        for chunk in input.chunks(4099) {
+            hasher.update(chunk);
+        }
+
+        assert_eq!(BLAKE3::default().hash_parallel(&input), hasher.finalize());
+    }
+}
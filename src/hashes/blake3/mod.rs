@@ -0,0 +1,4 @@
+mod algorithm;
+mod constants;
+
+pub use algorithm::*;
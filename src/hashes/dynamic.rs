@@ -0,0 +1,337 @@
+use crate::error::VCryptoError;
+use crate::hashes::{
+    BLAKE2b, BLAKE2bp, BLAKE2s, BLAKE2sp, Hasher, HashingAlgorithm, BLAKE3, MD5, RIPEMD160, SHA1,
+    SHA224, SHA256, SHA384, SHA512, SHA512_224, SHA512_256, SM3,
+};
+
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+
+/// An object safe hashing interface, allowing the algorithm to be selected at run time.
+pub trait DynHasher {
+    fn update(&mut self, input: &[u8]);
+
+    /// Writes the digest into the start of `output` and resets the hasher to its initial state.
+    /// `output` must be at least `output_size` bytes long.
+    fn finalize_into(&mut self, output: &mut [u8]) -> Result<(), VCryptoError>;
+
+    fn reset(&mut self);
+
+    /// The size of the digest in bytes, the configured length for algorithms such as BLAKE2b
+    /// built with a shorter digest.
+    fn output_size(&self) -> usize;
+
+    /// The size of the chunks processed by the underlying algorithm in bytes.
+    fn block_size(&self) -> usize;
+
+    fn box_clone(&self) -> Box<dyn DynHasher>;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Algorithm {
+    MD5,
+    SHA1,
+    SHA224,
+    SHA256,
+    SHA384,
+    SHA512,
+    SHA512_224,
+    SHA512_256,
+    BLAKE2b,
+    BLAKE2s,
+    BLAKE2bp,
+    BLAKE2sp,
+    BLAKE3,
+    RIPEMD160,
+    SM3,
+}
+
+impl<H: HashingAlgorithm + 'static> DynHasher for Hasher<H> {
+    fn update(&mut self, input: &[u8]) {
+        Hasher::update(self, input);
+    }
+
+    fn finalize_into(&mut self, output: &mut [u8]) -> Result<(), VCryptoError> {
+        if output.len() < self.output_len() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let digest = self.finalize_reset();
+        output[..digest.as_ref().len()].copy_from_slice(digest.as_ref());
+
+        return Ok(());
+    }
+
+    fn reset(&mut self) {
+        Hasher::reset(self);
+    }
+
+    fn output_size(&self) -> usize {
+        return Hasher::output_len(self);
+    }
+
+    fn block_size(&self) -> usize {
+        return H::CHUNK_SIZE;
+    }
+
+    fn box_clone(&self) -> Box<dyn DynHasher> {
+        return Box::new(self.clone());
+    }
+}
+
+impl Clone for Box<dyn DynHasher> {
+    fn clone(&self) -> Self {
+        return self.box_clone();
+    }
+}
+
+impl Algorithm {
+    pub fn hasher(&self) -> Box<dyn DynHasher> {
+        return match self {
+            Algorithm::MD5 => Box::new(MD5::hasher()),
+            Algorithm::SHA1 => Box::new(SHA1::hasher()),
+            Algorithm::SHA224 => Box::new(SHA224::hasher()),
+            Algorithm::SHA256 => Box::new(SHA256::hasher()),
+            Algorithm::SHA384 => Box::new(SHA384::hasher()),
+            Algorithm::SHA512 => Box::new(SHA512::hasher()),
+            Algorithm::SHA512_224 => Box::new(SHA512_224::hasher()),
+            Algorithm::SHA512_256 => Box::new(SHA512_256::hasher()),
+            Algorithm::BLAKE2b => Box::new(BLAKE2b::hasher()),
+            Algorithm::BLAKE2s => Box::new(BLAKE2s::hasher()),
+            Algorithm::BLAKE2bp => Box::new(BLAKE2bp::hasher()),
+            Algorithm::BLAKE2sp => Box::new(BLAKE2sp::hasher()),
+            Algorithm::BLAKE3 => Box::new(BLAKE3::hasher()),
+            Algorithm::RIPEMD160 => Box::new(RIPEMD160::hasher()),
+            Algorithm::SM3 => Box::new(SM3::hasher()),
+        };
+    }
+
+    pub const fn output_size(&self) -> usize {
+        return match self {
+            Algorithm::MD5 => MD5::OUTPUT_SIZE,
+            Algorithm::SHA1 => SHA1::OUTPUT_SIZE,
+            Algorithm::SHA224 => SHA224::OUTPUT_SIZE,
+            Algorithm::SHA256 => SHA256::OUTPUT_SIZE,
+            Algorithm::SHA384 => SHA384::OUTPUT_SIZE,
+            Algorithm::SHA512 => SHA512::OUTPUT_SIZE,
+            Algorithm::SHA512_224 => SHA512_224::OUTPUT_SIZE,
+            Algorithm::SHA512_256 => SHA512_256::OUTPUT_SIZE,
+            Algorithm::BLAKE2b => BLAKE2b::OUTPUT_SIZE,
+            Algorithm::BLAKE2s => BLAKE2s::OUTPUT_SIZE,
+            Algorithm::BLAKE2bp => BLAKE2bp::OUTPUT_SIZE,
+            Algorithm::BLAKE2sp => BLAKE2sp::OUTPUT_SIZE,
+            Algorithm::BLAKE3 => BLAKE3::OUTPUT_SIZE,
+            Algorithm::RIPEMD160 => RIPEMD160::OUTPUT_SIZE,
+            Algorithm::SM3 => SM3::OUTPUT_SIZE,
+        };
+    }
+
+    pub const fn block_size(&self) -> usize {
+        return match self {
+            Algorithm::MD5 => MD5::CHUNK_SIZE,
+            Algorithm::SHA1 => SHA1::CHUNK_SIZE,
+            Algorithm::SHA224 => SHA224::CHUNK_SIZE,
+            Algorithm::SHA256 => SHA256::CHUNK_SIZE,
+            Algorithm::SHA384 => SHA384::CHUNK_SIZE,
+            Algorithm::SHA512 => SHA512::CHUNK_SIZE,
+            Algorithm::SHA512_224 => SHA512_224::CHUNK_SIZE,
+            Algorithm::SHA512_256 => SHA512_256::CHUNK_SIZE,
+            Algorithm::BLAKE2b => BLAKE2b::CHUNK_SIZE,
+            Algorithm::BLAKE2s => BLAKE2s::CHUNK_SIZE,
+            Algorithm::BLAKE2bp => BLAKE2bp::CHUNK_SIZE,
+            Algorithm::BLAKE2sp => BLAKE2sp::CHUNK_SIZE,
+            Algorithm::BLAKE3 => BLAKE3::CHUNK_SIZE,
+            Algorithm::RIPEMD160 => RIPEMD160::CHUNK_SIZE,
+            Algorithm::SM3 => SM3::CHUNK_SIZE,
+        };
+    }
+
+    pub const fn name(&self) -> &'static str {
+        return match self {
+            Algorithm::MD5 => "MD5",
+            Algorithm::SHA1 => "SHA-1",
+            Algorithm::SHA224 => "SHA-224",
+            Algorithm::SHA256 => "SHA-256",
+            Algorithm::SHA384 => "SHA-384",
+            Algorithm::SHA512 => "SHA-512",
+            Algorithm::SHA512_224 => "SHA-512/224",
+            Algorithm::SHA512_256 => "SHA-512/256",
+            Algorithm::BLAKE2b => "BLAKE2b",
+            Algorithm::BLAKE2s => "BLAKE2s",
+            Algorithm::BLAKE2bp => "BLAKE2bp",
+            Algorithm::BLAKE2sp => "BLAKE2sp",
+            Algorithm::BLAKE3 => "BLAKE3",
+            Algorithm::RIPEMD160 => "RIPEMD-160",
+            Algorithm::SM3 => "SM3",
+        };
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = VCryptoError;
+
+    /// Parses an algorithm name, ignoring case and any '-' or '_' separators, e.g. "sha256",
+    /// "SHA-256" and "sha_256" are all accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut normalized = [0u8; 16];
+        let mut len = 0;
+
+        for b in s.bytes().filter(|b| *b != b'-' && *b != b'_') {
+            if len == normalized.len() {
+                return Err(VCryptoError::UnknownAlgorithm);
+            }
+
+            normalized[len] = b.to_ascii_lowercase();
+            len += 1;
+        }
+
+        return match &normalized[..len] {
+            b"md5" => Ok(Algorithm::MD5),
+            b"sha1" => Ok(Algorithm::SHA1),
+            b"sha224" => Ok(Algorithm::SHA224),
+            b"sha256" => Ok(Algorithm::SHA256),
+            b"sha384" => Ok(Algorithm::SHA384),
+            b"sha512" => Ok(Algorithm::SHA512),
+            b"sha512/224" | b"sha512224" => Ok(Algorithm::SHA512_224),
+            b"sha512/256" | b"sha512256" => Ok(Algorithm::SHA512_256),
+            b"blake2b" | b"blake2b512" => Ok(Algorithm::BLAKE2b),
+            b"blake2s" | b"blake2s256" => Ok(Algorithm::BLAKE2s),
+            b"blake2bp" => Ok(Algorithm::BLAKE2bp),
+            b"blake2sp" => Ok(Algorithm::BLAKE2sp),
+            b"blake3" => Ok(Algorithm::BLAKE3),
+            b"ripemd160" => Ok(Algorithm::RIPEMD160),
+            b"sm3" => Ok(Algorithm::SM3),
+            _ => Err(VCryptoError::UnknownAlgorithm),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ALGORITHMS: [Algorithm; 15] = [
+        Algorithm::MD5,
+        Algorithm::SHA1,
+        Algorithm::SHA224,
+        Algorithm::SHA256,
+        Algorithm::SHA384,
+        Algorithm::SHA512,
+        Algorithm::SHA512_224,
+        Algorithm::SHA512_256,
+        Algorithm::BLAKE2b,
+        Algorithm::BLAKE2s,
+        Algorithm::BLAKE2bp,
+        Algorithm::BLAKE2sp,
+        Algorithm::BLAKE3,
+        Algorithm::RIPEMD160,
+        Algorithm::SM3,
+    ];
+
+    #[test]
+    fn test_algorithm_from_str() {
+        assert_eq!("sha256".parse(), Ok(Algorithm::SHA256));
+        assert_eq!("SHA-512".parse(), Ok(Algorithm::SHA512));
+        assert_eq!("Blake2b".parse(), Ok(Algorithm::BLAKE2b));
+        assert_eq!(
+            "sha3-256".parse::<Algorithm>(),
+            Err(VCryptoError::UnknownAlgorithm)
+        );
+
+        for algorithm in ALGORITHMS {
+            assert_eq!(algorithm.to_string().parse(), Ok(algorithm));
+        }
+    }
+
+    #[test]
+    fn test_dyn_hasher_matches_static() {
+        let input = b"The quick brown fox jumps over the lazy dog";
+        let mut output = [0u8; 64];
+
+        for algorithm in ALGORITHMS {
+            let mut hasher = algorithm.hasher();
+            hasher.update(input);
+            hasher.finalize_into(&mut output).unwrap();
+
+            let expected = match algorithm {
+                Algorithm::MD5 => MD5::hash(input).to_vec(),
+                Algorithm::SHA1 => SHA1::hash(input).to_vec(),
+                Algorithm::SHA224 => SHA224::hash(input).to_vec(),
+                Algorithm::SHA256 => SHA256::hash(input).to_vec(),
+                Algorithm::SHA384 => SHA384::hash(input).to_vec(),
+                Algorithm::SHA512 => SHA512::hash(input).to_vec(),
+                Algorithm::SHA512_224 => SHA512_224::hash(input).to_vec(),
+                Algorithm::SHA512_256 => SHA512_256::hash(input).to_vec(),
+                Algorithm::BLAKE2b => BLAKE2b::hash(input).to_vec(),
+                Algorithm::BLAKE2s => BLAKE2s::hash(input).to_vec(),
+                Algorithm::BLAKE2bp => BLAKE2bp::hash(input).to_vec(),
+                Algorithm::BLAKE2sp => BLAKE2sp::hash(input).to_vec(),
+                Algorithm::BLAKE3 => BLAKE3::hash(input).to_vec(),
+                Algorithm::RIPEMD160 => RIPEMD160::hash(input).to_vec(),
+                Algorithm::SM3 => SM3::hash(input).to_vec(),
+            };
+
+            assert_eq!(hasher.output_size(), algorithm.output_size());
+            assert_eq!(&output[..algorithm.output_size()], expected.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_dyn_hasher_box_clone() {
+        let mut a = Algorithm::SHA256.hasher();
+        a.update(b"ab");
+
+        let mut b = a.clone();
+        b.update(b"c");
+
+        let mut output = [0u8; 32];
+
+        b.finalize_into(&mut output).unwrap();
+        assert_eq!(output, SHA256::hash(b"abc"));
+
+        a.finalize_into(&mut output).unwrap();
+        assert_eq!(output, SHA256::hash(b"ab"));
+
+        assert_eq!(
+            a.finalize_into(&mut [0u8; 31]),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_dyn_hasher_output_len() {
+        let mut hashers: [Box<dyn DynHasher>; 4] = [
+            Box::new(Hasher::new(BLAKE2b::builder().with_output_len(20).build())),
+            Box::new(Hasher::new(BLAKE2s::builder().with_output_len(16).build())),
+            Box::new(Hasher::new(BLAKE2bp::builder().with_output_len(32).build())),
+            Box::new(Hasher::new(BLAKE2sp::builder().with_output_len(8).build())),
+        ];
+
+        for (hasher, len) in hashers.iter_mut().zip([20, 16, 32, 8]) {
+            let mut output = [0u8; 64];
+
+            assert_eq!(hasher.output_size(), len);
+            assert_eq!(
+                hasher.finalize_into(&mut output[..len - 1]),
+                Err(VCryptoError::InvalidInput)
+            );
+            assert_eq!(hasher.finalize_into(&mut output[..len]), Ok(()));
+            assert!(output[len..].iter().all(|b| *b == 0));
+        }
+
+        assert_eq!(
+            hex::encode(Hasher::new(BLAKE2b::builder().with_output_len(20).build()).finalize()),
+            "3345524abf6bbe1809449224b5972c41790b6cf2"
+        );
+    }
+}
@@ -1,17 +1,23 @@
 use core::cmp::min;
 use core::default::Default;
 use core::ops::IndexMut;
+#[cfg(feature = "std")]
+use std::io;
 
+#[derive(Clone)]
 pub struct Hasher<H: HashingAlgorithm> {
     unprocessed_bytes: H::Chunk,
     algorithm: H,
+    initial_algorithm: H,
+    // The state after compressing the full buffer as a non-final chunk, when it is known up front
+    buffer_processed: Option<H>,
     bytes_processed: u128,
     chunk_len: usize,
 }
 
 pub trait HashingAlgorithm
 where
-    Self: Sized + Default,
+    Self: Sized + Default + Clone,
 {
     type Chunk: IndexMut<usize> + AsMut<[u8]> + AsRef<[u8]> + Clone + Copy;
     type Output: IndexMut<usize> + AsMut<[u8]> + AsRef<[u8]> + Clone + Copy;
@@ -32,6 +38,12 @@ where
 
     fn empty_chunk() -> Self::Chunk;
 
+    /// The length of the digest in bytes, which is less than `OUTPUT_SIZE` for algorithms
+    /// configured with a shorter digest.
+    fn output_len(&self) -> usize {
+        return Self::OUTPUT_SIZE;
+    }
+
     /// This function should update the current state of the hash.
     /// * `chunk` - The chunk of data that has been provided, it will be of the exact value of `CHUNK_SIZE`.
     /// * 'bytes_processed' - The number of bytes that have been processed thus far. This includes the chunk being provided
@@ -44,29 +56,55 @@ impl<H: HashingAlgorithm> Hasher<H> {
     pub fn new(algorithm: H) -> Self {
         return Self {
             unprocessed_bytes: H::empty_chunk(),
+            initial_algorithm: algorithm.clone(),
             algorithm,
+            buffer_processed: None,
             bytes_processed: 0,
             chunk_len: 0,
         };
     }
 
+    /// Creates a hasher that has been given the full chunk `block`, compressing it as a non-final
+    /// chunk up front. Hashers cloned from it continue from that state without compressing
+    /// `block` again, while finalizing with no further input still passes `block` to `finalize`
+    /// as the final chunk.
+    pub(crate) fn with_block(algorithm: H, block: H::Chunk) -> Self {
+        let bytes_processed = H::CHUNK_SIZE as u128 % H::LENGTH_MODULO;
+        let mut buffer_processed = algorithm.clone();
+        buffer_processed.update(block.as_ref(), bytes_processed);
+
+        return Self {
+            unprocessed_bytes: block,
+            initial_algorithm: algorithm.clone(),
+            algorithm,
+            buffer_processed: Some(buffer_processed),
+            bytes_processed,
+            chunk_len: H::CHUNK_SIZE,
+        };
+    }
+
     pub fn update(&mut self, input: &[u8]) {
         let mut total_processed = 0;
 
         while total_processed < input.len() {
-            let amount_processed = self.fill_buffer(&input[total_processed..]);
-
+            // A full buffer is only processed once more input arrives, so the final chunk is always
+            // passed to `finalize`.
             if self.buffer_is_full() {
-                self.algorithm.update(
-                    self.unprocessed_bytes.as_ref(),
-                    self.bytes_processed + H::CHUNK_SIZE as u128,
-                );
+                match self.buffer_processed.take() {
+                    Some(algorithm) => self.algorithm = algorithm,
+                    None => self.algorithm.update(
+                        self.unprocessed_bytes.as_ref(),
+                        self.bytes_processed.wrapping_add(total_processed as u128)
+                            % H::LENGTH_MODULO,
+                    ),
+                }
 
                 self.chunk_len = 0;
-            } else {
-                self.chunk_len += amount_processed;
             }
 
+            let amount_processed = self.fill_buffer(&input[total_processed..]);
+
+            self.chunk_len += amount_processed;
             total_processed += amount_processed;
         }
 
@@ -75,12 +113,46 @@ impl<H: HashingAlgorithm> Hasher<H> {
     }
 
     pub fn finalize(self) -> H::Output {
-        return self.algorithm.finalize(
+        return self.finalize_with(H::finalize);
+    }
+
+    /// Passes the algorithm, the final chunk and the total number of bytes processed to `f`,
+    /// allowing algorithms to offer other ways of producing their output.
+    pub(crate) fn finalize_with<T>(self, f: impl FnOnce(H, &[u8], u128) -> T) -> T {
+        return f(
+            self.algorithm,
             &self.unprocessed_bytes.as_ref()[0..self.chunk_len],
             self.bytes_processed,
         );
     }
 
+    /// Returns the digest of the data provided so far and resets the hasher to its initial state.
+    pub fn finalize_reset(&mut self) -> H::Output {
+        let output = self.digest_so_far();
+        self.reset();
+
+        return output;
+    }
+
+    /// The length of the digest `finalize` returns in bytes.
+    pub fn output_len(&self) -> usize {
+        return self.algorithm.output_len();
+    }
+
+    /// Returns the digest of the data provided so far without consuming the hasher, more data can
+    /// still be provided afterwards.
+    pub fn digest_so_far(&self) -> H::Output {
+        return self.clone().finalize();
+    }
+
+    /// Discards all data provided so far, returning the hasher to the state it was created with.
+    pub fn reset(&mut self) {
+        self.algorithm = self.initial_algorithm.clone();
+        self.buffer_processed = None;
+        self.bytes_processed = 0;
+        self.chunk_len = 0;
+    }
+
     fn fill_buffer(&mut self, input: &[u8]) -> usize {
         let amount_consumed = min(H::CHUNK_SIZE - self.chunk_len, input.len());
 
@@ -94,3 +166,73 @@ impl<H: HashingAlgorithm> Hasher<H> {
         return self.chunk_len == H::CHUNK_SIZE;
     }
 }
+
+impl<H: HashingAlgorithm> Default for Hasher<H> {
+    fn default() -> Self {
+        return Self::new(H::default());
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: HashingAlgorithm> io::Write for Hasher<H> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{BLAKE2b, SHA256};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_clone_common_prefix() {
+        let mut prefix = Hasher::<SHA256>::default();
+        prefix.update(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnop");
+
+        let mut a = prefix.clone();
+        let mut b = prefix;
+
+        a.update(b"nopq");
+        b.update(b"q");
+
+        assert_eq!(
+            a.finalize(),
+            SHA256::hash(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+        );
+        assert_eq!(
+            b.finalize(),
+            SHA256::hash(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopq")
+        );
+    }
+
+    #[test]
+    fn test_digest_so_far() {
+        let mut hasher = SHA256::hasher();
+
+        hasher.update(b"ab");
+        assert_eq!(hasher.digest_so_far(), SHA256::hash(b"ab"));
+
+        hasher.update(b"c");
+        assert_eq!(hasher.finalize(), SHA256::hash(b"abc"));
+    }
+
+    #[test]
+    fn test_finalize_reset() {
+        let mut hasher = BLAKE2b::hasher();
+
+        hasher.update(&[0x61; 200]);
+        assert_eq!(hasher.finalize_reset(), BLAKE2b::hash(&[0x61; 200]));
+
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize_reset(), BLAKE2b::hash(b"abc"));
+        assert_eq!(hasher.finalize(), BLAKE2b::hash(b""));
+    }
+}
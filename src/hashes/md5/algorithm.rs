@@ -2,6 +2,7 @@ use crate::hashes::HashingAlgorithm;
 
 use super::constants::*;
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct MD5 {
     hash_state: [u32; 4],
 }
@@ -1,11 +1,25 @@
 mod blake2b;
+mod blake2s;
+mod blake3;
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod dynamic;
 mod hasher;
 mod md5;
+mod ripemd160;
 mod sha1;
 mod sha2;
+mod sm3;
+mod variable_output;
 
 pub use blake2b::*;
+pub use blake2s::*;
+pub use blake3::*;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use dynamic::*;
 pub use hasher::*;
 pub use md5::*;
+pub use ripemd160::*;
 pub use sha1::*;
 pub use sha2::*;
+pub use sm3::*;
+pub use variable_output::VariableOutput;
@@ -0,0 +1,202 @@
+use super::constants::*;
+use crate::hashes::HashingAlgorithm;
+
+use byteorder::{ByteOrder, LittleEndian};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RIPEMD160 {
+    hash_state: [u32; 5],
+}
+
+impl RIPEMD160 {
+    fn f(j: usize, x: u32, y: u32, z: u32) -> u32 {
+        return match j / 16 {
+            0 => x ^ y ^ z,
+            1 => (x & y) | (!x & z),
+            2 => (x | !y) ^ z,
+            3 => (x & z) | (y & !z),
+            _ => x ^ (y | !z),
+        };
+    }
+}
+
+impl HashingAlgorithm for RIPEMD160 {
+    type Chunk = [u8; 64];
+    type Output = [u8; 20];
+
+    const CHUNK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+    const LENGTH_MODULO: u128 = 0xffff_ffff_ffff_ffff;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn update(&mut self, chunk: &[u8], _bytes_processed: u128) {
+        let mut msg = [0u32; 16];
+        LittleEndian::read_u32_into(chunk, &mut msg);
+
+        // The two parallel lines of the compression function
+        let mut left = self.hash_state;
+        let mut right = self.hash_state;
+
+        for j in 0..80 {
+            let t = left[0]
+                .wrapping_add(Self::f(j, left[1], left[2], left[3]))
+                .wrapping_add(msg[RIPEMD160_WORDS_LEFT[j]])
+                .wrapping_add(RIPEMD160_K_LEFT[j / 16])
+                .rotate_left(RIPEMD160_SHIFTS_LEFT[j])
+                .wrapping_add(left[4]);
+
+            left[0] = left[4];
+            left[4] = left[3];
+            left[3] = left[2].rotate_left(10);
+            left[2] = left[1];
+            left[1] = t;
+
+            let t = right[0]
+                .wrapping_add(Self::f(79 - j, right[1], right[2], right[3]))
+                .wrapping_add(msg[RIPEMD160_WORDS_RIGHT[j]])
+                .wrapping_add(RIPEMD160_K_RIGHT[j / 16])
+                .rotate_left(RIPEMD160_SHIFTS_RIGHT[j])
+                .wrapping_add(right[4]);
+
+            right[0] = right[4];
+            right[4] = right[3];
+            right[3] = right[2].rotate_left(10);
+            right[2] = right[1];
+            right[1] = t;
+        }
+
+        let t = self.hash_state[1]
+            .wrapping_add(left[2])
+            .wrapping_add(right[3]);
+
+        self.hash_state[1] = self.hash_state[2]
+            .wrapping_add(left[3])
+            .wrapping_add(right[4]);
+        self.hash_state[2] = self.hash_state[3]
+            .wrapping_add(left[4])
+            .wrapping_add(right[0]);
+        self.hash_state[3] = self.hash_state[4]
+            .wrapping_add(left[0])
+            .wrapping_add(right[1]);
+        self.hash_state[4] = self.hash_state[0]
+            .wrapping_add(left[1])
+            .wrapping_add(right[2]);
+        self.hash_state[0] = t;
+    }
+
+    fn finalize(mut self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        if partial_chunk.len() == 64 {
+            self.update(partial_chunk, total_bytes_processed);
+
+            let mut chunk = [0u8; 64];
+            chunk[0] = 0b1000_0000;
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_le_bytes(),
+            );
+            self.update(&chunk, total_bytes_processed);
+        } else if partial_chunk.len() + 9 > 64 {
+            let mut chunk_a = [0u8; 64];
+            chunk_a[0..partial_chunk.len()].copy_from_slice(partial_chunk);
+            chunk_a[partial_chunk.len()] = 0b1000_0000;
+
+            self.update(&chunk_a, total_bytes_processed);
+
+            let mut chunk = [0u8; 64];
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_le_bytes(),
+            );
+
+            self.update(&chunk, total_bytes_processed);
+        } else {
+            let mut chunk = [0u8; 64];
+
+            chunk[0..partial_chunk.len()].copy_from_slice(partial_chunk);
+            chunk[partial_chunk.len()] = 0b1000_0000;
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_le_bytes(),
+            );
+
+            self.update(&chunk, total_bytes_processed);
+        }
+
+        let mut output = [0u8; 20];
+
+        for (m, b) in self
+            .hash_state
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (i * 4, n.to_le_bytes()))
+        {
+            output[m..m + 4].copy_from_slice(&b);
+        }
+
+        return output;
+    }
+}
+
+impl Default for RIPEMD160 {
+    fn default() -> Self {
+        return Self {
+            hash_state: RIPEMD160_H_VALUES,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ripemd160_empty() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash(&[])),
+            "9c1185a5c5e9fc54612808977ee8f548b2258d31"
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_abc() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash(b"abc")),
+            "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_message_digest() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash(b"message digest")),
+            "5d0689ef49d2fae572b881b123a85ffa21595f36"
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_two_blocks() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "12a053384a9c0c88e405a06c27dcf49ada62eb2b"
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_digits() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash("1234567890".repeat(8).as_bytes())),
+            "9b752e45573d4b39f4dbd3323cab82bf63326bfb"
+        );
+    }
+
+    #[test]
+    fn test_ripemd160_million_a() {
+        assert_eq!(
+            hex::encode(RIPEMD160::hash("a".repeat(1_000_000).as_bytes())),
+            "52783243c1697bdbe16d37f97f68f08325dc1528"
+        );
+    }
+}
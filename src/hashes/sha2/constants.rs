@@ -19,6 +19,28 @@ pub const SHA384_H_VALUES: [u64; 8] = [
     0x47b5481dbefa4fa4,
 ];
 
+pub const SHA512_224_H_VALUES: [u64; 8] = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+
+pub const SHA512_256_H_VALUES: [u64; 8] = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
+
 pub const SHA512_H_VALUES: [u64; 8] = [
     0x6a09e667f3bcc908,
     0xbb67ae8584caa73b,
@@ -1,4 +1,7 @@
-use super::constants::{SHA384_H_VALUES, SHA512_H_VALUES, SHA512_ROUND_CONSTANTS};
+use super::constants::{
+    SHA384_H_VALUES, SHA512_224_H_VALUES, SHA512_256_H_VALUES, SHA512_H_VALUES,
+    SHA512_ROUND_CONSTANTS,
+};
 use crate::hashes::HashingAlgorithm;
 
 use core::default::Default;
@@ -10,6 +13,20 @@ pub struct SHA384 {
     internal_hasher: SHA512,
 }
 
+/// SHA-512/224 as defined in FIPS 180-4, SHA-512 with a distinct IV truncated to 224 bits.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SHA512_224 {
+    internal_hasher: SHA512,
+}
+
+/// SHA-512/256 as defined in FIPS 180-4, SHA-512 with a distinct IV truncated to 256 bits.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SHA512_256 {
+    internal_hasher: SHA512,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct SHA512 {
     hash_state: [u64; 8],
@@ -60,6 +77,84 @@ impl Default for SHA384 {
     }
 }
 
+impl HashingAlgorithm for SHA512_224 {
+    type Chunk = <SHA512 as HashingAlgorithm>::Chunk;
+    type Output = [u8; 28];
+
+    const CHUNK_SIZE: usize = <SHA512 as HashingAlgorithm>::CHUNK_SIZE;
+    const OUTPUT_SIZE: usize = 28;
+    const LENGTH_MODULO: u128 = <SHA512 as HashingAlgorithm>::LENGTH_MODULO;
+
+    fn empty_chunk() -> Self::Chunk {
+        return <SHA512 as HashingAlgorithm>::empty_chunk();
+    }
+
+    fn update(&mut self, chunk: &[u8], bytes_processed: u128) {
+        self.internal_hasher.update(chunk, bytes_processed);
+    }
+
+    fn finalize(self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        let output = self
+            .internal_hasher
+            .finalize(partial_chunk, total_bytes_processed);
+
+        let mut truncated = [0u8; 28];
+
+        truncated.copy_from_slice(&output[0..28]);
+
+        return truncated;
+    }
+}
+
+impl Default for SHA512_224 {
+    fn default() -> Self {
+        return Self {
+            internal_hasher: SHA512 {
+                hash_state: SHA512_224_H_VALUES,
+            },
+        };
+    }
+}
+
+impl HashingAlgorithm for SHA512_256 {
+    type Chunk = <SHA512 as HashingAlgorithm>::Chunk;
+    type Output = [u8; 32];
+
+    const CHUNK_SIZE: usize = <SHA512 as HashingAlgorithm>::CHUNK_SIZE;
+    const OUTPUT_SIZE: usize = 32;
+    const LENGTH_MODULO: u128 = <SHA512 as HashingAlgorithm>::LENGTH_MODULO;
+
+    fn empty_chunk() -> Self::Chunk {
+        return <SHA512 as HashingAlgorithm>::empty_chunk();
+    }
+
+    fn update(&mut self, chunk: &[u8], bytes_processed: u128) {
+        self.internal_hasher.update(chunk, bytes_processed);
+    }
+
+    fn finalize(self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        let output = self
+            .internal_hasher
+            .finalize(partial_chunk, total_bytes_processed);
+
+        let mut truncated = [0u8; 32];
+
+        truncated.copy_from_slice(&output[0..32]);
+
+        return truncated;
+    }
+}
+
+impl Default for SHA512_256 {
+    fn default() -> Self {
+        return Self {
+            internal_hasher: SHA512 {
+                hash_state: SHA512_256_H_VALUES,
+            },
+        };
+    }
+}
+
 impl HashingAlgorithm for SHA512 {
     type Chunk = [u8; 128];
     type Output = [u8; 64];
@@ -141,7 +236,7 @@ impl HashingAlgorithm for SHA512 {
             chunk[Self::CHUNK_SIZE - 16..]
                 .copy_from_slice(&(total_bytes_processed * 8).to_be_bytes());
             self.update(&chunk, total_bytes_processed);
-        } else if partial_chunk.len() + 9 > Self::CHUNK_SIZE {
+        } else if partial_chunk.len() + 17 > Self::CHUNK_SIZE {
             let mut chunk_a = [0u8; Self::CHUNK_SIZE];
             chunk_a[0..partial_chunk.len()].copy_from_slice(partial_chunk);
             chunk_a[partial_chunk.len()] = 0b1000_0000;
@@ -240,6 +335,16 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_sha512_two_blocks() {
+        let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+        assert_eq!(
+            hex::encode(SHA512::hash(input)),
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+        );
+    }
+
     #[test]
     fn test_sha384_abc() {
         let input = b"abc";
@@ -269,4 +374,56 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_sha512_224_empty() {
+        assert_eq!(
+            hex::encode(SHA512_224::hash(&[])),
+            "6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4"
+        );
+    }
+
+    #[test]
+    fn test_sha512_224_abc() {
+        assert_eq!(
+            hex::encode(SHA512_224::hash(b"abc")),
+            "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa"
+        );
+    }
+
+    #[test]
+    fn test_sha512_224_two_blocks() {
+        let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+        assert_eq!(
+            hex::encode(SHA512_224::hash(input)),
+            "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9"
+        );
+    }
+
+    #[test]
+    fn test_sha512_256_empty() {
+        assert_eq!(
+            hex::encode(SHA512_256::hash(&[])),
+            "c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a"
+        );
+    }
+
+    #[test]
+    fn test_sha512_256_abc() {
+        assert_eq!(
+            hex::encode(SHA512_256::hash(b"abc")),
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"
+        );
+    }
+
+    #[test]
+    fn test_sha512_256_two_blocks() {
+        let input = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+        assert_eq!(
+            hex::encode(SHA512_256::hash(input)),
+            "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a"
+        );
+    }
 }
@@ -0,0 +1,188 @@
+use super::constants::*;
+use crate::hashes::HashingAlgorithm;
+
+use byteorder::{BigEndian, ByteOrder};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SM3 {
+    hash_state: [u32; 8],
+}
+
+impl SM3 {
+    #[inline]
+    fn p0(x: u32) -> u32 {
+        return x ^ x.rotate_left(9) ^ x.rotate_left(17);
+    }
+
+    #[inline]
+    fn p1(x: u32) -> u32 {
+        return x ^ x.rotate_left(15) ^ x.rotate_left(23);
+    }
+}
+
+impl HashingAlgorithm for SM3 {
+    type Chunk = [u8; 64];
+    type Output = [u8; 32];
+
+    const CHUNK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 32;
+    const LENGTH_MODULO: u128 = 0xffff_ffff_ffff_ffff;
+
+    fn empty_chunk() -> Self::Chunk {
+        return [0u8; Self::CHUNK_SIZE];
+    }
+
+    fn update(&mut self, chunk: &[u8], _bytes_processed: u128) {
+        let mut w = [0u32; 68];
+        BigEndian::read_u32_into(chunk, &mut w[..16]);
+
+        for j in 16..68 {
+            w[j] = Self::p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15))
+                ^ w[j - 13].rotate_left(7)
+                ^ w[j - 6];
+        }
+
+        let mut working = self.hash_state;
+
+        for j in 0..64 {
+            let (t, ff, gg);
+
+            if j < 16 {
+                t = SM3_T_VALUES[0];
+                ff = working[0] ^ working[1] ^ working[2];
+                gg = working[4] ^ working[5] ^ working[6];
+            } else {
+                t = SM3_T_VALUES[1];
+                ff = (working[0] & working[1])
+                    | (working[0] & working[2])
+                    | (working[1] & working[2]);
+                gg = (working[4] & working[5]) | (!working[4] & working[6]);
+            }
+
+            let ss1 = working[0]
+                .rotate_left(12)
+                .wrapping_add(working[4])
+                .wrapping_add(t.rotate_left(j as u32 % 32))
+                .rotate_left(7);
+            let ss2 = ss1 ^ working[0].rotate_left(12);
+            let tt1 = ff
+                .wrapping_add(working[3])
+                .wrapping_add(ss2)
+                .wrapping_add(w[j] ^ w[j + 4]);
+            let tt2 = gg
+                .wrapping_add(working[7])
+                .wrapping_add(ss1)
+                .wrapping_add(w[j]);
+
+            working[3] = working[2];
+            working[2] = working[1].rotate_left(9);
+            working[1] = working[0];
+            working[0] = tt1;
+            working[7] = working[6];
+            working[6] = working[5].rotate_left(19);
+            working[5] = working[4];
+            working[4] = Self::p0(tt2);
+        }
+
+        for i in 0..8 {
+            self.hash_state[i] ^= working[i];
+        }
+    }
+
+    fn finalize(mut self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+        if partial_chunk.len() == 64 {
+            self.update(partial_chunk, total_bytes_processed);
+
+            let mut chunk = [0u8; 64];
+            chunk[0] = 0b1000_0000;
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_be_bytes(),
+            );
+            self.update(&chunk, total_bytes_processed);
+        } else if partial_chunk.len() + 9 > 64 {
+            let mut chunk_a = [0u8; 64];
+            chunk_a[0..partial_chunk.len()].copy_from_slice(partial_chunk);
+            chunk_a[partial_chunk.len()] = 0b1000_0000;
+
+            self.update(&chunk_a, total_bytes_processed);
+
+            let mut chunk = [0u8; 64];
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_be_bytes(),
+            );
+
+            self.update(&chunk, total_bytes_processed);
+        } else {
+            let mut chunk = [0u8; 64];
+
+            chunk[0..partial_chunk.len()].copy_from_slice(partial_chunk);
+            chunk[partial_chunk.len()] = 0b1000_0000;
+            chunk[56..].copy_from_slice(
+                &(((total_bytes_processed * 8) % Self::LENGTH_MODULO) as u64).to_be_bytes(),
+            );
+
+            self.update(&chunk, total_bytes_processed);
+        }
+
+        let mut output = [0u8; 32];
+
+        for (m, b) in self
+            .hash_state
+            .into_iter()
+            .enumerate()
+            .map(|(i, n)| (i * 4, n.to_be_bytes()))
+        {
+            output[m..m + 4].copy_from_slice(&b);
+        }
+
+        return output;
+    }
+}
+
+impl Default for SM3 {
+    fn default() -> Self {
+        return Self {
+            hash_state: SM3_H_VALUES,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_sm3_empty() {
+        assert_eq!(
+            hex::encode(SM3::hash(&[])),
+            "1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b"
+        );
+    }
+
+    #[test]
+    fn test_sm3_abc() {
+        // GB/T 32905-2016 example 1
+        assert_eq!(
+            hex::encode(SM3::hash(b"abc")),
+            "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0"
+        );
+    }
+
+    #[test]
+    fn test_sm3_two_blocks() {
+        // GB/T 32905-2016 example 2
+        assert_eq!(
+            hex::encode(SM3::hash("abcd".repeat(16).as_bytes())),
+            "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732"
+        );
+    }
+
+    #[test]
+    fn test_sm3_lazy_dog() {
+        assert_eq!(
+            hex::encode(SM3::hash(b"The quick brown fox jumps over the lazy dog")),
+            "5fdfe814b8573ca021983970fc79b2218c9570369b4859684e2e4c3fc76cb8ea"
+        );
+    }
+}
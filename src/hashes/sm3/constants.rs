@@ -0,0 +1,5 @@
+pub const SM3_H_VALUES: [u32; 8] = [
+    0x7380166f, 0x4914b2b9, 0x172442d7, 0xda8a0600, 0xa96f30bc, 0x163138aa, 0xe38dee4d, 0xb0fb0e4e,
+];
+
+pub const SM3_T_VALUES: [u32; 2] = [0x79cc4519, 0x7a879d8a];
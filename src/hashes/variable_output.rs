@@ -0,0 +1,84 @@
+use core::fmt::{self, Debug};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+
+/// The output of a hashing algorithm with a configurable digest length of at most `N` bytes.
+/// It dereferences to the digest bytes, excluding any unused capacity.
+#[derive(Clone, Copy)]
+pub struct VariableOutput<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> VariableOutput<N> {
+    pub(crate) fn new(bytes: [u8; N], len: usize) -> Self {
+        debug_assert!(len <= N);
+
+        return Self { bytes, len };
+    }
+}
+
+impl<const N: usize> Deref for VariableOutput<N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        return &self.bytes[..self.len];
+    }
+}
+
+impl<const N: usize> DerefMut for VariableOutput<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return &mut self.bytes[..self.len];
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for VariableOutput<N> {
+    fn as_ref(&self) -> &[u8] {
+        return self;
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for VariableOutput<N> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        return self;
+    }
+}
+
+impl<const N: usize> Index<usize> for VariableOutput<N> {
+    type Output = u8;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        return &self.deref()[index];
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for VariableOutput<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        return &mut self.deref_mut()[index];
+    }
+}
+
+impl<const N: usize> Debug for VariableOutput<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return self.deref().fmt(f);
+    }
+}
+
+impl<const N: usize> PartialEq for VariableOutput<N> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.deref() == other.deref();
+    }
+}
+
+impl<const N: usize> Eq for VariableOutput<N> {}
+
+impl<const N: usize, const M: usize> PartialEq<[u8; M]> for VariableOutput<N> {
+    fn eq(&self, other: &[u8; M]) -> bool {
+        return self.deref() == other.as_slice();
+    }
+}
+
+impl<const N: usize> PartialEq<[u8]> for VariableOutput<N> {
+    fn eq(&self, other: &[u8]) -> bool {
+        return self.deref() == other;
+    }
+}
@@ -1,35 +1,159 @@
-use crate::hashes::{HashingAlgorithm, MD5, SHA1, SHA224, SHA256, SHA384, SHA512};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::hashes::{Algorithm, DynHasher};
+use crate::hashes::{
+    Hasher, HashingAlgorithm, MD5, RIPEMD160, SHA1, SHA224, SHA256, SHA384, SHA512, SHA512_224,
+    SHA512_256, SM3,
+};
+use crate::util::constant_time_eq;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
 const OPAD: u8 = 0x5c;
 const IPAD: u8 = 0x36;
 
+/// The HMAC key pads, each already compressed into the state of its hasher, so computing a MAC
+/// only costs the compressions of the message and the inner hash. The pads stay buffered as well,
+/// as algorithms such as BLAKE2 compress the final block differently when the message is empty.
+#[derive(Clone)]
+pub(crate) struct HmacPads<H: HashingAlgorithm> {
+    inner: Hasher<H>,
+    outer: Hasher<H>,
+}
+
+impl<H: HashingAlgorithm> HmacPads<H> {
+    pub(crate) fn new(key: &[u8]) -> Self {
+        let mut k = H::empty_chunk();
+
+        if key.len() > H::CHUNK_SIZE {
+            k.as_mut()[0..H::OUTPUT_SIZE].copy_from_slice(H::hash(key).as_ref())
+        } else {
+            k.as_mut()[0..key.len()].copy_from_slice(key);
+        }
+
+        for b in k.as_mut().iter_mut() {
+            *b ^= IPAD;
+        }
+
+        let inner = Hasher::with_block(H::default(), k);
+
+        for b in k.as_mut().iter_mut() {
+            *b ^= IPAD ^ OPAD;
+        }
+
+        let outer = Hasher::with_block(H::default(), k);
+
+        return Self { inner, outer };
+    }
+
+    /// Returns a hasher for the inner hash, the message should be given to it before passing it
+    /// to `finish`.
+    pub(crate) fn inner(&self) -> Hasher<H> {
+        return self.inner.clone();
+    }
+
+    pub(crate) fn finish(&self, inner: Hasher<H>) -> H::Output {
+        let mut outer = self.outer.clone();
+        outer.update(inner.finalize().as_ref());
+
+        return outer.finalize();
+    }
+
+    pub(crate) fn mac(&self, msg: &[u8]) -> H::Output {
+        let mut inner = self.inner();
+        inner.update(msg);
+
+        return self.finish(inner);
+    }
+}
+
 pub(crate) fn hmac<H: HashingAlgorithm>(key: &[u8], msg: &[u8]) -> H::Output {
-    let mut o_kp = H::empty_chunk();
-    let mut dbl: Vec<u8> = Vec::with_capacity(H::CHUNK_SIZE * 2);
-    let mut i_kp: Vec<u8> = Vec::with_capacity(H::CHUNK_SIZE + msg.len());
+    return HmacPads::<H>::new(key).mac(msg);
+}
+
+/// HMAC over any hashing algorithm, computed incrementally.
+#[derive(Clone)]
+pub struct Hmac<H: HashingAlgorithm> {
+    pads: HmacPads<H>,
+    inner: Hasher<H>,
+}
+
+impl<H: HashingAlgorithm> Hmac<H> {
+    pub fn new(key: &[u8]) -> Self {
+        let pads = HmacPads::new(key);
+        let inner = pads.inner();
+
+        return Self { pads, inner };
+    }
+
+    pub fn update(&mut self, msg: &[u8]) {
+        self.inner.update(msg);
+    }
+
+    pub fn finalize(self) -> H::Output {
+        return self.pads.finish(self.inner);
+    }
+
+    /// Checks `tag` against the MAC of the data provided so far in constant time.
+    pub fn verify(self, tag: &[u8]) -> bool {
+        return constant_time_eq(self.finalize().as_ref(), tag);
+    }
+}
+
+/// Returns the hashers for the inner and outer HMAC pads, with the padded key already processed.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) fn dyn_hmac_pads(
+    algorithm: Algorithm,
+    key: &[u8],
+) -> (Box<dyn DynHasher>, Box<dyn DynHasher>) {
+    let mut inner = algorithm.hasher();
+    let mut outer = algorithm.hasher();
+    let mut k = vec![0u8; algorithm.block_size()];
 
-    if key.len() > H::CHUNK_SIZE {
-        o_kp.as_mut()[0..H::OUTPUT_SIZE].copy_from_slice(H::hash(key).as_ref())
+    if key.len() > algorithm.block_size() {
+        inner.update(key);
+        inner
+            .finalize_into(&mut k)
+            .expect("the block size is larger than the output size");
     } else {
-        o_kp.as_mut()[0..key.len()].copy_from_slice(key);
+        k[..key.len()].copy_from_slice(key);
     }
 
-    for i in 0..o_kp.as_ref().len() {
-        i_kp.push(o_kp.as_ref()[i] ^ IPAD);
+    for b in k.iter_mut() {
+        *b ^= IPAD;
     }
 
-    i_kp.extend_from_slice(msg);
+    inner.update(&k);
 
-    for i in 0..o_kp.as_ref().len() {
-        dbl.push(o_kp.as_ref()[i] ^ OPAD);
+    for b in k.iter_mut() {
+        *b ^= IPAD ^ OPAD;
     }
 
-    dbl.extend_from_slice(H::hash(&i_kp).as_ref());
+    outer.update(&k);
 
-    return H::hash(&dbl);
+    return (inner, outer);
+}
+
+/// Computes the HMAC of `msg` using a hashing algorithm selected at run time.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn hmac_dyn(algorithm: Algorithm, key: &[u8], msg: &[u8]) -> Vec<u8> {
+    let (mut inner, mut outer) = dyn_hmac_pads(algorithm, key);
+    let mut output = vec![0u8; algorithm.output_size()];
+
+    inner.update(msg);
+    inner
+        .finalize_into(&mut output)
+        .expect("output is sized for the algorithm");
+    outer.update(&output);
+    outer
+        .finalize_into(&mut output)
+        .expect("output is sized for the algorithm");
+
+    return output;
 }
 
 pub fn hmac_sha224(key: &[u8], msg: &[u8]) -> <SHA224 as HashingAlgorithm>::Output {
@@ -48,6 +172,14 @@ pub fn hmac_sha512(key: &[u8], msg: &[u8]) -> <SHA512 as HashingAlgorithm>::Outp
     return hmac::<SHA512>(key, msg);
 }
 
+pub fn hmac_sha512_224(key: &[u8], msg: &[u8]) -> <SHA512_224 as HashingAlgorithm>::Output {
+    return hmac::<SHA512_224>(key, msg);
+}
+
+pub fn hmac_sha512_256(key: &[u8], msg: &[u8]) -> <SHA512_256 as HashingAlgorithm>::Output {
+    return hmac::<SHA512_256>(key, msg);
+}
+
 pub fn hmac_sha1(key: &[u8], msg: &[u8]) -> <SHA1 as HashingAlgorithm>::Output {
     return hmac::<SHA1>(key, msg);
 }
@@ -56,9 +188,18 @@ pub fn hmac_md5(key: &[u8], msg: &[u8]) -> <MD5 as HashingAlgorithm>::Output {
     return hmac::<MD5>(key, msg);
 }
 
+pub fn hmac_ripemd160(key: &[u8], msg: &[u8]) -> <RIPEMD160 as HashingAlgorithm>::Output {
+    return hmac::<RIPEMD160>(key, msg);
+}
+
+pub fn hmac_sm3(key: &[u8], msg: &[u8]) -> <SM3 as HashingAlgorithm>::Output {
+    return hmac::<SM3>(key, msg);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hashes::{BLAKE2b, BLAKE2s};
     use hex::encode;
     use pretty_assertions::assert_eq;
 
@@ -95,6 +236,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_hmac_sha512_224() {
+        let key = b"key";
+        let input = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            encode(hmac_sha512_224(key, input)),
+            "a1afb4f708cb63570639195121785ada3dc615989cc3c73f38e306a3"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha512_256() {
+        let key = b"key";
+        let input = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            encode(hmac_sha512_256(key, input)),
+            "7fb65e03577da9151a1016e9c2e514d4d48842857f13927f348588173dca6d89"
+        );
+    }
+
+    #[test]
+    fn test_hmac_ripemd160() {
+        // RFC 2286 test cases 1 and 2
+        assert_eq!(
+            encode(hmac_ripemd160(&[0x0b; 20], b"Hi There")),
+            "24cb4bd67d20fc1a5d2ed7732dcc39377f0a5668"
+        );
+
+        assert_eq!(
+            encode(hmac_ripemd160(b"Jefe", b"what do ya want for nothing?")),
+            "dda6c0213a485a9e24f4742064a7f033b43c4069"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sm3() {
+        let key = b"key";
+        let input = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            encode(hmac_sm3(key, input)),
+            "bd4a34077888162b210645b8ebf74b9af357303789357a27c7fc457244ebd398"
+        );
+    }
+
+    #[test]
+    fn test_hmac_dyn() {
+        let key = b"key";
+        let input = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            encode(hmac_dyn("sha1".parse().unwrap(), key, input)),
+            "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9"
+        );
+
+        assert_eq!(
+            encode(hmac_dyn(Algorithm::SHA256, key, input)),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn test_hmac_dyn_large_key() {
+        let key = b"The quick brown fox jumps over the lazy dogThe quick brown fox jumps over the lazy dog";
+
+        assert_eq!(
+            encode(hmac_dyn(Algorithm::SHA256, key, b"message")),
+            "5597b93a2843078cbb0c920ae41dfe20f1685e10c67e423c11ab91adfc319d12"
+        );
+    }
+
     #[test]
     fn test_hmac_sha256_large_key() {
         let key = b"The quick brown fox jumps over the lazy dogThe quick brown fox jumps over the lazy dog";
@@ -105,4 +319,53 @@ mod tests {
             "5597b93a2843078cbb0c920ae41dfe20f1685e10c67e423c11ab91adfc319d12"
         );
     }
+
+    #[test]
+    fn test_hmac_streaming() {
+        let mut mac = Hmac::<SHA256>::new(b"Jefe");
+
+        for chunk in b"what do ya want for nothing?".chunks(5) {
+            mac.update(chunk);
+        }
+
+        assert!(mac
+            .clone()
+            .verify(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")));
+        assert!(!mac.clone().verify(&[0u8; 32]));
+        assert_eq!(
+            encode(mac.finalize()),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_hmac_blake2() {
+        // Computed with Python's hmac and hashlib.blake2b/blake2s. BLAKE2 compresses its final
+        // block differently, so the pad block must not be compressed before the message arrives.
+        let cases: [(&[u8], &str, &str); 2] = [
+            (
+                b"",
+                "019fe04bf010b8d72772e6b46897ecf74b4878c394ff2c4d5cfa0b7cc9bbefcb\
+                 28c36de23cef03089db9c3d900468c89804f135e9fdef7ec9b3c7abe50ed33d3",
+                "67148074efc0f6741b474ef81c4d98d266e880d372fe723d2569b1d414d234be",
+            ),
+            (
+                &[b'a'; 200],
+                "1b2b86f712216e368a1f1efa223802c7f0b1ce673ffb6c42d21ae14c17ef615d\
+                 ffca69908ddc9b76aa3c558663b12a175b2283e58bcf6b7743b14a5e6f893f7a",
+                "45586183b8f9bd3b36c445ad9f55888eedf32ddad65bf1eae525029a60867636",
+            ),
+        ];
+
+        for (msg, blake2b, blake2s) in cases {
+            assert_eq!(encode(hmac::<BLAKE2b>(b"key", msg)), blake2b);
+            assert_eq!(encode(hmac::<BLAKE2s>(b"key", msg)), blake2s);
+            assert_eq!(encode(hmac_dyn(Algorithm::BLAKE2b, b"key", msg)), blake2b);
+            assert_eq!(encode(hmac_dyn(Algorithm::BLAKE2s, b"key", msg)), blake2s);
+
+            let mut mac = Hmac::<BLAKE2b>::new(b"key");
+            mac.update(msg);
+            assert_eq!(encode(mac.finalize()), blake2b);
+        }
+    }
 }
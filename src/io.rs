@@ -0,0 +1,410 @@
+use crate::block_ciphers::modes::BlockMode;
+use crate::block_ciphers::padding::{Padding, PKCS7};
+use crate::error::VCryptoError;
+use crate::hashes::{Hasher, HashingAlgorithm};
+
+use core::marker::PhantomData;
+use std::io::{self, Read, Write};
+
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// Encrypts everything written to it with `M` before passing it on to the inner writer. The final
+/// padded block is only written once `finish` is called.
+pub struct EncryptingWriter<W: Write, M: BlockMode, P: Padding = PKCS7> {
+    inner: W,
+    mode: M,
+    unprocessed_data: Vec<u8>,
+    padding: PhantomData<P>,
+}
+
+/// Reads ciphertext from the inner reader and yields the decrypted plaintext. The final block is
+/// held back until the end of the input so that its padding can be removed.
+pub struct DecryptingReader<R: Read, M: BlockMode, P: Padding = PKCS7> {
+    inner: R,
+    mode: M,
+    ciphertext: Vec<u8>,
+    plaintext: Vec<u8>,
+    plaintext_pos: usize,
+    finished: bool,
+    error: Option<VCryptoError>,
+    padding: PhantomData<P>,
+}
+
+/// Hashes all data read through it.
+pub struct HashingReader<R: Read, H: HashingAlgorithm> {
+    inner: R,
+    hasher: Hasher<H>,
+}
+
+fn to_io_error<E: std::error::Error + Send + Sync + 'static>(err: E) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, err);
+}
+
+impl<W: Write, M: BlockMode, P: Padding> EncryptingWriter<W, M, P> {
+    pub fn new(inner: W, mode: M) -> Self {
+        return Self {
+            inner,
+            mode,
+            unprocessed_data: Vec::with_capacity(M::BLOCK_SIZE * 2),
+            padding: PhantomData,
+        };
+    }
+
+    pub fn get_ref(&self) -> &W {
+        return &self.inner;
+    }
+
+    /// Pads and writes the final block, then flushes and returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let msg_len = self.unprocessed_data.len();
+        self.unprocessed_data
+            .resize(P::padded_len(msg_len, M::BLOCK_SIZE), 0);
+
+        P::pad(&mut self.unprocessed_data, msg_len, M::BLOCK_SIZE).map_err(to_io_error)?;
+        self.mode
+            .encrypt_blocks(&mut self.unprocessed_data)
+            .map_err(to_io_error)?;
+
+        self.inner.write_all(&self.unprocessed_data)?;
+        self.inner.flush()?;
+
+        return Ok(self.inner);
+    }
+}
+
+impl<W: Write, M: BlockMode, P: Padding> Write for EncryptingWriter<W, M, P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.unprocessed_data.extend_from_slice(buf);
+
+        let complete_len =
+            self.unprocessed_data.len() - self.unprocessed_data.len() % M::BLOCK_SIZE;
+
+        if complete_len > 0 {
+            self.mode
+                .encrypt_blocks(&mut self.unprocessed_data[..complete_len])
+                .map_err(to_io_error)?;
+            self.inner
+                .write_all(&self.unprocessed_data[..complete_len])?;
+            self.unprocessed_data.drain(..complete_len);
+        }
+
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return self.inner.flush();
+    }
+}
+
+impl<R: Read, M: BlockMode, P: Padding> DecryptingReader<R, M, P> {
+    pub fn new(inner: R, mode: M) -> Self {
+        return Self {
+            inner,
+            mode,
+            ciphertext: Vec::with_capacity(READ_BUFFER_SIZE + M::BLOCK_SIZE),
+            plaintext: Vec::with_capacity(READ_BUFFER_SIZE),
+            plaintext_pos: 0,
+            finished: false,
+            error: None,
+            padding: PhantomData,
+        };
+    }
+
+    pub fn into_inner(self) -> R {
+        return self.inner;
+    }
+
+    fn fill_plaintext(&mut self) -> io::Result<()> {
+        // The ciphertext has already been decrypted in place, so a failure is kept and returned
+        // again instead of trying once more or reporting the end of the input.
+        if let Some(err) = self.error {
+            return Err(to_io_error(err));
+        }
+
+        let mut buffer = [0u8; READ_BUFFER_SIZE];
+
+        self.plaintext.clear();
+        self.plaintext_pos = 0;
+
+        while self.plaintext.is_empty() && !self.finished {
+            let n = self.inner.read(&mut buffer)?;
+
+            if n == 0 {
+                let msg_len = self
+                    .mode
+                    .decrypt_blocks(&mut self.ciphertext)
+                    .and_then(|_| P::unpad(&self.ciphertext, M::BLOCK_SIZE))
+                    .map_err(|err| {
+                        self.error = Some(err);
+                        to_io_error(err)
+                    })?;
+
+                self.finished = true;
+                self.plaintext
+                    .extend_from_slice(&self.ciphertext[..msg_len]);
+                self.ciphertext.clear();
+            } else {
+                self.ciphertext.extend_from_slice(&buffer[..n]);
+
+                // Always keep back at least one byte so the last block is decrypted with the padding
+                let available = (self.ciphertext.len() - 1) / M::BLOCK_SIZE * M::BLOCK_SIZE;
+
+                if available > 0 {
+                    self.mode
+                        .decrypt_blocks(&mut self.ciphertext[..available])
+                        .map_err(|err| {
+                            self.error = Some(err);
+                            to_io_error(err)
+                        })?;
+                    self.plaintext
+                        .extend_from_slice(&self.ciphertext[..available]);
+                    self.ciphertext.drain(..available);
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl<R: Read, M: BlockMode, P: Padding> Read for DecryptingReader<R, M, P> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.plaintext_pos == self.plaintext.len() {
+            self.fill_plaintext()?;
+        }
+
+        let n = buf.len().min(self.plaintext.len() - self.plaintext_pos);
+        buf[..n].copy_from_slice(&self.plaintext[self.plaintext_pos..self.plaintext_pos + n]);
+        self.plaintext_pos += n;
+
+        return Ok(n);
+    }
+}
+
+impl<R: Read, H: HashingAlgorithm> HashingReader<R, H> {
+    pub fn new(inner: R, algorithm: H) -> Self {
+        return Self {
+            inner,
+            hasher: Hasher::new(algorithm),
+        };
+    }
+
+    pub fn get_ref(&self) -> &R {
+        return &self.inner;
+    }
+
+    /// Returns the digest of everything that has been read so far.
+    pub fn finalize(self) -> H::Output {
+        return self.hasher.finalize();
+    }
+
+    pub fn into_parts(self) -> (R, Hasher<H>) {
+        return (self.inner, self.hasher);
+    }
+}
+
+impl<R: Read, H: HashingAlgorithm> Read for HashingReader<R, H> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+
+        return Ok(n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_ciphers::aes::{AESKey, AES};
+    use crate::block_ciphers::modes::{CBC, ECB};
+    use crate::hashes::SHA256;
+    use pretty_assertions::assert_eq;
+
+    const KEY: [u32; 8] = [
+        0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f, 0x10111213, 0x14151617, 0x18191a1b,
+        0x1c1d1e1f,
+    ];
+
+    #[test]
+    fn test_hasher_write() {
+        let mut hasher = SHA256::hasher();
+
+        io::copy(&mut &b"abc"[..], &mut hasher).unwrap();
+
+        assert_eq!(
+            hex::encode(hasher.finalize()),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_hashing_reader() {
+        let input = b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+        let mut reader = HashingReader::new(&input[..], SHA256::default());
+        let mut output = Vec::new();
+
+        reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, input);
+        assert_eq!(
+            hex::encode(reader.finalize()),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_encrypting_writer() {
+        let mut writer =
+            EncryptingWriter::<_, _>::new(Vec::new(), ECB::<AES>::new(AESKey::new_aes256(KEY)));
+
+        writer
+            .write_all(&hex::decode("0011223344556677").unwrap())
+            .unwrap();
+        writer
+            .write_all(&hex::decode("8899aabbccddeeff").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(writer.finish().unwrap()),
+            "8ea2b7ca516745bfeafc49904b4960899f3b7504926f8bd36e3118e903a4cd4a"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let input = "The quick brown fox jumped over the lazy dog.\n".repeat(1000);
+        let mut writer =
+            EncryptingWriter::<_, _>::new(Vec::new(), ECB::<AES>::new(AESKey::new_aes256(KEY)));
+
+        for chunk in input.as_bytes().chunks(33) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        let encrypted = writer.finish().unwrap();
+
+        assert_eq!(encrypted.len(), 46 * 1000 + 16 - (46 * 1000) % 16);
+
+        let mut reader = DecryptingReader::<_, _>::new(
+            encrypted.as_slice(),
+            ECB::<AES>::new(AESKey::new_aes256(KEY)),
+        );
+        let mut output = String::new();
+
+        reader.read_to_string(&mut output).unwrap();
+
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn test_decrypting_reader_invalid_padding() {
+        let mut reader =
+            DecryptingReader::<_, _>::new(&[0u8; 16][..], ECB::<AES>::new(AESKey::new_aes256(KEY)));
+
+        assert_eq!(
+            reader.read_to_end(&mut Vec::new()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        // The error is not followed by a clean end of the input
+        assert_eq!(
+            reader.read(&mut [0u8; 16]).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn test_cbc_encrypting_writer() {
+        // SP 800-38A F.2.5, followed by the PKCS#7 padding block
+        let iv = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let key = AESKey::from_bytes(
+            &hex::decode("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4")
+                .unwrap(),
+        )
+        .unwrap();
+        let mut writer = EncryptingWriter::<_, _>::new(
+            Vec::new(),
+            CBC::<AES>::new(key, iv.as_slice().try_into().unwrap()),
+        );
+
+        for chunk in hex::decode(
+            "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51\
+             30c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710",
+        )
+        .unwrap()
+        .chunks(10)
+        {
+            writer.write_all(chunk).unwrap();
+        }
+
+        let encrypted = writer.finish().unwrap();
+
+        assert_eq!(
+            hex::encode(&encrypted[..64]),
+            "f58c4c04d6e5f1ba779eabfb5f7bfbd69cfc4e967edb808d679f777bc6702c7d\
+             39f23369a9d9bacfa530e26304231461b2eb05e2c39be9fcda6c19078c6a9d1b"
+        );
+        assert_eq!(encrypted.len(), 80);
+    }
+
+    #[test]
+    fn test_cbc_round_trip() {
+        let iv = [0x24; 16];
+        let input = "The quick brown fox jumped over the lazy dog.\n".repeat(1000);
+
+        for len in [0, 15, 16, 17, input.len()] {
+            let mut writer = EncryptingWriter::<_, _>::new(
+                Vec::new(),
+                CBC::<AES>::new(AESKey::new_aes256(KEY), iv),
+            );
+
+            for chunk in input.as_bytes()[..len].chunks(33) {
+                writer.write_all(chunk).unwrap();
+            }
+
+            let encrypted = writer.finish().unwrap();
+
+            assert_eq!(
+                CBC::<AES>::new(AESKey::new_aes256(KEY), iv)
+                    .decrypt::<PKCS7>(&encrypted)
+                    .unwrap(),
+                input.as_bytes()[..len]
+            );
+
+            let mut reader = DecryptingReader::<_, _>::new(
+                encrypted.as_slice(),
+                CBC::<AES>::new(AESKey::new_aes256(KEY), iv),
+            );
+            let mut output = String::new();
+
+            reader.read_to_string(&mut output).unwrap();
+
+            assert_eq!(output, input[..len]);
+        }
+    }
+
+    #[test]
+    fn test_cbc_decrypting_reader_truncated() {
+        let iv = [0x24; 16];
+        let input = "The quick brown fox jumped over the lazy dog.\n".repeat(1000);
+        let encrypted = CBC::<AES>::new(AESKey::new_aes256(KEY), iv)
+            .encrypt::<PKCS7>(input.as_bytes())
+            .unwrap();
+
+        // Cut inside the last block and at a block boundary before it
+        for len in [encrypted.len() - 1, encrypted.len() - 16, 8] {
+            let mut reader = DecryptingReader::<_, _>::new(
+                &encrypted[..len],
+                CBC::<AES>::new(AESKey::new_aes256(KEY), iv),
+            );
+            let mut output = Vec::new();
+
+            assert_eq!(
+                reader.read_to_end(&mut output).unwrap_err().kind(),
+                io::ErrorKind::InvalidData
+            );
+            assert_eq!(
+                reader.read(&mut [0u8; 16]).unwrap_err().kind(),
+                io::ErrorKind::InvalidData
+            );
+        }
+    }
+}
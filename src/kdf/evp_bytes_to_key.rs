@@ -0,0 +1,128 @@
+use crate::hashes::HashingAlgorithm;
+use crate::util::zeroize;
+
+/// OpenSSL's `EVP_BytesToKey`, the PBKDF1 extension legacy encrypted PEM files and `openssl enc`
+/// without `-pbkdf2` derive their key and IV with. Each block is `count` iterations of `H` over
+/// the previous block, the password and the salt, and the blocks are split between `key` and `iv`
+/// in that order.
+///
+/// It is only provided to read data produced by those formats, a single iteration of MD5 is far
+/// too cheap to protect a password. Use PBKDF2 or a password hash for anything new.
+pub fn evp_bytes_to_key<H: HashingAlgorithm>(
+    password: &[u8],
+    salt: Option<&[u8; 8]>,
+    count: usize,
+    key: &mut [u8],
+    iv: &mut [u8],
+) {
+    let mut previous: Option<H::Output> = None;
+    let mut key_offset = 0;
+    let mut iv_offset = 0;
+
+    while key_offset < key.len() || iv_offset < iv.len() {
+        let mut hasher = H::hasher();
+
+        if let Some(previous) = &previous {
+            hasher.update(previous.as_ref());
+        }
+
+        hasher.update(password);
+
+        if let Some(salt) = salt {
+            hasher.update(salt);
+        }
+
+        let mut digest = hasher.finalize();
+
+        for _ in 1..count.max(1) {
+            let next = H::hash(digest.as_ref());
+            zeroize(digest.as_mut());
+            digest = next;
+        }
+
+        let mut bytes = digest.as_ref();
+
+        let amount = bytes.len().min(key.len() - key_offset);
+        key[key_offset..key_offset + amount].copy_from_slice(&bytes[..amount]);
+        key_offset += amount;
+        bytes = &bytes[amount..];
+
+        let amount = bytes.len().min(iv.len() - iv_offset);
+        iv[iv_offset..iv_offset + amount].copy_from_slice(&bytes[..amount]);
+        iv_offset += amount;
+
+        if let Some(mut previous) = previous.replace(digest) {
+            zeroize(previous.as_mut());
+        }
+    }
+
+    if let Some(mut previous) = previous {
+        zeroize(previous.as_mut());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{MD5, SHA256};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_openssl() {
+        // Printed by `openssl enc -P`
+        let mut key = [0u8; 32];
+        let mut iv = [0u8; 16];
+        evp_bytes_to_key::<MD5>(
+            b"password",
+            Some(&[1, 2, 3, 4, 5, 6, 7, 8]),
+            1,
+            &mut key,
+            &mut iv,
+        );
+        assert_eq!(
+            hex::encode(key),
+            "e7b0971e52ca5cc8d0539fb3412f6316f7ba2e6ee293d9f3457b99436b51ce02"
+        );
+        assert_eq!(hex::encode(iv), "8d450e2ed75a84a923d4eac9fe49226b");
+
+        let mut key = [0u8; 16];
+        let mut iv = [0u8; 16];
+        evp_bytes_to_key::<MD5>(b"password", None, 1, &mut key, &mut iv);
+        assert_eq!(hex::encode(key), "5f4dcc3b5aa765d61d8327deb882cf99");
+        assert_eq!(hex::encode(iv), "2b95990a9151374abd8ff8c5a7a0fe08");
+
+        let mut key = [0u8; 24];
+        let mut iv = [0u8; 16];
+        evp_bytes_to_key::<SHA256>(
+            b"secret",
+            Some(&[0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8]),
+            1,
+            &mut key,
+            &mut iv,
+        );
+        assert_eq!(
+            hex::encode(key),
+            "438c508bed4bb3c0c272d43486e3d717fa4f4b20466bee1f"
+        );
+        assert_eq!(hex::encode(iv), "17969b75f406dbbcf2f847b0e63474a6");
+    }
+
+    #[test]
+    fn test_iterations() {
+        // `openssl enc` always uses a count of 1, this was computed with Python's hashlib
+        let mut key = [0u8; 32];
+        let mut iv = [0u8; 16];
+        evp_bytes_to_key::<MD5>(
+            b"password",
+            Some(&[1, 2, 3, 4, 5, 6, 7, 8]),
+            1000,
+            &mut key,
+            &mut iv,
+        );
+        assert_eq!(
+            hex::encode(key),
+            "2699a412f542751988e26bb58932380585bb8c6bfa9bf3745af25d787fe80d51"
+        );
+        assert_eq!(hex::encode(iv), "9d89aec48c5a6e415bfd5112f3c8abbb");
+    }
+}
@@ -0,0 +1,467 @@
+use crate::block_ciphers::BlockCipher;
+use crate::cmac::Cmac;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::hmac::Hmac;
+
+/// A keyed pseudorandom function that KBKDF can be built on, the instance given to KBKDF is
+/// cloned for every invocation.
+pub trait Prf: Clone {
+    type Output: AsMut<[u8]> + AsRef<[u8]> + Clone + Copy;
+
+    const OUTPUT_SIZE: usize;
+
+    fn empty_output() -> Self::Output;
+
+    fn update(&mut self, data: &[u8]);
+
+    fn finalize(self) -> Self::Output;
+}
+
+impl<H: HashingAlgorithm> Prf for Hmac<H> {
+    type Output = H::Output;
+
+    const OUTPUT_SIZE: usize = H::OUTPUT_SIZE;
+
+    fn empty_output() -> Self::Output {
+        return H::hash(&[]);
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Hmac::update(self, data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        return Hmac::finalize(self);
+    }
+}
+
+impl<'a, C: BlockCipher<'a>> Prf for Cmac<'a, C> {
+    type Output = C::Block;
+
+    const OUTPUT_SIZE: usize = C::BLOCK_SIZE;
+
+    fn empty_output() -> Self::Output {
+        return C::empty_block();
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        Cmac::update(self, data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        return Cmac::finalize(self);
+    }
+}
+
+/// The modes of operation from section 4 of SP 800-108.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KbkdfMode {
+    /// Each block is the PRF of a counter and the fixed input data.
+    Counter,
+    /// Each block is the PRF of the previous block, starting from an IV, and the fixed input data.
+    Feedback,
+    /// A first pipeline iterates the PRF over the fixed input data, each of its outputs is used
+    /// with the fixed input data to produce a block of output.
+    DoublePipeline,
+}
+
+/// Where the counter is placed in each PRF input. The CAVP `BEFORE_FIXED` and `AFTER_ITER`
+/// locations are `BeforeIteration` and `MiddleFixed(0)` respectively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CounterPosition {
+    /// Before everything else, including the previous block or pipeline value.
+    BeforeIteration,
+    /// After the previous block or pipeline value, at the given offset into the fixed input data.
+    /// In counter mode an offset of 0 places the counter first.
+    MiddleFixed(usize),
+    /// After the fixed input data.
+    AfterFixed,
+}
+
+/// A key based key derivation function from NIST SP 800-108 over any PRF.
+#[derive(Clone)]
+pub struct Kbkdf<P: Prf> {
+    prf: P,
+    mode: KbkdfMode,
+    counter_width: Option<usize>,
+    counter_position: CounterPosition,
+    iv: P::Output,
+    iv_len: usize,
+}
+
+impl<P: Prf> Kbkdf<P> {
+    /// Creates a KBKDF with a 32 bit counter placed before the rest of each PRF input.
+    pub fn new(prf: P, mode: KbkdfMode) -> Self {
+        return Self {
+            prf,
+            mode,
+            counter_width: Some(4),
+            counter_position: CounterPosition::BeforeIteration,
+            iv: P::empty_output(),
+            iv_len: 0,
+        };
+    }
+
+    /// # Panics
+    /// If `bits` is not 8, 16, 24 or 32.
+    pub fn with_counter_width(mut self, bits: usize) -> Self {
+        assert!(
+            matches!(bits, 8 | 16 | 24 | 32),
+            "the counter must be 8, 16, 24 or 32 bits wide"
+        );
+
+        self.counter_width = Some(bits / 8);
+        return self;
+    }
+
+    pub fn with_counter_position(mut self, position: CounterPosition) -> Self {
+        self.counter_position = position;
+        return self;
+    }
+
+    /// Omits the counter from the PRF inputs, which SP 800-108 allows for the feedback and double
+    /// pipeline modes.
+    ///
+    /// # Panics
+    /// In counter mode.
+    pub fn without_counter(mut self) -> Self {
+        assert!(
+            self.mode != KbkdfMode::Counter,
+            "counter mode requires a counter"
+        );
+
+        self.counter_width = None;
+        return self;
+    }
+
+    /// Sets the IV used in place of the previous block when producing the first block in feedback
+    /// mode, the IV is empty by default.
+    ///
+    /// # Panics
+    /// If the mode is not feedback mode, or `iv` is longer than the output of the PRF.
+    pub fn with_iv(mut self, iv: &[u8]) -> Self {
+        assert!(
+            self.mode == KbkdfMode::Feedback,
+            "only feedback mode uses an IV"
+        );
+        assert!(
+            iv.len() <= P::OUTPUT_SIZE,
+            "the IV must not be longer than the PRF output"
+        );
+
+        self.iv.as_mut()[..iv.len()].copy_from_slice(iv);
+        self.iv_len = iv.len();
+        return self;
+    }
+
+    /// Fills `output` with key material derived from `fixed_input`, which SP 800-108 suggests
+    /// should be `label || 0x00 || context || [L]_2`.
+    ///
+    /// Returns `VCryptoError::InvalidInput` if `output` needs more blocks than the counter can
+    /// count, or if the counter position is beyond the end of `fixed_input`.
+    pub fn derive_into(&self, fixed_input: &[u8], output: &mut [u8]) -> Result<(), VCryptoError> {
+        let blocks = output.len().div_ceil(P::OUTPUT_SIZE) as u64;
+
+        if let Some(width) = self.counter_width {
+            if blocks >= 1 << (8 * width) {
+                return Err(VCryptoError::InvalidInput);
+            }
+        }
+
+        if let CounterPosition::MiddleFixed(offset) = self.counter_position {
+            if offset > fixed_input.len() {
+                return Err(VCryptoError::InvalidInput);
+            }
+        }
+
+        let mut previous = self.iv;
+        let mut previous_len = self.iv_len;
+        let mut pipeline = P::empty_output();
+
+        for (i, block) in output.chunks_mut(P::OUTPUT_SIZE).enumerate() {
+            let chaining_value = match self.mode {
+                KbkdfMode::Counter => &[][..],
+                KbkdfMode::Feedback => &previous.as_ref()[..previous_len],
+                KbkdfMode::DoublePipeline => {
+                    let mut prf = self.prf.clone();
+                    prf.update(if i == 0 {
+                        fixed_input
+                    } else {
+                        pipeline.as_ref()
+                    });
+
+                    pipeline = prf.finalize();
+                    pipeline.as_ref()
+                }
+            };
+
+            let k = self.prf_block(i as u32 + 1, chaining_value, fixed_input);
+
+            block.copy_from_slice(&k.as_ref()[..block.len()]);
+            previous = k;
+            previous_len = P::OUTPUT_SIZE;
+        }
+
+        return Ok(());
+    }
+
+    fn prf_block(&self, counter: u32, chaining_value: &[u8], fixed_input: &[u8]) -> P::Output {
+        let counter_bytes = counter.to_be_bytes();
+        let counter = match self.counter_width {
+            Some(width) => &counter_bytes[4 - width..],
+            None => &[][..],
+        };
+
+        let mut prf = self.prf.clone();
+
+        match self.counter_position {
+            CounterPosition::BeforeIteration => {
+                prf.update(counter);
+                prf.update(chaining_value);
+                prf.update(fixed_input);
+            }
+            CounterPosition::MiddleFixed(offset) => {
+                prf.update(chaining_value);
+                prf.update(&fixed_input[..offset]);
+                prf.update(counter);
+                prf.update(&fixed_input[offset..]);
+            }
+            CounterPosition::AfterFixed => {
+                prf.update(chaining_value);
+                prf.update(fixed_input);
+                prf.update(counter);
+            }
+        }
+
+        return prf.finalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block_ciphers::aes::{AESKey, AES};
+    use crate::hashes::{SHA1, SHA256, SHA512};
+    use pretty_assertions::assert_eq;
+
+    const KEY: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f,
+    ];
+    const FIXED_INPUT: &str = "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f";
+
+    fn aes_cmac() -> Cmac<'static, AES> {
+        return Cmac::new(AESKey::new_aes128([
+            0x00010203, 0x04050607, 0x08090a0b, 0x0c0d0e0f,
+        ]))
+        .unwrap();
+    }
+
+    fn derive<P: Prf>(kbkdf: Kbkdf<P>, fixed_input: &str, output_len: usize) -> String {
+        let mut output = [0u8; 128];
+        kbkdf
+            .derive_into(
+                &hex::decode(fixed_input).unwrap(),
+                &mut output[..output_len],
+            )
+            .unwrap();
+
+        return hex::encode(&output[..output_len]);
+    }
+
+    #[test]
+    fn test_kbkdf_counter_cavp() {
+        // KDFCTR_gen.rsp, CMAC_AES128 and HMAC_SHA256 with an 8 bit counter before the fixed data
+        let cmac = Cmac::<AES>::new(AESKey::new_aes128([
+            0xdff1e50a, 0xc0b69dc4, 0x0f1051d4, 0x6c2b069c,
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            derive(
+                Kbkdf::new(cmac, KbkdfMode::Counter).with_counter_width(8),
+                "c16e6e02c5a3dcc8d78b9ac1306877761310455b4e41469951d9e6c2245a064b\
+                 33fd8c3b01203a7824485bf0a64060c4648b707d2607935699316ea5",
+                16
+            ),
+            "8be8f0869b3c0ba97b71863d1b9f7813"
+        );
+
+        let hmac = Hmac::<SHA256>::new(
+            &hex::decode("3edc6b5b8f7aadbd713732b482b8f979286e1ea3b8f8f99c30c884cfe3349b83")
+                .unwrap(),
+        );
+
+        assert_eq!(
+            derive(
+                Kbkdf::new(hmac, KbkdfMode::Counter).with_counter_width(8),
+                "98e9988bb4cc8b34d7922e1c68ad692ba2a1d9ae15149571675f17a77ad49e80\
+                 c8d2a85e831a26445b1f0ff44d7084a17206b4896c8112daad18605a",
+                16
+            ),
+            "6c037652990674a07844732d0ad985f9"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_counter_positions() {
+        // Generated with the KBKDFHMAC implementation of the Python cryptography package
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA1>::new(&KEY), KbkdfMode::Counter)
+                    .with_counter_width(24)
+                    .with_counter_position(CounterPosition::AfterFixed),
+                FIXED_INPUT,
+                50
+            ),
+            "4c1c390af925d23aec2fc0335410732d746ae4e2ec476267e551de4e3399db61\
+             3c393f0c1de5da7138e344f6fc47628b899f"
+        );
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA512>::new(&KEY), KbkdfMode::Counter)
+                    .with_counter_width(16)
+                    .with_counter_position(CounterPosition::MiddleFixed(10)),
+                FIXED_INPUT,
+                100
+            ),
+            "94eb557c56b4b973c97c30d6fbac17034c64c954256e0321c8c2b20eecedc95b\
+             0a5a7185a0028039dfe1ca5b37d181c45a6825930ebaec2ced9f6e129a77df54\
+             414fb0d7256e058d51aad85ea9c0260a038c2b970f8ee0e1e630f4b0b83630a0\
+             9eb9f891"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_feedback() {
+        // Generated with `openssl kdf ... -kdfopt mode:FEEDBACK KBKDF`, which places a 32 bit
+        // counter between the previous block and the fixed data
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA256>::new(&KEY), KbkdfMode::Feedback)
+                    .with_counter_position(CounterPosition::MiddleFixed(0))
+                    .with_iv(&[0xaa; 32]),
+                "4c4142454c434f4e54455854",
+                40
+            ),
+            "991c5bcfee44e2227c3b8c3eaab9339e0c74d18c1140944c1d320765a3479712\
+             800622945fbbc0bf"
+        );
+        assert_eq!(
+            derive(
+                Kbkdf::new(aes_cmac(), KbkdfMode::Feedback)
+                    .with_counter_position(CounterPosition::MiddleFixed(0))
+                    .with_iv(&[0xbb; 16]),
+                "4c4142454c00434f4e5445585400000140",
+                40
+            ),
+            "30c0836cd6d2a765c527fbac4d13234f45b072f3f29e4b84acc5f5c19e1cebfe\
+             d85b2aea8fc32c93"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_feedback_counter_options() {
+        // `openssl kdf -keylen 40 -kdfopt mac:CMAC -kdfopt cipher:AES-128-CBC
+        // -kdfopt hexkey:000102030405060708090a0b0c0d0e0f -kdfopt mode:FEEDBACK
+        // -kdfopt hexseed:00000000000000000000000000000000 -kdfopt hexinfo:<FIXED_INPUT>
+        // -kdfopt use-l:0 -kdfopt use-separator:0 -kdfopt r:8 KBKDF`
+        assert_eq!(
+            derive(
+                Kbkdf::new(aes_cmac(), KbkdfMode::Feedback)
+                    .with_counter_width(8)
+                    .with_counter_position(CounterPosition::MiddleFixed(0))
+                    .with_iv(&[0; 16]),
+                FIXED_INPUT,
+                40
+            ),
+            "a4e9bb0ad770653d2eb32abf74c0e96a212964297ddf64ca182a8e8c90a5e92d\
+             bcb4f2c3fafbd6b4"
+        );
+
+        // OpenSSL cannot move the counter or drop it, these were computed from SP 800-108 section
+        // 4.2 with Python's `hmac.digest(KEY, counter + K(i-1) + FIXED_INPUT, "sha256")`
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA256>::new(&KEY), KbkdfMode::Feedback)
+                    .with_counter_width(8)
+                    .with_iv(&[0; 32]),
+                FIXED_INPUT,
+                40
+            ),
+            "c8183006f352f7f80dca3e8133371792fa00865b2530c337fa686b0893b91f73\
+             352b3880f7648e30"
+        );
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA256>::new(&KEY), KbkdfMode::Feedback).without_counter(),
+                FIXED_INPUT,
+                80
+            ),
+            "21b8e892bb84c0760c1c21ff44332bc1255e9a7d3987c0342a3aa331793c67c5\
+             215133e3c23c49fcae86d7f6ce28ab5b38a89544db6908531388f8bdd6585309\
+             ae9231734ae548d5e4e733639e1f2419"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_double_pipeline() {
+        // SP 800-108 section 4.3 computed one PRF call at a time, A(i) = PRF(A(i-1)) with
+        // A(0) = FIXED_INPUT and K(i) = PRF(A(i) || counter || FIXED_INPUT). The CMAC blocks come
+        // from `openssl mac -cipher AES-128-CBC -macopt hexkey:000102030405060708090a0b0c0d0e0f
+        // CMAC`, the HMAC blocks from Python's `hmac.digest(KEY, ..., "sha256")`
+        assert_eq!(
+            derive(
+                Kbkdf::new(aes_cmac(), KbkdfMode::DoublePipeline)
+                    .with_counter_position(CounterPosition::MiddleFixed(0)),
+                FIXED_INPUT,
+                40
+            ),
+            "23819e17cb312819248f2add836af3cca0dbbb07c23405b065422da9d61558af\
+             fb2aabf1493a95f5"
+        );
+        // K(i) = PRF(A(i) || FIXED_INPUT || counter)
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA256>::new(&KEY), KbkdfMode::DoublePipeline)
+                    .with_counter_width(8)
+                    .with_counter_position(CounterPosition::AfterFixed),
+                FIXED_INPUT,
+                80
+            ),
+            "2655a878a19ff30a2be63ae40902d27024536d3d90bd0c8322f11a328fa84437\
+             557e1bb923d346629fa173f064bcaaaea7f9f1c613397afece5e6d1d4130d5c0\
+             3976a9cc7eaa2eb0701b074a9eb7ed48"
+        );
+        // Without a counter the first block is the same as the second block of feedback mode
+        // with an empty IV
+        assert_eq!(
+            derive(
+                Kbkdf::new(Hmac::<SHA256>::new(&KEY), KbkdfMode::DoublePipeline).without_counter(),
+                FIXED_INPUT,
+                40
+            ),
+            "215133e3c23c49fcae86d7f6ce28ab5b38a89544db6908531388f8bdd6585309\
+             3ee67242fb82d1db"
+        );
+    }
+
+    #[test]
+    fn test_kbkdf_errors() {
+        let kbkdf = Kbkdf::new(aes_cmac(), KbkdfMode::Counter).with_counter_width(8);
+        let mut output = [0u8; 16 * 256];
+
+        assert_eq!(kbkdf.derive_into(&[], &mut output[..16 * 255]), Ok(()));
+        assert_eq!(
+            kbkdf.derive_into(&[], &mut output),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            kbkdf
+                .with_counter_position(CounterPosition::MiddleFixed(4))
+                .derive_into(&[0; 3], &mut output[..16]),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+}
@@ -1,3 +1,7 @@
+mod evp_bytes_to_key;
+mod kbkdf;
 mod pbkdf2;
 
+pub use evp_bytes_to_key::*;
+pub use kbkdf::*;
 pub use pbkdf2::*;
@@ -1,49 +1,137 @@
-use crate::hashes::{HashingAlgorithm, SHA1, SHA224, SHA256, SHA384, SHA512};
-use crate::hmac::hmac;
+use crate::error::VCryptoError;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::hashes::Algorithm;
+use crate::hashes::{
+    HashingAlgorithm, RIPEMD160, SHA1, SHA224, SHA256, SHA384, SHA512, SHA512_224, SHA512_256, SM3,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::hmac::dyn_hmac_pads;
+use crate::hmac::HmacPads;
+use core::ops::ControlFlow;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
 
 pub fn pbkdf2<H: HashingAlgorithm, const O: usize>(
     password: &[u8],
     salt: &[u8],
     iterations: usize,
 ) -> [u8; O] {
-    let mut working = [0u8; O];
-    let mut amount_completed = 0;
-    let mut iteration = 1;
+    let mut output = [0u8; O];
+    pbkdf2_into::<H>(password, salt, iterations, &mut output)
+        .expect("an array cannot hold 2^32 - 1 output blocks");
 
-    while (O - amount_completed) > H::OUTPUT_SIZE {
-        working[amount_completed..amount_completed + H::OUTPUT_SIZE]
-            .copy_from_slice(pbkdf2_f::<H>(password, salt, iteration, iterations).as_ref());
+    return output;
+}
 
-        amount_completed += H::OUTPUT_SIZE;
-        iteration += 1;
-    }
+/// PBKDF2 with HMAC over `H`, filling the whole of `output`. An iteration count of 0 is treated as
+/// 1. Returns `VCryptoError::InvalidInput` if `output` is longer than 2^32 - 1 blocks.
+pub fn pbkdf2_into<H: HashingAlgorithm>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+    output: &mut [u8],
+) -> Result<(), VCryptoError> {
+    return pbkdf2_into_with_progress::<H>(password, salt, iterations, output, |_, _| {
+        return ControlFlow::Continue(());
+    });
+}
 
-    if (O - amount_completed) > 0 {
-        working[amount_completed..].copy_from_slice(
-            &pbkdf2_f::<H>(password, salt, 1, iterations).as_ref()[0..(O - amount_completed)],
-        );
+/// Like `pbkdf2_into`, calling `progress` with the number of iterations completed and the total
+/// number of iterations across all of the output blocks after each iteration. Returning
+/// `ControlFlow::Break` from `progress` stops the derivation with `VCryptoError::Cancelled`, in
+/// which case `output` holds no usable key material.
+pub fn pbkdf2_into_with_progress<H: HashingAlgorithm>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+    output: &mut [u8],
+    mut progress: impl FnMut(u64, u64) -> ControlFlow<()>,
+) -> Result<(), VCryptoError> {
+    let pads = HmacPads::<H>::new(password);
+    let iterations = iterations.max(1) as u64;
+    let total = iterations * output.len().div_ceil(H::OUTPUT_SIZE) as u64;
+    let mut completed = 0;
+
+    for (i, block) in output.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+        let block_index = u32::try_from(i + 1).map_err(|_| VCryptoError::InvalidInput)?;
+
+        let mut u1 = pads.inner();
+        u1.update(salt);
+        u1.update(&block_index.to_be_bytes());
+
+        let mut u = pads.finish(u1);
+        let mut t = u;
+
+        for j in 0..iterations {
+            if j > 0 {
+                u = pads.mac(u.as_ref());
+
+                for (a, b) in t.as_mut().iter_mut().zip(u.as_ref().iter()) {
+                    *a ^= *b;
+                }
+            }
+
+            completed += 1;
+
+            if progress(completed, total).is_break() {
+                output.fill(0);
+
+                return Err(VCryptoError::Cancelled);
+            }
+        }
+
+        block.copy_from_slice(&t.as_ref()[..block.len()]);
     }
 
-    return working;
+    return Ok(());
 }
 
-fn pbkdf2_f<H: HashingAlgorithm>(password: &[u8], salt: &[u8], i: u32, c: usize) -> H::Output {
-    let mut initial_salt = salt.to_vec();
-    initial_salt.extend_from_slice(&i.to_be_bytes());
+/// PBKDF2 using an HMAC hashing algorithm selected at run time, filling the whole of `output`.
+/// Returns `VCryptoError::InvalidInput` if `output` is longer than 2^32 - 1 blocks.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn pbkdf2_dyn(
+    algorithm: Algorithm,
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+    output: &mut [u8],
+) -> Result<(), VCryptoError> {
+    if output.len().div_ceil(algorithm.output_size()) > u32::MAX as usize {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    let (inner, outer) = dyn_hmac_pads(algorithm, password);
+    let mut u = vec![0u8; algorithm.output_size()];
 
-    let mut output = hmac::<H>(password, &initial_salt);
-    let mut previous = output;
+    for (i, block) in output.chunks_mut(algorithm.output_size()).enumerate() {
+        let mut h = inner.clone();
+        h.update(salt);
+        h.update(&(i as u32 + 1).to_be_bytes());
 
-    for _ in 2..=c {
-        // xor every byte with the new output
-        previous = hmac::<H>(password, previous.as_ref());
+        let mut t = vec![0u8; algorithm.output_size()];
 
-        for (i, b) in previous.as_ref().into_iter().enumerate() {
-            output.as_mut()[i] ^= *b;
+        for _ in 0..iterations.max(1) {
+            h.finalize_into(&mut u)
+                .expect("u is sized for the algorithm");
+
+            let mut o = outer.clone();
+            o.update(&u);
+            o.finalize_into(&mut u)
+                .expect("u is sized for the algorithm");
+
+            for (a, b) in t.iter_mut().zip(u.iter()) {
+                *a ^= *b;
+            }
+
+            h = inner.clone();
+            h.update(&u);
         }
+
+        block.copy_from_slice(&t[..block.len()]);
     }
 
-    return output;
+    return Ok(());
 }
 
 pub fn pbkdf2_hmac_sha1<const O: usize>(
@@ -86,11 +174,86 @@ pub fn pbkdf2_hmac_sha512<const O: usize>(
     return pbkdf2::<SHA512, O>(password, salt, iterations);
 }
 
+pub fn pbkdf2_hmac_sha512_224<const O: usize>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+) -> [u8; O] {
+    return pbkdf2::<SHA512_224, O>(password, salt, iterations);
+}
+
+pub fn pbkdf2_hmac_sha512_256<const O: usize>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+) -> [u8; O] {
+    return pbkdf2::<SHA512_256, O>(password, salt, iterations);
+}
+
+pub fn pbkdf2_hmac_ripemd160<const O: usize>(
+    password: &[u8],
+    salt: &[u8],
+    iterations: usize,
+) -> [u8; O] {
+    return pbkdf2::<RIPEMD160, O>(password, salt, iterations);
+}
+
+pub fn pbkdf2_hmac_sm3<const O: usize>(password: &[u8], salt: &[u8], iterations: usize) -> [u8; O] {
+    return pbkdf2::<SM3, O>(password, salt, iterations);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hashes::Hasher;
+    use core::cell::Cell;
     use pretty_assertions::assert_eq;
 
+    std::thread_local! {
+        static COMPRESSIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// SHA-256 counting the blocks it compresses on the current thread.
+    #[derive(Clone, Default)]
+    struct CountingSHA256(SHA256);
+
+    impl HashingAlgorithm for CountingSHA256 {
+        type Chunk = <SHA256 as HashingAlgorithm>::Chunk;
+        type Output = <SHA256 as HashingAlgorithm>::Output;
+
+        const CHUNK_SIZE: usize = SHA256::CHUNK_SIZE;
+        const OUTPUT_SIZE: usize = SHA256::OUTPUT_SIZE;
+        const LENGTH_MODULO: u128 = SHA256::LENGTH_MODULO;
+
+        fn empty_chunk() -> Self::Chunk {
+            return SHA256::empty_chunk();
+        }
+
+        fn update(&mut self, chunk: &[u8], bytes_processed: u128) {
+            COMPRESSIONS.with(|c| c.set(c.get() + 1));
+            self.0.update(chunk, bytes_processed);
+        }
+
+        fn finalize(self, partial_chunk: &[u8], total_bytes_processed: u128) -> Self::Output {
+            // The 0x80 byte and the 64 bit length have to fit after the message
+            let blocks = if partial_chunk.len() + 9 > Self::CHUNK_SIZE {
+                2
+            } else {
+                1
+            };
+            COMPRESSIONS.with(|c| c.set(c.get() + blocks));
+
+            return self.0.finalize(partial_chunk, total_bytes_processed);
+        }
+    }
+
+    fn count_compressions(f: impl FnOnce()) -> usize {
+        COMPRESSIONS.with(|c| c.set(0));
+        f();
+
+        return COMPRESSIONS.with(|c| c.get());
+    }
+
     #[test]
     fn test_pbkdf2_sha1() {
         let password = b"my_password";
@@ -121,6 +284,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pbkdf2_sha512_224() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha512_224::<28>(b"password", b"salt", 4096)),
+            "ed54af699cc307e08965098bda5ff4e41ea1931f46da771c1ea9128e"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_sha512_256() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha512_256::<32>(b"password", b"salt", 4096)),
+            "f2fbe5f8ec3618bb145279a8c6a8dfa476c282a3ed53d8c257d51ce021d3877d"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_ripemd160() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_ripemd160::<20>(b"password", b"salt", 4096)),
+            "99a40d3fe4ee95869791d9faa248645627827621"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_sm3() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sm3::<32>(b"password", b"salt", 4096)),
+            "b6e8f2074c87432b78f62e5ced980fdff89e86af2f693dab1638e2b3683045dd"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_dyn() {
+        let salt = hex::decode("b54bc5611be6de9720b8e9165de2c0f2").unwrap();
+        let mut output = [0u8; 20];
+
+        pbkdf2_dyn(Algorithm::SHA1, b"my_password", &salt, 4000, &mut output).unwrap();
+
+        assert_eq!(
+            hex::encode(output),
+            "85909a5b4fa1b904d2e7c48661498b9773ce2503"
+        );
+    }
+
     #[test]
     fn test_pbkdf2_sha1_long_inputs() {
         let password = b"this test should be longer than one block and a bit longer than 2 blocks. This means it must be 3 or more blocks, how about that! Well this last bit of text is just filling for space :)";
@@ -135,4 +343,127 @@ mod tests {
             "57514ed7177a1825d4629c12132623b2ba456aa6"
         );
     }
+
+    #[test]
+    fn test_pbkdf2_rfc6070() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<20>(b"password", b"salt", 1)),
+            "0c60c80f961f0e71f3a9b524af6012062fe037a6"
+        );
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<20>(b"password", b"salt", 2)),
+            "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"
+        );
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<20>(b"password", b"salt", 4096)),
+            "4b007901b765489abead49d926f721d065a429c1"
+        );
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<25>(
+                b"passwordPASSWORDpassword",
+                b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+                4096
+            )),
+            "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"
+        );
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<16>(b"pass\0word", b"sa\0lt", 4096)),
+            "56fa6aa75548099dcc37d7f03425e0c3"
+        );
+    }
+
+    #[test]
+    #[ignore = "takes 16777216 iterations"]
+    fn test_pbkdf2_rfc6070_slow() {
+        assert_eq!(
+            hex::encode(pbkdf2_hmac_sha1::<20>(b"password", b"salt", 16777216)),
+            "eefe3d61cd4da4e4e9945b3d6ba2158c2634e984"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_into_multiple_blocks() {
+        let mut output = [0u8; 80];
+        pbkdf2_into::<SHA256>(b"password", b"salt", 1000, &mut output).unwrap();
+
+        assert_eq!(
+            hex::encode(output),
+            "632c2812e46d4604102ba7618e9d6d7d2f8128f6266b4a03264d2a0460b7dcb3\
+             88b3b1131f741bcbeb02541c8c2e97bd8bed62ab6425542e45512b7312f440eb\
+             c6e21f4356a5edf32cf0394e0d5be940"
+        );
+
+        let mut output = [0u8; 150];
+        pbkdf2_into::<SHA512>(b"password", b"salt", 1000, &mut output).unwrap();
+
+        assert_eq!(
+            hex::encode(output),
+            "afe6c5530785b6cc6b1c6453384731bd5ee432ee549fd42fb6695779ad8a1c5b\
+             f59de69c48f774efc4007d5298f9033c0241d5ab69305e7b64eceeb8d834cfec\
+             6afdec3c1c23982a121f2d4be008889378a49a0dfb104f0d2856e38f44271cda\
+             f6de434196647bc5673cd6c148611ced6e9003b65879feccc89226ecc5e22090\
+             795445cc7314fcf414878a42ffd39cd3b90dcd41e065"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_progress() {
+        let mut output = [0u8; 25];
+        let mut calls = 0;
+
+        pbkdf2_into_with_progress::<SHA1>(b"password", b"salt", 10, &mut output, |done, total| {
+            calls += 1;
+            assert_eq!(done, calls);
+            assert_eq!(total, 20);
+
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+
+        assert_eq!(calls, 20);
+        assert_eq!(output, pbkdf2_hmac_sha1::<25>(b"password", b"salt", 10));
+    }
+
+    #[test]
+    fn test_pbkdf2_cancel() {
+        let mut output = [0xffu8; 40];
+        let result =
+            pbkdf2_into_with_progress::<SHA1>(b"password", b"salt", 10, &mut output, |done, _| {
+                if done == 15 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+
+        assert_eq!(result, Err(VCryptoError::Cancelled));
+        assert_eq!(output, [0u8; 40]);
+    }
+
+    #[test]
+    fn test_pbkdf2_compressions_per_iteration() {
+        let mut output = [0u8; 32];
+        let one = count_compressions(|| {
+            pbkdf2_into::<CountingSHA256>(b"password", b"salt", 1, &mut output).unwrap()
+        });
+        let eleven = count_compressions(|| {
+            pbkdf2_into::<CountingSHA256>(b"password", b"salt", 11, &mut output).unwrap()
+        });
+
+        // The pads are compressed once, each further iteration is one inner and one outer block
+        assert_eq!(one, 4);
+        assert_eq!(eleven - one, 2 * 10);
+        assert_eq!(output, pbkdf2::<SHA256, 32>(b"password", b"salt", 11));
+
+        // Finalizing straight after the pad passes it to `finalize`, which compresses it along
+        // with the length block
+        let inner = Hasher::<CountingSHA256>::with_block(CountingSHA256::default(), [0x36; 64]);
+        assert_eq!(
+            count_compressions(|| {
+                inner.clone().finalize();
+            }),
+            2
+        );
+        assert_eq!(inner.finalize(), SHA256::hash(&[0x36; 64]));
+    }
 }
@@ -3,11 +3,39 @@
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod base64;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod bignum;
 pub mod block_ciphers;
+pub mod cmac;
+mod curve25519;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod der;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod dh;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod ecc;
+pub mod ed25519;
 pub mod error;
 pub mod hashes;
-#[cfg(any(feature = "alloc", feature = "std"))]
 pub mod hmac;
-#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg(feature = "std")]
+pub mod io;
 pub mod kdf;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod merkle;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod openssl_enc;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod password;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod pem;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod pkcs8;
 pub mod random;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod rsa;
+pub mod sp800_185;
+mod util;
+pub mod x25519;
@@ -0,0 +1,613 @@
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// Prepended to the data of a leaf before hashing, see RFC 6962 section 2.1.
+const LEAF_PREFIX: u8 = 0x00;
+/// Prepended to the two child hashes of an interior node before hashing.
+const NODE_PREFIX: u8 = 0x01;
+
+/// A Merkle tree built as described in RFC 6962, with leaves and interior nodes hashed with
+/// different prefixes so a leaf can never be mistaken for a node. Leaves can be appended at any
+/// time, the root is then updated in O(log n) time.
+#[derive(Clone)]
+pub struct MerkleTree<H: HashingAlgorithm> {
+    leaves: Vec<H::Output>,
+    // The roots of the perfect subtrees making up the tree, largest first. There is one for each
+    // bit set in the number of leaves.
+    frontier: Vec<H::Output>,
+}
+
+/// Proves that a leaf is included in a tree of a given size.
+#[derive(Clone)]
+pub struct AuditPath<H: HashingAlgorithm> {
+    leaf_index: u64,
+    tree_size: u64,
+    path: Vec<H::Output>,
+}
+
+/// Proves that a tree is an append only extension of an older tree.
+#[derive(Clone)]
+pub struct ConsistencyProof<H: HashingAlgorithm> {
+    old_size: u64,
+    new_size: u64,
+    path: Vec<H::Output>,
+}
+
+pub fn leaf_hash<H: HashingAlgorithm>(data: &[u8]) -> H::Output {
+    let mut hasher = H::hasher();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(data);
+
+    return hasher.finalize();
+}
+
+pub fn node_hash<H: HashingAlgorithm>(left: &H::Output, right: &H::Output) -> H::Output {
+    let mut hasher = H::hasher();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left.as_ref());
+    hasher.update(right.as_ref());
+
+    return hasher.finalize();
+}
+
+/// Returns the largest power of 2 smaller than `n`, `n` must be at least 2.
+fn split_point(n: usize) -> usize {
+    return 1 << (usize::BITS - 1 - (n - 1).leading_zeros());
+}
+
+fn hashes_equal<H: HashingAlgorithm>(a: &H::Output, b: &H::Output) -> bool {
+    return a.as_ref() == b.as_ref();
+}
+
+fn output_from_slice<H: HashingAlgorithm>(bytes: &[u8]) -> H::Output {
+    let mut output = H::hash(&[]);
+    output.as_mut().copy_from_slice(bytes);
+
+    return output;
+}
+
+/// Computes the root of a tree with the given leaf hashes.
+fn subtree_root<H: HashingAlgorithm>(leaves: &[H::Output]) -> H::Output {
+    return match leaves.len() {
+        0 => H::hash(&[]),
+        1 => leaves[0],
+        n => {
+            let k = split_point(n);
+
+            node_hash::<H>(
+                &subtree_root::<H>(&leaves[..k]),
+                &subtree_root::<H>(&leaves[k..]),
+            )
+        }
+    };
+}
+
+impl<H: HashingAlgorithm> MerkleTree<H> {
+    pub fn new() -> Self {
+        return Self {
+            leaves: Vec::new(),
+            frontier: Vec::new(),
+        };
+    }
+
+    /// Appends a leaf containing `data`, returning its index.
+    pub fn push(&mut self, data: &[u8]) -> u64 {
+        return self.push_leaf_hash(leaf_hash::<H>(data));
+    }
+
+    /// Appends a leaf that has already been hashed with `leaf_hash`, returning its index.
+    pub fn push_leaf_hash(&mut self, leaf: H::Output) -> u64 {
+        let index = self.leaves.len();
+        self.leaves.push(leaf);
+
+        // Merge the perfect subtrees completed by this leaf, in the same way as binary addition
+        let mut merged = leaf;
+        let mut size = index;
+
+        while size & 1 == 1 {
+            let left = self
+                .frontier
+                .pop()
+                .expect("frontier has an entry for every set bit");
+            merged = node_hash::<H>(&left, &merged);
+            size >>= 1;
+        }
+
+        self.frontier.push(merged);
+
+        return index as u64;
+    }
+
+    pub fn len(&self) -> u64 {
+        return self.leaves.len() as u64;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.leaves.is_empty();
+    }
+
+    pub fn leaf_hashes(&self) -> &[H::Output] {
+        return &self.leaves;
+    }
+
+    /// The root of the whole tree, the root of an empty tree is the hash of the empty string.
+    pub fn root(&self) -> H::Output {
+        let mut subtrees = self.frontier.iter().rev();
+
+        return match subtrees.next() {
+            Some(last) => subtrees.fold(*last, |right, left| node_hash::<H>(left, &right)),
+            None => H::hash(&[]),
+        };
+    }
+
+    /// The root of the tree as it was when it contained `tree_size` leaves.
+    pub fn root_at(&self, tree_size: u64) -> Result<H::Output, VCryptoError> {
+        if tree_size > self.len() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(subtree_root::<H>(&self.leaves[..tree_size as usize]));
+    }
+
+    /// Returns the audit path of a leaf in the current tree.
+    pub fn audit_path(&self, leaf_index: u64) -> Result<AuditPath<H>, VCryptoError> {
+        return self.audit_path_at(leaf_index, self.len());
+    }
+
+    /// Returns the audit path of a leaf in the tree as it was when it contained `tree_size`
+    /// leaves.
+    pub fn audit_path_at(
+        &self,
+        leaf_index: u64,
+        tree_size: u64,
+    ) -> Result<AuditPath<H>, VCryptoError> {
+        if leaf_index >= tree_size || tree_size > self.len() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut path = Vec::new();
+        Self::build_audit_path(
+            leaf_index as usize,
+            &self.leaves[..tree_size as usize],
+            &mut path,
+        );
+
+        return Ok(AuditPath {
+            leaf_index,
+            tree_size,
+            path,
+        });
+    }
+
+    /// Returns a proof that the current tree is an extension of the tree with `old_size` leaves.
+    pub fn consistency_proof(&self, old_size: u64) -> Result<ConsistencyProof<H>, VCryptoError> {
+        return self.consistency_proof_between(old_size, self.len());
+    }
+
+    pub fn consistency_proof_between(
+        &self,
+        old_size: u64,
+        new_size: u64,
+    ) -> Result<ConsistencyProof<H>, VCryptoError> {
+        if old_size == 0 || old_size > new_size || new_size > self.len() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut path = Vec::new();
+        Self::build_consistency_path(
+            old_size as usize,
+            &self.leaves[..new_size as usize],
+            true,
+            &mut path,
+        );
+
+        return Ok(ConsistencyProof {
+            old_size,
+            new_size,
+            path,
+        });
+    }
+
+    // PATH(m, D[n]) from RFC 6962 section 2.1.1
+    fn build_audit_path(m: usize, leaves: &[H::Output], path: &mut Vec<H::Output>) {
+        if leaves.len() <= 1 {
+            return;
+        }
+
+        let k = split_point(leaves.len());
+
+        if m < k {
+            Self::build_audit_path(m, &leaves[..k], path);
+            path.push(subtree_root::<H>(&leaves[k..]));
+        } else {
+            Self::build_audit_path(m - k, &leaves[k..], path);
+            path.push(subtree_root::<H>(&leaves[..k]));
+        }
+    }
+
+    // SUBPROOF(m, D[n], b) from RFC 6962 section 2.1.2
+    fn build_consistency_path(
+        m: usize,
+        leaves: &[H::Output],
+        complete_subtree: bool,
+        path: &mut Vec<H::Output>,
+    ) {
+        let n = leaves.len();
+
+        if m == n {
+            if !complete_subtree {
+                path.push(subtree_root::<H>(leaves));
+            }
+
+            return;
+        }
+
+        let k = split_point(n);
+
+        if m <= k {
+            Self::build_consistency_path(m, &leaves[..k], complete_subtree, path);
+            path.push(subtree_root::<H>(&leaves[k..]));
+        } else {
+            Self::build_consistency_path(m - k, &leaves[k..], false, path);
+            path.push(subtree_root::<H>(&leaves[..k]));
+        }
+    }
+}
+
+impl<H: HashingAlgorithm> Default for MerkleTree<H> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+/// Writes the two sizes followed by the hashes, all sizes are big endian.
+fn serialize_proof<H: HashingAlgorithm>(a: u64, b: u64, path: &[H::Output]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16 + path.len() * H::OUTPUT_SIZE);
+    bytes.extend_from_slice(&a.to_be_bytes());
+    bytes.extend_from_slice(&b.to_be_bytes());
+
+    for hash in path {
+        bytes.extend_from_slice(hash.as_ref());
+    }
+
+    return bytes;
+}
+
+fn deserialize_proof<H: HashingAlgorithm>(
+    bytes: &[u8],
+) -> Result<(u64, u64, Vec<H::Output>), VCryptoError> {
+    if bytes.len() < 16 || !(bytes.len() - 16).is_multiple_of(H::OUTPUT_SIZE) {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    let a = u64::from_be_bytes(bytes[0..8].try_into().unwrap());
+    let b = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
+    let path = bytes[16..]
+        .chunks_exact(H::OUTPUT_SIZE)
+        .map(output_from_slice::<H>)
+        .collect();
+
+    return Ok((a, b, path));
+}
+
+impl<H: HashingAlgorithm> AuditPath<H> {
+    pub fn leaf_index(&self) -> u64 {
+        return self.leaf_index;
+    }
+
+    pub fn tree_size(&self) -> u64 {
+        return self.tree_size;
+    }
+
+    pub fn path(&self) -> &[H::Output] {
+        return &self.path;
+    }
+
+    /// Checks that a leaf containing `data` is included in the tree with the given root.
+    pub fn verify(&self, data: &[u8], root: &H::Output) -> bool {
+        return self.verify_leaf_hash(&leaf_hash::<H>(data), root);
+    }
+
+    /// Verifies the path as described in RFC 9162 section 2.1.3.2.
+    pub fn verify_leaf_hash(&self, leaf: &H::Output, root: &H::Output) -> bool {
+        if self.leaf_index >= self.tree_size {
+            return false;
+        }
+
+        let mut f_n = self.leaf_index;
+        let mut s_n = self.tree_size - 1;
+        let mut r = *leaf;
+
+        for p in self.path.iter() {
+            if s_n == 0 {
+                return false;
+            }
+
+            if f_n & 1 == 1 || f_n == s_n {
+                r = node_hash::<H>(p, &r);
+
+                while f_n & 1 == 0 && f_n != 0 {
+                    f_n >>= 1;
+                    s_n >>= 1;
+                }
+            } else {
+                r = node_hash::<H>(&r, p);
+            }
+
+            f_n >>= 1;
+            s_n >>= 1;
+        }
+
+        return s_n == 0 && hashes_equal::<H>(&r, root);
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return serialize_proof::<H>(self.leaf_index, self.tree_size, &self.path);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let (leaf_index, tree_size, path) = deserialize_proof::<H>(bytes)?;
+
+        return Ok(Self {
+            leaf_index,
+            tree_size,
+            path,
+        });
+    }
+}
+
+impl<H: HashingAlgorithm> ConsistencyProof<H> {
+    pub fn old_size(&self) -> u64 {
+        return self.old_size;
+    }
+
+    pub fn new_size(&self) -> u64 {
+        return self.new_size;
+    }
+
+    pub fn path(&self) -> &[H::Output] {
+        return &self.path;
+    }
+
+    /// Verifies the proof against the roots of both trees, as described in RFC 9162 section
+    /// 2.1.4.2.
+    pub fn verify(&self, old_root: &H::Output, new_root: &H::Output) -> bool {
+        if self.old_size == 0 || self.old_size > self.new_size {
+            return false;
+        }
+
+        if self.old_size == self.new_size {
+            return self.path.is_empty() && hashes_equal::<H>(old_root, new_root);
+        }
+
+        let mut path = self.path.iter();
+
+        // When the old tree is a complete subtree its root is not included in the proof
+        let first = if self.old_size.is_power_of_two() {
+            *old_root
+        } else {
+            match path.next() {
+                Some(hash) => *hash,
+                None => return false,
+            }
+        };
+
+        let mut f_n = self.old_size - 1;
+        let mut s_n = self.new_size - 1;
+
+        while f_n & 1 == 1 {
+            f_n >>= 1;
+            s_n >>= 1;
+        }
+
+        let mut f_r = first;
+        let mut s_r = first;
+
+        for c in path {
+            if s_n == 0 {
+                return false;
+            }
+
+            if f_n & 1 == 1 || f_n == s_n {
+                f_r = node_hash::<H>(c, &f_r);
+                s_r = node_hash::<H>(c, &s_r);
+
+                while f_n & 1 == 0 && f_n != 0 {
+                    f_n >>= 1;
+                    s_n >>= 1;
+                }
+            } else {
+                s_r = node_hash::<H>(&s_r, c);
+            }
+
+            f_n >>= 1;
+            s_n >>= 1;
+        }
+
+        return s_n == 0 && hashes_equal::<H>(&f_r, old_root) && hashes_equal::<H>(&s_r, new_root);
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        return serialize_proof::<H>(self.old_size, self.new_size, &self.path);
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, VCryptoError> {
+        let (old_size, new_size, path) = deserialize_proof::<H>(bytes)?;
+
+        return Ok(Self {
+            old_size,
+            new_size,
+            path,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use pretty_assertions::assert_eq;
+
+    // The leaves used by the Certificate Transparency test vectors
+    const LEAVES: [&[u8]; 8] = [
+        b"",
+        b"\x00",
+        b"\x10",
+        b"\x20\x21",
+        b"\x30\x31",
+        b"\x40\x41\x42\x43",
+        b"\x50\x51\x52\x53\x54\x55\x56\x57",
+        b"\x60\x61\x62\x63\x64\x65\x66\x67\x68\x69\x6a\x6b\x6c\x6d\x6e\x6f",
+    ];
+
+    const ROOTS: [&str; 8] = [
+        "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+        "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+        "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+        "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+        "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+        "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+        "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+    ];
+
+    fn test_tree() -> MerkleTree<SHA256> {
+        let mut tree = MerkleTree::new();
+
+        for leaf in LEAVES {
+            tree.push(leaf);
+        }
+
+        return tree;
+    }
+
+    fn encode_path(path: &[[u8; 32]]) -> Vec<String> {
+        return path.iter().map(hex::encode).collect();
+    }
+
+    #[test]
+    fn test_merkle_roots() {
+        let mut tree = MerkleTree::<SHA256>::new();
+
+        assert_eq!(
+            hex::encode(tree.root()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        for (i, leaf) in LEAVES.iter().enumerate() {
+            assert_eq!(tree.push(leaf), i as u64);
+            assert_eq!(hex::encode(tree.root()), ROOTS[i]);
+        }
+
+        for (i, root) in ROOTS.iter().enumerate() {
+            assert_eq!(hex::encode(tree.root_at(i as u64 + 1).unwrap()), *root);
+        }
+    }
+
+    #[test]
+    fn test_merkle_audit_paths() {
+        let tree = test_tree();
+
+        let path = tree.audit_path(0).unwrap();
+        assert_eq!(
+            encode_path(path.path()),
+            [
+                "96a296d224f285c67bee93c30f8a309157f0daa35dc5b87e410b78630a09cfc7",
+                "5f083f0a1a33ca076a95279832580db3e0ef4584bdff1f54c8a360f50de3031e",
+                "6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4",
+            ]
+        );
+
+        let path = tree.audit_path_at(5, 7).unwrap();
+        assert_eq!(
+            encode_path(path.path()),
+            [
+                "bc1a0643b12e4d2d7c77918f44e0f4f79a838b6cf9ec5b5c283e1f4d88599e6b",
+                "b08693ec2e721597130641e8211e7eedccb4c26413963eee6c1e2ed16ffb1a5f",
+                "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+            ]
+        );
+
+        for tree_size in 1..=8 {
+            let root = tree.root_at(tree_size).unwrap();
+
+            for index in 0..tree_size {
+                let path = tree.audit_path_at(index, tree_size).unwrap();
+
+                assert!(path.verify(LEAVES[index as usize], &root));
+                assert!(!path.verify(b"not a leaf", &root));
+            }
+        }
+
+        assert_eq!(tree.audit_path(8).err(), Some(VCryptoError::InvalidInput));
+    }
+
+    #[test]
+    fn test_merkle_consistency_proofs() {
+        let tree = test_tree();
+
+        let proof = tree.consistency_proof(3).unwrap();
+        assert_eq!(
+            encode_path(proof.path()),
+            [
+                "0298d122906dcfc10892cb53a73992fc5b9f493ea4c9badb27b791b4127a7fe7",
+                "07506a85fd9dd2f120eb694f86011e5bb4662e5c415a62917033d4a9624487e7",
+                "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+                "6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4",
+            ]
+        );
+
+        let proof = tree.consistency_proof(4).unwrap();
+        assert_eq!(
+            encode_path(proof.path()),
+            ["6b47aaf29ee3c2af9af889bc1fb9254dabd31177f16232dd6aab035ca39bf6e4"]
+        );
+
+        for new_size in 1..=8 {
+            let new_root = tree.root_at(new_size).unwrap();
+
+            for old_size in 1..=new_size {
+                let old_root = tree.root_at(old_size).unwrap();
+                let proof = tree.consistency_proof_between(old_size, new_size).unwrap();
+
+                assert!(proof.verify(&old_root, &new_root));
+
+                if old_size != new_size {
+                    assert!(!proof.verify(&new_root, &new_root));
+                }
+            }
+        }
+
+        assert_eq!(
+            tree.consistency_proof(0).err(),
+            Some(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_merkle_proof_serialization() {
+        let tree = test_tree();
+        let root = tree.root();
+
+        let path = tree.audit_path(6).unwrap();
+        let bytes = path.to_bytes();
+        assert_eq!(bytes.len(), 16 + 3 * 32);
+
+        let decoded = AuditPath::<SHA256>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.leaf_index(), 6);
+        assert_eq!(decoded.tree_size(), 8);
+        assert!(decoded.verify(LEAVES[6], &root));
+
+        let proof = tree.consistency_proof(6).unwrap();
+        let decoded = ConsistencyProof::<SHA256>::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(decoded.verify(&tree.root_at(6).unwrap(), &root));
+
+        assert_eq!(
+            AuditPath::<SHA256>::from_bytes(&bytes[..bytes.len() - 1]).err(),
+            Some(VCryptoError::InvalidInput)
+        );
+    }
+}
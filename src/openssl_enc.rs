@@ -0,0 +1,270 @@
+//! The file format of `openssl enc`: the magic `Salted__`, an 8 byte salt and the ciphertext,
+//! padded with PKCS#7.
+//!
+//! The key and IV are derived from the password and the salt either with `EVP_BytesToKey` over
+//! MD5, what `openssl enc -md md5` does and what OpenSSL did by default before 1.1.0, or with
+//! PBKDF2 over HMAC-SHA256 as `openssl enc -pbkdf2 -iter N` does. Neither authenticates the
+//! ciphertext, so a wrong password or tampered data is only noticed when the padding is invalid.
+
+use crate::block_ciphers::aes::{AESKey, AES};
+use crate::block_ciphers::blowfish::{Blowfish, BlowfishKey};
+use crate::block_ciphers::modes::{CBC, ECB};
+use crate::block_ciphers::padding::PKCS7;
+use crate::error::VCryptoError;
+use crate::hashes::{MD5, SHA256};
+use crate::kdf::{evp_bytes_to_key, pbkdf2_into};
+use crate::random::RandomSource;
+use crate::util::zeroize;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The magic the salt follows.
+pub const MAGIC: &[u8; 8] = b"Salted__";
+
+/// The iteration count `openssl enc -pbkdf2` uses when `-iter` is not given.
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 10000;
+
+/// The longest key and IV of the supported ciphers.
+const MAX_KEY_IV_LEN: usize = 32 + 16;
+
+/// The ciphers, named as on the `openssl enc` command line.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum EncCipher {
+    /// `-aes-128-cbc`
+    Aes128Cbc,
+    /// `-aes-192-cbc`
+    Aes192Cbc,
+    /// `-aes-256-cbc`
+    Aes256Cbc,
+    /// `-bf-cbc`, Blowfish with OpenSSL's default 128 bit key
+    BlowfishCbc,
+    /// `-bf-ecb`, Blowfish with OpenSSL's default 128 bit key
+    BlowfishEcb,
+}
+
+impl EncCipher {
+    pub const fn key_len(self) -> usize {
+        return match self {
+            EncCipher::Aes128Cbc => 16,
+            EncCipher::Aes192Cbc => 24,
+            EncCipher::Aes256Cbc => 32,
+            EncCipher::BlowfishCbc | EncCipher::BlowfishEcb => 16,
+        };
+    }
+
+    pub const fn iv_len(self) -> usize {
+        return match self {
+            EncCipher::Aes128Cbc | EncCipher::Aes192Cbc | EncCipher::Aes256Cbc => 16,
+            EncCipher::BlowfishCbc => 8,
+            EncCipher::BlowfishEcb => 0,
+        };
+    }
+}
+
+/// How the key and IV are derived from the password and the salt.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum EncKdf {
+    /// `-md md5`, a single iteration of `EVP_BytesToKey` over MD5.
+    BytesToKeyMd5,
+    /// `-pbkdf2 -iter N`, PBKDF2 over HMAC-SHA256 with N iterations.
+    Pbkdf2 { iterations: u32 },
+}
+
+/// Encrypts and decrypts data in the format of `openssl enc` with a given cipher and key
+/// derivation, which are not recorded in the data and have to match the command line used.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct OpenSslEnc {
+    cipher: EncCipher,
+    kdf: EncKdf,
+}
+
+impl OpenSslEnc {
+    pub fn new(cipher: EncCipher, kdf: EncKdf) -> Self {
+        return Self { cipher, kdf };
+    }
+
+    pub fn cipher(&self) -> EncCipher {
+        return self.cipher;
+    }
+
+    pub fn kdf(&self) -> EncKdf {
+        return self.kdf;
+    }
+
+    /// Encrypts `plaintext` with a random salt.
+    pub fn encrypt(
+        &self,
+        password: &[u8],
+        plaintext: &[u8],
+        rng: &mut impl RandomSource,
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let mut salt = [0u8; 8];
+        rng.fill_bytes(&mut salt)?;
+
+        return self.encrypt_with_salt(password, &salt, plaintext);
+    }
+
+    /// Encrypts `plaintext` with the given salt, as `openssl enc -S` does. Returns
+    /// `VCryptoError::InvalidInput` if the PBKDF2 iteration count is 0.
+    pub fn encrypt_with_salt(
+        &self,
+        password: &[u8],
+        salt: &[u8; 8],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let mut key_iv = self.derive_key_iv(password, salt)?;
+        let (key, iv) =
+            key_iv[..self.cipher.key_len() + self.cipher.iv_len()].split_at(self.cipher.key_len());
+
+        let ciphertext = match self.cipher {
+            EncCipher::Aes128Cbc | EncCipher::Aes192Cbc | EncCipher::Aes256Cbc => {
+                CBC::<AES>::new(AESKey::from_bytes(key)?, iv.try_into().unwrap())
+                    .encrypt::<PKCS7>(plaintext)
+            }
+            EncCipher::BlowfishCbc => {
+                CBC::<Blowfish>::new(BlowfishKey::new(key)?, iv.try_into().unwrap())
+                    .encrypt::<PKCS7>(plaintext)
+            }
+            EncCipher::BlowfishEcb => {
+                let mut cipher = ECB::<Blowfish>::new(BlowfishKey::new(key)?);
+                cipher.update(plaintext);
+
+                cipher.finish::<PKCS7>()
+            }
+        };
+
+        zeroize(&mut key_iv);
+
+        let ciphertext = ciphertext?;
+        let mut output = Vec::with_capacity(16 + ciphertext.len());
+        output.extend_from_slice(MAGIC);
+        output.extend_from_slice(salt);
+        output.extend_from_slice(&ciphertext);
+
+        return Ok(output);
+    }
+
+    /// Decrypts data starting with `Salted__` and the salt. Returns
+    /// `VCryptoError::InvalidEncoding` if the header is missing and, almost always,
+    /// `VCryptoError::InvalidPadding` if the password, cipher or key derivation is wrong.
+    pub fn decrypt(&self, password: &[u8], data: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let salt = Self::salt(data)?;
+        let ciphertext = &data[16..];
+
+        let mut key_iv = self.derive_key_iv(password, &salt)?;
+        let (key, iv) =
+            key_iv[..self.cipher.key_len() + self.cipher.iv_len()].split_at(self.cipher.key_len());
+
+        let plaintext = match self.cipher {
+            EncCipher::Aes128Cbc | EncCipher::Aes192Cbc | EncCipher::Aes256Cbc => {
+                CBC::<AES>::new(AESKey::from_bytes(key)?, iv.try_into().unwrap())
+                    .decrypt::<PKCS7>(ciphertext)
+            }
+            EncCipher::BlowfishCbc => {
+                CBC::<Blowfish>::new(BlowfishKey::new(key)?, iv.try_into().unwrap())
+                    .decrypt::<PKCS7>(ciphertext)
+            }
+            EncCipher::BlowfishEcb => {
+                ECB::<Blowfish>::new(BlowfishKey::new(key)?).decrypt::<PKCS7>(ciphertext)
+            }
+        };
+
+        zeroize(&mut key_iv);
+
+        return plaintext;
+    }
+
+    /// Returns the salt of data written by `openssl enc`, or `VCryptoError::InvalidEncoding` if
+    /// it does not start with `Salted__` and the salt.
+    pub fn salt(data: &[u8]) -> Result<[u8; 8], VCryptoError> {
+        if data.len() < 16 || &data[..8] != MAGIC {
+            return Err(VCryptoError::InvalidEncoding);
+        }
+
+        return Ok(data[8..16].try_into().unwrap());
+    }
+
+    /// Derives the key followed by the IV, in the way `openssl enc` splits them.
+    fn derive_key_iv(
+        &self,
+        password: &[u8],
+        salt: &[u8; 8],
+    ) -> Result<[u8; MAX_KEY_IV_LEN], VCryptoError> {
+        let mut key_iv = [0u8; MAX_KEY_IV_LEN];
+        let (key, iv) = key_iv.split_at_mut(self.cipher.key_len());
+        let iv = &mut iv[..self.cipher.iv_len()];
+
+        match self.kdf {
+            EncKdf::BytesToKeyMd5 => evp_bytes_to_key::<MD5>(password, Some(salt), 1, key, iv),
+            EncKdf::Pbkdf2 { iterations: 0 } => return Err(VCryptoError::InvalidInput),
+            EncKdf::Pbkdf2 { iterations } => {
+                let len = self.cipher.key_len() + self.cipher.iv_len();
+                pbkdf2_into::<SHA256>(password, salt, iterations as usize, &mut key_iv[..len])?;
+            }
+        }
+
+        return Ok(key_iv);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    const CIPHERS: [EncCipher; 5] = [
+        EncCipher::Aes128Cbc,
+        EncCipher::Aes192Cbc,
+        EncCipher::Aes256Cbc,
+        EncCipher::BlowfishCbc,
+        EncCipher::BlowfishEcb,
+    ];
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"openssl enc", &[], &[]);
+
+        for cipher in CIPHERS {
+            for kdf in [EncKdf::BytesToKeyMd5, EncKdf::Pbkdf2 { iterations: 10 }] {
+                let enc = OpenSslEnc::new(cipher, kdf);
+                let block_size = cipher.iv_len().max(8);
+
+                for len in [0, 1, 7, 8, 16, 33] {
+                    let plaintext = vec![0x42; len];
+                    let encrypted = enc.encrypt(b"password", &plaintext, &mut rng).unwrap();
+
+                    assert_eq!(&encrypted[..8], MAGIC);
+                    assert_eq!(encrypted.len(), 16 + (len / block_size + 1) * block_size);
+                    assert_eq!(enc.decrypt(b"password", &encrypted).unwrap(), plaintext);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let enc = OpenSslEnc::new(EncCipher::Aes256Cbc, EncKdf::BytesToKeyMd5);
+        let encrypted = enc
+            .encrypt_with_salt(b"password", &[1; 8], b"message")
+            .unwrap();
+
+        for data in [&encrypted[..15], b"Salted_!01234567"] {
+            assert_eq!(
+                enc.decrypt(b"password", data),
+                Err(VCryptoError::InvalidEncoding)
+            );
+        }
+
+        assert_eq!(
+            enc.decrypt(b"password", &encrypted[..encrypted.len() - 1]),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            OpenSslEnc::new(EncCipher::Aes256Cbc, EncKdf::Pbkdf2 { iterations: 0 })
+                .encrypt_with_salt(b"password", &[1; 8], b"message"),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+}
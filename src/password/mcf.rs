@@ -0,0 +1,164 @@
+use crate::error::VCryptoError;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::{String, ToString};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A password hash in the modular crypt format, `$<id>$<field>$<field>...`.
+///
+/// The format only fixes the leading identifier, the meaning and encoding of the fields is up to
+/// each scheme.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct McfString {
+    id: String,
+    fields: Vec<String>,
+}
+
+impl McfString {
+    /// # Panics
+    /// If `id` is empty or contains characters outside of `[a-z0-9-]`.
+    pub fn new(id: &str) -> Self {
+        assert!(is_valid_id(id), "invalid modular crypt identifier");
+
+        return Self {
+            id: id.to_string(),
+            fields: Vec::new(),
+        };
+    }
+
+    /// # Panics
+    /// If `field` is empty or contains characters that are not printable ASCII, '$' or ':'.
+    pub fn with_field(mut self, field: &str) -> Self {
+        assert!(is_valid_field(field), "invalid modular crypt field");
+
+        self.fields.push(field.to_string());
+        return self;
+    }
+
+    pub fn id(&self) -> &str {
+        return &self.id;
+    }
+
+    pub fn fields(&self) -> &[String] {
+        return &self.fields;
+    }
+}
+
+impl FromStr for McfString {
+    type Err = VCryptoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s
+            .strip_prefix('$')
+            .ok_or(VCryptoError::InvalidInput)?
+            .split('$');
+        let id = fields.next().ok_or(VCryptoError::InvalidInput)?;
+
+        if !is_valid_id(id) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut mcf = Self::new(id);
+
+        for field in fields {
+            if !is_valid_field(field) {
+                return Err(VCryptoError::InvalidInput);
+            }
+
+            mcf.fields.push(field.to_string());
+        }
+
+        return Ok(mcf);
+    }
+}
+
+impl Display for McfString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "${}", self.id)?;
+
+        for field in &self.fields {
+            write!(f, "${}", field)?;
+        }
+
+        return Ok(());
+    }
+}
+
+fn is_valid_id(id: &str) -> bool {
+    return !id.is_empty()
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+}
+
+fn is_valid_field(field: &str) -> bool {
+    return !field.is_empty()
+        && field
+            .bytes()
+            .all(|b| b.is_ascii_graphic() && b != b'$' && b != b':');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mcf_parse() {
+        let mcf: McfString = "$pbkdf2-sha256$6400$0ZrzXitFSGltTQnBWOsdAw$Y11AchqV4b0sUisdZd0Xr97KWoymNE0LNNrnEgY4H9M"
+            .parse()
+            .unwrap();
+
+        assert_eq!(mcf.id(), "pbkdf2-sha256");
+        assert_eq!(
+            mcf.fields(),
+            [
+                "6400",
+                "0ZrzXitFSGltTQnBWOsdAw",
+                "Y11AchqV4b0sUisdZd0Xr97KWoymNE0LNNrnEgY4H9M"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mcf_round_trip() {
+        for s in [
+            "$1$saltsalt$qjXMvbEw8oaL.CzflDugX/",
+            "$6$rounds=5000$salt$hash",
+            "$pbkdf2-sha256$6400$0ZrzXitFSGltTQnBWOsdAw$Y11AchqV4b0sUisdZd0Xr97KWoymNE0LNNrnEgY4H9M",
+        ] {
+            assert_eq!(s.parse::<McfString>().unwrap().to_string(), s);
+        }
+
+        assert_eq!(
+            McfString::new("1")
+                .with_field("salt")
+                .with_field("hash")
+                .to_string(),
+            "$1$salt$hash"
+        );
+    }
+
+    #[test]
+    fn test_mcf_invalid() {
+        for s in [
+            "",
+            "1$salt",
+            "$",
+            "$1$$hash",
+            "$1$salt:hash",
+            "$1$salt hash",
+            "$MD5$salt",
+        ] {
+            assert_eq!(
+                s.parse::<McfString>(),
+                Err(VCryptoError::InvalidInput),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Password hashing behind a common interface, along with the PHC string and modular crypt
+//! formats the hashes are stored in.
+
+mod mcf;
+mod pbkdf2;
+mod phc;
+
+use crate::error::VCryptoError;
+pub use mcf::*;
+pub use pbkdf2::*;
+pub use phc::*;
+
+pub(crate) use phc::parse_decimal;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+pub trait PasswordHasher {
+    /// Hashes `password` with `salt`, returning the encoded hash to be stored.
+    fn hash_password(&self, password: &[u8], salt: &[u8]) -> Result<String, VCryptoError>;
+
+    /// Checks `password` against an encoded hash, returning an error if the hash is malformed or
+    /// uses an algorithm this hasher does not support.
+    fn verify_password(&self, password: &[u8], hash: &str) -> Result<bool, VCryptoError>;
+
+    /// Returns true if the encoded hash was not produced with this hasher's algorithm and at
+    /// least its cost, so the password should be hashed again after it has been verified.
+    fn needs_rehash(&self, hash: &str) -> Result<bool, VCryptoError>;
+}
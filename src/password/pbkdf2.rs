@@ -0,0 +1,411 @@
+use super::{McfString, PasswordHasher, PhcString};
+use crate::base64;
+use crate::error::VCryptoError;
+use crate::hashes::{SHA256, SHA512};
+use crate::kdf::pbkdf2_into;
+use crate::util::constant_time_eq;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::{String, ToString};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The lowest iteration count a new hash can be created with, from SP 800-132.
+pub const PBKDF2_MIN_ITERATIONS: u32 = 1000;
+pub const PBKDF2_SHA256_DEFAULT_ITERATIONS: u32 = 600_000;
+pub const PBKDF2_SHA512_DEFAULT_ITERATIONS: u32 = 210_000;
+pub const PBKDF2_MIN_SALT_LEN: usize = 8;
+/// Longer passwords are rejected so that hashing cannot be made arbitrarily expensive.
+pub const PBKDF2_MAX_PASSWORD_LEN: usize = 4096;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pbkdf2Algorithm {
+    Sha256,
+    Sha512,
+}
+
+impl Pbkdf2Algorithm {
+    /// The identifier used in PHC and modular crypt strings.
+    pub fn id(self) -> &'static str {
+        return match self {
+            Pbkdf2Algorithm::Sha256 => "pbkdf2-sha256",
+            Pbkdf2Algorithm::Sha512 => "pbkdf2-sha512",
+        };
+    }
+
+    pub fn output_size(self) -> usize {
+        return match self {
+            Pbkdf2Algorithm::Sha256 => 32,
+            Pbkdf2Algorithm::Sha512 => 64,
+        };
+    }
+
+    fn from_id(id: &str) -> Result<Self, VCryptoError> {
+        return match id {
+            "pbkdf2-sha256" => Ok(Pbkdf2Algorithm::Sha256),
+            "pbkdf2-sha512" => Ok(Pbkdf2Algorithm::Sha512),
+            _ => Err(VCryptoError::UnknownAlgorithm),
+        };
+    }
+
+    fn derive(
+        self,
+        password: &[u8],
+        salt: &[u8],
+        iterations: u32,
+        output: &mut [u8],
+    ) -> Result<(), VCryptoError> {
+        return match self {
+            Pbkdf2Algorithm::Sha256 => {
+                pbkdf2_into::<SHA256>(password, salt, iterations as usize, output)
+            }
+            Pbkdf2Algorithm::Sha512 => {
+                pbkdf2_into::<SHA512>(password, salt, iterations as usize, output)
+            }
+        };
+    }
+}
+
+/// Hashes passwords with PBKDF2, producing PHC strings such as
+/// `$pbkdf2-sha256$i=600000,l=32$<salt>$<hash>`.
+///
+/// Both PHC strings and the modular crypt format used by passlib,
+/// `$pbkdf2-sha256$<iterations>$<salt>$<hash>` with '.' in place of '+', can be verified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pbkdf2Hasher {
+    algorithm: Pbkdf2Algorithm,
+    iterations: u32,
+}
+
+/// The parameters and contents of a parsed PBKDF2 password hash.
+struct Pbkdf2Hash {
+    algorithm: Pbkdf2Algorithm,
+    iterations: u32,
+    salt: Vec<u8>,
+    hash: Vec<u8>,
+    is_phc: bool,
+}
+
+impl Pbkdf2Hasher {
+    pub fn new(algorithm: Pbkdf2Algorithm, iterations: u32) -> Result<Self, VCryptoError> {
+        if iterations < PBKDF2_MIN_ITERATIONS {
+            return Err(VCryptoError::InvalidCost {
+                cost: iterations as usize,
+                min: PBKDF2_MIN_ITERATIONS as usize,
+                max: u32::MAX as usize,
+            });
+        }
+
+        return Ok(Self {
+            algorithm,
+            iterations,
+        });
+    }
+
+    pub fn sha256() -> Self {
+        return Self {
+            algorithm: Pbkdf2Algorithm::Sha256,
+            iterations: PBKDF2_SHA256_DEFAULT_ITERATIONS,
+        };
+    }
+
+    pub fn sha512() -> Self {
+        return Self {
+            algorithm: Pbkdf2Algorithm::Sha512,
+            iterations: PBKDF2_SHA512_DEFAULT_ITERATIONS,
+        };
+    }
+
+    pub fn algorithm(&self) -> Pbkdf2Algorithm {
+        return self.algorithm;
+    }
+
+    pub fn iterations(&self) -> u32 {
+        return self.iterations;
+    }
+
+    fn parse(hash: &str) -> Result<Pbkdf2Hash, VCryptoError> {
+        let mcf: McfString = hash.parse()?;
+        let algorithm = Pbkdf2Algorithm::from_id(mcf.id())?;
+
+        let parsed = if mcf.fields().first().is_some_and(|f| f.contains('=')) {
+            Self::parse_phc(algorithm, &hash.parse()?)?
+        } else {
+            Self::parse_passlib(algorithm, &mcf)?
+        };
+
+        if parsed.iterations == 0 {
+            return Err(VCryptoError::InvalidCost {
+                cost: 0,
+                min: 1,
+                max: u32::MAX as usize,
+            });
+        }
+
+        if parsed.hash.is_empty() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(parsed);
+    }
+
+    fn parse_phc(algorithm: Pbkdf2Algorithm, phc: &PhcString) -> Result<Pbkdf2Hash, VCryptoError> {
+        if phc.version().is_some() || phc.params().iter().any(|(n, _)| n != "i" && n != "l") {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let iterations = super::parse_decimal(phc.param("i").ok_or(VCryptoError::InvalidInput)?)?;
+        let salt = phc.salt().ok_or(VCryptoError::InvalidInput)?;
+        let hash = phc.hash().ok_or(VCryptoError::InvalidInput)?;
+
+        if let Some(length) = phc.param("l") {
+            if super::parse_decimal(length)? as usize != hash.len() {
+                return Err(VCryptoError::InvalidInput);
+            }
+        }
+
+        return Ok(Pbkdf2Hash {
+            algorithm,
+            iterations,
+            salt: salt.to_vec(),
+            hash: hash.to_vec(),
+            is_phc: true,
+        });
+    }
+
+    fn parse_passlib(
+        algorithm: Pbkdf2Algorithm,
+        mcf: &McfString,
+    ) -> Result<Pbkdf2Hash, VCryptoError> {
+        let [iterations, salt, hash] = mcf.fields() else {
+            return Err(VCryptoError::InvalidInput);
+        };
+
+        return Ok(Pbkdf2Hash {
+            algorithm,
+            iterations: super::parse_decimal(iterations)?,
+            salt: decode_ab64(salt)?,
+            hash: decode_ab64(hash)?,
+            is_phc: false,
+        });
+    }
+}
+
+impl Default for Pbkdf2Hasher {
+    fn default() -> Self {
+        return Self::sha256();
+    }
+}
+
+impl PasswordHasher for Pbkdf2Hasher {
+    fn hash_password(&self, password: &[u8], salt: &[u8]) -> Result<String, VCryptoError> {
+        check_password_length(password)?;
+
+        if salt.len() < PBKDF2_MIN_SALT_LEN {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut hash = vec![0u8; self.algorithm.output_size()];
+        self.algorithm
+            .derive(password, salt, self.iterations, &mut hash)?;
+
+        return Ok(PhcString::new(self.algorithm.id())
+            .with_param("i", &self.iterations.to_string())
+            .with_param("l", &hash.len().to_string())
+            .with_salt(salt)
+            .with_hash(&hash)
+            .to_string());
+    }
+
+    fn verify_password(&self, password: &[u8], hash: &str) -> Result<bool, VCryptoError> {
+        check_password_length(password)?;
+
+        let parsed = Self::parse(hash)?;
+        let mut expected = vec![0u8; parsed.hash.len()];
+
+        parsed
+            .algorithm
+            .derive(password, &parsed.salt, parsed.iterations, &mut expected)?;
+
+        return Ok(constant_time_eq(&expected, &parsed.hash));
+    }
+
+    fn needs_rehash(&self, hash: &str) -> Result<bool, VCryptoError> {
+        let parsed = Self::parse(hash)?;
+
+        return Ok(!parsed.is_phc
+            || parsed.algorithm != self.algorithm
+            || parsed.iterations < self.iterations
+            || parsed.hash.len() != self.algorithm.output_size());
+    }
+}
+
+fn check_password_length(password: &[u8]) -> Result<(), VCryptoError> {
+    if password.len() > PBKDF2_MAX_PASSWORD_LEN {
+        return Err(VCryptoError::InvalidPasswordLength {
+            password_length: password.len(),
+            min: 0,
+            max: PBKDF2_MAX_PASSWORD_LEN,
+        });
+    }
+
+    return Ok(());
+}
+
+/// Decodes passlib's variant of base64, which uses '.' in place of '+' and has no padding.
+fn decode_ab64(s: &str) -> Result<Vec<u8>, VCryptoError> {
+    if s.contains(['+', '=']) {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    return base64::decode_no_pad(&s.replace('.', "+")).map_err(|_| VCryptoError::InvalidInput);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_pbkdf2_hash_password() {
+        let hasher = Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 1000).unwrap();
+
+        assert_eq!(
+            hasher
+                .hash_password(b"password", b"saltsaltsaltsalt")
+                .unwrap(),
+            "$pbkdf2-sha256$i=1000,l=32$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA"
+        );
+
+        let hasher = Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha512, 1000).unwrap();
+        let salt: Vec<u8> = (0..16).collect();
+
+        assert_eq!(
+            hasher
+                .hash_password(b"correct horse battery staple", &salt)
+                .unwrap(),
+            "$pbkdf2-sha512$i=1000,l=64$AAECAwQFBgcICQoLDA0ODw$A6AmFIti7CKlYdoPiV9jf1d9llp37o27Wms8Zx8fwMJGCOigJzAthOW3Pg+XF5PnNiYnsQsIz1N5NtynrEm78w"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_verify_password() {
+        let hasher = Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha512, 1000).unwrap();
+        let hash = hasher
+            .hash_password(b"correct horse battery staple", b"saltsaltsaltsalt")
+            .unwrap();
+
+        assert_eq!(
+            hasher.verify_password(b"correct horse battery staple", &hash),
+            Ok(true)
+        );
+        assert_eq!(
+            hasher.verify_password(b"correct horse battery stapler", &hash),
+            Ok(false)
+        );
+
+        // Hashes of the other algorithm can still be verified
+        assert_eq!(
+            hasher.verify_password(
+                b"password",
+                "$pbkdf2-sha256$i=1000,l=32$c2FsdHNhbHRzYWx0c2FsdA$8nX7hwFEzIB8aPajJTYK8weHQc5Ngz0pFVAKvSu4jQA"
+            ),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_verify_passlib() {
+        // From the passlib documentation
+        let hash = "$pbkdf2-sha256$6400$0ZrzXitFSGltTQnBWOsdAw$Y11AchqV4b0sUisdZd0Xr97KWoymNE0LNNrnEgY4H9M";
+        let hasher = Pbkdf2Hasher::sha256();
+
+        assert_eq!(hasher.verify_password(b"password", hash), Ok(true));
+        assert_eq!(hasher.verify_password(b"passw0rd", hash), Ok(false));
+        assert_eq!(hasher.needs_rehash(hash), Ok(true));
+    }
+
+    #[test]
+    fn test_pbkdf2_needs_rehash() {
+        let hasher = Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 2000).unwrap();
+        let hash = hasher
+            .hash_password(b"password", b"saltsaltsaltsalt")
+            .unwrap();
+
+        assert_eq!(hasher.needs_rehash(&hash), Ok(false));
+        assert_eq!(
+            Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 1000)
+                .unwrap()
+                .needs_rehash(&hash),
+            Ok(false)
+        );
+        assert_eq!(
+            Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 4000)
+                .unwrap()
+                .needs_rehash(&hash),
+            Ok(true)
+        );
+        assert_eq!(
+            Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha512, 2000)
+                .unwrap()
+                .needs_rehash(&hash),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_errors() {
+        let hasher = Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 1000).unwrap();
+
+        assert_eq!(
+            Pbkdf2Hasher::new(Pbkdf2Algorithm::Sha256, 999),
+            Err(VCryptoError::InvalidCost {
+                cost: 999,
+                min: 1000,
+                max: u32::MAX as usize
+            })
+        );
+        assert_eq!(
+            hasher.hash_password(&[0x61; 4097], b"saltsaltsaltsalt"),
+            Err(VCryptoError::InvalidPasswordLength {
+                password_length: 4097,
+                min: 0,
+                max: 4096
+            })
+        );
+        assert_eq!(
+            hasher.hash_password(b"password", b"salt"),
+            Err(VCryptoError::InvalidInput)
+        );
+        assert_eq!(
+            hasher.verify_password(b"password", "$pbkdf2-sha256$i=0$c2FsdA$aGFzaA"),
+            Err(VCryptoError::InvalidCost {
+                cost: 0,
+                min: 1,
+                max: u32::MAX as usize
+            })
+        );
+        assert_eq!(
+            hasher.verify_password(b"password", "$pbkdf2-sha1$i=1000$c2FsdA$aGFzaA"),
+            Err(VCryptoError::UnknownAlgorithm)
+        );
+
+        for hash in [
+            "$pbkdf2-sha256$i=1000$c2FsdA",
+            "$pbkdf2-sha256$i=1000,l=5$c2FsdA$aGFzaA",
+            "$pbkdf2-sha256$i=1000,m=5$c2FsdA$aGFzaA",
+            "$pbkdf2-sha256$v=1$i=1000$c2FsdA$aGFzaA",
+            "$pbkdf2-sha256$1000$c2FsdA",
+            "$pbkdf2-sha256$1000$c2Fsd+A$aGFzaA",
+        ] {
+            assert_eq!(
+                hasher.verify_password(b"password", hash),
+                Err(VCryptoError::InvalidInput),
+                "{}",
+                hash
+            );
+        }
+    }
+}
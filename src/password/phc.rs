@@ -0,0 +1,282 @@
+use crate::base64;
+use crate::error::VCryptoError;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::{String, ToString};
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+const MAX_NAME_LEN: usize = 32;
+
+/// A password hash in the PHC string format,
+/// `$<id>[$v=<version>][$<param>=<value>(,<param>=<value>)*][$<salt>[$<hash>]]`.
+///
+/// The salt and hash are encoded with the standard base64 alphabet without padding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PhcString {
+    id: String,
+    version: Option<u32>,
+    params: Vec<(String, String)>,
+    salt: Option<Vec<u8>>,
+    hash: Option<Vec<u8>>,
+}
+
+impl PhcString {
+    /// # Panics
+    /// If `id` is not 1 to 32 characters from `[a-z0-9-]`.
+    pub fn new(id: &str) -> Self {
+        assert!(is_valid_name(id), "invalid PHC algorithm identifier");
+
+        return Self {
+            id: id.to_string(),
+            version: None,
+            params: Vec::new(),
+            salt: None,
+            hash: None,
+        };
+    }
+
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        return self;
+    }
+
+    /// # Panics
+    /// If `name` is not 1 to 32 characters from `[a-z0-9-]`, if `value` is empty or contains
+    /// characters outside of `[a-zA-Z0-9/+.-]`, or if the parameter has already been set.
+    pub fn with_param(mut self, name: &str, value: &str) -> Self {
+        assert!(is_valid_name(name), "invalid PHC parameter name");
+        assert!(is_valid_value(value), "invalid PHC parameter value");
+        assert!(self.param(name).is_none(), "duplicate PHC parameter");
+
+        self.params.push((name.to_string(), value.to_string()));
+        return self;
+    }
+
+    pub fn with_salt(mut self, salt: &[u8]) -> Self {
+        self.salt = Some(salt.to_vec());
+        return self;
+    }
+
+    /// # Panics
+    /// If no salt has been set, the hash can only follow a salt.
+    pub fn with_hash(mut self, hash: &[u8]) -> Self {
+        assert!(self.salt.is_some(), "a PHC hash requires a salt");
+
+        self.hash = Some(hash.to_vec());
+        return self;
+    }
+
+    pub fn id(&self) -> &str {
+        return &self.id;
+    }
+
+    pub fn version(&self) -> Option<u32> {
+        return self.version;
+    }
+
+    pub fn params(&self) -> &[(String, String)] {
+        return &self.params;
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        return self
+            .params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str());
+    }
+
+    pub fn salt(&self) -> Option<&[u8]> {
+        return self.salt.as_deref();
+    }
+
+    pub fn hash(&self) -> Option<&[u8]> {
+        return self.hash.as_deref();
+    }
+}
+
+impl FromStr for PhcString {
+    type Err = VCryptoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s
+            .strip_prefix('$')
+            .ok_or(VCryptoError::InvalidInput)?
+            .split('$');
+
+        let id = fields.next().ok_or(VCryptoError::InvalidInput)?;
+
+        if !is_valid_name(id) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut phc = Self::new(id);
+        let mut field = fields.next();
+
+        if let Some(version) = field.and_then(|f| f.strip_prefix("v=")) {
+            phc.version = Some(parse_decimal(version)?);
+            field = fields.next();
+        }
+
+        if let Some(params) = field.filter(|f| f.contains('=')) {
+            for param in params.split(',') {
+                let (name, value) = param.split_once('=').ok_or(VCryptoError::InvalidInput)?;
+
+                if !is_valid_name(name) || !is_valid_value(value) || phc.param(name).is_some() {
+                    return Err(VCryptoError::InvalidInput);
+                }
+
+                phc.params.push((name.to_string(), value.to_string()));
+            }
+
+            field = fields.next();
+        }
+
+        if let Some(salt) = field {
+            phc.salt = Some(decode_b64(salt)?);
+            field = fields.next();
+        }
+
+        if let Some(hash) = field {
+            phc.hash = Some(decode_b64(hash)?);
+        }
+
+        if fields.next().is_some() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(phc);
+    }
+}
+
+impl Display for PhcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "${}", self.id)?;
+
+        if let Some(version) = self.version {
+            write!(f, "$v={}", version)?;
+        }
+
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            write!(f, "{}{}={}", if i == 0 { '$' } else { ',' }, name, value)?;
+        }
+
+        if let Some(salt) = &self.salt {
+            write!(f, "${}", encode_b64(salt))?;
+        }
+
+        if let Some(hash) = &self.hash {
+            write!(f, "${}", encode_b64(hash))?;
+        }
+
+        return Ok(());
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    return !name.is_empty()
+        && name.len() <= MAX_NAME_LEN
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+}
+
+fn is_valid_value(value: &str) -> bool {
+    return !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"/+.-".contains(&b));
+}
+
+/// Parses a decimal number without a sign or leading zeros, as required by the PHC format.
+pub(crate) fn parse_decimal(s: &str) -> Result<u32, VCryptoError> {
+    if s.is_empty() || (s.len() > 1 && s.starts_with('0')) || !s.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    return s.parse().map_err(|_| VCryptoError::InvalidInput);
+}
+
+fn decode_b64(s: &str) -> Result<Vec<u8>, VCryptoError> {
+    return base64::decode_no_pad(s).map_err(|_| VCryptoError::InvalidInput);
+}
+
+fn encode_b64(bytes: &[u8]) -> String {
+    return base64::encode_no_pad(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_phc_parse() {
+        let phc: PhcString = "$argon2id$v=19$m=65536,t=2,p=1$gZiV/M1gPc22ElAH/Jh1Hw$CWOrkoo7oJBQ/iyh7uJ0LO2aLEfrHwTWllSAxT0zRno"
+            .parse()
+            .unwrap();
+
+        assert_eq!(phc.id(), "argon2id");
+        assert_eq!(phc.version(), Some(19));
+        assert_eq!(phc.param("m"), Some("65536"));
+        assert_eq!(phc.param("t"), Some("2"));
+        assert_eq!(phc.param("p"), Some("1"));
+        assert_eq!(phc.param("x"), None);
+        assert_eq!(
+            hex::encode(phc.salt().unwrap()),
+            "819895fccd603dcdb6125007fc98751f"
+        );
+        assert_eq!(phc.hash().unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_phc_round_trip() {
+        for s in [
+            "$pbkdf2-sha256",
+            "$pbkdf2-sha256$i=1000",
+            "$pbkdf2-sha256$i=1000,l=32$c2FsdA",
+            "$pbkdf2-sha256$c2FsdA$aGFzaA",
+            "$argon2id$v=19$m=65536,t=2,p=1$gZiV/M1gPc22ElAH/Jh1Hw$CWOrkoo7oJBQ/iyh7uJ0LO2aLEfrHwTWllSAxT0zRno",
+        ] {
+            assert_eq!(s.parse::<PhcString>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_phc_build() {
+        let phc = PhcString::new("pbkdf2-sha512")
+            .with_param("i", "1000")
+            .with_salt(b"salt")
+            .with_hash(b"hash");
+
+        assert_eq!(phc.to_string(), "$pbkdf2-sha512$i=1000$c2FsdA$aGFzaA");
+    }
+
+    #[test]
+    fn test_phc_invalid() {
+        for s in [
+            "",
+            "pbkdf2-sha256",
+            "$",
+            "$PBKDF2",
+            "$pbkdf2$i=1000,i=1000",
+            "$pbkdf2$i=",
+            "$pbkdf2$v=01",
+            "$pbkdf2$c2FsdA==",
+            "$pbkdf2$c2Fsd!",
+            "$pbkdf2$c2FsdA$aGFzaA$aGFzaA",
+            "$an-identifier-that-is-longer-than-32",
+        ] {
+            assert_eq!(
+                s.parse::<PhcString>(),
+                Err(VCryptoError::InvalidInput),
+                "{}",
+                s
+            );
+        }
+    }
+}
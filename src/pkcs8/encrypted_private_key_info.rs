@@ -0,0 +1,153 @@
+use super::pbes2::Pbes2Params;
+use super::private_key_info::PrivateKeyInfo;
+use crate::der::{AlgorithmIdentifier, Reader, Writer};
+use crate::error::VCryptoError;
+use crate::util::zeroize;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// A PrivateKeyInfo encrypted with a password.
+///
+/// ```text
+/// EncryptedPrivateKeyInfo ::= SEQUENCE {
+///     encryptionAlgorithm  EncryptionAlgorithmIdentifier,
+///     encryptedData        EncryptedData }
+/// ```
+///
+/// Only PBES2 with PBKDF2 is supported for decryption, the PKCS#5 v1.5 and PKCS#12 schemes and
+/// scrypt return `VCryptoError::UnknownAlgorithm`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EncryptedPrivateKeyInfo<'a> {
+    algorithm: AlgorithmIdentifier<'a>,
+    encrypted_data: &'a [u8],
+}
+
+impl<'a> EncryptedPrivateKeyInfo<'a> {
+    pub fn new(algorithm: AlgorithmIdentifier<'a>, encrypted_data: &'a [u8]) -> Self {
+        return Self {
+            algorithm,
+            encrypted_data,
+        };
+    }
+
+    /// Decodes the whole of `data` as an EncryptedPrivateKeyInfo.
+    pub fn from_der(data: &'a [u8]) -> Result<Self, VCryptoError> {
+        let mut reader = Reader::new(data);
+        let info = Self::decode(&mut reader)?;
+        reader.finish()?;
+
+        return Ok(info);
+    }
+
+    pub fn decode(reader: &mut Reader<'a>) -> Result<Self, VCryptoError> {
+        let mut sequence = reader.read_sequence()?;
+        let algorithm = AlgorithmIdentifier::decode(&mut sequence)?;
+        let encrypted_data = sequence.read_octet_string()?;
+        sequence.finish()?;
+
+        return Ok(Self {
+            algorithm,
+            encrypted_data,
+        });
+    }
+
+    pub fn encode(&self, writer: &mut Writer) {
+        writer.write_sequence(|writer| {
+            self.algorithm.encode(writer);
+            writer.write_octet_string(self.encrypted_data);
+        });
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut writer = Writer::new();
+        self.encode(&mut writer);
+
+        return writer.into_bytes();
+    }
+
+    pub fn algorithm(&self) -> AlgorithmIdentifier<'a> {
+        return self.algorithm;
+    }
+
+    pub fn encrypted_data(&self) -> &'a [u8] {
+        return self.encrypted_data;
+    }
+
+    /// Encrypts `private_key_info` with `password` and returns the encoding of the
+    /// EncryptedPrivateKeyInfo.
+    pub fn encrypt(
+        private_key_info: &PrivateKeyInfo<'_>,
+        password: &[u8],
+        params: &Pbes2Params,
+    ) -> Vec<u8> {
+        let mut plaintext = private_key_info.to_der();
+        let encrypted_data = params.encrypt(password, &plaintext);
+        zeroize(&mut plaintext);
+
+        let mut writer = Writer::new();
+        writer.write_sequence(|writer| {
+            params.encode(writer);
+            writer.write_octet_string(&encrypted_data);
+        });
+
+        return writer.into_bytes();
+    }
+
+    /// Decrypts the encoding of the PrivateKeyInfo, which `PrivateKeyInfo::from_der` can then
+    /// decode. The plaintext is checked to decode as a PrivateKeyInfo, as a wrong password can
+    /// produce valid padding; either is reported as `VCryptoError::InvalidPadding`.
+    pub fn decrypt(&self, password: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let params = Pbes2Params::from_algorithm_identifier(&self.algorithm)?;
+        let mut plaintext = params.decrypt(password, self.encrypted_data)?;
+
+        if PrivateKeyInfo::from_der(&plaintext).is_err() {
+            zeroize(&mut plaintext);
+
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        return Ok(plaintext);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::der::oid;
+    use crate::hashes::SHA256;
+    use crate::pkcs8::{Pbes2Cipher, Pbes2Prf};
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"encrypted private key info", &[], &[]);
+        let algorithm = AlgorithmIdentifier::new(oid::ED25519);
+        let private_key = [0x04, 0x20, 0x11, 0x22, 0x33];
+        let info = PrivateKeyInfo::new(algorithm, &private_key);
+
+        let params = Pbes2Params::generate(
+            Pbes2Prf::HmacWithSha256,
+            Pbes2Cipher::Aes256Cbc,
+            100,
+            &mut rng,
+        )
+        .unwrap();
+        let der = EncryptedPrivateKeyInfo::encrypt(&info, b"password", &params);
+        let encrypted = EncryptedPrivateKeyInfo::from_der(&der).unwrap();
+
+        assert_eq!(encrypted.to_der(), der);
+        assert_eq!(
+            Pbes2Params::from_algorithm_identifier(&encrypted.algorithm()),
+            Ok(params)
+        );
+
+        let decrypted = encrypted.decrypt(b"password").unwrap();
+        assert_eq!(PrivateKeyInfo::from_der(&decrypted), Ok(info));
+        assert_eq!(
+            encrypted.decrypt(b"drowssap"),
+            Err(VCryptoError::InvalidPadding)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! PKCS#8 private key containers, RFC 5208 and RFC 5958: the PrivateKeyInfo holding a key of any
+//! algorithm, and the EncryptedPrivateKeyInfo protecting one with a password through the PBES2
+//! scheme of PKCS#5 v2, as written by `openssl pkcs8 -topk8` and `openssl genpkey`.
+
+mod encrypted_private_key_info;
+mod pbes2;
+mod private_key_info;
+
+pub use encrypted_private_key_info::*;
+pub use pbes2::*;
+pub use private_key_info::*;
+
+/// The PEM label of a PrivateKeyInfo.
+pub const PRIVATE_KEY_LABEL: &str = "PRIVATE KEY";
+
+/// The PEM label of an EncryptedPrivateKeyInfo.
+pub const ENCRYPTED_PRIVATE_KEY_LABEL: &str = "ENCRYPTED PRIVATE KEY";
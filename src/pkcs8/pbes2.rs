@@ -0,0 +1,519 @@
+use crate::block_ciphers::aes::{AESKey, AES};
+use crate::block_ciphers::modes::CBC;
+use crate::block_ciphers::padding::PKCS7;
+use crate::der::{oid, AlgorithmIdentifier, ObjectIdentifier, Reader, Tag, Writer};
+use crate::error::VCryptoError;
+use crate::hashes::{SHA1, SHA256};
+use crate::kdf::pbkdf2_into;
+use crate::random::RandomSource;
+use crate::util::zeroize;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The length of the salts `Pbes2Params::generate` picks.
+pub const SALT_LENGTH: usize = 16;
+
+/// The highest iteration count that is accepted. The count is read from the encrypted key, so
+/// without a limit a crafted key could make `decrypt` run for hours; this is well above the
+/// 600,000 iterations OWASP recommends for PBKDF2-HMAC-SHA256.
+pub const MAX_ITERATIONS: u32 = 10_000_000;
+
+/// The pseudorandom function PBKDF2 is used with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Pbes2Prf {
+    HmacWithSha1,
+    HmacWithSha256,
+}
+
+/// The cipher the data is encrypted with, always with PKCS#7 padding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Pbes2Cipher {
+    Aes128Cbc,
+    Aes256Cbc,
+}
+
+impl Pbes2Prf {
+    pub fn oid(self) -> ObjectIdentifier {
+        return match self {
+            Pbes2Prf::HmacWithSha1 => oid::HMAC_WITH_SHA1,
+            Pbes2Prf::HmacWithSha256 => oid::HMAC_WITH_SHA256,
+        };
+    }
+
+    /// Returns the PRF identified by `oid`, or `VCryptoError::UnknownAlgorithm`.
+    pub fn from_oid(oid: &ObjectIdentifier) -> Result<Self, VCryptoError> {
+        return [Pbes2Prf::HmacWithSha1, Pbes2Prf::HmacWithSha256]
+            .into_iter()
+            .find(|prf| prf.oid() == *oid)
+            .ok_or(VCryptoError::UnknownAlgorithm);
+    }
+}
+
+impl Pbes2Cipher {
+    pub fn oid(self) -> ObjectIdentifier {
+        return match self {
+            Pbes2Cipher::Aes128Cbc => oid::AES_128_CBC,
+            Pbes2Cipher::Aes256Cbc => oid::AES_256_CBC,
+        };
+    }
+
+    /// Returns the cipher identified by `oid`, or `VCryptoError::UnknownAlgorithm`.
+    pub fn from_oid(oid: &ObjectIdentifier) -> Result<Self, VCryptoError> {
+        return [Pbes2Cipher::Aes128Cbc, Pbes2Cipher::Aes256Cbc]
+            .into_iter()
+            .find(|cipher| cipher.oid() == *oid)
+            .ok_or(VCryptoError::UnknownAlgorithm);
+    }
+
+    pub const fn key_len(self) -> usize {
+        return match self {
+            Pbes2Cipher::Aes128Cbc => 16,
+            Pbes2Cipher::Aes256Cbc => 32,
+        };
+    }
+}
+
+/// The PKCS#5 v2 password based encryption scheme of RFC 8018 section 6.2, with PBKDF2 as the
+/// key derivation function.
+///
+/// ```text
+/// PBES2-params ::= SEQUENCE {
+///     keyDerivationFunc  AlgorithmIdentifier {{PBES2-KDFs}},
+///     encryptionScheme   AlgorithmIdentifier {{PBES2-Encs}} }
+///
+/// PBKDF2-params ::= SEQUENCE {
+///     salt            OCTET STRING,
+///     iterationCount  INTEGER (1..MAX),
+///     keyLength       INTEGER (1..MAX) OPTIONAL,
+///     prf             AlgorithmIdentifier {{PBKDF2-PRFs}} DEFAULT algid-hmacWithSHA1 }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Pbes2Params {
+    prf: Pbes2Prf,
+    cipher: Pbes2Cipher,
+    salt: Vec<u8>,
+    iterations: u32,
+    iv: [u8; 16],
+}
+
+impl Pbes2Params {
+    /// Returns `VCryptoError::InvalidInput` if `iterations` is 0 or `salt` is empty, and
+    /// `VCryptoError::InvalidCost` if `iterations` is above `MAX_ITERATIONS`.
+    pub fn new(
+        prf: Pbes2Prf,
+        cipher: Pbes2Cipher,
+        salt: &[u8],
+        iterations: u32,
+        iv: [u8; 16],
+    ) -> Result<Self, VCryptoError> {
+        if iterations == 0 || salt.is_empty() {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        if iterations > MAX_ITERATIONS {
+            return Err(VCryptoError::InvalidCost {
+                cost: iterations as usize,
+                min: 1,
+                max: MAX_ITERATIONS as usize,
+            });
+        }
+
+        return Ok(Self {
+            prf,
+            cipher,
+            salt: salt.to_vec(),
+            iterations,
+            iv,
+        });
+    }
+
+    /// Creates parameters with a random salt and IV, for encrypting new data.
+    pub fn generate(
+        prf: Pbes2Prf,
+        cipher: Pbes2Cipher,
+        iterations: u32,
+        rng: &mut impl RandomSource,
+    ) -> Result<Self, VCryptoError> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut salt)?;
+        rng.fill_bytes(&mut iv)?;
+
+        return Self::new(prf, cipher, &salt, iterations, iv);
+    }
+
+    /// Decodes the parameters of a PBES2 AlgorithmIdentifier. Returns
+    /// `VCryptoError::UnknownAlgorithm` for another scheme or for a key derivation function,
+    /// PRF or cipher that is not supported, such as scrypt, and `VCryptoError::InvalidCost` for an
+    /// iteration count above `MAX_ITERATIONS`.
+    pub fn from_algorithm_identifier(
+        identifier: &AlgorithmIdentifier<'_>,
+    ) -> Result<Self, VCryptoError> {
+        if identifier.oid() != oid::PBES2 {
+            return Err(VCryptoError::UnknownAlgorithm);
+        }
+
+        let mut parameters = Reader::new(identifier.parameters_with_tag(Tag::SEQUENCE)?);
+        let kdf = AlgorithmIdentifier::decode(&mut parameters)?;
+        let encryption = AlgorithmIdentifier::decode(&mut parameters)?;
+        parameters.finish()?;
+
+        if kdf.oid() != oid::PBKDF2 {
+            return Err(VCryptoError::UnknownAlgorithm);
+        }
+
+        let mut kdf_parameters = Reader::new(kdf.parameters_with_tag(Tag::SEQUENCE)?);
+        let salt = kdf_parameters.read_octet_string()?;
+        let iterations = kdf_parameters.read_u64()?;
+
+        if iterations > MAX_ITERATIONS as u64 {
+            return Err(VCryptoError::InvalidCost {
+                cost: iterations as usize,
+                min: 1,
+                max: MAX_ITERATIONS as usize,
+            });
+        }
+
+        let key_len = if kdf_parameters.peek_tag() == Some(Tag::INTEGER) {
+            Some(kdf_parameters.read_u64()?)
+        } else {
+            None
+        };
+
+        let prf = if kdf_parameters.is_empty() {
+            Pbes2Prf::HmacWithSha1
+        } else {
+            let prf = AlgorithmIdentifier::decode(&mut kdf_parameters)?;
+            prf.expect_no_parameters()?;
+
+            Pbes2Prf::from_oid(&prf.oid())?
+        };
+
+        kdf_parameters.finish()?;
+
+        let cipher = Pbes2Cipher::from_oid(&encryption.oid())?;
+
+        if key_len.is_some_and(|len| len != cipher.key_len() as u64) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let iv = encryption
+            .parameters_with_tag(Tag::OCTET_STRING)?
+            .try_into()
+            .map_err(|_| VCryptoError::InvalidInput)?;
+
+        return Self::new(prf, cipher, salt, iterations as u32, iv);
+    }
+
+    /// Writes the PBES2 AlgorithmIdentifier. The key length is left out as it is implied by the
+    /// cipher, and so is the PRF when it is the default HMAC-SHA1, as DER requires.
+    pub fn encode(&self, writer: &mut Writer) {
+        writer.write_sequence(|writer| {
+            writer.write_oid(&oid::PBES2);
+            writer.write_sequence(|writer| {
+                writer.write_sequence(|writer| {
+                    writer.write_oid(&oid::PBKDF2);
+                    writer.write_sequence(|writer| {
+                        writer.write_octet_string(&self.salt);
+                        writer.write_u64(self.iterations as u64);
+
+                        if self.prf != Pbes2Prf::HmacWithSha1 {
+                            writer.write_sequence(|writer| {
+                                writer.write_oid(&self.prf.oid());
+                                writer.write_null();
+                            });
+                        }
+                    });
+                });
+
+                writer.write_sequence(|writer| {
+                    writer.write_oid(&self.cipher.oid());
+                    writer.write_octet_string(&self.iv);
+                });
+            });
+        });
+    }
+
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut writer = Writer::new();
+        self.encode(&mut writer);
+
+        return writer.into_bytes();
+    }
+
+    pub fn prf(&self) -> Pbes2Prf {
+        return self.prf;
+    }
+
+    pub fn cipher(&self) -> Pbes2Cipher {
+        return self.cipher;
+    }
+
+    pub fn salt(&self) -> &[u8] {
+        return &self.salt;
+    }
+
+    pub fn iterations(&self) -> u32 {
+        return self.iterations;
+    }
+
+    pub fn iv(&self) -> [u8; 16] {
+        return self.iv;
+    }
+
+    /// Encrypts `data` with the key derived from `password`, padding it with PKCS#7.
+    pub fn encrypt(&self, password: &[u8], data: &[u8]) -> Vec<u8> {
+        let (key, key_len) = self.derive_key(password);
+        let cipher =
+            AESKey::from_bytes(&key.0[..key_len]).expect("the key length matches the cipher");
+
+        return CBC::<AES>::new(cipher, self.iv)
+            .encrypt::<PKCS7>(data)
+            .expect("PKCS#7 supports 16 byte blocks");
+    }
+
+    /// Decrypts `data`. A wrong password almost always returns `VCryptoError::InvalidPadding`,
+    /// but there is no integrity check, so the plaintext has to be validated by parsing it.
+    pub fn decrypt(&self, password: &[u8], data: &[u8]) -> Result<Vec<u8>, VCryptoError> {
+        let (key, key_len) = self.derive_key(password);
+        let cipher =
+            AESKey::from_bytes(&key.0[..key_len]).expect("the key length matches the cipher");
+
+        return CBC::<AES>::new(cipher, self.iv).decrypt::<PKCS7>(data);
+    }
+
+    fn derive_key(&self, password: &[u8]) -> (ZeroizingKey, usize) {
+        let mut key = ZeroizingKey([0u8; 32]);
+        let key_len = self.cipher.key_len();
+        let output = &mut key.0[..key_len];
+        let iterations = self.iterations as usize;
+
+        match self.prf {
+            Pbes2Prf::HmacWithSha1 => pbkdf2_into::<SHA1>(password, &self.salt, iterations, output),
+            Pbes2Prf::HmacWithSha256 => {
+                pbkdf2_into::<SHA256>(password, &self.salt, iterations, output)
+            }
+        }
+        .expect("the key is at most two blocks");
+
+        return (key, key_len);
+    }
+}
+
+/// The derived key, wiped when dropped.
+struct ZeroizingKey([u8; 32]);
+
+impl Drop for ZeroizingKey {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    // The encryptionAlgorithm of an `openssl pkcs8 -topk8 -v2 aes-256-cbc -v2prf hmacWithSHA256
+    // -iter 2048` key
+    const OPENSSL_SHA256_AES256: &str = "305f06092a864886f70d01050d3052303106092a864886f70d01050c\
+                                         3024041022f61a0cf943485080f964969cac073602020800300c06082a\
+                                         864886f70d02090500301d060960864801650304012a0410dfe2e08696\
+                                         d25a20fb76098ad2791854";
+
+    #[test]
+    fn test_parameters() {
+        let der = hex::decode(OPENSSL_SHA256_AES256).unwrap();
+        let params =
+            Pbes2Params::from_algorithm_identifier(&AlgorithmIdentifier::from_der(&der).unwrap())
+                .unwrap();
+
+        assert_eq!(params.prf(), Pbes2Prf::HmacWithSha256);
+        assert_eq!(params.cipher(), Pbes2Cipher::Aes256Cbc);
+        assert_eq!(
+            hex::encode(params.salt()),
+            "22f61a0cf943485080f964969cac0736"
+        );
+        assert_eq!(params.iterations(), 2048);
+        assert_eq!(hex::encode(params.iv()), "dfe2e08696d25a20fb76098ad2791854");
+        assert_eq!(params.to_der(), der);
+
+        // The default PRF is left out
+        let params = Pbes2Params::new(
+            Pbes2Prf::HmacWithSha1,
+            Pbes2Cipher::Aes128Cbc,
+            &[0xaa; 8],
+            1,
+            [0xbb; 16],
+        )
+        .unwrap();
+        let der = params.to_der();
+        assert_eq!(
+            hex::encode(&der),
+            "304806092a864886f70d01050d303b301a06092a864886f70d01050c300d0408aaaaaaaaaaaaaaaa02\
+             0101301d06096086480165030401020410bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+        );
+        assert_eq!(
+            Pbes2Params::from_algorithm_identifier(&AlgorithmIdentifier::from_der(&der).unwrap()),
+            Ok(params)
+        );
+    }
+
+    #[test]
+    fn test_key_length() {
+        // An explicit key length has to match the cipher
+        for (key_len, expected) in [(32, Ok(())), (16, Err(VCryptoError::InvalidInput))] {
+            let mut writer = Writer::new();
+            writer.write_sequence(|writer| {
+                writer.write_oid(&oid::PBES2);
+                writer.write_sequence(|writer| {
+                    writer.write_sequence(|writer| {
+                        writer.write_oid(&oid::PBKDF2);
+                        writer.write_sequence(|writer| {
+                            writer.write_octet_string(&[0xaa; 8]);
+                            writer.write_u64(2048);
+                            writer.write_u64(key_len);
+                        });
+                    });
+                    writer.write_sequence(|writer| {
+                        writer.write_oid(&oid::AES_256_CBC);
+                        writer.write_octet_string(&[0xbb; 16]);
+                    });
+                });
+            });
+            let der = writer.into_bytes();
+
+            assert_eq!(
+                Pbes2Params::from_algorithm_identifier(
+                    &AlgorithmIdentifier::from_der(&der).unwrap()
+                )
+                .map(|params| params.cipher()),
+                expected.map(|_| Pbes2Cipher::Aes256Cbc)
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_iterations() {
+        for (iterations, expected) in [
+            (MAX_ITERATIONS as u64, Ok(MAX_ITERATIONS)),
+            (
+                MAX_ITERATIONS as u64 + 1,
+                Err(VCryptoError::InvalidCost {
+                    cost: MAX_ITERATIONS as usize + 1,
+                    min: 1,
+                    max: MAX_ITERATIONS as usize,
+                }),
+            ),
+            (
+                u64::MAX,
+                Err(VCryptoError::InvalidCost {
+                    cost: u64::MAX as usize,
+                    min: 1,
+                    max: MAX_ITERATIONS as usize,
+                }),
+            ),
+        ] {
+            let mut writer = Writer::new();
+            writer.write_sequence(|writer| {
+                writer.write_oid(&oid::PBES2);
+                writer.write_sequence(|writer| {
+                    writer.write_sequence(|writer| {
+                        writer.write_oid(&oid::PBKDF2);
+                        writer.write_sequence(|writer| {
+                            writer.write_octet_string(&[0xaa; 8]);
+                            writer.write_u64(iterations);
+                        });
+                    });
+                    writer.write_sequence(|writer| {
+                        writer.write_oid(&oid::AES_128_CBC);
+                        writer.write_octet_string(&[0xbb; 16]);
+                    });
+                });
+            });
+            let der = writer.into_bytes();
+
+            assert_eq!(
+                Pbes2Params::from_algorithm_identifier(
+                    &AlgorithmIdentifier::from_der(&der).unwrap()
+                )
+                .map(|params| params.iterations()),
+                expected
+            );
+        }
+
+        assert!(Pbes2Params::new(
+            Pbes2Prf::HmacWithSha256,
+            Pbes2Cipher::Aes128Cbc,
+            &[1],
+            MAX_ITERATIONS + 1,
+            [0; 16]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let der = hex::decode(OPENSSL_SHA256_AES256).unwrap();
+
+        // AES-192-CBC instead of AES-256-CBC
+        let mut aes192 = der.clone();
+        let position = aes192.len() - 19;
+        assert_eq!(aes192[position], 0x2a);
+        aes192[position] = 0x16;
+
+        // hmacWithSHA384 instead of hmacWithSHA256
+        let mut sha384 = der.clone();
+        let position = sha384.len() - 34;
+        assert_eq!(sha384[position], 0x09);
+        sha384[position] = 0x0a;
+
+        for der in [aes192, sha384] {
+            assert_eq!(
+                Pbes2Params::from_algorithm_identifier(
+                    &AlgorithmIdentifier::from_der(&der).unwrap()
+                ),
+                Err(VCryptoError::UnknownAlgorithm)
+            );
+        }
+
+        assert_eq!(
+            Pbes2Params::from_algorithm_identifier(&AlgorithmIdentifier::new(oid::PBKDF2)),
+            Err(VCryptoError::UnknownAlgorithm)
+        );
+        assert_eq!(
+            Pbes2Params::new(
+                Pbes2Prf::HmacWithSha1,
+                Pbes2Cipher::Aes128Cbc,
+                &[1],
+                0,
+                [0; 16]
+            ),
+            Err(VCryptoError::InvalidInput)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"pbes2 round trip", &[], &[]);
+
+        for prf in [Pbes2Prf::HmacWithSha1, Pbes2Prf::HmacWithSha256] {
+            for cipher in [Pbes2Cipher::Aes128Cbc, Pbes2Cipher::Aes256Cbc] {
+                let params = Pbes2Params::generate(prf, cipher, 10, &mut rng).unwrap();
+                let encrypted = params.encrypt(b"password", b"some private key");
+
+                assert_eq!(encrypted.len(), 32);
+                assert_eq!(
+                    params.decrypt(b"password", &encrypted).unwrap(),
+                    b"some private key"
+                );
+                assert_ne!(
+                    params.decrypt(b"passw0rd", &encrypted).ok(),
+                    Some(b"some private key".to_vec())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::RandomSource;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::hmac::Hmac;
+
+/// The maximum number of bytes a single call to `generate` may return, 2^19 bits.
+pub const HMAC_DRBG_MAX_REQUEST_BYTES: usize = 1 << 16;
+/// The number of requests after which the generator has to be reseeded.
+pub const HMAC_DRBG_RESEED_INTERVAL: u64 = 1 << 48;
+
+/// The HMAC_DRBG deterministic random bit generator from section 10.1.2 of NIST SP 800-90A.
+///
+/// The generator is only as unpredictable as the entropy it is seeded with, which should contain
+/// at least as many bits of entropy as the security strength required.
+#[derive(Clone)]
+pub struct HmacDrbg<H: HashingAlgorithm> {
+    k: H::Output,
+    v: H::Output,
+    reseed_counter: u64,
+}
+
+impl<H: HashingAlgorithm> HmacDrbg<H> {
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        let mut k = H::hash(&[]);
+        let mut v = k;
+
+        k.as_mut().fill(0x00);
+        v.as_mut().fill(0x01);
+
+        let mut drbg = Self {
+            k,
+            v,
+            reseed_counter: 1,
+        };
+        drbg.update(&[entropy, nonce, personalization]);
+
+        return drbg;
+    }
+
+    pub fn reseed(&mut self, entropy: &[u8], additional_input: &[u8]) {
+        self.update(&[entropy, additional_input]);
+        self.reseed_counter = 1;
+    }
+
+    /// Fills `output` with random bytes. Returns `VCryptoError::InvalidInput` if more than
+    /// `HMAC_DRBG_MAX_REQUEST_BYTES` bytes are requested, and
+    /// `VCryptoError::RandomSourceFailure` once the generator has to be reseeded.
+    pub fn generate(
+        &mut self,
+        output: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), VCryptoError> {
+        if output.len() > HMAC_DRBG_MAX_REQUEST_BYTES {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        if self.reseed_counter > HMAC_DRBG_RESEED_INTERVAL {
+            return Err(VCryptoError::RandomSourceFailure);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+
+        for block in output.chunks_mut(H::OUTPUT_SIZE) {
+            self.v = self.mac(&[self.v.as_ref()]);
+            block.copy_from_slice(&self.v.as_ref()[..block.len()]);
+        }
+
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+
+        return Ok(());
+    }
+
+    /// The HMAC_DRBG_Update function, `provided_data` is the concatenation of the slices.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let provided = provided_data.iter().any(|data| !data.is_empty());
+
+        for separator in [0x00u8, 0x01] {
+            if separator == 0x01 && !provided {
+                break;
+            }
+
+            let mut mac = Hmac::<H>::new(self.k.as_ref());
+            mac.update(self.v.as_ref());
+            mac.update(&[separator]);
+
+            for data in provided_data {
+                mac.update(data);
+            }
+
+            self.k = mac.finalize();
+            self.v = self.mac(&[self.v.as_ref()]);
+        }
+    }
+
+    fn mac(&self, data: &[&[u8]]) -> H::Output {
+        let mut mac = Hmac::<H>::new(self.k.as_ref());
+
+        for d in data {
+            mac.update(d);
+        }
+
+        return mac.finalize();
+    }
+}
+
+impl<H: HashingAlgorithm> RandomSource for HmacDrbg<H> {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), VCryptoError> {
+        for chunk in dest.chunks_mut(HMAC_DRBG_MAX_REQUEST_BYTES) {
+            self.generate(chunk, &[])?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{SHA1, SHA256};
+    use pretty_assertions::assert_eq;
+
+    fn decode(s: &str) -> Vec<u8> {
+        return hex::decode(s).unwrap();
+    }
+
+    #[test]
+    fn test_hmac_drbg_sha256() {
+        // CAVP HMAC_DRBG.rsp, SHA-256 without prediction resistance, COUNT = 0
+        let mut drbg = HmacDrbg::<SHA256>::new(
+            &decode("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488"),
+            &decode("659ba96c601dc69fc902940805ec0ca8"),
+            &[],
+        );
+        let mut output = [0u8; 128];
+
+        drbg.generate(&mut output, &[]).unwrap();
+        drbg.generate(&mut output, &[]).unwrap();
+
+        assert_eq!(
+            hex::encode(output),
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc107694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8"
+        );
+    }
+
+    #[test]
+    fn test_hmac_drbg_additional_input() {
+        let mut drbg = HmacDrbg::<SHA1>::new(b"entropy input", b"nonce", b"personalization");
+        let mut output = [0u8; 30];
+
+        drbg.generate(&mut output, b"first").unwrap();
+        drbg.reseed(b"more entropy", b"reseed");
+        drbg.generate(&mut output, b"second").unwrap();
+
+        assert_eq!(
+            hex::encode(output),
+            "a5d7a0b0c37a5176f5b17b3154a12decf3111064945e7d5bef300e518061"
+        );
+    }
+
+    #[test]
+    fn test_hmac_drbg_limits() {
+        let mut drbg = HmacDrbg::<SHA256>::new(&[0u8; 32], &[0u8; 16], &[]);
+        let mut large = vec![0u8; HMAC_DRBG_MAX_REQUEST_BYTES + 1];
+
+        assert_eq!(
+            drbg.generate(&mut large, &[]),
+            Err(VCryptoError::InvalidInput)
+        );
+
+        // fill_bytes splits large requests
+        drbg.fill_bytes(&mut large).unwrap();
+
+        drbg.reseed_counter = HMAC_DRBG_RESEED_INTERVAL + 1;
+        assert_eq!(
+            drbg.generate(&mut [0u8; 16], &[]),
+            Err(VCryptoError::RandomSourceFailure)
+        );
+
+        drbg.reseed(&[1u8; 32], &[]);
+        assert!(drbg.generate(&mut [0u8; 16], &[]).is_ok());
+    }
+}
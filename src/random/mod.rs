@@ -0,0 +1,22 @@
+mod hmac_drbg;
+#[cfg(all(feature = "std", unix))]
+mod os;
+
+pub use hmac_drbg::*;
+#[cfg(all(feature = "std", unix))]
+pub use os::*;
+
+use crate::error::VCryptoError;
+
+/// A source of cryptographically secure random bytes.
+pub trait RandomSource {
+    /// Fills `dest` entirely with random bytes, or returns `VCryptoError::RandomSourceFailure`
+    /// if the source cannot provide them.
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), VCryptoError>;
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), VCryptoError> {
+        return (**self).fill_bytes(dest);
+    }
+}
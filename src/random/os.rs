@@ -0,0 +1,43 @@
+use super::RandomSource;
+use crate::error::VCryptoError;
+use std::fs::File;
+use std::io::Read;
+
+/// Random bytes from the operating system, read from `/dev/urandom`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsRandom;
+
+impl OsRandom {
+    pub fn new() -> Self {
+        return Self;
+    }
+}
+
+impl RandomSource for OsRandom {
+    fn fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), VCryptoError> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+
+        return File::open("/dev/urandom")
+            .and_then(|mut file| file.read_exact(dest))
+            .map_err(|_| VCryptoError::RandomSourceFailure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_os_random() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+
+        OsRandom::new().fill_bytes(&mut a).unwrap();
+        OsRandom::new().fill_bytes(&mut b).unwrap();
+
+        assert_ne!(a, [0u8; 32]);
+        assert_ne!(a, b);
+    }
+}
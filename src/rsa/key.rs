@@ -0,0 +1,409 @@
+use super::prime::generate_prime;
+use crate::bignum::{BigUint, Montgomery};
+use crate::error::VCryptoError;
+use crate::random::RandomSource;
+use core::fmt;
+
+/// The public exponent used by `RsaPrivateKey::generate`.
+pub const RSA_DEFAULT_EXPONENT: u64 = 65537;
+/// The smallest modulus, in bits, that `RsaPrivateKey::generate` creates.
+pub const RSA_MIN_GENERATED_BITS: usize = 1024;
+/// The largest modulus, in bits, that keys are created or loaded with.
+pub const RSA_MAX_BITS: usize = 16384;
+
+/// An RSA public key, the modulus `n` and the public exponent `e`.
+#[derive(Clone)]
+pub struct RsaPublicKey {
+    n: BigUint,
+    e: BigUint,
+    montgomery: Montgomery,
+}
+
+/// An RSA private key with the CRT parameters of RFC 8017 section 3.2, using two primes.
+#[derive(Clone)]
+pub struct RsaPrivateKey {
+    public_key: RsaPublicKey,
+    d: BigUint,
+    p: BigUint,
+    q: BigUint,
+    dp: BigUint,
+    dq: BigUint,
+    qinv: BigUint,
+    p_montgomery: Montgomery,
+    q_montgomery: Montgomery,
+}
+
+impl RsaPublicKey {
+    /// Returns `VCryptoError::InvalidKey` unless `n` is odd and `e` is odd and between 3 and
+    /// `n - 1`.
+    pub fn new(n: BigUint, e: BigUint) -> Result<Self, VCryptoError> {
+        if n.bits() > RSA_MAX_BITS {
+            return Err(VCryptoError::InvalidKeyLengthLarger {
+                key_length: n.bits(),
+                max: RSA_MAX_BITS,
+            });
+        }
+
+        if n.is_even() || e.is_even() || e < BigUint::from(3u64) || e >= n {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        let montgomery = Montgomery::new(&n)?;
+
+        return Ok(Self { n, e, montgomery });
+    }
+
+    pub fn n(&self) -> &BigUint {
+        return &self.n;
+    }
+
+    pub fn e(&self) -> &BigUint {
+        return &self.e;
+    }
+
+    /// Returns the size of the modulus in bits.
+    pub fn bits(&self) -> usize {
+        return self.n.bits();
+    }
+
+    /// Returns the size of the modulus in bytes, which is the length of ciphertexts and
+    /// signatures.
+    pub fn size(&self) -> usize {
+        return self.n.bits().div_ceil(8);
+    }
+
+    /// The RSAEP and RSAVP1 primitives, `m^e mod n`.
+    pub(crate) fn public_operation(&self, m: &BigUint) -> Result<BigUint, VCryptoError> {
+        if m >= &self.n {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        return Ok(self.montgomery.pow_vartime(m, &self.e));
+    }
+}
+
+impl RsaPrivateKey {
+    /// Generates a key with a modulus of exactly `bits` bits and the public exponent 65537.
+    pub fn generate(rng: &mut impl RandomSource, bits: usize) -> Result<Self, VCryptoError> {
+        return Self::generate_with_exponent(rng, bits, RSA_DEFAULT_EXPONENT);
+    }
+
+    /// Generates a key with a modulus of exactly `bits` bits and the public exponent `e`, which
+    /// has to be odd and at least 3.
+    ///
+    /// As in FIPS 186-5 appendix A.1.3, the primes differ in their 100 most significant bits and
+    /// the private exponent is larger than `2^(bits / 2)`.
+    pub fn generate_with_exponent(
+        rng: &mut impl RandomSource,
+        bits: usize,
+        e: u64,
+    ) -> Result<Self, VCryptoError> {
+        if bits < RSA_MIN_GENERATED_BITS {
+            return Err(VCryptoError::InvalidKeyLengthSmaller {
+                key_length: bits,
+                min: RSA_MIN_GENERATED_BITS,
+            });
+        }
+
+        if bits > RSA_MAX_BITS {
+            return Err(VCryptoError::InvalidKeyLengthLarger {
+                key_length: bits,
+                max: RSA_MAX_BITS,
+            });
+        }
+
+        if e < 3 || e.is_multiple_of(2) {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let e = BigUint::from(e);
+        let min_distance = BigUint::one() << (bits / 2 - 100);
+        let min_d = BigUint::one() << (bits / 2);
+
+        loop {
+            let p = generate_prime(bits.div_ceil(2), &e, rng)?;
+            let q = generate_prime(bits / 2, &e, rng)?;
+
+            let distance = if p > q { &p - &q } else { &q - &p };
+
+            if distance <= min_distance {
+                continue;
+            }
+
+            let p_minus_one = &p - &BigUint::one();
+            let q_minus_one = &q - &BigUint::one();
+            let lcm = &(&p_minus_one * &q_minus_one) / &p_minus_one.gcd(&q_minus_one);
+            let d = e.modinv(&lcm).expect("e is coprime to p - 1 and q - 1");
+
+            if d <= min_d {
+                continue;
+            }
+
+            return Self::from_components(&p * &q, e, d, p, q);
+        }
+    }
+
+    /// Creates a key from the modulus, the public and private exponents and the two primes, and
+    /// computes the CRT parameters. Returns `VCryptoError::InvalidKey` if the components are not
+    /// consistent.
+    pub fn from_components(
+        n: BigUint,
+        e: BigUint,
+        d: BigUint,
+        p: BigUint,
+        q: BigUint,
+    ) -> Result<Self, VCryptoError> {
+        let public_key = RsaPublicKey::new(n, e)?;
+
+        if p.bits() < 2 || q.bits() < 2 || &p * &q != public_key.n || d >= public_key.n {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        let p_minus_one = &p - &BigUint::one();
+        let q_minus_one = &q - &BigUint::one();
+
+        // d has to be an inverse of e modulo both p - 1 and q - 1
+        let ed = &d * &public_key.e;
+
+        if !(&ed % &p_minus_one).is_one() || !(&ed % &q_minus_one).is_one() {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        let qinv = q.modinv(&p).ok_or(VCryptoError::InvalidKey)?;
+
+        return Ok(Self {
+            dp: &d % &p_minus_one,
+            dq: &d % &q_minus_one,
+            qinv,
+            p_montgomery: Montgomery::new(&p)?,
+            q_montgomery: Montgomery::new(&q)?,
+            public_key,
+            d,
+            p,
+            q,
+        });
+    }
+
+    pub fn public_key(&self) -> &RsaPublicKey {
+        return &self.public_key;
+    }
+
+    pub fn d(&self) -> &BigUint {
+        return &self.d;
+    }
+
+    pub fn p(&self) -> &BigUint {
+        return &self.p;
+    }
+
+    pub fn q(&self) -> &BigUint {
+        return &self.q;
+    }
+
+    /// Returns `d mod (p - 1)`.
+    pub fn dp(&self) -> &BigUint {
+        return &self.dp;
+    }
+
+    /// Returns `d mod (q - 1)`.
+    pub fn dq(&self) -> &BigUint {
+        return &self.dq;
+    }
+
+    /// Returns `q^-1 mod p`.
+    pub fn qinv(&self) -> &BigUint {
+        return &self.qinv;
+    }
+
+    /// The RSADP and RSASP1 primitives, `c^d mod n`, computed with the CRT.
+    ///
+    /// The input is blinded with a random `r^e` so the timing of the exponentiation, and of the
+    /// reductions around it, is unrelated to `c`. The result is checked with the public exponent
+    /// so a fault in either half of the CRT cannot leak a factor of `n`.
+    pub(crate) fn private_operation(
+        &self,
+        rng: &mut impl RandomSource,
+        c: &BigUint,
+    ) -> Result<BigUint, VCryptoError> {
+        let n = &self.public_key.n;
+
+        if c >= n {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let (r, r_inv) = loop {
+            let r = BigUint::random_below(n, rng)?;
+
+            if let Some(r_inv) = r.modinv(n) {
+                break (r, r_inv);
+            }
+        };
+
+        let montgomery = &self.public_key.montgomery;
+        let blinded = montgomery.mul(c, &montgomery.pow_vartime(&r, &self.public_key.e));
+
+        let m1 = self.p_montgomery.pow(&blinded, &self.dp);
+        let m2 = self.q_montgomery.pow(&blinded, &self.dq);
+
+        // h = qinv * (m1 - m2) mod p, m = m2 + h * q
+        let difference = &(&m1 + &self.p) - &(&m2 % &self.p);
+        let h = self.p_montgomery.mul(&self.qinv, &difference);
+        let m = &m2 + &(&h * &self.q);
+
+        if montgomery.pow_vartime(&m, &self.public_key.e) != blinded {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(montgomery.mul(&m, &r_inv));
+    }
+}
+
+impl fmt::Debug for RsaPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f
+            .debug_struct("RsaPublicKey")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish();
+    }
+}
+
+// The private components are left out so they do not end up in logs
+impl fmt::Debug for RsaPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return f
+            .debug_struct("RsaPrivateKey")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive();
+    }
+}
+
+impl PartialEq for RsaPublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        return self.n == other.n && self.e == other.e;
+    }
+}
+
+impl Eq for RsaPublicKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use crate::rsa::test_key;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_generate() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"rsa key generation", &[], &[]);
+        let key = RsaPrivateKey::generate(&mut rng, 1024).unwrap();
+        let public_key = key.public_key();
+
+        assert_eq!(public_key.bits(), 1024);
+        assert_eq!(public_key.size(), 128);
+        assert_eq!(public_key.e(), &BigUint::from(65537u64));
+        assert_eq!(&(key.p() * key.q()), public_key.n());
+
+        let m = BigUint::from_be_bytes(b"RSA private operation");
+        let c = public_key.public_operation(&m).unwrap();
+
+        assert_eq!(key.private_operation(&mut rng, &c).unwrap(), m);
+    }
+
+    #[test]
+    fn test_generate_odd_size() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"rsa odd size", &[], &[]);
+        let key = RsaPrivateKey::generate_with_exponent(&mut rng, 1025, 3).unwrap();
+
+        assert_eq!(key.public_key().bits(), 1025);
+        assert_eq!(key.public_key().e(), &BigUint::from(3u64));
+    }
+
+    #[test]
+    fn test_generate_invalid_parameters() {
+        let mut rng = HmacDrbg::<SHA256>::new(&[0u8; 32], &[], &[]);
+
+        assert_eq!(
+            RsaPrivateKey::generate(&mut rng, 512).unwrap_err(),
+            VCryptoError::InvalidKeyLengthSmaller {
+                key_length: 512,
+                min: 1024
+            }
+        );
+        assert_eq!(
+            RsaPrivateKey::generate_with_exponent(&mut rng, 2048, 65536).unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_crt_parameters() {
+        let key = test_key();
+        let one = BigUint::one();
+
+        assert_eq!(key.dp(), &(key.d() % &(key.p() - &one)));
+        assert_eq!(key.dq(), &(key.d() % &(key.q() - &one)));
+        assert_eq!(&(key.qinv() * key.q()) % key.p(), one);
+    }
+
+    #[test]
+    fn test_from_components_invalid() {
+        let key = test_key();
+        let n = key.public_key().n().clone();
+        let e = key.public_key().e().clone();
+
+        // d + 1 is not an inverse of e
+        assert_eq!(
+            RsaPrivateKey::from_components(
+                n.clone(),
+                e.clone(),
+                key.d() + &BigUint::one(),
+                key.p().clone(),
+                key.q().clone()
+            )
+            .unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            RsaPrivateKey::from_components(
+                n.clone(),
+                e.clone(),
+                key.d().clone(),
+                key.p().clone(),
+                key.p().clone()
+            )
+            .unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            RsaPublicKey::new(n.clone(), BigUint::from(65536u64)).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+        assert_eq!(
+            RsaPublicKey::new(&n + &BigUint::one(), e).unwrap_err(),
+            VCryptoError::InvalidKey
+        );
+    }
+
+    #[test]
+    fn test_private_operation_out_of_range() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(&[0u8; 32], &[], &[]);
+
+        assert_eq!(
+            key.private_operation(&mut rng, key.public_key().n())
+                .unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_debug_hides_private_components() {
+        let key = test_key();
+        let debug = format!("{:?}", key);
+
+        assert!(debug.starts_with("RsaPrivateKey { public_key: RsaPublicKey { n: "));
+        assert!(!debug.contains(&format!("{:x}", key.d())));
+    }
+}
@@ -0,0 +1,52 @@
+use crate::hashes::HashingAlgorithm;
+
+/// Fills `mask` with the output of the MGF1 mask generation function from RFC 8017 appendix
+/// B.2.1.
+pub fn mgf1<H: HashingAlgorithm>(seed: &[u8], mask: &mut [u8]) {
+    mask.fill(0);
+    mgf1_xor::<H>(seed, mask);
+}
+
+/// XORs the MGF1 output for `seed` into `data`, which is how every use of MGF1 in RFC 8017
+/// applies the mask.
+pub(crate) fn mgf1_xor<H: HashingAlgorithm>(seed: &[u8], data: &mut [u8]) {
+    for (counter, block) in data.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+        let mut hasher = H::hasher();
+        hasher.update(seed);
+        hasher.update(&(counter as u32).to_be_bytes());
+
+        for (b, m) in block.iter_mut().zip(hasher.finalize().as_ref()) {
+            *b ^= m;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{SHA1, SHA256};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mgf1() {
+        let mut mask = [0u8; 50];
+
+        mgf1::<SHA1>(b"foo", &mut mask[..3]);
+        assert_eq!(hex::encode(&mask[..3]), "1ac907");
+
+        mgf1::<SHA1>(b"foo", &mut mask[..5]);
+        assert_eq!(hex::encode(&mask[..5]), "1ac9075cd4");
+
+        mgf1::<SHA1>(b"bar", &mut mask);
+        assert_eq!(
+            hex::encode(mask),
+            "bc0c655e016bc2931d85a2e675181adcef7f581f76df2739da74faac41627be2f7f415c89e983fd0ce80ced9878641cb4876"
+        );
+
+        mgf1::<SHA256>(b"bar", &mut mask);
+        assert_eq!(
+            hex::encode(mask),
+            "382576a7841021cc28fc4c0948753fb8312090cea942ea4c4e735d10dc724b155f9f6069f289d61daca0cb814502ef04eae1"
+        );
+    }
+}
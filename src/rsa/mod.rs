@@ -0,0 +1,28 @@
+mod key;
+mod mgf1;
+mod oaep;
+mod pkcs1v15;
+mod prime;
+mod pss;
+
+pub use key::*;
+pub use mgf1::mgf1;
+pub use pkcs1v15::Pkcs1v15Digest;
+pub use prime::is_probable_prime;
+
+/// A 1024 bit key generated with OpenSSL, large enough for most tests while keeping them fast.
+#[cfg(test)]
+pub(crate) fn test_key() -> RsaPrivateKey {
+    use crate::bignum::BigUint;
+
+    let component = |hex_string: &str| BigUint::from_be_bytes(&hex::decode(hex_string).unwrap());
+
+    return RsaPrivateKey::from_components(
+        component("aec1a3de18e88255245122bbffb75276d3a68de47afbdd3185ede4b0ea47a6756fcff6c037ce2da809666aefa5f58f2452f9676187e10880119403cafa9b745f07172592377ef7cf92c501620379c813fc1087ca80d99f756f77ece408e3fb9aac1c6c6b8dca14b1869251ab8fa0c16ef1de6b4a1aa2a9b0a3f6872c9a719485"),
+        BigUint::from(65537u64),
+        component("6d33d1eb8293208685d831a55b093a7dc88a86966507a8543f05c6678b2ed4e503c6e7c17bddda07ec3fd4c90662e95ce080bb4a65f78831474ac008020c4c6de0ba56d31a06b6537a4f2d67ac817ef43ce0704013cab3a9e434a43b7db22916a62ffce7be9715d126626ed11f082202bdfbe582bac746688a7406578360b4a1"),
+        component("de5a651fd4353713b1d849f97010b7adcde00735ce7927df3311c34ffd905ec8e09ee96e9a47ac0cc08e595d07b816ca406c7b444f66a023cd9d8b97154cebad"),
+        component("c9336cc1389361ca9ef3fb623012d8b0f821daefd318a6ea8eb2829e20aeb45987631ce28328f74770027984e5bac3c46470bf2ddaecb761897983bc3748e739"),
+    )
+    .unwrap();
+}
@@ -0,0 +1,238 @@
+use super::mgf1::mgf1_xor;
+use super::{RsaPrivateKey, RsaPublicKey};
+use crate::bignum::BigUint;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::random::RandomSource;
+use crate::util::{ct_eq_mask, ct_select_usize};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+impl RsaPublicKey {
+    /// Encrypts `message` with RSAES-OAEP from RFC 8017 section 7.1, using `H` both for the label
+    /// and MGF1. The message can be at most `size() - 2 * H::OUTPUT_SIZE - 2` bytes long.
+    pub fn encrypt_oaep<H: HashingAlgorithm>(
+        &self,
+        rng: &mut impl RandomSource,
+        message: &[u8],
+        label: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let k = self.size();
+        let h_len = H::OUTPUT_SIZE;
+
+        if k < 2 * h_len + 2 || message.len() > k - 2 * h_len - 2 {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        // EM = 0x00 || maskedSeed || maskedDB, where DB = lHash || PS || 0x01 || M
+        let mut em = vec![0u8; k];
+        let (seed, db) = em[1..].split_at_mut(h_len);
+
+        let message_start = db.len() - message.len();
+
+        db[..h_len].copy_from_slice(H::hash(label).as_ref());
+        db[message_start - 1] = 0x01;
+        db[message_start..].copy_from_slice(message);
+
+        rng.fill_bytes(seed)?;
+        mgf1_xor::<H>(seed, db);
+        mgf1_xor::<H>(db, seed);
+
+        let c = self.public_operation(&BigUint::from_be_bytes(&em))?;
+
+        return c.to_be_bytes_padded(k);
+    }
+}
+
+impl RsaPrivateKey {
+    /// Decrypts a RSAES-OAEP ciphertext, using `H` both for the label and MGF1.
+    ///
+    /// Every way a ciphertext can be invalid returns the same `VCryptoError::InvalidPadding`, and
+    /// the padding is checked in time that does not depend on where it is wrong, as required to
+    /// resist Manger's attack.
+    pub fn decrypt_oaep<H: HashingAlgorithm>(
+        &self,
+        rng: &mut impl RandomSource,
+        ciphertext: &[u8],
+        label: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let k = self.public_key().size();
+        let h_len = H::OUTPUT_SIZE;
+
+        if k < 2 * h_len + 2 {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        if ciphertext.len() != k {
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        let m = self
+            .private_operation(rng, &BigUint::from_be_bytes(ciphertext))
+            .map_err(|_| VCryptoError::InvalidPadding)?;
+        let mut em = m.to_be_bytes_padded(k)?;
+
+        let (y, rest) = em.split_at_mut(1);
+        let (seed, db) = rest.split_at_mut(h_len);
+
+        mgf1_xor::<H>(db, seed);
+        mgf1_xor::<H>(seed, db);
+
+        let mut valid = ct_eq_mask(y[0], 0);
+
+        for (a, b) in db[..h_len].iter().zip(H::hash(label).as_ref()) {
+            valid &= ct_eq_mask(*a, *b);
+        }
+
+        // Find the 0x01 after the zero padding, reading every byte whatever is found
+        let mut looking = 0xffu8;
+        let mut separator = 0;
+
+        for (i, b) in db.iter().enumerate().skip(h_len) {
+            let is_zero = ct_eq_mask(*b, 0x00);
+            let is_one = ct_eq_mask(*b, 0x01);
+
+            separator = ct_select_usize(looking & is_one, i, separator);
+            valid &= !looking | is_zero | is_one;
+            looking &= !is_one;
+        }
+
+        valid &= !looking;
+
+        if valid != 0xff {
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        return Ok(db[separator + 1..].to_vec());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{SHA1, SHA256};
+    use crate::random::HmacDrbg;
+    use crate::rsa::test_key;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_oaep_round_trip() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"oaep round trip", &[], &[]);
+        let max = key.public_key().size() - 2 * 32 - 2;
+
+        for len in [0, 1, 16, max] {
+            let message = vec![0x5a; len];
+            let ciphertext = key
+                .public_key()
+                .encrypt_oaep::<SHA256>(&mut rng, &message, b"label")
+                .unwrap();
+
+            assert_eq!(ciphertext.len(), key.public_key().size());
+            assert_eq!(
+                key.decrypt_oaep::<SHA256>(&mut rng, &ciphertext, b"label")
+                    .unwrap(),
+                message
+            );
+        }
+
+        assert_eq!(
+            key.public_key()
+                .encrypt_oaep::<SHA256>(&mut rng, &vec![0; max + 1], &[])
+                .unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_oaep_invalid_ciphertext() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"oaep invalid", &[], &[]);
+        let ciphertext = key
+            .public_key()
+            .encrypt_oaep::<SHA1>(&mut rng, b"message", &[])
+            .unwrap();
+
+        // Wrong label and wrong hash
+        assert_eq!(
+            key.decrypt_oaep::<SHA1>(&mut rng, &ciphertext, b"label")
+                .unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+        assert_eq!(
+            key.decrypt_oaep::<SHA256>(&mut rng, &ciphertext, &[])
+                .unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+
+        let mut modified = ciphertext.clone();
+        modified[10] ^= 1;
+        assert_eq!(
+            key.decrypt_oaep::<SHA1>(&mut rng, &modified, &[])
+                .unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+
+        // Out of range and wrongly sized ciphertexts fail the same way
+        assert_eq!(
+            key.decrypt_oaep::<SHA1>(&mut rng, &[0xff; 128], &[])
+                .unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+        assert_eq!(
+            key.decrypt_oaep::<SHA1>(&mut rng, &ciphertext[1..], &[])
+                .unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+    }
+
+    #[test]
+    fn test_oaep_padding_errors() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"oaep padding", &[], &[]);
+        let k = key.public_key().size();
+
+        // Encodes a DB directly so every part of the padding can be broken
+        let encrypt = |db: &[u8], y: u8, rng: &mut HmacDrbg<SHA256>| {
+            let mut em = vec![y; 1];
+            let mut seed = [0x42u8; 20];
+            let mut db = db.to_vec();
+
+            mgf1_xor::<SHA1>(&seed, &mut db);
+            mgf1_xor::<SHA1>(&db, &mut seed);
+            em.extend_from_slice(&seed);
+            em.extend_from_slice(&db);
+
+            let c = key
+                .public_key()
+                .public_operation(&BigUint::from_be_bytes(&em))
+                .unwrap();
+
+            key.decrypt_oaep::<SHA1>(rng, &c.to_be_bytes_padded(k).unwrap(), &[])
+        };
+
+        let mut db = vec![0u8; k - 21];
+        db[..20].copy_from_slice(SHA1::hash(&[]).as_ref());
+        db[100] = 0x01;
+        db[101..].fill(0xaa);
+
+        assert_eq!(encrypt(&db, 0, &mut rng).unwrap(), vec![0xaa; k - 21 - 101]);
+        assert!(encrypt(&db, 1, &mut rng).is_err());
+
+        let mut no_separator = db.clone();
+        no_separator[100] = 0;
+        no_separator[101..].fill(0);
+        assert!(encrypt(&no_separator, 0, &mut rng).is_err());
+
+        let mut nonzero_padding = db.clone();
+        nonzero_padding[50] = 0x02;
+        assert!(encrypt(&nonzero_padding, 0, &mut rng).is_err());
+
+        let mut wrong_label_hash = db;
+        wrong_label_hash[0] ^= 0x80;
+        assert!(encrypt(&wrong_label_hash, 0, &mut rng).is_err());
+    }
+}
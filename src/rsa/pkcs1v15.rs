@@ -0,0 +1,339 @@
+use super::{RsaPrivateKey, RsaPublicKey};
+use crate::bignum::BigUint;
+use crate::error::VCryptoError;
+use crate::hashes::{
+    HashingAlgorithm, SHA1, SHA224, SHA256, SHA384, SHA512, SHA512_224, SHA512_256,
+};
+use crate::random::RandomSource;
+use crate::util::{constant_time_eq, ct_eq_mask, ct_select_usize};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The smallest amount of random padding in an RSAES-PKCS1-v1_5 message.
+const MIN_PADDING_LEN: usize = 8;
+
+/// A hashing algorithm that can be used with RSASSA-PKCS1-v1_5 signatures.
+pub trait Pkcs1v15Digest: HashingAlgorithm {
+    /// The DER encoding of the DigestInfo structure up to the digest itself, from the notes in
+    /// RFC 8017 section 9.2.
+    const DIGEST_INFO_PREFIX: &'static [u8];
+}
+
+impl Pkcs1v15Digest for SHA1 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x21, 0x30, 0x09, 0x06, 0x05, 0x2b, 0x0e, 0x03, 0x02, 0x1a, 0x05, 0x00, 0x04, 0x14,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA224 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x2d, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04,
+        0x05, 0x00, 0x04, 0x1c,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA256 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+        0x05, 0x00, 0x04, 0x20,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA384 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x41, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02,
+        0x05, 0x00, 0x04, 0x30,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA512 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x51, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+        0x05, 0x00, 0x04, 0x40,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA512_224 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x2d, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x05,
+        0x05, 0x00, 0x04, 0x1c,
+    ];
+}
+
+impl Pkcs1v15Digest for SHA512_256 {
+    const DIGEST_INFO_PREFIX: &'static [u8] = &[
+        0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x06,
+        0x05, 0x00, 0x04, 0x20,
+    ];
+}
+
+impl RsaPublicKey {
+    /// Encrypts `message` with RSAES-PKCS1-v1_5 from RFC 8017 section 7.2. The message can be at
+    /// most `size() - 11` bytes long.
+    ///
+    /// This scheme is only provided for compatibility, `encrypt_oaep` should be preferred.
+    pub fn encrypt_pkcs1v15(
+        &self,
+        rng: &mut impl RandomSource,
+        message: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let k = self.size();
+
+        if message.len() + MIN_PADDING_LEN + 3 > k {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        // EM = 0x00 || 0x02 || PS || 0x00 || M, where PS are non zero random bytes
+        let mut em = vec![0u8; k];
+        let padding_end = k - message.len() - 1;
+
+        em[1] = 0x02;
+        rng.fill_bytes(&mut em[2..padding_end])?;
+
+        for b in em[2..padding_end].iter_mut() {
+            while *b == 0 {
+                let mut replacement = [0u8; 1];
+                rng.fill_bytes(&mut replacement)?;
+                *b = replacement[0];
+            }
+        }
+
+        em[padding_end + 1..].copy_from_slice(message);
+
+        let c = self.public_operation(&BigUint::from_be_bytes(&em))?;
+
+        return c.to_be_bytes_padded(k);
+    }
+
+    /// Verifies a RSASSA-PKCS1-v1_5 signature of `message`, from RFC 8017 section 8.2.
+    ///
+    /// The expected encoding is built and compared as a whole instead of parsing the signature,
+    /// so no leniency in the DigestInfo parsing can be abused to forge signatures.
+    pub fn verify_pkcs1v15<H: Pkcs1v15Digest>(&self, message: &[u8], signature: &[u8]) -> bool {
+        let k = self.size();
+
+        if signature.len() != k {
+            return false;
+        }
+
+        let expected = match pkcs1v15_signature_encoding::<H>(message, k) {
+            Ok(expected) => expected,
+            Err(_) => return false,
+        };
+
+        return match self.public_operation(&BigUint::from_be_bytes(signature)) {
+            Ok(m) => m
+                .to_be_bytes_padded(k)
+                .is_ok_and(|em| constant_time_eq(&em, &expected)),
+            Err(_) => false,
+        };
+    }
+}
+
+impl RsaPrivateKey {
+    /// Decrypts a RSAES-PKCS1-v1_5 ciphertext.
+    ///
+    /// Every way a ciphertext can be invalid returns the same `VCryptoError::InvalidPadding` and
+    /// the padding is checked in constant time. Even so, a caller that reveals whether decryption
+    /// failed, through an error message or its own timing, gives Bleichenbacher's attack the
+    /// oracle it needs, so `decrypt_oaep` should be preferred.
+    pub fn decrypt_pkcs1v15(
+        &self,
+        rng: &mut impl RandomSource,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let k = self.public_key().size();
+
+        if k < MIN_PADDING_LEN + 3 || ciphertext.len() != k {
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        let m = self
+            .private_operation(rng, &BigUint::from_be_bytes(ciphertext))
+            .map_err(|_| VCryptoError::InvalidPadding)?;
+        let em = m.to_be_bytes_padded(k)?;
+
+        let mut valid = ct_eq_mask(em[0], 0x00) & ct_eq_mask(em[1], 0x02);
+
+        // Find the first zero after the padding, reading every byte whatever is found
+        let mut looking = 0xffu8;
+        let mut separator = 0;
+
+        for (i, b) in em.iter().enumerate().skip(2) {
+            let is_zero = ct_eq_mask(*b, 0x00);
+
+            separator = ct_select_usize(looking & is_zero, i, separator);
+            looking &= !is_zero;
+        }
+
+        // The padding has to be at least 8 bytes, so the separator is at index 10 or later
+        let short_padding =
+            ((separator as u64).wrapping_sub(MIN_PADDING_LEN as u64 + 2) >> 63) as u8;
+        valid &= !looking & short_padding.wrapping_sub(1);
+
+        if valid != 0xff {
+            return Err(VCryptoError::InvalidPadding);
+        }
+
+        return Ok(em[separator + 1..].to_vec());
+    }
+
+    /// Signs `message` with RSASSA-PKCS1-v1_5 from RFC 8017 section 8.2. The random source is
+    /// only used for blinding, the signature itself is deterministic.
+    pub fn sign_pkcs1v15<H: Pkcs1v15Digest>(
+        &self,
+        rng: &mut impl RandomSource,
+        message: &[u8],
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let k = self.public_key().size();
+        let em = pkcs1v15_signature_encoding::<H>(message, k)?;
+        let s = self.private_operation(rng, &BigUint::from_be_bytes(&em))?;
+
+        return s.to_be_bytes_padded(k);
+    }
+}
+
+/// EMSA-PKCS1-v1_5 encoding, 0x00 || 0x01 || 0xff... || 0x00 || DigestInfo.
+fn pkcs1v15_signature_encoding<H: Pkcs1v15Digest>(
+    message: &[u8],
+    k: usize,
+) -> Result<Vec<u8>, VCryptoError> {
+    let t_len = H::DIGEST_INFO_PREFIX.len() + H::OUTPUT_SIZE;
+
+    if k < t_len + MIN_PADDING_LEN + 3 {
+        return Err(VCryptoError::InvalidInput);
+    }
+
+    let mut em = vec![0xffu8; k];
+    em[0] = 0x00;
+    em[1] = 0x01;
+    em[k - t_len - 1] = 0x00;
+    em[k - t_len..k - H::OUTPUT_SIZE].copy_from_slice(H::DIGEST_INFO_PREFIX);
+    em[k - H::OUTPUT_SIZE..].copy_from_slice(H::hash(message).as_ref());
+
+    return Ok(em);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::HmacDrbg;
+    use crate::rsa::test_key;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_digest_info_prefixes() {
+        assert_eq!(
+            hex::encode(SHA256::DIGEST_INFO_PREFIX),
+            "3031300d060960864801650304020105000420"
+        );
+        assert_eq!(
+            hex::encode(SHA512_224::DIGEST_INFO_PREFIX),
+            "302d300d06096086480165030402050500041c"
+        );
+        assert_eq!(
+            hex::encode(SHA512::DIGEST_INFO_PREFIX),
+            "3051300d060960864801650304020305000440"
+        );
+    }
+
+    #[test]
+    fn test_pkcs1v15_encryption_round_trip() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pkcs1v15 round trip", &[], &[]);
+        let max = key.public_key().size() - 11;
+
+        for len in [0, 1, max] {
+            let message = vec![0xa5; len];
+            let ciphertext = key
+                .public_key()
+                .encrypt_pkcs1v15(&mut rng, &message)
+                .unwrap();
+
+            assert_eq!(
+                key.decrypt_pkcs1v15(&mut rng, &ciphertext).unwrap(),
+                message
+            );
+        }
+
+        assert_eq!(
+            key.public_key()
+                .encrypt_pkcs1v15(&mut rng, &vec![0; max + 1])
+                .unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_pkcs1v15_padding_errors() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pkcs1v15 padding", &[], &[]);
+        let k = key.public_key().size();
+
+        let decrypt = |em: &[u8], rng: &mut HmacDrbg<SHA256>| {
+            let c = key
+                .public_key()
+                .public_operation(&BigUint::from_be_bytes(em))
+                .unwrap();
+
+            key.decrypt_pkcs1v15(rng, &c.to_be_bytes_padded(k).unwrap())
+        };
+
+        let mut em = vec![0x11u8; k];
+        em[0] = 0x00;
+        em[1] = 0x02;
+        em[10] = 0x00;
+
+        assert_eq!(decrypt(&em, &mut rng).unwrap(), vec![0x11; k - 11]);
+
+        // A separator at index 10 gives exactly 8 bytes of padding, 9 gives too few
+        let mut short = em.clone();
+        short[9] = 0x00;
+        assert_eq!(
+            decrypt(&short, &mut rng).unwrap_err(),
+            VCryptoError::InvalidPadding
+        );
+
+        let mut wrong_type = em.clone();
+        wrong_type[1] = 0x01;
+        assert!(decrypt(&wrong_type, &mut rng).is_err());
+
+        let mut no_separator = em;
+        no_separator[10] = 0x11;
+        assert!(decrypt(&no_separator, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_pkcs1v15_signature() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pkcs1v15 signature", &[], &[]);
+        let signature = key.sign_pkcs1v15::<SHA256>(&mut rng, b"message").unwrap();
+
+        // Signatures are deterministic whatever the blinding
+        assert_eq!(
+            key.sign_pkcs1v15::<SHA256>(&mut rng, b"message").unwrap(),
+            signature
+        );
+
+        assert!(key
+            .public_key()
+            .verify_pkcs1v15::<SHA256>(b"message", &signature));
+        assert!(!key
+            .public_key()
+            .verify_pkcs1v15::<SHA256>(b"messagf", &signature));
+        assert!(!key
+            .public_key()
+            .verify_pkcs1v15::<SHA224>(b"message", &signature));
+        assert!(!key
+            .public_key()
+            .verify_pkcs1v15::<SHA256>(b"message", &signature[1..]));
+
+        let signature = key.sign_pkcs1v15::<SHA512>(&mut rng, b"message").unwrap();
+        assert!(key
+            .public_key()
+            .verify_pkcs1v15::<SHA512>(b"message", &signature));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::bignum::{BigUint, Montgomery};
+use crate::error::VCryptoError;
+use crate::random::RandomSource;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+
+/// The number of small primes candidates are divided by before running Miller-Rabin.
+const SMALL_PRIME_COUNT: usize = 171;
+
+/// The odd primes below 1024, computed at compile time with a sieve of Eratosthenes.
+const SMALL_PRIMES: [u64; SMALL_PRIME_COUNT] = small_primes();
+
+const fn small_primes() -> [u64; SMALL_PRIME_COUNT] {
+    let mut composite = [false; 1024];
+    let mut primes = [0; SMALL_PRIME_COUNT];
+    let mut count = 0;
+    let mut i = 3;
+
+    while i < 1024 {
+        if !composite[i] {
+            primes[count] = i as u64;
+            count += 1;
+
+            let mut j = i * i;
+
+            while j < 1024 {
+                composite[j] = true;
+                j += i;
+            }
+        }
+
+        i += 2;
+    }
+
+    return primes;
+}
+
+/// Returns the number of Miller-Rabin rounds needed for an error probability below 2^-100 for a
+/// random candidate of `bits` bits, from table C.2 of FIPS 186-4.
+pub(crate) fn miller_rabin_rounds(bits: usize) -> usize {
+    return match bits {
+        1536.. => 4,
+        1024.. => 5,
+        512.. => 7,
+        _ => 40,
+    };
+}
+
+/// Tests whether `candidate` is prime with `rounds` rounds of the Miller-Rabin test, using random
+/// bases. A composite number is reported as prime with a probability of at most 4^-rounds.
+pub fn is_probable_prime(
+    candidate: &BigUint,
+    rounds: usize,
+    rng: &mut impl RandomSource,
+) -> Result<bool, VCryptoError> {
+    if candidate.bits() <= 10 {
+        let small = candidate.limbs().first().copied().unwrap_or(0);
+
+        return Ok(small == 2 || SMALL_PRIMES.contains(&small));
+    }
+
+    if candidate.is_even() || SMALL_PRIMES.iter().any(|p| candidate.rem_limb(*p) == 0) {
+        return Ok(false);
+    }
+
+    let one = BigUint::one();
+    let minus_one = candidate - &one;
+
+    // candidate - 1 = d * 2^s with d odd
+    let s = (0..)
+        .find(|i| minus_one.bit(*i))
+        .expect("the candidate is odd");
+    let d = &minus_one >> s;
+
+    let montgomery = Montgomery::new(candidate)?;
+    let base_range = candidate - &BigUint::from(3u64);
+
+    'rounds: for _ in 0..rounds {
+        // A base in [2, candidate - 2]
+        let base = &BigUint::random_below(&base_range, rng)? + &BigUint::from(2u64);
+        let mut x = montgomery.pow(&base, &d);
+
+        if x == one || x == minus_one {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = montgomery.mul(&x, &x);
+
+            if x == minus_one {
+                continue 'rounds;
+            }
+        }
+
+        return Ok(false);
+    }
+
+    return Ok(true);
+}
+
+/// Generates a random prime of exactly `bits` bits with its two most significant bits set, so
+/// the product of two such primes has exactly the sum of their sizes in bits. `p - 1` is coprime
+/// to `e`.
+pub(crate) fn generate_prime(
+    bits: usize,
+    e: &BigUint,
+    rng: &mut impl RandomSource,
+) -> Result<BigUint, VCryptoError> {
+    let mut bytes = vec![0u8; bits.div_ceil(8)];
+    let top_bit = (bits - 1) % 8;
+
+    loop {
+        rng.fill_bytes(&mut bytes)?;
+
+        bytes[0] &= ((1u16 << (top_bit + 1)) - 1) as u8;
+        bytes[0] |= 1 << top_bit;
+
+        // The second most significant bit may be in the next byte
+        if top_bit == 0 {
+            bytes[1] |= 0x80;
+        } else {
+            bytes[0] |= 1 << (top_bit - 1);
+        }
+
+        let last = bytes.len() - 1;
+        bytes[last] |= 1;
+
+        let candidate = BigUint::from_be_bytes(&bytes);
+
+        if SMALL_PRIMES.iter().any(|p| candidate.rem_limb(*p) == 0) {
+            continue;
+        }
+
+        if !(&candidate - &BigUint::one()).gcd(e).is_one() {
+            continue;
+        }
+
+        if is_probable_prime(&candidate, miller_rabin_rounds(bits), rng)? {
+            return Ok(candidate);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    fn rng() -> HmacDrbg<SHA256> {
+        return HmacDrbg::new(b"prime test entropy", &[], &[]);
+    }
+
+    #[test]
+    fn test_small_primes() {
+        assert_eq!(SMALL_PRIMES[0], 3);
+        assert_eq!(SMALL_PRIMES[SMALL_PRIME_COUNT - 1], 1021);
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        let mut rng = rng();
+        let prime =
+            |n: &BigUint, rng: &mut HmacDrbg<SHA256>| is_probable_prime(n, 20, rng).unwrap();
+
+        assert!(prime(&BigUint::from(2u64), &mut rng));
+        assert!(prime(&BigUint::from(1021u64), &mut rng));
+        assert!(!prime(&BigUint::from(1u64), &mut rng));
+        assert!(!prime(&BigUint::from(1023u64), &mut rng));
+        assert!(prime(&BigUint::from(1031u64), &mut rng));
+
+        // 2^127 - 1 and 2^521 - 1 are Mersenne primes, 2^67 - 1 is not
+        assert!(prime(
+            &(&(BigUint::one() << 127) - &BigUint::one()),
+            &mut rng
+        ));
+        assert!(prime(
+            &(&(BigUint::one() << 521) - &BigUint::one()),
+            &mut rng
+        ));
+        assert!(!prime(
+            &(&(BigUint::one() << 67) - &BigUint::one()),
+            &mut rng
+        ));
+
+        // A Carmichael number with no factors below 1024, 1171 * 2341 * 3511
+        let carmichael = BigUint::from(1171u64 * 2341 * 3511);
+        assert!(!prime(&carmichael, &mut rng));
+    }
+
+    #[test]
+    fn test_generate_prime() {
+        let mut rng = rng();
+        let e = BigUint::from(65537u64);
+
+        for bits in [256, 257, 263] {
+            let p = generate_prime(bits, &e, &mut rng).unwrap();
+
+            assert_eq!(p.bits(), bits);
+            assert!(p.bit(bits - 2));
+            assert!(is_probable_prime(&p, 20, &mut rng).unwrap());
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use super::mgf1::mgf1_xor;
+use super::{RsaPrivateKey, RsaPublicKey};
+use crate::bignum::BigUint;
+use crate::error::VCryptoError;
+use crate::hashes::HashingAlgorithm;
+use crate::random::RandomSource;
+use crate::util::constant_time_eq;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec;
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+const TRAILER_FIELD: u8 = 0xbc;
+
+impl RsaPrivateKey {
+    /// Signs `message` with RSASSA-PSS from RFC 8017 section 8.1, using `H` both for the message
+    /// and MGF1 and a random salt of `salt_len` bytes. A salt as long as the digest is the usual
+    /// choice.
+    pub fn sign_pss<H: HashingAlgorithm>(
+        &self,
+        rng: &mut impl RandomSource,
+        message: &[u8],
+        salt_len: usize,
+    ) -> Result<Vec<u8>, VCryptoError> {
+        let mod_bits = self.public_key().bits();
+        let em_bits = mod_bits - 1;
+        let em_len = em_bits.div_ceil(8);
+        let h_len = H::OUTPUT_SIZE;
+
+        if em_len < h_len + salt_len + 2 {
+            return Err(VCryptoError::InvalidInput);
+        }
+
+        let mut salt = vec![0u8; salt_len];
+        rng.fill_bytes(&mut salt)?;
+
+        let h = pss_hash::<H>(&H::hash(message), &salt);
+
+        // EM = maskedDB || H || 0xbc, where DB = PS || 0x01 || salt
+        let mut em = vec![0u8; em_len];
+        let (db, rest) = em.split_at_mut(em_len - h_len - 1);
+
+        let salt_start = db.len() - salt_len;
+
+        db[salt_start - 1] = 0x01;
+        db[salt_start..].copy_from_slice(&salt);
+        mgf1_xor::<H>(h.as_ref(), db);
+        db[0] &= 0xff >> (8 * em_len - em_bits);
+
+        rest[..h_len].copy_from_slice(h.as_ref());
+        rest[h_len] = TRAILER_FIELD;
+
+        let s = self.private_operation(rng, &BigUint::from_be_bytes(&em))?;
+
+        return s.to_be_bytes_padded(self.public_key().size());
+    }
+}
+
+impl RsaPublicKey {
+    /// Verifies a RSASSA-PSS signature of `message`, using `H` both for the message and MGF1. The
+    /// salt has to be `salt_len` bytes long, or any length when `salt_len` is `None`.
+    pub fn verify_pss<H: HashingAlgorithm>(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        salt_len: Option<usize>,
+    ) -> bool {
+        if signature.len() != self.size() {
+            return false;
+        }
+
+        let em_bits = self.bits() - 1;
+        let em_len = em_bits.div_ceil(8);
+        let h_len = H::OUTPUT_SIZE;
+
+        if em_len < h_len + salt_len.unwrap_or(0) + 2 {
+            return false;
+        }
+
+        // The encoded message is one byte shorter than the modulus when its size is a multiple
+        // of 8 bits, that byte has to be zero
+        let mut em = match self
+            .public_operation(&BigUint::from_be_bytes(signature))
+            .and_then(|m| m.to_be_bytes_padded(em_len))
+        {
+            Ok(em) => em,
+            Err(_) => return false,
+        };
+
+        if em[em_len - 1] != TRAILER_FIELD {
+            return false;
+        }
+
+        let (db, rest) = em.split_at_mut(em_len - h_len - 1);
+        let h = &rest[..h_len];
+        let unused_bits = 8 * em_len - em_bits;
+
+        if db[0] & !(0xff >> unused_bits) != 0 {
+            return false;
+        }
+
+        mgf1_xor::<H>(h, db);
+        db[0] &= 0xff >> unused_bits;
+
+        let separator = match db.iter().position(|b| *b != 0) {
+            Some(separator) if db[separator] == 0x01 => separator,
+            _ => return false,
+        };
+
+        let salt = &db[separator + 1..];
+
+        if salt_len.is_some_and(|len| len != salt.len()) {
+            return false;
+        }
+
+        return constant_time_eq(pss_hash::<H>(&H::hash(message), salt).as_ref(), h);
+    }
+}
+
+/// Hashes M' = 0x00 00 00 00 00 00 00 00 || mHash || salt.
+fn pss_hash<H: HashingAlgorithm>(message_hash: &H::Output, salt: &[u8]) -> H::Output {
+    let mut hasher = H::hasher();
+    hasher.update(&[0u8; 8]);
+    hasher.update(message_hash.as_ref());
+    hasher.update(salt);
+
+    return hasher.finalize();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::{SHA1, SHA256, SHA512};
+    use crate::random::HmacDrbg;
+    use crate::rsa::test_key;
+
+    #[test]
+    fn test_pss_round_trip() {
+        let key = test_key();
+        let public_key = key.public_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pss round trip", &[], &[]);
+
+        for salt_len in [0, 20, 32, 128 - 32 - 2] {
+            let signature = key
+                .sign_pss::<SHA256>(&mut rng, b"message", salt_len)
+                .unwrap();
+
+            assert!(public_key.verify_pss::<SHA256>(b"message", &signature, Some(salt_len)));
+            assert!(public_key.verify_pss::<SHA256>(b"message", &signature, None));
+            assert!(!public_key.verify_pss::<SHA256>(b"message", &signature, Some(salt_len + 1)));
+            assert!(!public_key.verify_pss::<SHA256>(b"messagf", &signature, None));
+            assert!(!public_key.verify_pss::<SHA1>(b"message", &signature, None));
+        }
+
+        assert_eq!(
+            key.sign_pss::<SHA256>(&mut rng, b"message", 128 - 32 - 1)
+                .unwrap_err(),
+            VCryptoError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_pss_randomized() {
+        let key = test_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pss randomized", &[], &[]);
+        let a = key.sign_pss::<SHA512>(&mut rng, b"message", 32).unwrap();
+        let b = key.sign_pss::<SHA512>(&mut rng, b"message", 32).unwrap();
+
+        assert_ne!(a, b);
+        assert!(key
+            .public_key()
+            .verify_pss::<SHA512>(b"message", &a, Some(32)));
+        assert!(key
+            .public_key()
+            .verify_pss::<SHA512>(b"message", &b, Some(32)));
+    }
+
+    #[test]
+    fn test_pss_invalid_signature() {
+        let key = test_key();
+        let public_key = key.public_key();
+        let mut rng = HmacDrbg::<SHA256>::new(b"pss invalid", &[], &[]);
+        let signature = key.sign_pss::<SHA256>(&mut rng, b"message", 32).unwrap();
+
+        for i in [0, 50, 127] {
+            let mut modified = signature.clone();
+            modified[i] ^= 0x01;
+
+            assert!(!public_key.verify_pss::<SHA256>(b"message", &modified, None));
+        }
+
+        assert!(!public_key.verify_pss::<SHA256>(b"message", &signature[1..], None));
+        assert!(!public_key.verify_pss::<SHA256>(b"message", &[0xff; 128], None));
+    }
+}
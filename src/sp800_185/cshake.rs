@@ -0,0 +1,189 @@
+use super::encoding::{bit_len, left_encode, right_encode, MAX_ENCODED_LEN};
+use super::keccak::KeccakSponge;
+#[cfg(feature = "std")]
+use std::io;
+
+/// cSHAKE from section 3 of SP 800-185, SHAKE with a function name and customization string for
+/// domain separation. Use the [`CShake128`] and [`CShake256`] aliases, `RATE` is the sponge rate
+/// in bytes.
+#[derive(Clone)]
+pub struct CShake<const RATE: usize> {
+    sponge: KeccakSponge<RATE>,
+    domain_separation: u8,
+}
+
+pub type CShake128 = CShake<168>;
+pub type CShake256 = CShake<136>;
+
+/// Produces the extendable output of a finalized cSHAKE, KMAC, TupleHash or ParallelHash.
+#[derive(Clone)]
+pub struct CShakeReader<const RATE: usize> {
+    sponge: KeccakSponge<RATE>,
+}
+
+impl<const RATE: usize> CShake<RATE> {
+    /// Creates a new instance, when both `function_name` and `customization` are empty this is
+    /// identical to SHAKE.
+    pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = Self {
+            sponge: KeccakSponge::new(),
+            domain_separation: 0x1f,
+        };
+
+        if function_name.is_empty() && customization.is_empty() {
+            return cshake;
+        }
+
+        cshake.domain_separation = 0x04;
+        cshake.update_left_encode(RATE as u64);
+        cshake.update_encode_string(function_name);
+        cshake.update_encode_string(customization);
+        cshake.sponge.zero_pad();
+
+        return cshake;
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    /// Fills `output` with the hash of the data provided so far.
+    pub fn finalize_into(self, output: &mut [u8]) {
+        self.finalize_xof().fill(output);
+    }
+
+    /// Finalizes the hash, returning a reader for the extendable output.
+    pub fn finalize_xof(mut self) -> CShakeReader<RATE> {
+        self.sponge.pad(self.domain_separation);
+
+        return CShakeReader {
+            sponge: self.sponge,
+        };
+    }
+
+    pub(crate) fn update_left_encode(&mut self, x: u64) {
+        let mut buffer = [0u8; MAX_ENCODED_LEN];
+
+        self.sponge.absorb(left_encode(x, &mut buffer));
+    }
+
+    pub(crate) fn update_right_encode(&mut self, x: u64) {
+        let mut buffer = [0u8; MAX_ENCODED_LEN];
+
+        self.sponge.absorb(right_encode(x, &mut buffer));
+    }
+
+    pub(crate) fn update_encode_string(&mut self, s: &[u8]) {
+        self.update_left_encode(bit_len(s));
+        self.sponge.absorb(s);
+    }
+
+    /// Completes a `bytepad` started by absorbing `left_encode(RATE)`.
+    pub(crate) fn finish_bytepad(&mut self) {
+        self.sponge.zero_pad();
+    }
+}
+
+impl<const RATE: usize> CShakeReader<RATE> {
+    /// Fills `output` with the next bytes of the extendable output.
+    pub fn fill(&mut self, output: &mut [u8]) {
+        self.sponge.squeeze(output);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const RATE: usize> io::Read for CShakeReader<RATE> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fill(buf);
+
+        return Ok(buf.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn cshake128(input: &[u8], function_name: &[u8], customization: &[u8]) -> String {
+        let mut cshake = CShake128::new(function_name, customization);
+        let mut output = [0u8; 32];
+
+        cshake.update(input);
+        cshake.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    fn cshake256(input: &[u8], function_name: &[u8], customization: &[u8]) -> String {
+        let mut cshake = CShake256::new(function_name, customization);
+        let mut output = [0u8; 64];
+
+        cshake.update(input);
+        cshake.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    #[test]
+    fn test_cshake128() {
+        // NIST cSHAKE samples #1 and #2
+        let input: [u8; 200] = core::array::from_fn(|i| i as u8);
+
+        assert_eq!(
+            cshake128(&input[..4], b"", b"Email Signature"),
+            "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5"
+        );
+        assert_eq!(
+            cshake128(&input, b"", b"Email Signature"),
+            "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b"
+        );
+    }
+
+    #[test]
+    fn test_cshake256() {
+        // NIST cSHAKE samples #3 and #4
+        let input: [u8; 200] = core::array::from_fn(|i| i as u8);
+
+        assert_eq!(
+            cshake256(&input[..4], b"", b"Email Signature"),
+            "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd1\
+             64020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+        );
+        assert_eq!(
+            cshake256(&input, b"", b"Email Signature"),
+            "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac864302730917\
+             27f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+        );
+    }
+
+    #[test]
+    fn test_cshake_empty_is_shake() {
+        assert_eq!(
+            cshake128(b"", b"", b""),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            &cshake256(b"", b"", b"")[..64],
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f"
+        );
+    }
+
+    #[test]
+    fn test_cshake_reader() {
+        let mut cshake = CShake128::new(b"", b"Email Signature");
+        cshake.update(&[0, 1, 2, 3]);
+
+        let mut reader = cshake.finalize_xof();
+        let mut output = [0u8; 32];
+
+        for chunk in output.chunks_mut(5) {
+            reader.fill(chunk);
+        }
+
+        assert_eq!(
+            hex::encode(output),
+            "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5"
+        );
+    }
+}
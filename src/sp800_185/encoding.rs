@@ -0,0 +1,120 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// The longest possible encoding of a 64 bit integer, one length byte and up to 8 value bytes.
+pub const MAX_ENCODED_LEN: usize = 9;
+
+/// Writes `left_encode(x)` from section 2.3.1 of SP 800-185 into `buffer`, returning the encoded
+/// bytes.
+pub fn left_encode(x: u64, buffer: &mut [u8; MAX_ENCODED_LEN]) -> &[u8] {
+    let n = encoded_len(x);
+
+    buffer[0] = n as u8;
+    buffer[1..=n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+
+    return &buffer[..=n];
+}
+
+/// Writes `right_encode(x)` from section 2.3.1 of SP 800-185 into `buffer`, returning the encoded
+/// bytes.
+pub fn right_encode(x: u64, buffer: &mut [u8; MAX_ENCODED_LEN]) -> &[u8] {
+    let n = encoded_len(x);
+
+    buffer[..n].copy_from_slice(&x.to_be_bytes()[8 - n..]);
+    buffer[n] = n as u8;
+
+    return &buffer[..=n];
+}
+
+/// Returns `encode_string(s)` from section 2.3.2 of SP 800-185, the bit length of `s` followed by
+/// `s` itself.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn encode_string(s: &[u8]) -> Vec<u8> {
+    let mut buffer = [0u8; MAX_ENCODED_LEN];
+    let mut encoded = Vec::with_capacity(MAX_ENCODED_LEN + s.len());
+
+    encoded.extend_from_slice(left_encode(bit_len(s), &mut buffer));
+    encoded.extend_from_slice(s);
+
+    return encoded;
+}
+
+/// Returns `bytepad(x, w)` from section 2.3.3 of SP 800-185, `x` prefixed with `left_encode(w)`
+/// and padded with zeros to a multiple of `w` bytes.
+///
+/// # Panics
+/// If `w` is 0.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub fn bytepad(x: &[u8], w: usize) -> Vec<u8> {
+    assert!(w > 0, "bytepad requires a non zero width");
+
+    let mut buffer = [0u8; MAX_ENCODED_LEN];
+    let mut padded = Vec::with_capacity(MAX_ENCODED_LEN + x.len() + w);
+
+    padded.extend_from_slice(left_encode(w as u64, &mut buffer));
+    padded.extend_from_slice(x);
+
+    while !padded.len().is_multiple_of(w) {
+        padded.push(0);
+    }
+
+    return padded;
+}
+
+/// The length of `s` in bits, as used by `encode_string`.
+pub(crate) fn bit_len(s: &[u8]) -> u64 {
+    return (s.len() as u64)
+        .checked_mul(8)
+        .expect("strings longer than 2^61 bytes cannot be encoded");
+}
+
+fn encoded_len(x: u64) -> usize {
+    return (64 - x.leading_zeros() as usize).div_ceil(8).max(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_left_encode() {
+        let mut buffer = [0u8; MAX_ENCODED_LEN];
+
+        assert_eq!(left_encode(0, &mut buffer), &[0x01, 0x00]);
+        assert_eq!(left_encode(168, &mut buffer), &[0x01, 0xa8]);
+        assert_eq!(left_encode(256, &mut buffer), &[0x02, 0x01, 0x00]);
+        assert_eq!(
+            left_encode(u64::MAX, &mut buffer),
+            &[0x08, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_right_encode() {
+        let mut buffer = [0u8; MAX_ENCODED_LEN];
+
+        assert_eq!(right_encode(0, &mut buffer), &[0x00, 0x01]);
+        assert_eq!(right_encode(256, &mut buffer), &[0x01, 0x00, 0x02]);
+        assert_eq!(
+            right_encode(0x010203, &mut buffer),
+            &[0x01, 0x02, 0x03, 0x03]
+        );
+    }
+
+    #[test]
+    fn test_encode_string() {
+        assert_eq!(encode_string(b""), [0x01, 0x00]);
+        assert_eq!(encode_string(b"KMAC"), [0x01, 0x20, b'K', b'M', b'A', b'C']);
+    }
+
+    #[test]
+    fn test_bytepad() {
+        assert_eq!(bytepad(b"", 4), [0x01, 0x04, 0x00, 0x00]);
+        assert_eq!(bytepad(&[0xaa, 0xbb], 4), [0x01, 0x04, 0xaa, 0xbb]);
+        assert_eq!(
+            bytepad(&[0xaa, 0xbb, 0xcc], 4),
+            [0x01, 0x04, 0xaa, 0xbb, 0xcc, 0x00, 0x00, 0x00]
+        );
+    }
+}
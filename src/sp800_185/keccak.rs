@@ -0,0 +1,187 @@
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step, in the order lanes are visited by the pi step.
+const RHO_OFFSETS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lane each step of the combined rho and pi steps moves to, lanes are indexed by x + 5y.
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// The Keccak-f[1600] permutation from FIPS 202.
+pub(crate) fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // Theta
+        let mut columns = [0u64; 5];
+
+        for x in 0..5 {
+            columns[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut current = state[1];
+
+        for i in 0..24 {
+            let next = state[PI_LANES[i]];
+            state[PI_LANES[i]] = current.rotate_left(RHO_OFFSETS[i]);
+            current = next;
+        }
+
+        // Chi
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+
+            for x in 0..5 {
+                state[x + 5 * y] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota
+        state[0] ^= round_constant;
+    }
+}
+
+/// A Keccak sponge absorbing and squeezing `RATE` bytes per permutation.
+#[derive(Clone)]
+pub(crate) struct KeccakSponge<const RATE: usize> {
+    state: [u64; 25],
+    position: usize,
+}
+
+impl<const RATE: usize> KeccakSponge<RATE> {
+    pub(crate) fn new() -> Self {
+        return Self {
+            state: [0; 25],
+            position: 0,
+        };
+    }
+
+    pub(crate) fn absorb(&mut self, data: &[u8]) {
+        for &b in data {
+            self.xor_byte(self.position, b);
+            self.position += 1;
+
+            if self.position == RATE {
+                keccak_f1600(&mut self.state);
+                self.position = 0;
+            }
+        }
+    }
+
+    /// Pads the data absorbed so far with zeros up to a multiple of the rate.
+    pub(crate) fn zero_pad(&mut self) {
+        if self.position != 0 {
+            keccak_f1600(&mut self.state);
+            self.position = 0;
+        }
+    }
+
+    /// Applies the domain separation bits, which must include the first bit of the pad10*1 rule,
+    /// and the rest of the padding, switching the sponge to squeezing.
+    pub(crate) fn pad(&mut self, domain_separation: u8) {
+        self.xor_byte(self.position, domain_separation);
+        self.xor_byte(RATE - 1, 0x80);
+
+        keccak_f1600(&mut self.state);
+        self.position = 0;
+    }
+
+    pub(crate) fn squeeze(&mut self, output: &mut [u8]) {
+        for b in output.iter_mut() {
+            if self.position == RATE {
+                keccak_f1600(&mut self.state);
+                self.position = 0;
+            }
+
+            *b = (self.state[self.position / 8] >> (8 * (self.position % 8))) as u8;
+            self.position += 1;
+        }
+    }
+
+    fn xor_byte(&mut self, position: usize, b: u8) {
+        self.state[position / 8] ^= (b as u64) << (8 * (position % 8));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn shake128(input: &[u8], output_len: usize) -> String {
+        let mut sponge = KeccakSponge::<168>::new();
+        let mut output = [0u8; 256];
+
+        sponge.absorb(input);
+        sponge.pad(0x1f);
+        sponge.squeeze(&mut output[..output_len]);
+
+        return hex::encode(&output[..output_len]);
+    }
+
+    #[test]
+    fn test_keccak_f1600_zero_state() {
+        // The first lanes of Keccak-f[1600] applied to the all zero state, from the Keccak team's
+        // KeccakF-1600-IntermediateValues.txt
+        let mut state = [0u64; 25];
+        keccak_f1600(&mut state);
+
+        assert_eq!(state[0], 0xf1258f7940e1dde7);
+        assert_eq!(state[1], 0x84d5ccf933c0478a);
+        assert_eq!(state[24], 0xeaf1ff7b5ceca249);
+    }
+
+    #[test]
+    fn test_shake128() {
+        // FIPS 202 SHAKE128
+        assert_eq!(
+            shake128(b"", 32),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            shake128(b"The quick brown fox jumps over the lazy dog", 32),
+            "f4202e3c5852f9182a0430fd8144f0a74b95e7417ecae17db0f8cfeed0e3e66e"
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use super::cshake::{CShake, CShakeReader};
+use crate::util::constant_time_eq;
+
+/// KMAC from section 4 of SP 800-185, a MAC built on cSHAKE with a key and customization string.
+/// Use the [`Kmac128`] and [`Kmac256`] aliases.
+#[derive(Clone)]
+pub struct Kmac<const RATE: usize> {
+    cshake: CShake<RATE>,
+}
+
+pub type Kmac128 = Kmac<168>;
+pub type Kmac256 = Kmac<136>;
+
+impl<const RATE: usize> Kmac<RATE> {
+    pub fn new(key: &[u8], customization: &[u8]) -> Self {
+        let mut cshake = CShake::new(b"KMAC", customization);
+
+        cshake.update_left_encode(RATE as u64);
+        cshake.update_encode_string(key);
+        cshake.finish_bytepad();
+
+        return Self { cshake };
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.cshake.update(data);
+    }
+
+    /// Fills `output` with the MAC of the data provided so far, the length of `output` is part
+    /// of the input so shorter outputs are not prefixes of longer ones.
+    pub fn finalize_into(mut self, output: &mut [u8]) {
+        self.cshake.update_right_encode(output_bits(output.len()));
+        self.cshake.finalize_into(output);
+    }
+
+    /// Finalizes the MAC as KMACXOF, returning a reader for the extendable output.
+    pub fn finalize_xof(mut self) -> CShakeReader<RATE> {
+        self.cshake.update_right_encode(0);
+
+        return self.cshake.finalize_xof();
+    }
+
+    /// Checks `tag` against the MAC of the data provided so far in constant time, the expected
+    /// output length is the length of `tag`.
+    pub fn verify(mut self, tag: &[u8]) -> bool {
+        if tag.is_empty() {
+            return false;
+        }
+
+        self.cshake.update_right_encode(output_bits(tag.len()));
+
+        let mut reader = self.cshake.finalize_xof();
+        let mut equal = true;
+        let mut buffer = [0u8; 64];
+
+        for expected in tag.chunks(buffer.len()) {
+            let mac = &mut buffer[..expected.len()];
+            reader.fill(mac);
+
+            equal &= constant_time_eq(mac, expected);
+        }
+
+        return equal;
+    }
+}
+
+pub(crate) fn output_bits(output_len: usize) -> u64 {
+    return (output_len as u64)
+        .checked_mul(8)
+        .expect("outputs longer than 2^61 bytes cannot be encoded");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const KEY: &str = "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+
+    fn kmac128(input_len: usize, customization: &[u8]) -> String {
+        let input: [u8; 200] = core::array::from_fn(|i| i as u8);
+        let mut kmac = Kmac128::new(&hex::decode(KEY).unwrap(), customization);
+        let mut output = [0u8; 32];
+
+        kmac.update(&input[..input_len]);
+        kmac.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    fn kmac256(input_len: usize, customization: &[u8]) -> String {
+        let input: [u8; 200] = core::array::from_fn(|i| i as u8);
+        let mut kmac = Kmac256::new(&hex::decode(KEY).unwrap(), customization);
+        let mut output = [0u8; 64];
+
+        kmac.update(&input[..input_len]);
+        kmac.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    #[test]
+    fn test_kmac128() {
+        // NIST KMAC samples #1 to #3
+        assert_eq!(
+            kmac128(4, b""),
+            "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"
+        );
+        assert_eq!(
+            kmac128(4, b"My Tagged Application"),
+            "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"
+        );
+        assert_eq!(
+            kmac128(200, b"My Tagged Application"),
+            "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230"
+        );
+    }
+
+    #[test]
+    fn test_kmac256() {
+        // NIST KMAC samples #4 to #6
+        assert_eq!(
+            kmac256(4, b"My Tagged Application"),
+            "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7\
+             f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+        );
+        assert_eq!(
+            kmac256(200, b""),
+            "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691\
+             589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69"
+        );
+        assert_eq!(
+            kmac256(200, b"My Tagged Application"),
+            "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d9\
+             70fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+        );
+    }
+
+    #[test]
+    fn test_kmac_xof() {
+        // NIST KMACXOF samples #1 and #4
+        let key = hex::decode(KEY).unwrap();
+        let mut output = [0u8; 64];
+
+        let mut kmac = Kmac128::new(&key, b"");
+        kmac.update(&[0, 1, 2, 3]);
+        kmac.finalize_xof().fill(&mut output[..32]);
+
+        assert_eq!(
+            hex::encode(&output[..32]),
+            "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35"
+        );
+
+        let mut kmac = Kmac256::new(&key, b"My Tagged Application");
+        kmac.update(&[0, 1, 2, 3]);
+        kmac.finalize_xof().fill(&mut output);
+
+        assert_eq!(
+            hex::encode(output),
+            "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa9\
+             6faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b"
+        );
+    }
+
+    #[test]
+    fn test_kmac_verify() {
+        let mut kmac = Kmac128::new(&hex::decode(KEY).unwrap(), b"");
+        kmac.update(&[0, 1, 2, 3]);
+
+        let tag = hex::decode("e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e")
+            .unwrap();
+
+        assert!(kmac.clone().verify(&tag));
+        // The output length is bound into the MAC, so truncated tags never verify
+        assert!(!kmac.clone().verify(&tag[..16]));
+        assert!(!kmac.clone().verify(&[0u8; 32]));
+        assert!(!kmac.verify(&[]));
+    }
+}
@@ -0,0 +1,15 @@
+//! The SHA-3 derived functions from NIST SP 800-185: cSHAKE, KMAC, TupleHash and ParallelHash,
+//! along with the encoding primitives they are defined in terms of.
+
+mod cshake;
+mod encoding;
+mod keccak;
+mod kmac;
+mod parallel_hash;
+mod tuple_hash;
+
+pub use cshake::*;
+pub use encoding::*;
+pub use kmac::{Kmac, Kmac128, Kmac256};
+pub use parallel_hash::*;
+pub use tuple_hash::*;
@@ -0,0 +1,228 @@
+use super::cshake::{CShake, CShakeReader};
+use super::kmac::output_bits;
+
+/// The largest output of the per block hash, used by ParallelHash256.
+const MAX_BLOCK_OUTPUT_LEN: usize = 64;
+
+/// ParallelHash from section 6 of SP 800-185, the input is split into blocks that are hashed
+/// independently before being combined. Use the [`ParallelHash128`] and [`ParallelHash256`]
+/// aliases.
+#[derive(Clone)]
+pub struct ParallelHash<const RATE: usize> {
+    cshake: CShake<RATE>,
+    block: CShake<RATE>,
+    block_size: usize,
+    block_len: usize,
+    blocks: u64,
+}
+
+pub type ParallelHash128 = ParallelHash<168>;
+pub type ParallelHash256 = ParallelHash<136>;
+
+impl<const RATE: usize> ParallelHash<RATE> {
+    /// # Panics
+    /// If `block_size` is 0.
+    pub fn new(block_size: usize, customization: &[u8]) -> Self {
+        assert!(block_size > 0, "the block size must not be 0");
+
+        let mut cshake = CShake::new(b"ParallelHash", customization);
+        cshake.update_left_encode(block_size as u64);
+
+        return Self {
+            cshake,
+            block: CShake::new(b"", b""),
+            block_size,
+            block_len: 0,
+            blocks: 0,
+        };
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let mut total_processed = 0;
+
+        while total_processed < data.len() {
+            let amount = (self.block_size - self.block_len).min(data.len() - total_processed);
+
+            self.block
+                .update(&data[total_processed..total_processed + amount]);
+            self.block_len += amount;
+            total_processed += amount;
+
+            if self.block_len == self.block_size {
+                self.finish_block();
+            }
+        }
+    }
+
+    /// Provides `data` like `update`, hashing its blocks on multiple threads.
+    #[cfg(feature = "std")]
+    pub fn update_parallel(&mut self, data: &[u8]) {
+        // Complete the current block first so the rest of the data starts on a block boundary
+        let head_len = ((self.block_size - self.block_len) % self.block_size).min(data.len());
+        self.update(&data[..head_len]);
+
+        let data = &data[head_len..];
+        let block_size = self.block_size;
+        let full_blocks_len = data.len() - data.len() % self.block_size;
+        let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let blocks_per_thread = (full_blocks_len / self.block_size).div_ceil(threads).max(1);
+
+        let outputs: Vec<Vec<u8>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = data[..full_blocks_len]
+                .chunks(blocks_per_thread * self.block_size)
+                .map(|blocks| {
+                    scope.spawn(move || {
+                        let mut outputs = Vec::with_capacity(blocks.len());
+
+                        for block in blocks.chunks(block_size) {
+                            let mut output = [0u8; MAX_BLOCK_OUTPUT_LEN];
+                            let output = &mut output[..Self::block_output_len()];
+
+                            let mut cshake = CShake::<RATE>::new(b"", b"");
+                            cshake.update(block);
+                            cshake.finalize_into(output);
+
+                            outputs.extend_from_slice(output);
+                        }
+
+                        outputs
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("a ParallelHash thread panicked"))
+                .collect()
+        });
+
+        for output in outputs {
+            self.cshake.update(&output);
+        }
+
+        self.blocks += (full_blocks_len / self.block_size) as u64;
+        self.update(&data[full_blocks_len..]);
+    }
+
+    /// Fills `output` with the hash of the data provided so far, the length of `output` is part
+    /// of the input so shorter outputs are not prefixes of longer ones.
+    pub fn finalize_into(self, output: &mut [u8]) {
+        self.finish(output_bits(output.len())).finalize_into(output);
+    }
+
+    /// Finalizes the hash as ParallelHashXOF, returning a reader for the extendable output.
+    pub fn finalize_xof(self) -> CShakeReader<RATE> {
+        return self.finish(0).finalize_xof();
+    }
+
+    fn finish(mut self, output_bits: u64) -> CShake<RATE> {
+        if self.block_len != 0 {
+            self.finish_block();
+        }
+
+        self.cshake.update_right_encode(self.blocks);
+        self.cshake.update_right_encode(output_bits);
+
+        return self.cshake;
+    }
+
+    fn finish_block(&mut self) {
+        let mut output = [0u8; MAX_BLOCK_OUTPUT_LEN];
+        let output = &mut output[..Self::block_output_len()];
+        let block = core::mem::replace(&mut self.block, CShake::new(b"", b""));
+
+        block.finalize_into(output);
+
+        self.cshake.update(output);
+        self.block_len = 0;
+        self.blocks += 1;
+    }
+
+    /// Each block is hashed to twice the security strength, which is the capacity of the sponge.
+    fn block_output_len() -> usize {
+        return 200 - RATE;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const INPUT: &str = "000102030405060710111213141516172021222324252627";
+
+    fn parallel_hash<const RATE: usize, const N: usize>(customization: &[u8]) -> String {
+        let mut parallel_hash = ParallelHash::<RATE>::new(8, customization);
+        let mut output = [0u8; N];
+
+        parallel_hash.update(&hex::decode(INPUT).unwrap());
+        parallel_hash.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    #[test]
+    fn test_parallel_hash128() {
+        // NIST ParallelHash samples #1 and #2
+        assert_eq!(
+            parallel_hash::<168, 32>(b""),
+            "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5"
+        );
+        assert_eq!(
+            parallel_hash::<168, 32>(b"Parallel Data"),
+            "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206"
+        );
+    }
+
+    #[test]
+    fn test_parallel_hash256() {
+        // NIST ParallelHash sample #4
+        assert_eq!(
+            parallel_hash::<136, 64>(b""),
+            "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c45110553\
+             1b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429"
+        );
+    }
+
+    #[test]
+    fn test_parallel_hash_xof() {
+        // NIST ParallelHashXOF sample #1
+        let mut parallel_hash = ParallelHash128::new(8, b"");
+        let mut output = [0u8; 32];
+
+        parallel_hash.update(&hex::decode(INPUT).unwrap());
+        parallel_hash.finalize_xof().fill(&mut output);
+
+        assert_eq!(
+            hex::encode(output),
+            "fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3"
+        );
+    }
+
+    #[test]
+    fn test_parallel_hash_partial_blocks() {
+        let input: Vec<u8> = (0..1000u32).map(|i| i as u8).collect();
+
+        let mut expected = ParallelHash256::new(64, b"");
+        expected.update(&input);
+
+        let mut expected_output = [0u8; 64];
+        expected.finalize_into(&mut expected_output);
+
+        for split in [0, 1, 63, 64, 65, 500] {
+            let mut streaming = ParallelHash256::new(64, b"");
+            let mut parallel = ParallelHash256::new(64, b"");
+            let mut output = [0u8; 64];
+
+            streaming.update(&input[..split]);
+            streaming.update(&input[split..]);
+            streaming.finalize_into(&mut output);
+            assert_eq!(output, expected_output);
+
+            parallel.update(&input[..split]);
+            parallel.update_parallel(&input[split..]);
+            parallel.finalize_into(&mut output);
+            assert_eq!(output, expected_output);
+        }
+    }
+}
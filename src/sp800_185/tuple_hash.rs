@@ -0,0 +1,120 @@
+use super::cshake::{CShake, CShakeReader};
+use super::kmac::output_bits;
+
+/// TupleHash from section 5 of SP 800-185, hashes a sequence of strings such that the boundaries
+/// between them are unambiguous. Use the [`TupleHash128`] and [`TupleHash256`] aliases.
+#[derive(Clone)]
+pub struct TupleHash<const RATE: usize> {
+    cshake: CShake<RATE>,
+}
+
+pub type TupleHash128 = TupleHash<168>;
+pub type TupleHash256 = TupleHash<136>;
+
+impl<const RATE: usize> TupleHash<RATE> {
+    pub fn new(customization: &[u8]) -> Self {
+        return Self {
+            cshake: CShake::new(b"TupleHash", customization),
+        };
+    }
+
+    /// Appends `element` to the tuple being hashed.
+    pub fn push(&mut self, element: &[u8]) {
+        self.cshake.update_encode_string(element);
+    }
+
+    /// Fills `output` with the hash of the tuple, the length of `output` is part of the input so
+    /// shorter outputs are not prefixes of longer ones.
+    pub fn finalize_into(mut self, output: &mut [u8]) {
+        self.cshake.update_right_encode(output_bits(output.len()));
+        self.cshake.finalize_into(output);
+    }
+
+    /// Finalizes the hash as TupleHashXOF, returning a reader for the extendable output.
+    pub fn finalize_xof(mut self) -> CShakeReader<RATE> {
+        self.cshake.update_right_encode(0);
+
+        return self.cshake.finalize_xof();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ELEMENTS: [&[u8]; 3] = [
+        &[0x00, 0x01, 0x02],
+        &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15],
+        &[0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28],
+    ];
+
+    fn tuple_hash<const RATE: usize, const N: usize>(
+        elements: &[&[u8]],
+        customization: &[u8],
+    ) -> String {
+        let mut tuple_hash = TupleHash::<RATE>::new(customization);
+        let mut output = [0u8; N];
+
+        for element in elements {
+            tuple_hash.push(element);
+        }
+
+        tuple_hash.finalize_into(&mut output);
+
+        return hex::encode(output);
+    }
+
+    #[test]
+    fn test_tuple_hash128() {
+        // NIST TupleHash samples #1 to #3
+        assert_eq!(
+            tuple_hash::<168, 32>(&ELEMENTS[..2], b""),
+            "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1"
+        );
+        assert_eq!(
+            tuple_hash::<168, 32>(&ELEMENTS[..2], b"My Tuple App"),
+            "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb"
+        );
+        assert_eq!(
+            tuple_hash::<168, 32>(&ELEMENTS, b"My Tuple App"),
+            "e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84"
+        );
+    }
+
+    #[test]
+    fn test_tuple_hash256() {
+        // NIST TupleHash sample #4
+        assert_eq!(
+            tuple_hash::<136, 64>(&ELEMENTS[..2], b""),
+            "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec6073\
+             11ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194"
+        );
+    }
+
+    #[test]
+    fn test_tuple_hash_boundaries() {
+        let joined: [&[u8]; 1] = [&[0x00, 0x01, 0x02, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15]];
+
+        assert_ne!(
+            tuple_hash::<168, 32>(&ELEMENTS[..2], b""),
+            tuple_hash::<168, 32>(&joined, b"")
+        );
+    }
+
+    #[test]
+    fn test_tuple_hash_xof() {
+        // NIST TupleHashXOF sample #1
+        let mut tuple_hash = TupleHash128::new(b"");
+        let mut output = [0u8; 32];
+
+        tuple_hash.push(ELEMENTS[0]);
+        tuple_hash.push(ELEMENTS[1]);
+        tuple_hash.finalize_xof().fill(&mut output);
+
+        assert_eq!(
+            hex::encode(output),
+            "2f103cd7c32320353495c68de1a8129245c6325f6f2a3d608d92179c96e68488"
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/// Compares two byte slices in time that depends only on their lengths, not their contents.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut difference = 0u8;
+
+    for (x, y) in a.iter().zip(b.iter()) {
+        difference |= x ^ y;
+    }
+
+    return core::hint::black_box(difference) == 0;
+}
+
+/// Returns 0xff if `a == b` and 0x00 otherwise, without branching on the values.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) fn ct_eq_mask(a: u8, b: u8) -> u8 {
+    let difference = (a ^ b) as u32;
+
+    return (((difference | difference.wrapping_neg()) >> 31) as u8).wrapping_sub(1);
+}
+
+/// Returns `a` if `mask` is 0xff and `b` if it is 0x00, without branching on the values.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) fn ct_select_usize(mask: u8, a: usize, b: usize) -> usize {
+    let mask = 0usize.wrapping_sub((mask & 1) as usize);
+
+    return (a & mask) | (b & !mask);
+}
+
+/// Overwrites `bytes` with zeros in a way the compiler cannot remove, for secrets that are about
+/// to be dropped.
+pub(crate) fn zeroize(bytes: &mut [u8]) {
+    for b in bytes.iter_mut() {
+        // SAFETY: `b` is a valid and aligned reference to a single byte
+        unsafe { core::ptr::write_volatile(b, 0) };
+    }
+
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
@@ -0,0 +1,383 @@
+use crate::curve25519::FieldElement;
+use crate::error::VCryptoError;
+use crate::random::RandomSource;
+use crate::util::{constant_time_eq, zeroize};
+use core::fmt;
+
+/// The u-coordinate of the Curve25519 base point.
+pub const X25519_BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// The constant (A - 2) / 4 of the curve, where A = 486662.
+const A24: u32 = 121665;
+
+/// The u-coordinates of the points of order 1, 2, 4 and 8, reduced modulo p. Multiplying any of
+/// them by a clamped scalar gives zero.
+const LOW_ORDER_POINTS: [[u8; 32]; 5] = [
+    [0; 32],
+    [
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ],
+    [
+        0xe0, 0xeb, 0x7a, 0x7c, 0x3b, 0x41, 0xb8, 0xae, 0x16, 0x56, 0xe3, 0xfa, 0xf1, 0x9f, 0xc4,
+        0x6a, 0xda, 0x09, 0x8d, 0xeb, 0x9c, 0x32, 0xb1, 0xfd, 0x86, 0x62, 0x05, 0x16, 0x5f, 0x49,
+        0xb8, 0x00,
+    ],
+    [
+        0x5f, 0x9c, 0x95, 0xbc, 0xa3, 0x50, 0x8c, 0x24, 0xb1, 0xd0, 0xb1, 0x55, 0x9c, 0x83, 0xef,
+        0x5b, 0x04, 0x44, 0x5c, 0xc4, 0x58, 0x1c, 0x8e, 0x86, 0xd8, 0x22, 0x4e, 0xdd, 0xd0, 0x9f,
+        0x11, 0x57,
+    ],
+    [
+        0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+];
+
+/// The X25519 function from RFC 7748 section 5, multiplying the point with u-coordinate `u` by
+/// the clamped `scalar` with a Montgomery ladder.
+///
+/// The ladder performs the same operations whatever the scalar, so it is safe to use with secret
+/// scalars.
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k = scalar;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    let x1 = FieldElement::from_bytes(&u);
+    let mut x2 = FieldElement::ONE;
+    let mut z2 = FieldElement::ZERO;
+    let mut x3 = x1;
+    let mut z3 = FieldElement::ONE;
+    let mut swap = 0u8;
+
+    for t in (0..255).rev() {
+        let k_t = (k[t / 8] >> (t % 8)) & 1;
+
+        swap ^= k_t;
+        FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+        FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+        swap = k_t;
+
+        let a = x2.add(&z2);
+        let aa = a.square();
+        let b = x2.sub(&z2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x3.add(&z3);
+        let d = x3.sub(&z3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+
+        x3 = da.add(&cb).square();
+        z3 = x1.mul(&da.sub(&cb).square());
+        x2 = aa.mul(&bb);
+        z2 = e.mul(&aa.add(&e.mul_small(A24)));
+    }
+
+    FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+    FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+    zeroize(&mut k);
+
+    return x2.mul(&z2.invert()).to_bytes();
+}
+
+/// A secret key that can be used for any number of key exchanges and saved.
+#[derive(Clone)]
+pub struct StaticSecret([u8; 32]);
+
+/// A secret key that can only be used for a single key exchange, for forward secrecy.
+pub struct EphemeralSecret([u8; 32]);
+
+/// A public key, the u-coordinate of the secret key times the base point.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PublicKey([u8; 32]);
+
+/// The result of a key exchange, which is zeroized when dropped. It should be passed through a
+/// KDF before being used as a key.
+pub struct SharedSecret([u8; 32]);
+
+impl StaticSecret {
+    pub fn random(rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes)?;
+
+        return Ok(Self(bytes));
+    }
+
+    /// Returns the secret as given, before clamping.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        return self.0;
+    }
+
+    /// Returns `VCryptoError::InvalidKey` if `public_key` has a low order, so the shared secret
+    /// would be zero whatever this secret is.
+    pub fn diffie_hellman(&self, public_key: &PublicKey) -> Result<SharedSecret, VCryptoError> {
+        return SharedSecret::new(x25519(self.0, public_key.0));
+    }
+}
+
+impl EphemeralSecret {
+    pub fn random(rng: &mut impl RandomSource) -> Result<Self, VCryptoError> {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes)?;
+
+        return Ok(Self(bytes));
+    }
+
+    /// Consumes the secret so it cannot be reused. Returns `VCryptoError::InvalidKey` if
+    /// `public_key` has a low order.
+    pub fn diffie_hellman(self, public_key: &PublicKey) -> Result<SharedSecret, VCryptoError> {
+        return SharedSecret::new(x25519(self.0, public_key.0));
+    }
+}
+
+impl PublicKey {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        return &self.0;
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        return self.0;
+    }
+
+    /// Checks whether the key is one of the points of order at most 8, including their non
+    /// canonical encodings, which would let the other side force a known shared secret.
+    pub fn is_low_order(&self) -> bool {
+        let u = FieldElement::from_bytes(&self.0).to_bytes();
+        let mut low_order = false;
+
+        for point in LOW_ORDER_POINTS.iter() {
+            low_order |= constant_time_eq(&u, point);
+        }
+
+        return low_order;
+    }
+}
+
+impl SharedSecret {
+    fn new(bytes: [u8; 32]) -> Result<Self, VCryptoError> {
+        let shared_secret = Self(bytes);
+
+        // Only a low order public key gives zero, as the clamped scalar is a multiple of 8
+        if constant_time_eq(&shared_secret.0, &[0u8; 32]) {
+            return Err(VCryptoError::InvalidKey);
+        }
+
+        return Ok(shared_secret);
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        return &self.0;
+    }
+}
+
+impl From<[u8; 32]> for StaticSecret {
+    fn from(bytes: [u8; 32]) -> Self {
+        return Self(bytes);
+    }
+}
+
+impl From<[u8; 32]> for PublicKey {
+    fn from(bytes: [u8; 32]) -> Self {
+        return Self(bytes);
+    }
+}
+
+impl From<&StaticSecret> for PublicKey {
+    fn from(secret: &StaticSecret) -> Self {
+        return Self(x25519(secret.0, X25519_BASEPOINT));
+    }
+}
+
+impl From<&EphemeralSecret> for PublicKey {
+    fn from(secret: &EphemeralSecret) -> Self {
+        return Self(x25519(secret.0, X25519_BASEPOINT));
+    }
+}
+
+impl Drop for StaticSecret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl Drop for EphemeralSecret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+impl fmt::Debug for StaticSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "StaticSecret(..)");
+    }
+}
+
+impl fmt::Debug for EphemeralSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "EphemeralSecret(..)");
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "SharedSecret(..)");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hashes::SHA256;
+    use crate::random::HmacDrbg;
+    use pretty_assertions::assert_eq;
+
+    fn decode(s: &str) -> [u8; 32] {
+        return hex::decode(s).unwrap().try_into().unwrap();
+    }
+
+    fn iterate(iterations: usize) -> String {
+        let mut k = X25519_BASEPOINT;
+        let mut u = X25519_BASEPOINT;
+
+        for _ in 0..iterations {
+            let result = x25519(k, u);
+            u = k;
+            k = result;
+        }
+
+        return hex::encode(k);
+    }
+
+    #[test]
+    fn test_x25519() {
+        // RFC 7748 section 5.2
+        assert_eq!(
+            hex::encode(x25519(
+                decode("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+                decode("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c")
+            )),
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"
+        );
+
+        // The most significant bit of this u-coordinate is set and has to be ignored
+        assert_eq!(
+            hex::encode(x25519(
+                decode("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+                decode("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493")
+            )),
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"
+        );
+    }
+
+    #[test]
+    fn test_x25519_iterated() {
+        // RFC 7748 section 5.2
+        assert_eq!(
+            iterate(1),
+            "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"
+        );
+        assert_eq!(
+            iterate(1000),
+            "684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"
+        );
+    }
+
+    #[test]
+    #[ignore = "takes minutes to run"]
+    fn test_x25519_iterated_million() {
+        assert_eq!(
+            iterate(1_000_000),
+            "7c3911e0ab2586fd864497297e575e6f3bc601c0883c30df5f4dd2d24f665424"
+        );
+    }
+
+    #[test]
+    fn test_diffie_hellman() {
+        // RFC 7748 section 6.1
+        let alice = StaticSecret::from(decode(
+            "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+        ));
+        let bob = StaticSecret::from(decode(
+            "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+        ));
+        let alice_public = PublicKey::from(&alice);
+        let bob_public = PublicKey::from(&bob);
+
+        assert_eq!(
+            hex::encode(alice_public.as_bytes()),
+            "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+        );
+        assert_eq!(
+            hex::encode(bob_public.as_bytes()),
+            "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f"
+        );
+
+        let shared = "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742";
+
+        assert_eq!(
+            hex::encode(alice.diffie_hellman(&bob_public).unwrap().as_bytes()),
+            shared
+        );
+        assert_eq!(
+            hex::encode(bob.diffie_hellman(&alice_public).unwrap().as_bytes()),
+            shared
+        );
+    }
+
+    #[test]
+    fn test_ephemeral_secret() {
+        let mut rng = HmacDrbg::<SHA256>::new(b"x25519 ephemeral", &[], &[]);
+        let alice = EphemeralSecret::random(&mut rng).unwrap();
+        let bob = StaticSecret::random(&mut rng).unwrap();
+        let alice_public = PublicKey::from(&alice);
+        let bob_public = PublicKey::from(&bob);
+
+        let alice_shared = alice.diffie_hellman(&bob_public).unwrap();
+        let bob_shared = bob.diffie_hellman(&alice_public).unwrap();
+
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+        assert_eq!(format!("{:?}", alice_shared), "SharedSecret(..)");
+    }
+
+    #[test]
+    fn test_low_order_points() {
+        let secret = StaticSecret::from([0x42; 32]);
+
+        for point in LOW_ORDER_POINTS {
+            let public_key = PublicKey::from(point);
+
+            assert!(public_key.is_low_order());
+            assert_eq!(
+                secret.diffie_hellman(&public_key).unwrap_err(),
+                VCryptoError::InvalidKey
+            );
+        }
+
+        // Non canonical encodings, p, p + 1 and 1 with the ignored high bit set
+        let mut p = LOW_ORDER_POINTS[4];
+        p[0] += 1;
+        let mut p_plus_one = p;
+        p_plus_one[0] += 1;
+        let mut one_high_bit = LOW_ORDER_POINTS[1];
+        one_high_bit[31] |= 0x80;
+
+        for point in [p, p_plus_one, one_high_bit] {
+            assert!(PublicKey::from(point).is_low_order());
+            assert!(secret.diffie_hellman(&PublicKey::from(point)).is_err());
+        }
+
+        assert!(!PublicKey::from(X25519_BASEPOINT).is_low_order());
+    }
+}
@@ -0,0 +1,378 @@
+use pretty_assertions::assert_eq;
+use vox_cryptography::bignum::{BigUint, Montgomery, U4096};
+
+fn big(hex: &str) -> BigUint {
+    let padded = if hex.len() % 2 == 1 {
+        format!("0{}", hex)
+    } else {
+        hex.to_string()
+    };
+
+    BigUint::from_be_bytes(&hex::decode(padded).unwrap())
+}
+
+struct ArithmeticCase {
+    a: &'static str,
+    b: &'static str,
+    sum: &'static str,
+    difference: &'static str,
+    product: &'static str,
+    quotient: &'static str,
+    remainder: &'static str,
+    gcd: &'static str,
+}
+
+const ARITHMETIC: [ArithmeticCase; 27] = [
+	ArithmeticCase {
+		a: "fe1b14343b106980",
+		b: "d50caef9618a9261",
+		sum: "1d327c32d9c9afbe1",
+		difference: "290e653ad985d71f",
+		product: "d3791ec2587b620b74fd1dcc1c62f980",
+		quotient: "1",
+		remainder: "290e653ad985d71f",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "e6e9d6a12a8161e5",
+		b: "e2b8a158e9f0fcf8",
+		sum: "1c9a277fa14725edd",
+		difference: "4313548409064ed",
+		product: "cc80f915cebe1c4c4a38d915bd6441d8",
+		quotient: "1",
+		remainder: "4313548409064ed",
+		gcd: "3",
+	},
+	ArithmeticCase {
+		a: "c890afe0b0ac88b8e57b47b993f3cfc7",
+		b: "c6db76078d954e50",
+		sum: "c890afe0b0ac88b9ac56bdc121891e17",
+		difference: "c890afe0b0ac88b81e9fd1b2065e8177",
+		product: "9bcbd846d9cfa05c3549e88a2f342b81528ec3dc0f529030",
+		quotient: "10232dd8177c2e6fd",
+		remainder: "158ba031fa698ab7",
+		gcd: "5",
+	},
+	ArithmeticCase {
+		a: "c36492adbb4bb95cda1a4658622ff19b",
+		b: "826355459390c87c",
+		sum: "c36492adbb4bb95d5c7d9b9df5c0ba17",
+		difference: "c36492adbb4bb95c57b6f112ce9f291f",
+		product: "6384e37671bc5c3ef13316d60f70b83204dd77ffec2a1f14",
+		quotient: "17fa0eba53c59e008",
+		remainder: "12c172ce53212dbb",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "84b184cfd6dc3c3bff72b36ba95d5ec73fc31a98c7fd59a0",
+		b: "fc96170a27b1519df2e4d9af707c2899",
+		sum: "84b184cfd6dc3c3cfc08ca75d10eb06532a7f44838798239",
+		difference: "84b184cfd6dc3c3b02dc9c6181ac0d294cde40e957813107",
+		product: "82ec8ab19fb981090fda147fcd60621d4f12b0bff6769eaa60993eb5206ae8a2daeb5edc85eb90a0",
+		quotient: "867c9e2947dd9077",
+		remainder: "7286d6a8c972cfe1a33bc1d32c5b6a81",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "c1ec61502ae1fc8851a264abb921a5c0fadf6031265b9716",
+		b: "9573164a9eeb0203b0f2b5d2a7977bac",
+		sum: "c1ec61502ae1fc88e7157af6580ca7c4abd21603cdf312c2",
+		difference: "c1ec61502ae1fc87bc2f4e611a36a3bd49ecaa5e7ec41b6a",
+		product: "7135c2b6370bb9ef0d67e0a2c08e287ec503681681be91053fbc3ed518429bd9da101c153f1b14c8",
+		quotient: "14c2e776f27980ef6",
+		remainder: "83549529fe1724df4103f93d2fe757ce",
+		gcd: "a",
+	},
+	ArithmeticCase {
+		a: "f9a5c140bb7aa4415c367095b9eabb84129d9ca5374379d5bc1dd3d8d74ec826",
+		b: "c8266838ddec9d4f6ebeb4400873189296772783c8c8d2761eac708b0f3b5607",
+		sum: "1c1cc297999674190caf524d5c25dd416a914c429000c4c4bdaca4463e68a1e2d",
+		difference: "317f5907dd8e06f1ed77bc55b177a2f17c2675216e7aa75f9d71634dc813721f",
+		product: "c32ef3380d430ce56e7cf0721fb84dca5aa6ad38a472affb61f8c43005042e72a2c7fb31e8640ef482d217272ac78bd63e7383eba40a7329fe722cc6b5263d0a",
+		quotient: "1",
+		remainder: "317f5907dd8e06f1ed77bc55b177a2f17c2675216e7aa75f9d71634dc813721f",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "b9ae678d515b5b07443d65ec0db41c812d808c68fae19a66e69c9cfdb058928d",
+		b: "a4c23874e9c8e3801943aaf51faf3b7004bee4f7ab81fe968e24341020003f96",
+		sum: "15e70a0023b243e875d8110e12d6357f1323f7160a66398fd74c0d10dd058d223",
+		difference: "14ec2f18679277872af9baf6ee04e11128c1a7714f5f9bd0587868ed905852f7",
+		product: "77809973f47574e39154c9d1bc8c1e87675d732963b200625a0ab34e859ba29c98c370738eb70193b6f6220119dfc1c2cbdfdb1ca48968b535934a2cbff6919e",
+		quotient: "1",
+		remainder: "14ec2f18679277872af9baf6ee04e11128c1a7714f5f9bd0587868ed905852f7",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "97f0e77daaebb6861b517272255a3355664288d84d299e5e0eb12942d8b60441cbeeac87e345923a6f5567212e9d7aaf93e9f59b9bb36f0a06aceac69eb1c2e0",
+		b: "e5dc083c637e4695d1a80f5fbaa9d0a2a305812369683dada3",
+		sum: "97f0e77daaebb6861b517272255a3355664288d84d299e5e0eb12942d8b60441cbeeac87e34593204b5da384ace410813bf95556458411ad0c2e0e3006ef7083",
+		difference: "97f0e77daaebb6861b517272255a3355664288d84d299e5e0eb12942d8b60441cbeeac87e3459154934d2abdb056e4ddebda95e0f1e2cc67012bc75d3674153d",
+		product: "886d16fdb8a3079e92881a577b9ab7a19dd88b50977fb90dfb39ad1a7ad875b5b5d436b3bc827ca3981e4ce0c2ed311d4e0038de49845bfea15c60ebe6ed4df4958a72b44152815e71cda45ea03c1908a4b3fb4b1b9b4074a0",
+		quotient: "a938689a60232e17b9a48ffdd291b1c475838b2e948021a59f7314691c7fca01b7834ba5b883fc",
+		remainder: "7fb6d83c1f35d0f8e27b7773919908c87f460b29ac95f86d6c",
+		gcd: "3",
+	},
+	ArithmeticCase {
+		a: "e15ec917073114388a2f7f42479d6f39be93ba2a88457b9c6cf8578266df288eba6c1e33f21b8ca8755ad4f613ef16dd228c092f15fc0d51058bd2bb3afbb5fc",
+		b: "ce23892f622d23ed4952661ac776b83ed8a7b5dbec03a5c5a7",
+		sum: "e15ec917073114388a2f7f42479d6f39be93ba2a88457b9c6cf8578266df288eba6c1e33f21b8d7698e404584113042674f223f68cb44c29ad41aea73ea17ba3",
+		difference: "e15ec917073114388a2f7f42479d6f39be93ba2a88457b9c6cf8578266df288eba6c1e33f21b8bda51d1a593e6cb2993d025ee679f43ce785dd5f6cf3755f055",
+		product: "b5798e8b7661be53dd5a047d685353b82b18b5fd597db7fbec676ce1fcc7ad6b0f494de971dab0c2709747ccd2b40de211801aed87104295839a66df55f8133dbba11558a0280f25a0e101b1f661912c14ffa4dc356caaa364",
+		quotient: "117e217daad966a3377640a14706871d3c0fc7f5f3a40e10fd66c80f49acd9e94de1c9d4c5d0735",
+		remainder: "21e78234e6c65ebd75789631cb5836cb490cf4d1738f973969",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "b879dba43de38e8cf1fe34e4f630831919775ab3d930f04c99e9eafe6b1b028a876a2d839beb204c15d748aabfe6f1fcb5b96adea7cad5cd69cb455de399edebe513324d20ae04b9b485782ca916e2b0fc8dc44f1bfd008ad6bad644c059c089da099928dfe5395bae9b3deedccbbd13b8626f980a22fbf9b7591f2810d3af43",
+		b: "edd6cd0c3626fd2b8ac8fd3b46a42223ab254c20f7b6a0dc153f00a973342e0dc9f6f009691dec50b8d74119ae116fd075abb1c3bbc17f895cfa76c56ff8a49f",
+		sum: "b879dba43de38e8cf1fe34e4f630831919775ab3d930f04c99e9eafe6b1b028a876a2d839beb204c15d748aabfe6f1fcb5b96adea7cad5cd69cb455de399edecd2e9ff5956d501e53f4e7567efbb04d4a7b3107013b3a166ebf9d6ee338dee97a4008932490325ac67727f088add2ce42e0e215bc5e47b83145395ed80cc53e2",
+		difference: "b879dba43de38e8cf1fe34e4f630831919775ab3d930f04c99e9eafe6b1b028a876a2d839beb204c15d748aabfe6f1fcb5b96adea7cad5cd69cb455de399edeaf73c6540ea87078e29bc7af16272c08d5168782e24465faec17bd59b4d25927c1012a91f76c74d0af5c3fcd52eba4d4342b6bdd44e617c705a5ea862a0db0aa4",
+		product: "ab6399f70a36054ed3a7198a8b63dee23de4314cd26cf4cf2edd08eb71a72bfaa37782f81290fdc3df05c0d0122ce93599401fe1e9ec383df6927c8468a4a5dc424eb21600f4c3237c7ddf5ef72265f5ce6de812984acd34c3cbf1733ded1ceff4811b048d402d39ac55f28987a346aa9b6cf7356602d1fc8f39acf037c44b017b5b9b515b733094886334690f78a52b9a120db9fbd3c9056e415d026a8de10346cc82ef4b86f901b8adb2b57242ba234df4796a2c6bb78d4788db48e5a8c69d",
+		quotient: "c68fef05150d0d63197967f2a38f6cb8998ce4bf1c12deed969126c7af387d73c968295c4f30e4a6def4703defcca6088abf09638ce060d7d8a2c50c736d7f90",
+		remainder: "e7c75ac64b0a30f6b8dd9aed1683439a62aa970045baf380dd60380ba9057117349a9a283f4cdcd47fc9b9e4a47a84eaf0e4a2164d88f762fb38ec47369934d3",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "8c3273fd3cb9abb3fcdeac7e107a48f73da45f50aaa347c8780248a32e3d79e539bd6c0c07f1327536be4a292a0c76938d5b078036dd706d14a06dd8bb6578c470e80f09c0499c6e4b7437e4e2971d60c9a382b2613b664d9f685032ecb42538ced3715c5363a2d0b43c6868e5bf41f2609341e646e6f2bc3fb43212afa633be",
+		b: "bd66f8389946d722727e269bff5da487d295f3981e571249b8ba857bad141abf625b4f683a76b2781f390b7c543df0f97e2749dd27b7e78b9f691e3191293fdb",
+		sum: "8c3273fd3cb9abb3fcdeac7e107a48f73da45f50aaa347c8780248a32e3d79e539bd6c0c07f1327536be4a292a0c76938d5b078036dd706d14a06dd8bb6578c52e4f074259907390bdf25e80e1f4c1e89c39764a7f9278975822d5ae99c83ff8312ec0c48dda5548d37573e539fd32ebdeba8bc36e9eda47df1d504440cf7399",
+		difference: "8c3273fd3cb9abb3fcdeac7e107a48f73da45f50aaa347c8780248a32e3d79e539bd6c0c07f1327536be4a292a0c76938d5b078036dd706d14a06dd8bb6578c3b38116d12702c54bd8f61148e33978d8f70d8f1a42e45403e6adcab73fa00a796c7821f418ecf05895035cec918150f8e26bf8091f2f0b30a04b13e11e7cf3e3",
+		product: "67b9a3ac0c12eae6bfb5480f20b5d28adb08f80e1f3eb044e31a5060d6ecd479d77ad04b6cc9c299a223e52d4dbc813a4de577a28446c9b3b2c9be0c35feeaf675b455998d830c73b695fa87ab252739dc9abac6f679d25e4630aa21b093ad9762dff60ec63ff6d8d6d041fead748c8165bcc72582443c77a6e4202564133b7b6d9b391f37da0ae1ca92c4306ed2ea4afb9a1c5760cfdf087c433a91bac4787e43a790b2b8d693f512d2419ce00947d735fa8ec7b867d491c5c2df811158058a",
+		quotient: "bd7e490ec1adccbf6a6bb13792a8be4e5df87d27dedc92f23d7d42d2f076246ccee38c634ca75e02ab4fcb6e0fb0a7aab690f7c65b970d8ce84711bc5d39a36b",
+		remainder: "7ef82bc7d15e5404a495c53ffc40e1a9f70038c200eec8f50c7802901627a36a804834001fa9216fb170bc178d7a2d4215462094f8217063708045b7f8fd1235",
+		gcd: "19",
+	},
+	ArithmeticCase {
+		a: "fdd27234cc286f1167362deb4da0328d6aa78b5c965f23de9f283bbf4789af0db4e49e2ede71f58c1f7fff77d45b3dfb3b08cd119ca9c9146134d256da9b5a34424ce0d226d98b3748b0891f4ec9f4e9f48b6bdde7fc83d2ee9ce484ec03bc5f66380fd3ae83b88936d04d195a1dcc89b19788b898610b63aa9f73408281e2ae0abbf3f3125c34ccf6254801c8a0fb3ff90146c6ba4a4bcd694742f42d50711feb77a646a652cada84ac06d18353272e272f17afa3680813277ce18df113750fb55f931296ea632b2db78b5f07a3d8e45a930220d26affa0455f8adee4cad7535b4960cff87ee76c648b506e7f71091fc609aaf38d743109316af664b68a3f94",
+		b: "c1d7fb3606dc7b0988fc6626b54b0414d1289b36b7698757ffb3e83bd36479e39c4b563a0de7be86600acfe8d373f7d691b9cf2bf0a2882478b94d187ec2d76e3f3d66dd1274955c4a9b1f2479679d16e74f0fee2eb7c4bd7bdad4c03ba811ba70f361d79819ef02784966268008a8c218fb06e33d32382fd9d3be82b10a12c3",
+		sum: "fdd27234cc286f1167362deb4da0328d6aa78b5c965f23de9f283bbf4789af0db4e49e2ede71f58c1f7fff77d45b3dfb3b08cd119ca9c9146134d256da9b5a34424ce0d226d98b3748b0891f4ec9f4e9f48b6bdde7fc83d2ee9ce484ec03bc5f66380fd3ae83b88936d04d195a1dcc89b19788b898610b63aa9f73408281e2aecc93ef291938afd67f21ae287debff54ca29e1fd71b3d32568fb2b3000b4eb0387c2fc80b43a8960e4b6d6ba56c71f04b8e8e6db940a9037a0362ea66fd64c7df49cf9efa95ef8877852aa83810b75fb41e2120f0122c45dc13a5f9f2072e90dcc3cc2a79098d66edcd4b694ff79b1e1df04b1d6caa669390b3eb4e767945257",
+		difference: "fdd27234cc286f1167362deb4da0328d6aa78b5c965f23de9f283bbf4789af0db4e49e2ede71f58c1f7fff77d45b3dfb3b08cd119ca9c9146134d256da9b5a34424ce0d226d98b3748b0891f4ec9f4e9f48b6bdde7fc83d2ee9ce484ec03bc5f66380fd3ae83b88936d04d195a1dcc89b19788b898610b63aa9f73408281e2ad48e3f8bd0b7fb9c36d28e1db1355f72b27d8ab9002e0c47569935ab859ebf73c4f2c500c986b0c5424a136e8afdf2f5795754883b2c57feeaec3947572509da176222c358475cdcee31c6c3a8e3c3bcd7343f232a3b33ae2c984b61ea922c598ea55fef86064f869ec41ea47ff68605dad0ea4105041f8d9579737e205802cd1",
+		product: "c031ccea9f6d56850f1ad96603ad33abdc2b5bbd3a8b1cfc09526a88cd4f4dac9b289952475761efb0bda1aa5aa93b4e651af939d5f1ff2c52f51bded0570a907698a559fc067d54d8381cfdae3588a36c88995ee805dcba4dc5b7ed413994ff895bd34a8092fe07ab02e9d8454fd8b16abb2294be51d3f0816287ba420b9b7e321b9242a74a27ed23f6ac7ba70e6146f518c98ad4cd9e7c4d5a504690b5fadcb9538c72b5363ed708f7df1fa515cdf0ed1ba2051283c14886499c8ff6d6dfa9c98a9d0ee59f613769626aa6d34b93ce461d0be871dc305d1a48c2f72253c6188ad809e80263739bfa1dc5eabf17dd43117a6a802e4f4c94cc70fe0ad3991dc1875bd471d2b957893f489f3da1a0b4c0c1ad2903f8750613bc59e5826fe033ceb1c6873921ea7918e232641920c89eafc3c0582f4b48ce298a330f7b4bb6e2d5bacbfc3a77d714e72d4ef9e82edb972897496a1815f4917946df32381047bf29d5e0d29bee75cadb5bee015afc907e0bc87408138f9a70a594519c10938ed5bc",
+		quotient: "14f35e6cb704b9ec7eed2a904c17657175703efd6028db8185d4e09def1e54387979586ba390ecc79aebd3e8efea578d6739fa0c89d69cf34191f4961ed037571a8344290e324f34ded5a6634d2deecf0daf8e662dba40ba6f7e56a76d73ea1f40d9122065332710e5793d7e0db5325e845bc3ec7986f8d43aa793f166a93dcdd",
+		remainder: "3570ab91d6338cbcb86f05acf17a466890688470db52cc2629a41ad2e1936d9113edfbccff687689be53928fec8d462b9de9684d139a21489ded25d23bee8fddf77f139b69b8d57fd1bf486100b08e26b1e213d3deeff9f053cb9a26a28fee03793d74ea858a692e7e04ce2f302995654077eef9073498019de6c11db4bf793d",
+		gcd: "7",
+	},
+	ArithmeticCase {
+		a: "ef762d11cd845d42a8a19647c10eff69165b1d787c0553e646ceff512e3aefc45e943aff308ec2540a4152ecb885219089b7f032915debc51057f1ce7d54b6182f2a4a3db0821e8782a2b06d96201a2153cc9dc886e5943d0b55b8d32fabc2d9b9ca9d5a62c238cc3f0afd27ba2030f98689aff9c4304ec58e93a029061c8cfe9f3ffdf8455af7e2d959cf49d2d6c67a3bc196e706ebdc4860044a3187e59eb5971382cea7b828f506ccf5b22603cf59668c3283838906159d915b1edf70e8612a6c5c3038c8f228042f352cde1a2eac6d7db2292c193070946957b65ddc7c4ceb1c2c03528c039b7fa2068dcd944093699c839d3207ed35a1644007c288a9c4",
+		b: "f50f5bdadd2abd89290f4d7e59cf13ed639f28c85ee110931263026315c3f95c2dd86ceecb7f40e4831372285f4a74cdb7c0afaac0a0d55670493046ab6f40b3519ba5c0fff117f1e4714e4dd5d5c0598b663fb2111975b9ce545479638179594df0019a1b2c8ae04f75e40eb6b46d0c044b1287705e9af3db35b6b3da745a41",
+		sum: "ef762d11cd845d42a8a19647c10eff69165b1d787c0553e646ceff512e3aefc45e943aff308ec2540a4152ecb885219089b7f032915debc51057f1ce7d54b6182f2a4a3db0821e8782a2b06d96201a2153cc9dc886e5943d0b55b8d32fabc2d9b9ca9d5a62c238cc3f0afd27ba2030f98689aff9c4304ec58e93a029061c8cff944f59d32285b56c02691cc82ca5da679f60bfaf65ccecdb72674c949da99811c4ebefbd733769d989e067da854e44271e4ce22e4429db6c0dda8b658ae029147c0801f138ba0a19e8a0837ab3efef05f8e3f1db3d32a62a62bdac2fc15df5a6390c2d9d6db88e7bcf17ea9c8448ad9f6de79624a26688297c99f6bb9cfd0405",
+		difference: "ef762d11cd845d42a8a19647c10eff69165b1d787c0553e646ceff512e3aefc45e943aff308ec2540a4152ecb885219089b7f032915debc51057f1ce7d54b6182f2a4a3db0821e8782a2b06d96201a2153cc9dc886e5943d0b55b8d32fabc2d9b9ca9d5a62c238cc3f0afd27ba2030f98689aff9c4304ec58e93a029061c8cfdaa30a21d68303a59b04a81cb7907b28cd8226e1ea80acbb54da147ce7221a559693b15dfdc38e81083b98389c6b95a8baecb82d8c2e830bf2d482ad83401a7add8d0b66f38d7da361fbde6df08446e52e21772771affbab6c615033cfa5b02f39d2c2a69375f78bb302c227f16dfd38765517115c1a95241c62e8953e8144f83",
+		product: "e53a76fa69436ccfa16c201c4adffd97f8ddea16cf71b4f0d460dcebd1fb6e1ca08af95ccbd5f313375ba7ec3fdc4a8a3b8db6deb60368efff108e45f924da500cc00f2bbb2a0e55cd150eb1cb7c08ff37f379927ab0898f2b328d80b6e58ca36da594ccde0f94fd3b4798d157d32b2ad9c15300f15b86c6775295371cc5a97381486bfa805b44d83abb2e50bf9250755bc1a46c0ea08d157cc1e4933f35f1f4542493c1c2e23c9797349c798a368e8a6a91b5ece930114341082c0b57cac6e4187f00bd136bde2a518a3b3c9331c50d1dbbf39b317b61d449017f3e928429ed6ae0009443ca2d88ef070758afb2e7d7b82cbf9b78078bfe0e957226c077a2def469817fb018932906ec01ba7b0b5bc7cf848d02c847ec2fcf8acbaf08b2016a518f8fdc5ea875b2177e6f55d3ea4ea139ac51595789e91a299a68c6ad5f61c6c13739468e88005c450412b2e0abf58055c2a2f7fbc2bfdf8a56394100db2011ebc60e1eb75c0ad0b39bc34ad421ad1259423d109c9a1b9ad7f78ee6453202c4",
+		quotient: "fa26d63e4155573da19631824e2cfeae980735a16d2187fff43c12d09452ab79a2969d28393c218bb53e77e4128b74a4aac73f035d60803b1368dc14304c6bba5632f86c9c767baaacd597656795ce92d428355978450bd250d57ca5bcec5fc90b59292a733bd91d4d62ca3f86756330d5398b0d002abb993d2a8289ca78f438",
+		remainder: "57c839f7c4d658435d6112564dfa6538d2e5b9e6f40e3abe9452e182fd07668d53f020895b3f7c695ee9a0f86967f56cad512f014d536b3381d6c77c676faca9bdaa923932102d3aecf867105e212d238151e1b1162f43ead1d990fabc8e9740bfc0dcabfc4cbb78fc3cc7af00ace170fd5d3da69d275ff6a30f57ee7a96f78c",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "8289a80a168ef7c7b0a8c1ed6bcb237c7ebfea762c4e7905056efe815e8b67fe9c6d9f684bd67de10fb526f833e32f5ad3ab852609b2b13f389f9dc9a7b12f466b7f1f70733d3e3aaa1dcb4ef1ba6754999711d26a265266c9c2f873edd718465fa69b543bd14b1af38be35ca5e03df25fae88b903205018b80526ccc0b9e7b68c1afac48b660a81e055937d67ac325a27f734314e2c7a39929d0e3bfeaf80c343aaefffd871c437113ffafb17ac264a4930f8474545c66f4ad191d5da79f819ed20c5d44032fb0d48981204480a798b524bbf12aa38930fd4f331d4317aaad90d9201db85c00c97469f981fc0f039ee0687df3dd010138ccda17d223ef6d16ef01e0ae4e76e9569294d76acb129ee2a92957c5a45bbd4d9b391da208846b4f99df2a886af9b7e9fab5a2d8540a3c276a897aeac7bdd376d677640ade2f5233fc5abee9a87b50493c57c8dc978eddafedf7177c5e64eeeedc0bcd1232b0e4f36faaee04a588ae9ae46141cf6c7984564ef33107dd992369c86b0a47c4681e037b850bc8df6bbb1472634511dea114928040412efe57793531e4d9514b11e7c7ad45f28a1e0dd6832d2fadc93f941ccc26c7f6326e709ce8c0d735fe731e7c7670404a40ad63298ba52c6776a5020ae98ec6defb7e730adaf4ee0a9e950bea82c03b3e59bd7c57e0210b4d06716e23c24ea1c5b9b28bd1bdb43a6b6b42c48b8cf",
+		b: "6d5f115762889e5167a3379189930b72f0fb6e5bdd5d0d49c87a2db2e749d526d0a09e79ec41bac4ed148bed161f1e2aa4c756329e165d00ca9031089e4b6165272e56b408b7c8f8e53b0954453a50df63533e826e554660f82a440173bed4c6463a7a2691566f6eaea0f945f2846af5163ddc6d042a6662f84fe908c51051e6d5e829b9f8d288bbb255cf4ff5654099c20b95ffd00c3307177bb433b05f4eb8e11fd9d5ce09ceb5dfc94a22e56d4081bb56292be4a90a6c8cd4535a15140f0fa836aa227512778f679ab58aa25c65121ea3c2bcba80650dd872cfd5fb53b3a10c3b0bd29f380bd3cca98c8fdb70cc2d78e4151cf68311868fae0fee90f732b8",
+		sum: "8289a80a168ef7c7b0a8c1ed6bcb237c7ebfea762c4e7905056efe815e8b67fe9c6d9f684bd67de10fb526f833e32f5ad3ab852609b2b13f389f9dc9a7b12f466b7f1f70733d3e3aaa1dcb4ef1ba6754999711d26a265266c9c2f873edd718465fa69b543bd14b1af38be35ca5e03df25fae88b903205018b80526ccc0b9e7b68c1afac48b660a81e055937d67ac325a27f734314e2c7a39929d0e3bfeaf80c343aaefffd871c437113ffafb17ac264a4930f8474545c66f4ad191d5da79f819ed20c5d44032fb0d48981204480a798b524bbf12aa38930fd4f331d4317aaad90d9201db85c00c97469f981fc0f039ee0687df3dd010138ccda17d223ef6d16f5d7d1c3c49f733ba90f0ae3e3abcf99d8390eab62318e2237c0c07d36f908a206e9347009bdd3964986eb97256c2e0a14d5f04df19f3946e320671b6814084a4ecda454e906ccd8caab7971dbe282bde42c4b64854a4354eb8e715249ecd23fd40e95a70e9e1591cf4b5163cba1cb05a0570eceaddbc9cff7f008d850b92321e8e38e647ef8e3a02d88a206ddf7689c1c60fa8efb583c65a35c94948617dcb33b57f0277aee736e8b2c426b6deaf0d4427d58c52cbb2d8f89a47b34146fbd676ac3b4e2d4b451049ba612cf4f27d13ab0b11b274a1b112bd275379bf4c125bcd0feef16e76fd89d5dd5e5cf6f2530852630070b81f402d61d354c6a2bd3feb87",
+		difference: "8289a80a168ef7c7b0a8c1ed6bcb237c7ebfea762c4e7905056efe815e8b67fe9c6d9f684bd67de10fb526f833e32f5ad3ab852609b2b13f389f9dc9a7b12f466b7f1f70733d3e3aaa1dcb4ef1ba6754999711d26a265266c9c2f873edd718465fa69b543bd14b1af38be35ca5e03df25fae88b903205018b80526ccc0b9e7b68c1afac48b660a81e055937d67ac325a27f734314e2c7a39929d0e3bfeaf80c343aaefffd871c437113ffafb17ac264a4930f8474545c66f4ad191d5da79f819ed20c5d44032fb0d48981204480a798b524bbf12aa38930fd4f331d4317aaad90d9201db85c00c97469f981fc0f039ee0687df3dd010138ccda17d223ef6d16e82bef98d84e5f717c1aa3f1b2796e2b7a19a0dfe685ec78feb17ac6da0fcdfd2cd520a0cc359c3dabe45a1982a84a44c03d05879ddc6da6c9ce60fa544a9c1da9e7d97e67efd3b9ae041847533b38a1f7c1e394377f9a88cc8928d21b74f7a70b4746623c7347a3f977323b0d513da6fd8f53410d567d0398e60bb7381718e50e26892d3fde9288b73de81cdf4ac088e41f87cf0156b604c06d1e0e100bf2dc1f33f4ecc12d3997cf331927113d48c40b12939fb0260c41f809f0c8d1cd3b8575bcdf9e86120212aeb2bc1dfadc44986cdca2cfb2cb048a1766dda13556af48af778d9c9388d722e440b43d73b716ff77138467e323a0a54b3f8a6c59b518617",
+		product: "37c516795a8861aabc78994d96d4248947bd9d65feb4a42259598fda82c7904fa80dbb2e7c4f65e85d13d4365ec740fa0efa2c985aba21aa9088932d479f09228a781477afd2c848cd29427775f7bc8c3fe3ddd859c4910f896549df67371d36a086a40141431d8383561e389fcd6c1aad044ea48b7e3d8381aecc782c596db2c94894a0f8e08c4226d88e14be29a1c3b6aca1a1a7f62b136a7cd4d638b8ce2e538eccfa9c977a288629a2388bf02a49b1650073019a3641d0dc37e9ff0d19b040991c0acd09c6df5b0a14332812f1e87a3b49c52cf18d8f3a4e431fb275f7fdabcc26c047fe8d0509f8628afb6a4ac426e8383aa2b776e64dd335d815c8d9b7cbf3f55dc86bc065e99023d12fe0a443b25ce60d27ba8a9c708bfd401b96a58fae7c16245f5a4fc074a0406e985c5c97b6eb9f9a471f02f1bd1f4322a807f97a26365441a80bb4774fe98f8e1d8acd6beca3e2011075b61c8ff2bf37346bbabd713f6291ba18753acecc383f7d6c6ef43cbeea387824646f5f3e232563be068bc0e37c0610e9eea28fa0d504d9cbdd30da594569bfa823586182dfcaf5782f4458bba2e3a77e8e2d61256773420f6a000edb682f51b0f4b7a1bfc6e6e905af2e685e5989ddbf1fee1f25ec3052f11b4fede18641275cdeeda1cc0bb1b0a3974fe50bbeffd8c58c96e73d2d35b674742bc97776ea756f9d6de7f4ea94648fa7a196ac6942e2d8d3b794d5ff8993c9bd2795a3aa7447abf13f33b688ca786020c4e0cd62316cdac5cddc9dd371b6bc0fe1fa74250e335288da7a732f93277d9cad263d2227a64b92fd9009c6dd60655648d4be411ba0cf10df761134533dbbb015fb6779a9d699a3e5743c456cd0033063e1140cddf402e54e643a1f7a394f604a50717a688394c43a6b9d5013eab9bed1c217f5d2e509048dac0573df1eb20302ff883a0a99d41baa9aa922dbe9c079a094ba3cf0d9cb85c35943d05e3c57235880efaaa74d158acfc87929c510b86936a8de788fb263ff7a7541418edd2015bcbfd9d19aa4f5009ea51944c66eb68b2461c8037b6921838b7c6415b6c81642c8",
+		quotient: "1318b041e9b32e35a094dfab1fb5efbbc6d64a448a2dbb4c375589bf555f166adaf678a0af07148019d06f2ff842bd00452e4f3bbfc9506adc7a77510524a876226189974c7f9e4c3a4f88d20b38f59b065ff0022427f6033af9d52f5f981e0cb8afb5f287036e588537006d118e2e173b5b2d3c65448bfda20e38e2395f9c5fc8f690c37445706f33f07c6e143fa26d87d5d17f98dbc68cc52c7962a28409d22f5b305ab262f4a2ac5ae5082c9c2a3966a42a012b0d2bf81d672f05c4eefa8cc8c5ab77131f8005d7b4e8fe170d77496c21d674d9bf529c640b2f02c65dc65ed4472db6803fbc555f6c863cdbe45fd1472dbbc19cf7086a3c2be418ec457a5f4",
+		remainder: "5642435b5d37a30b832c3cc2bed50eee846f5ad11f58245eb2587f0402ccfb621862c5acb3deecab22b840ac28801a6420c6571ace1774af3ac4f74701343e8be6c25f65d0c67e76107f47a9f16f94412f073d010f5bb1ced5439cab3bf9bc470f7bc2e7cfc0ad7ca813e63946f1e9fa8a999a62d36cea0def65b27cd1379236107534fc326fff3cc4d5b3cd43dcf570541fd2d37f5dcd22fa08a3d21cbb4aed89b7da85fec18e60fa5d405cae02cbb159a8ca9f7535a3a033a3045ff0f903e292f17198ef51fc247a2fe739c2691300defc00a3f3424b3c7f5edf757e2d3e901d8e4f5f0e6049d8783f19d5d28968e2da139bdd92d3a472cedb62fb9073c96f",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "f0aa27cfd113d81f7b9caeccf23c43c927470236dc17c587752852cd79a06dfd6efb8bd64a26857226cc8bad726a696705d69e59186f9c8af0aab0b425efad104dd0bbe3c38a08b19fd876ff62e5d1bcc85ec25358b9b866e91a17977ac7c5377210d8e359959eca7f46c0dd6a75c427d8c43591826b1a8f590f1957157934d24a9dd66637f3f7b27a5aadd74079e55e366b7c9d8ad8ab613df9018a752586f1b7963a8b2fb5507611736202bf3aa51b2940e669f29dacdb15578b294c8c2861e19fbc2d2551cbc81366e4d8f30fc4073e2ed13c5d60f75f47b2cddafc8923093349204a1738f50125c77007a5d1fd625a43536cc81ed30e1c9e716aeedf6a65b45f556e8e39d05e12abe28914fbc7ec14c5775d7e762a8ac857c7d401e1b3df9569edc159e753972bd76dc325e3b78140a0e0f5fc14003f20f815872cb0236cdfa21330f493ee753f1ecf02ccfba3885378fa91c9bb4e6aefb9da5a44cb1ddee5d46528cd5278991670cfb214f58c82a95acf02d441d53a06aac77d29046c07393e4993861257af3ea2cc611698fa04bc5ab1987e0849477c4008ea452973dacc6ff3a4450445eca2c49975fc24498cf189b1b22f22a0d934e6ed814a0c2e2eb15c1bc3afe853ec28b4e74482d19233b2179335851063d1d7d8bab5e607251a16437171eb59415e9655fb7af49f026cbce218485edf7f633322cb6c78718eb5",
+		b: "7530af59b644cf903c488d8e660587e8d3eafa59f6bd7709576cca34d6306666f550b8a76887b4ac31d534b13f24813098099b6270fe974dc7f68fca048414391805b93df3d37fbd8cecd56c216bf11ea3db2ac067fc539d279846fb6dc9b6dc9ce195a61958ea56bd83ad9d3abad1847738904080e6b200b31b58349811d8dd11b9636feca42b346cef4be497b2c4b9b800f22fab87e36ddd35e623bfb7d6c4760516e586fd2f269ea5ee62e652315ea2f1160f83e665960182226df0c4790736b32ba4555c78d5fb4550b73d64c353db2b075642b7d02b49bc5b884a287e138a60cbfb1c94bac4151a7e7b58a4940b16beac393b0b3a8651a2e227ebae655f",
+		sum: "f0aa27cfd113d81f7b9caeccf23c43c927470236dc17c587752852cd79a06dfd6efb8bd64a26857226cc8bad726a696705d69e59186f9c8af0aab0b425efad104dd0bbe3c38a08b19fd876ff62e5d1bcc85ec25358b9b866e91a17977ac7c5377210d8e359959eca7f46c0dd6a75c427d8c43591826b1a8f590f1957157934d24a9dd66637f3f7b27a5aadd74079e55e366b7c9d8ad8ab613df9018a752586f1b7963a8b2fb5507611736202bf3aa51b2940e669f29dacdb15578b294c8c2861e19fbc2d2551cbc81366e4d8f30fc4073e2ed13c5d60f75f47b2cddafc8923093349204a1738f50125c77007a5d1fd625a43536cc81ed30e1c9e716aeedf6a66299004c8447e9fee4ef470177b014fd4e8b071b77533a1941fc49208d8121a468abaa668c26f08435daca274650838b1d8aa7c586d12978ce8eea551313437a5f7a7cc6ee8676e32cc0ba46eee6794a6f754255231b7a20817522155b294d4bb82b5facee6ab62efd3f47d4f4fb05e0720935f435528873ab9c61fb1c11644e44af7ad0372b682e3ab921845ae4bbebe745ba3c829902cb55975ef0e04e14a9f42750a89cc017513416a87d8e2767aeb947ac7c1b309066f36690fef3ad0a735e80f47680544ccc223fa37fbc03655878d429a8bc7c833fd2195163e302fa32da0a43d6d07edfc22ab7079f64d439677d3a0c48199eab9e984c5ad94641ff414",
+		difference: "f0aa27cfd113d81f7b9caeccf23c43c927470236dc17c587752852cd79a06dfd6efb8bd64a26857226cc8bad726a696705d69e59186f9c8af0aab0b425efad104dd0bbe3c38a08b19fd876ff62e5d1bcc85ec25358b9b866e91a17977ac7c5377210d8e359959eca7f46c0dd6a75c427d8c43591826b1a8f590f1957157934d24a9dd66637f3f7b27a5aadd74079e55e366b7c9d8ad8ab613df9018a752586f1b7963a8b2fb5507611736202bf3aa51b2940e669f29dacdb15578b294c8c2861e19fbc2d2551cbc81366e4d8f30fc4073e2ed13c5d60f75f47b2cddafc8923093349204a1738f50125c77007a5d1fd625a43536cc81ed30e1c9e716aeedf6a653f2ea614d7f500cdd66354faaef6400340da7d0387b8b38170eafd9f2bb14d78a0193519f15f9eeafa023911e6bf3650a89745938b1568f1590185bd282c0f33c79c59f300c06eb7b231f996ab8fb269af9dcfd161befacdc821935ed701670248f2cf82b3f98e4258ed2214da3abafe32223ec2535b2339538f6f4890f2932a2784e623996e2c7ad1b3807c7ee6354b0459bf68d28065d99f0a22c685719d16566adcbebe0716c6041eab1315d2182e4e989ba2ab3c3b433364cb135947b5277aa8f01f5a8bdb162d6f968d456ccedfd6ec8bdf425893a68e1c5f2d9bdea7068be2a576cec4869a813b7cff9bfa6e61a6236c0f23d444dce17fe9448cc32956",
+		product: "6e2b88f2193dd54caa749bda74ef144aabbda3d4ded76072d5e17cb31b1319c92cd18b66fecdbcb2219c87b50f5ea0ec2109497bb1dc7d31bbb5fe244c08c8c841a4ec1da028d15c9de68aa5aa3624910bf5e35324a91efb6833803f37008c7c81323db1b98b545c3201d01ba61b874ffc7060412866483c1e6de669ce959788a6754333ea074f4997205989ffaf5c79f55497783edb26747be24e5c554021843b4a253e2be62359b2b4f96de4a38a8d35ef02c57b7bf28640a2dbed93e73f0f77e44307ae6aaccb34ad3db5b9cde8dff0dc5eb991826cf0c3306900e960a8cfa9f8f8e11eb48308c8b511de67dfc6c4718dffc9911b53d7f9e593c37e94ef47a1e77c66c2a72c8684aa2c7dde145f313969411e0e4a5852eab72c3521047bb9419c79ed8fd43e89576327c5db78ade635ed4736cb6a42621d52fc2c199abcbde4d1d1b3f3abf53fb05b119b1617abf3e4a0eb17237cdad7726dff21251ef7d38f9d718456b8a7dd5bb4550850e7dca5377e19a4b19c0d3a4284f48cca4660a2dcb8002a28d1233d7b47bcdb02bf7cbd22258668d94bc8a86b414c9727f0ba00939535757dee52fdea46f8d37f2279bddb83c1f0b3422da137c0cca4c759e287f754d0864b96eec97dd15e0df78cc7400cc21c1a4db46c317d7063daa6583ec8456433a5ed4a3053c0eda2f342e103510f162d6e5166a302e30b4c501a9e57c5dcddf3c874a12ef62c3ec45fdf0c7dffe5adaf6d66b6d48a5c81cf2536ba5d4210ffa4724ad142816f18c1e32d906ff4eb4d1c46a1e8128caadea53b0f12ce79367fd646ec6f4ecea67f71de21e80c92ef6f07a15fb7fea2b7f20772d794f071f66a35c3edbb9d24dc663fa3f7dd00be753e593216f61027d1fc8c5e88926b1ad8bb256ee36193b778eaf2fa00ad3d5fc6b6256498a686f42244ca273c6eee4e4a969af01c95aac0e1d9367f17ace1175029e3bf5b4687ad35750cad7722a200c4dbc8853ded65594e299857ed4f027113adbc78c6f0c46c4eb8eec4d83151dc4d613a595e696bbe488e70a9c77789a3f77258e834bfb5fbb747eb87a5775e2b",
+		quotient: "20dba5d91cf252f340990ee7d05e0e298ed48dcec920b4f2c38395d1178ee905c8d0fc329c0dd3b312fae02b3c524704d7fe7f2835fcbc66baf03da755f8f28e0d722aeba91318924db39852cd55a07dbd6f8501006070cc140c6a7dbe8424952194d0cfc0af7fa16a59edfc0bd29ec416fa15f6ed8c1ef0878060eaa1649fbcb37d3931cc87f1d1441480655a2618da066b898b0136bc5faaae25c43fad2e37243798279326881768af43116f59543180f62acd22fff84a60fb68b4d65f1e5bf46ab70a95d7c047d600e013c79edb6bc740c3e10799a9c2f31be24982e18af621d6059cc9734aeea9a69c510c155368b0fbe9dba6141208ae7d70918b63a965f",
+		remainder: "37dc39dc4f08ee9567089b4f24ff66e12666254e3a66bef641616ee0f004977e68733d811ca27a1ad4ba0800516982535472104e917fa89686524609b11849b327532ae45809b9650adff39d29e5b14e3a7665f5d55179a6630ef7f5a192002f8fadf7e9a29faa9bcd15ec84286cfec0577d9d3ca1768b27b17e453cc0e892505748285db467e4226104fca94d0afd1351380497f6d042c7641d8e541b4d7b8c8058e2ffda0be8fe2354b355e41e0b294ec17b186fd6e6d68265b9bf3ff74e8877b108e3c53d4b9196441cd3373141f5ffe8aee9946a57f32939ec373423ca821d92f06a4c7ab1d851439a23ab582f80accace4635ba5ad8c2e1410751ce4674",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "b212775602e3f06a9eaffc856c34138269a009ff2bc5a4d99dab7f989e6418a6ca5ffc7989cb876fe1cc970985ec81115cf2a10053f153fe97a17971e8199b4b05c3b63c68dafb58b8ecff720c42a6e87d369bf0d706a6771498851fe4abf6733bb456d171a76046ba734e5995d1ba2f9b563ede9eca9c0e058b8e42793983caf0d075ee2a22d943fa9277f3d12ebc8d5ddabe13f674fa552dc84666e0e0d41ed732ec044899f8899352a4083d72a9d65d3ce8fac88edefc2754d72d6020c517a11b4dc6342a1d0c225fa1284a6b352299bc01c3e616846357a1fffc55f63de3a77d29f16a7c79c40b904ab65b7acc1b4877342f3c5f88d67571a3af4ec366356f26849ca4861930c7216bab69241f777f19eb21cb027ef73ddd78a064177f179a4ac41ad0c299a1875cc588c7ee820cb731a06555a142a932c0867de8d0df28785f87fd978647828f6308a34c62dfeac1fd58b94c57e49b85f7a9b6345add8851eef0018f4d332085d427755fbab9f4506d0fb4cb173e",
+		b: "958524cf71e74403e1343266d167908bb5c61c6ef37bdb0288dd7d063e1a75347a46b99236c56bee5a2a1811ece78df26ad0ff3a35ee32d8c246015f17a12d52c9445b4014fe3cd3fc39585637d413f5b231ce766072a4fe806eb35d27389098063345081f7ee48559b86d9eb5230aae1188c2a96a893ad6d31e73ccd196915b5eb253ab77f1be0ed419ea0d00bdf44ecf6e0325e135383174e0e11720165605646f78c2a7f0fe814352157b6698b5cec082c53cfe92c91d51b148c50c9d036e5f5d7b72648fd8238f8dd071b3331a88cfa655abb7d4e82afe84c0f02add05476f3e3d6bc8778fcbb5f87678309426993f9b468a75c8d10cb636fa78567848de04da910ea525e1b3c6fe3166e162fcf4158728d5e5a64093519ecc53c6374ac851445c08b1968a2bcca345a17ed0cb9a28f37bae4d13f1d88df9fc2f7348d3585e80dce5843db6335c075c4da04164bc09fbf8d63ca496c0c14c2e6ef45b2b08a46b0c658f404246ef1d5e4a4bd2307600bbcde4774cb1",
+		sum: "147979c2574cb346e7fe42eec3d9ba40e1f66266e1f417fdc2688fc9edc7e8ddb44a6b60bc090f35e3bf6af1b72d40f03c7c3a03a89df86d759e77ad0ffbac89dcf08117c7dd9382cb52657c84416bade2f686a6737794b759507387d0be4870b41e79bd9912644cc142bbbf84af4c4ddacdf01880953d6e4d8aa020f4ad015264f82c999a2149752ceac6200d1ecb0dc2d48c139d7aa3286a2a9277e00f72a243ba264c6f08af70ad6a4b983a40b5fa51dbfae37c721a81979061ff26cbdc8860078c93898b9f52fb1ed7199fd9e4fab6962576f9deb6c8e5626c0ec80d3432b16bb675d32f4098fc188c12e8c0ef2b488127ab9b22859e32ba89e27a53baf13740115ab49abfae48e1f9d124a871c6b94a113f7b0a8bf8a8f7c44f42a4ec9dfeb8f2023825923cd54000b2a46bf4da6e0251c13a2b53481c0ba82ad5c19b280d6e064e31bc3fdb5eb6a64f0eca444a6cbf9518f88fc7b5c4743d82528b60890f659fc671e8d756774f185bfab8cea6a5128dd994263ef",
+		difference: "1c8d528690fcac66bd7bca1e9acc82f6b3d9ed903849c9d714ce02926049a372501942e753061b8187a27ef79904f31ef221a1c61e032125d55b7812d0786df83c7f5afc53dcbe84bcb3a71bd46e92f2cb04cd7a769401789429d1c2bd7365db358111c952287bc160bae0bae0aeaf8189cd7c3534416137326d1a75a7a2f26f921e2242b2311b3526788de6d070c83e8e6cbaee153fc223b8e7654fc0ca7e1972c37341a0a8fa0850008e8cd6d9f4079cba23bdc9fc15ded5a38e685383c1a941bdd253cf9a44e892d1d0b697381a99ca15ac182e419c38591d3f0c2b19389c383eec85a204e9f85597d43e2ae6a58208dbeda4c696b7c9bf3aa936f84b1d576a4bf38dff60377d00233a4487c122836992c24be55c3e63ec3eac4c9de0344f490668121f2c0f75bab97fe7491db6728e3e24b7088d50d0a4c68a4e75880bd019deab181348914f335bac55ac217b2eb8015fe30fb34ddac4ab7b473fffb27fad83e39c000cf0d996b6c92b13e8897e4fb141d053ca8d",
+		product: "68015ca7f44fc6f49a534534743adf043bd6fa16ebf6c8b47cd51b4c7110aade2627a8c77447805bcb07f3bebe8d8f11fd77b8d5385de7afc511b7368c91009c4240874368d6880362afb0971f03bef376fbd40ba2f9b033dd46ec1db32daa8bf4cb8ec7591147418e1d570ab442817d5c31e0ad622da82e2c5fa6981e65f74d101bbbce59cb64bff6570629876cb736ddbc7adf072b726777128e32f48be49647bcd18038d06c669b4f66410bee89fa9c646b4e982e10b3ded9834f377c6d8b65ffd4df32aa6be586ab9785ba95958f9674a7ac5d68f78c779ef236d4e472d5dba02ad345ee86bb754a88b3950263af5eb518c513b7290ceed38c43bd623dbea5d351a260619f6aa053c32ddf21f63aed2a7ae7f9f63fcb315bf2f580e1934be8779679760cb8ba5c4d80a2cb19e0ec0364d554f39ba8a118abeca721f77dc306f8ce9a0c53bf80749d85b2a775d9886c62afce48d16b6b93ee8c843a861dfbc3abfd419b901c316faa883c9ec386afc24d2be3f6204c260f42f8344d890903300beb445841af5a2f7cac9d75d98d66daa103007d132f100fc8f5bc852ff2305b97f20cdc34784413ca14cc26574c11533578ab50496fb5b237748b725fa3ea0105d75ef3ec503d571eb4e95e4349bc4aaf123d2c08ca8318fbe0a1c89bd2b6dc40365f049288c746813c64142c1841c8b151a6cb7f66cfff59b1a2540e61575a63af80ac018ed888a35e7843060a5b05090da8fd31d6f9128883d1b4618abaa2a314838010159d0e5abfc22ddbc2a1d7b0c9930b93568f21d729efd08d6ba7cebea7bec42c9877048864f4365a7efe0f3500c0b796f689fc1e5b1f35273b0815cc0b94740c0d5276552786314811d14abb4b7bd770bfd6f34c2af22e66bbbdbfcedd311c930df6964f34a1fc53e4c4c6bf333b6d9546285cd1437c80306e7fcac01d1a8557f8b1c0abb980da63b39fe1c22873700d3c540aad2f3240787a70623b87f6eff4ddba6be48205fe65d5d26ad28a4502fad90ebd98c8fbb720ed8e36ced85e24992fe9e349512379de",
+		quotient: "1",
+		remainder: "1c8d528690fcac66bd7bca1e9acc82f6b3d9ed903849c9d714ce02926049a372501942e753061b8187a27ef79904f31ef221a1c61e032125d55b7812d0786df83c7f5afc53dcbe84bcb3a71bd46e92f2cb04cd7a769401789429d1c2bd7365db358111c952287bc160bae0bae0aeaf8189cd7c3534416137326d1a75a7a2f26f921e2242b2311b3526788de6d070c83e8e6cbaee153fc223b8e7654fc0ca7e1972c37341a0a8fa0850008e8cd6d9f4079cba23bdc9fc15ded5a38e685383c1a941bdd253cf9a44e892d1d0b697381a99ca15ac182e419c38591d3f0c2b19389c383eec85a204e9f85597d43e2ae6a58208dbeda4c696b7c9bf3aa936f84b1d576a4bf38dff60377d00233a4487c122836992c24be55c3e63ec3eac4c9de0344f490668121f2c0f75bab97fe7491db6728e3e24b7088d50d0a4c68a4e75880bd019deab181348914f335bac55ac217b2eb8015fe30fb34ddac4ab7b473fffb27fad83e39c000cf0d996b6c92b13e8897e4fb141d053ca8d",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "f665cddb0b6965b89591c88c11f338fba0a642c19cfb6badc5790fe9f180b1cae677ccad8c8e2e39792014843c3c796cf33f371bf38ad20d01f8da6a1a31c7df720254f3970ec999559b3fbff2d375c95f313fc676048bee990c234d9863a4faabf2d0ce4b7c1f71a99a2da3231d049a8893b455151f0ca4ebc34bf76e4495d6b1b1afc088fa4b2c1ce160a06fdb9f76ea1c170802ad54f314a820d59e8f36388f6d9f85a70485ae3af6be496fbedc4268c27ea494e6481a2e70ddc14028d072dcb77436ee2e6aa3d586c2ad27706027305cb344bff25d26172866107ac0d1a369e5e4116b05ec32203fd57b69b4632bc12059815030532a7c3faaf3a61a26c5767aefa4de5b05a7d05b9172809133381aeba12f87cfb69ce61a31c21a9679426529a2ed1310ac25e1261bded10fe49f74726e73bbb404c223677aa3a0fac897bea315b0b13469ba57931ac1a32a82024ec85143b104698aeac2b2b3104263eadb7cabea8f1118a2bc39550568a04eeb27e3d642ed98f7",
+		b: "9869b1007d842887c38a546a53298fb6933c3aa2ab984e70d5e683b56371c41c2573df86ffd34e5ec26c12c9ba9cad0bc34bd9bb5234df608bd79decb95c2004856527477861aeba161c2cd253b75f855a8f5d7d93122d7186b0064a7c339f157193759aa61328f217118fc7d1bc0ad9f58cec8459f33965bcfb9de8a14a172b53c8eb9359f5a3fc51204efc85c9da5d4ff1253711f129527665abd2849e04442f842bc3bf7917abaa93e4c187fe7ed023f2d56416e44230342de3cbf453fb905e01bad623e3a620df94b2e09d367bc3a1ce96c8a237f60e8b4af8ceddab19311e3e5c3d7e61dbff62543cabb9c68e5ad3d61efea082fe14bfd71c55bbe4bdfed55999b97e334e306fa0dee2bb7c17cba477769a069cfe30b01c4952dc26b283b1b57cbc1b211055c97b7cb063830576cc8bbf4222256ddd27b78bfd50353b04a40ebe59e161778a6b25880368d4f594c67afe9610cc47e6d6b914027bd4a2c6e8d2d1458f98c4ae9f5a2c9cb1f41ef819242dd9c05e80",
+		sum: "18ecf7edb88ed8e40591c1cf6651cc8b233e27d644893ba1e9b5f939f54f275e70bebac348c617c983b8c274df6d92678b68b10d745bfb16d8dd07856d38de7e3f7677c3b0f7078536bb76c92468ad54eb9c09d440916b9601fbc2998149744101d864668f18f4863c0abbd6af4d90f747e20a0d96f12460aa8bee9e00f8ead02057a9b53e2efef286e01af9cf5a579d43a0d3c3f149e7e458b0dcca8232d3a7cbef1cb49667d9d59e58aa30af7bd5b128cb55408abca8a4a629ec18d347ccc033ab92f0d121210c4b51b758dc4a6dbead22b4a0d622a5334a2735edf586bead48824404ee967c83182941227237af18694f6787ff0b3513f3c16c74961fee4c44bd4895e5c8e53d83ffc70553c0d4b03bf6317c98e6cb4cd96367b14f6bd2bc616df1fa92e31bc7baaa1988f3492ea1640fe2db5ddd9729f4b1f06a0f130039c62b1d40a9295e144c2b8a2c50bff779715434fd9c1d0b171c17bc6b58c1706b1c44f7d301ea9dd515b9381a21a946de34108041cadf777",
+		difference: "5dfc1cda8de53d30d2077421bec9a9450d6a081ef1631d3cef928c348e0eedaec103ed268cbadfdab6b401ba819fcc612ff35d60a155f2ac76213c7d60d5a7daec9d2dac1ead1adf3f7f12ed9f1c164404a1e248e2f25e7d125c1d031c3005e53a5f5b33a568f67f92889ddb5160f9c09306c7d0bb2bd33f2ec7ae0eccfa7eab5de8c42d2f04a72fcbc111a3ea11c5199a2af1d0f0bc2ba09e42750319f131f45fe973c1e78b6e029062d987e7c05d7244cfa9407e0205e9fa42f9f54bd4d4e27eb5b960ca4ac482f5f20fcc8a39e4638e8e1c7c1dba67178bdd6d419d15b8724ba787d3eca41032bdeb98cfafedd4d0ed4a3a82afad5515bc688e9dea3568c6a12155eb6027b77760bab28fc5151b6c76742a958132b86c35fde86f3e6fc6beb3742630f7ef9bd017aa9f2e6d8cdf28a7e6af31998e96e4fbafeea650c58d931a945756cfd2f22fec6d92be3a558c6d884d52ada03821a414099eb0946dc123f2a9daa4ff7853f41cdf2868b6ac2ff30ebfa8692d3a77",
+		product: "92b22c5859c19e88de6b5885d7f6f6eab880bd205c653106da9ca6433c5dfcc1b39fd527018bbff4cc1736045b4be2104ddc9c4a77558156ea07b523845e65c4788e2adcc782e03ff1f729b4b882a23da433801be70ee39c7fc3f2e89f03792edd7382c6fe685d3b000d56a95b255289eafc304bd42613081fff627373ae0f9c8a558b4e8e1aa855bd05c6568d9d574da2ea705fc4c7819ba5009fe3d93654d01628658ef0d73d0d54a6f7aaaf0c2f7c47cfd0c8bd8586ca8c43a7529842e457d0415ec0b3ae8767e1c079e42c3e7af8b3aeaf30e324eaf32f09030281c0c222db9ca3e1c6755cdc6c48f6ee572119ddcb5bef6ef2e3447d97fd954e1750262842c1ea296c779962636067c3a8ed69df387fb980d629f8d24eac7f420a3bcd4834ea2c94ffa6fadc3df07a48c6861091060a1266cbdd0554c674efeca4418cbb2f9581bb3e997591db8f2fc0516e53a490f6cd5aeb8c2fc2de900f03866b5244e1874fdb4ba02cb384be2abaefa162568570356a74255ca7685eb9441bf7922f4b81325754b7d7b752c575b2e9eaf8b7366c212b7c32d69f4554f71237edacbad476d59895370dc04b36b0acbb298a12f41a711da4d4585e9e07c87609a9e33aec230a83722d6832429322b81f6260ae59131463120428fc62004c12a4b814c80cc01cf0d22cc48e8d0b030d426efe0e5c870ce3140871c312bf77711f78edc2935069cd96628911f77a5dafc225fa22c7694ab7905247f65eb0adb3874c345e599ab3f2da507e7bce5b1ddb9b6c90a2e34b91100a5ca00b1f08614b196ecbb3f7bcbec9637aeacf0d95644571ede58f70f36f6ea7101e6e1b8c1fccc773992fc7de4d2406c68c613e6dbcb980dad5e19946c3a2cefffad56972c1fad7d898fca76b9ce6300795d641c3909ef45ce8837c99fb10f5a3795b7481cae8ef771e049e784554171e36a88fca9fc4693c5e41771b87cd86cb99958467d1061ed30c388a55aa3c8422e87515ed00efcc2b6865020d98c3d240edb4a669c11154dce9be9e3f96ec8c421e6dcffbcd372d80",
+		quotient: "1",
+		remainder: "5dfc1cda8de53d30d2077421bec9a9450d6a081ef1631d3cef928c348e0eedaec103ed268cbadfdab6b401ba819fcc612ff35d60a155f2ac76213c7d60d5a7daec9d2dac1ead1adf3f7f12ed9f1c164404a1e248e2f25e7d125c1d031c3005e53a5f5b33a568f67f92889ddb5160f9c09306c7d0bb2bd33f2ec7ae0eccfa7eab5de8c42d2f04a72fcbc111a3ea11c5199a2af1d0f0bc2ba09e42750319f131f45fe973c1e78b6e029062d987e7c05d7244cfa9407e0205e9fa42f9f54bd4d4e27eb5b960ca4ac482f5f20fcc8a39e4638e8e1c7c1dba67178bdd6d419d15b8724ba787d3eca41032bdeb98cfafedd4d0ed4a3a82afad5515bc688e9dea3568c6a12155eb6027b77760bab28fc5151b6c76742a958132b86c35fde86f3e6fc6beb3742630f7ef9bd017aa9f2e6d8cdf28a7e6af31998e96e4fbafeea650c58d931a945756cfd2f22fec6d92be3a558c6d884d52ada03821a414099eb0946dc123f2a9daa4ff7853f41cdf2868b6ac2ff30ebfa8692d3a77",
+		gcd: "9",
+	},
+	ArithmeticCase {
+		a: "140a39fe09ee893f5f23157567af3de434b400b558e145a7325ffd847e721bfd9d0a1ade9ba10e7eda14b1a12a1a4e75478a6daf34d1acd25140c8336388c26aaf1fb2ed73a668d47f4eaced30c8e033b7614c470fbb0b9724dd21afd5e8f86d248",
+		b: "1f0085e8537de9704",
+		sum: "140a39fe09ee893f5f23157567af3de434b400b558e145a7325ffd847e721bfd9d0a1ade9ba10e7eda14b1a12a1a4e75478a6daf34d1acd25140c8336388c26aaf1fb2ed73a668d47f4eaced30c8e033b7614c470fbb0b9724fc2235be3c765694c",
+		difference: "140a39fe09ee893f5f23157567af3de434b400b558e145a7325ffd847e721bfd9d0a1ade9ba10e7eda14b1a12a1a4e75478a6daf34d1acd25140c8336388c26aaf1fb2ed73a668d47f4eaced30c8e033b7614c470fbb0b9724be2129ed957a83b44",
+		product: "26d4781432298126086e21b2f0bd97994f4e802a8b1d25be96069620a2816a188fd96e6b7b90fe0ac2fa4dd6f5825bbf7da7440f4bc46573d988f9dd60ad189ad61f3445039cd207ca0acc67835cef999fffc8e0d8e28cbbba180c522df2f09be726136d9a29693c120",
+		quotient: "a57af310653dd7298d29771ec5eb82fb8595ca7e8d00f402ef7b7808768ee12f1796db368eb9fe58cee571f8a4dd81f788e8dbb1e712bfc2068cba5c3849fd5197a1fe2f1f3d5579d722881f2a03cb9da14f1fc2fe191ad78a",
+		remainder: "2772e04fc64d0e20",
+		gcd: "4",
+	},
+	ArithmeticCase {
+		a: "1496120044f8cab0ed7304a8e777e5764c8598d1cd5e58cb8036b2dc1863edeecb7e8b40049248212d23a4746ce47c97572e9a2d9d61c422e9be659ea8593cf0d25434d61131b92d595723e155b794a9e8fb4e32353a97d01c56e7b526f35a0aa31",
+		b: "126f4444f88a8afb3",
+		sum: "1496120044f8cab0ed7304a8e777e5764c8598d1cd5e58cb8036b2dc1863edeecb7e8b40049248212d23a4746ce47c97572e9a2d9d61c422e9be659ea8593cf0d25434d61131b92d595723e155b794a9e8fb4e32353a97d01c6956f96bebe4959e4",
+		difference: "1496120044f8cab0ed7304a8e777e5764c8598d1cd5e58cb8036b2dc1863edeecb7e8b40049248212d23a4746ce47c97572e9a2d9d61c422e9be659ea8593cf0d25434d61131b92d595723e155b794a9e8fb4e32353a97d01c447870e1facf7fa7e",
+		product: "17b7fd33b98c607cb00028ef8d5137644900e995f09e7c145a92a592cf8210bf6f3fbfd305a4b4544cdfde45010c89868d91af521d1e8538cd8273dfe8d4fed9dff559141ea1a82e1df8656d5335dc0a558595411891fd5b5fb500472d22669d372718abe640bd67f43",
+		quotient: "11de0f4f5c7920467db59340d19ffa81194a13221c6f63741d12385a34835b6bdd9cdc2aee802a47312565264a92e010a259316704cd290b794bb968482678dafd89e76de72d35e1614269620b1d3c097173772c34fdaf12c50",
+		remainder: "e08f706ead33fe41",
+		gcd: "1",
+	},
+	ArithmeticCase {
+		a: "2f28ef82eb38b9170db8e5a901afb8c96",
+		b: "19613220b37fd966cbde8650bf10b9230",
+		sum: "488a21a39eb8927dd9976bf9c0c071ec6",
+		difference: "15c7bd6237b8dfb041da5f58429effa66",
+		product: "4ace7228e8a59b3dca01b52ea1bda912df1136f43c1c231a075255b02c5c9e820",
+		quotient: "1",
+		remainder: "15c7bd6237b8dfb041da5f58429effa66",
+		gcd: "2",
+	},
+	ArithmeticCase {
+		a: "3eb05258566065cc711687ba24045d1ac",
+		b: "1b3f2ad4907469aa0ed3e4dbac1e2e220",
+		sum: "59ef7d2ce6d4cf767fea6c95d0228b3cc",
+		difference: "23712783c5ebfc226242a2de77e62ef8c",
+		product: "6ac10908fbfcbdfece0db9eef681274a9604ceee1c942d26f985924b371ac0d80",
+		quotient: "2",
+		remainder: "831fcaf35779278536ebe02cbc800d6c",
+		gcd: "4",
+	},
+	ArithmeticCase {
+		a: "3039",
+		b: "3039",
+		sum: "6072",
+		difference: "0",
+		product: "9156cb1",
+		quotient: "1",
+		remainder: "0",
+		gcd: "3039",
+	},
+	ArithmeticCase {
+		a: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		b: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		sum: "10000000000000000000000000000000000000000000000000000000000000000fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+		difference: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000000",
+		product: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000001",
+		quotient: "10000000000000000000000000000000000000000000000000000000000000001",
+		remainder: "0",
+		gcd: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+	},
+	ArithmeticCase {
+		a: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		b: "10000000000000001",
+		sum: "10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000",
+		difference: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffffffffffffe",
+		product: "10000000000000000fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffffffffffff",
+		quotient: "ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff",
+		remainder: "0",
+		gcd: "10000000000000001",
+	},
+	ArithmeticCase {
+		a: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		b: "3",
+		sum: "100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+		difference: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc",
+		product: "2fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd",
+		quotient: "55555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555",
+		remainder: "0",
+		gcd: "3",
+	},
+	ArithmeticCase {
+		a: "10000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+		b: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		sum: "100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+		difference: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+		product: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+		quotient: "100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+		remainder: "1",
+		gcd: "1",
+	},
+];
+
+// (base, exponent, modulus, base^exponent mod modulus, base^-1 mod modulus)
+const MODULAR: [(&str, &str, &str, &str, Option<&str>); 18] = [
+	("f6641f9bd4989655a862332d", "c3bc25565e1a768d", "b6c9ea6bd51ad19b", "5bda69f2dcb7261d", Some("35f0d8b56c91fd0d")),
+	("a547c49f148bf0d3a51ef75b", "96a8a4f109e8a635", "80159a6216e03138", "4715354cdc4f9b43", Some("503b224f86142903")),
+	("15843d37b766e4759cf8cfd14", "1395c779ea7b6b022", "16a9ab655e4bd16fd", "f7cca60170bd60a9", Some("163ba7fb08a3b2351")),
+	("1ccf080a42ed2fc3ef8d8e516", "17802f928de3cbb3e", "18f1c122846a7e488", "8e45945a9d7835d0", None),
+	("361bdad1319d7f87b4581606612eff8eadb1e379", "36faf564a004f0fa4ca2e80c17785599", "7e8e5e426473659d90505458b073fb15", "73d9f30dd34d4a963eecb0ac8ad16402", None),
+	("3103c52259ca1e4d6ec09a4f3511233c398a63bb", "301129a98236e803186daaa5b2768c35", "41641ae4e334eba7beda8bf8458e8eec", "2435fe866eaf2582def6623a7828ef", Some("32517caf9c79ff2a05eba1f09d425967")),
+	("275e701a13e65222589272089f28fe8894ba8c0c83c35576f350ea0075ca7b56613f706e", "cfd308d097843852d25affb39b1084652ca035cbce37171cccebcf7ad826551c", "8eec7648e3cc350bde2be20d9325d78e3af65fe2e0f1544270e440a03993a5d1", "8069c83213a99dff7438ea1593cbcfa9c47c35be90683d4b23acc8b95854f5fe", Some("407cde5bcb767ec9bf88243cd46632ce672d698abf29167d71eba18bd1570358")),
+	("897ff8f987af0e2470786ec855ebee795375063ef31fe45b3cc22569c25e041ab3cf8b7f", "9eb3e576aa0d0c23d4ffb9fa29424d765a7929343d158b37fc26e5656916e3db", "97e00dd3e9be3cfab37cbdfed8b3a393ba53b463be758ec521dcd230ed973418", "951fb4ce4adef9815801213a9bed64e544d2fbd4687c41bb9d4573ec76cb3807", Some("4556d757a4efc10983b5acbaa366422b6b921f9da01359fd6090e7672e02f727")),
+	("48ee31eab730d87a68fd99a1f42a2a156eccbc433bc380b56911d74fcb38f2624f6f166bb594439305d8c97360792fb24b243b5c", "35b6e03c2135cea933912a6ce05b96d6d1e267e9143092a1393c265dbb3bbe6803884f42b78acfeef6969afcab5a9d7b", "aa0a7ba5de457bd037611b6243ecfa43895ce7a88a6b95ba6b300b72058a3e2c73c633e5207c29f7fdbedc5bf7af9981", "4d90d56b002918541b7050a9789b87707f19a1679cfccaa8d6dd7f8882ec0aefbad357df4b84092052db779eae1a72ac", None),
+	("7944b23b3673c2c5865006e760e904655f8f31281d8aa9222bd26fbfdbf700addc08ce7fcea122234d9a338e2ccd460070251b84", "54e5824f4a2759e718b405b107a44bac021d5e5bc1ed5b3e94174249cd87f4402411dc45c843655b9976215a75f9f07e", "ee9781ada41c6e692d554578684d4c69c97e0042a0d0a7b3d309b3f1fe8863a9a1d6e678fbd987706408f068ca736b98", "83cbf725e9cb4b1c137b6f3b922a63ea103ae2341d34e53a052fa21f25e1d906f39c9a3b83aedbb744aafb5fbcc758d8", None),
+	("1701e7e554b99b3158354112b6b7f59ff559a61739bd61a950e9f64361746917a9f0ee07a24665d4ede7787097ee807b372e611ecaaed8d083e60f8d19e929e778b720d3710", "359c62d7bb354a5630382c1c4275a27f52703a52dbb5f546121397e4ad14e06fc4db6a83797388245175694c32e91f4c1083ca7ac88062e498fa5804282a39a9fa", "1bf390df63a1fe6542fb1ef41b99c1c081c62185e5a7d3afc269f1ab46027e83fc8e957684f2c8c048faced8eb3c351d57f52a3b9cfbcc33996e9e2294eec4ebc2b", "11dac35b5395623437625c7c5d65f5b13ad01e42565c1e3fdcfc2b9b20185a2c89fc927d4320c100cd700a8f2e0760169d08d8079fbc610fee13e79d615fe0f800d", None),
+	("1e5eef9b2f0c9337b662a2e9bec1a42ed98af5f7fc45593538916c7b77c96d0ff1d0e00629adb44735cc9b25cb78fb0413bae0db197a33ef7443e9724152e04b9e61da21247", "bb60aae928487f91c77b90f441e6cb73837afd147c498036ddbe9bebbc3afe0e556015ce192e7e7fbc86831bdfe867ba514fee17808d2ca3e453fe74a022ac9c27", "19217e00d4644c52adb78d051c0ef75c76f6880084295deaf0f01270a8de6afd98de6e34c0d1f013233031f39f13522d7b1f182ebd76d0a8398c8b84c4cd27f6aa0", "24746e53c0f9996c765fea291b8413d7311dd471f345bc204f0cadcde51850b4e855db6abde7e854d18a60e37993e1ceb63483d780c801c760b29912211557697", Some("e0e12e371b0cf960180a0318293ce631f07ac24602c62fb6d11585a0bf0a9bca3e24ded4970cd8fa4a4cea2e057769230844ef1c629610a7a19e851ed89730a337")),
+	("80e3110be1c529e2c85afa541df99d593493ce745c8d94126a824ae1612c7b90af801471a02810d3cb48635e1f31df7ba61bbaf3cb711566712c8d7443222d2a8811f27e861ba5a8f5fc41f60c4015e1831bc3553f823dbd054501d1fbb7d52ecf0e0ca2201b034e3a8fc8180d2080228b97a44bab68be2b0d768db0006c2d55a87a0254", "14c8794f9fe46b765db41f93bbb6524fc02b9a99533a7e09e1bd5e5c5fb051c9798814b40d0706debf1974f3b45ea1560ea16ff8e4d2a2ded2b3056b6dc1fd14b1423a55cecd90a34b6127332fe4af83c76a559806a0aaa5fcef29a248e8f128774c612a56a043104e8444449779e51656ef60da2d3049aaf4dfec5b1c9e3369", "84a32cb51649edeb419d20fae045f087cbbbec831093894ab10e627bf06bf3097744767bb7e06310057ea3b2632c4937144c62a3453ae64cb985fc5f906fd31b7e46cac8710a4d30a4e745c45a8d4f459ad4a5f3b80e1af6c71029ba603a04fcac0f3a3835b4b8b1c4ba204ef18e2bc949ba770bec533018a69fc1df402fb109", "22a1755de94a64a7713b24f6154b32712dab338158a912d8851f4c1ad19785a32d013fe97a3f7b2901b49c67a2c6ca1b6843cf7b64fae84740c046bf82926d336e558e882baaaffb9a53959fd084b1e7bbddb85e43696e4674a28e12a47eefc09a77e23beef5cd0766ca4e7428f3865c402ef370e23c5b9338223691a287bf4c", None),
+	("56ffaa9b29e0aa2dd33affbf4c19d1840e89bb3b5688188c13dbc1b69eef9e71af974d14f5c0dab6c9085d5df5a2b5eb140e0bd88ee920e2d717f29ace47a13b2d64fc2e80a9f9f90de4ba66eb9529c70aad8c2f2f390fa98e77a0bff137e39e87514cad52ce667afb8ca49c495448b8e756b79a0aa5a6871b8e6da52f3a1527413a8720", "beba2248871ef3cafff43a35944f4aa28a3179991606ae4e9b0ae232a7db4015b08bf1982590b527febeb80eb333c6360ab974ef0acb1e2c6b4e29943f204f3d2ab34831c3de5cd554aa08c2ef0f037accaf32e0bb3ee8f4863b0190f3be57a09ca131a78a42b1fa2bbb4fbf6ab6d5841c336f9e9cb2dfebc18852503ac12b18", "88a61b7eda6804d2f220c2dd14b27ae2219712c4e2bb812908d96291a45bb5fb63894b0c6acd4e0018df764058e084740b3b60a34dc344b05713ef172f5a99498f34a6fca452692d1da763325b087f75719390080ea9f2aedd1da82bae305cd0b018d459090e1dd0342b83ade3932bc86158a4ae49e1bf9b67c41e0e1a066bc8", "37749ac88df4febaebd0cfd9b98cff72042c87a4495c0fb2178234d9e330710a0f9b160d913f04de76b986670ab3f9f9967f2ee817fc8fe5795e852020fe9720f497b819a077dcfcb030de7d2dbfc059c71fbdd45780ef5550d5efa2be61fdfdbb876366ffb67785031167789a5fb9ee5f8d1fc71ded7394c35c58da827b4b88", None),
+	("265ccc11f2d1f87b7a4a7cc0a926861c6ce412f847aecd91a4b965f2245fced5d7d5f6382a9adea3fa93652089316c16d71181ed98d609056d5d854191712ffe347c06d45487387a63512048492292c34c6e696d155062b46493be904a88fc1bf12ba3e4a84f29f306314c0d45685019b9b6a46b3d2d1e96d93e73eb4f5ca2835bf16dd34b0dfe85557277c337e03c0db3a4f73d98ff46fd36053fe36411bf54aa49ca3754c6d6d6fcceb61a1ccc13d1f24a0da777cd9b150957f2b6b8135b61fbd10e72bc9e162b1b8bfca94d75eb6770395fee9fd52a2f947a105130830bbd8585d7d8938e78b383909a915361238d576acb4ece602a8445ef2cd9872939149d4541aa", "11d678e13c29abf5c363b974a093b3e43a40109e171829d60e54c09014b50330caf54441a6edaae691773e3fafcb71224419f4fb4fb0400cd22527d6e92a5d9978425b602a83674f607c5b48688825d14df00f0f00fc0751883572b8fe6f5617a4da96d9adc87d7cd2b9fbd62ac9e90553ec04e406a673e453bd4befcdbcb66e99a98e572f623d2543b4c0d136b8ad13feaf19dc747e6bd8fce442f0300c3c0a733a1a53320eda4c674f34d8c34aa64c4c8b7ea206e1bdf7c10027d3d3d9750f12fdbcb6c49ee1efcfe728ab2404174a6d214fdfdafe71002db298810732dfa91ad4b143272f7f53c68d29646b8ad5390bec39ad4d646c71408d38ae2e20b5f2", "c10217e217785cd8a8b81ff5df90d18f6dc0a225e9bc71c5c0e2a12cc6ac00a31f94797ce96218adeea4f8bd8db05d41b4d622bb91808908dd6a325d61557e8f683dba0ca14c55bae4f324b6381c2fcdae55e9847d2068c54493881f778fef8cc747de8deee42995c0a649b52d4eb73e291ec19c29927aac15a5e4fd89ae0332412b74b2ce116b71c38e3dfbb1065d825ff12f4075f99991734d51927f499da82e6c057b6dd7a71831aff4db0feb759c8b0629d99dd81be7e1cafc6738bd147ccc248be23a1d48cb0ae50cf46316be547f7956f06a536be38b3954bd7e0b45cba85b33b4388df682613d66bbb31b8ac8ab109671cf21b94c6f37686f757b59b1", "228142eecca5a62b93dad7b418fb695c3c1855cd1ae8b3df7a5c87458fc29c84e15fc64d014a796627384268cf1d4b5a4ea81be03d1ab4bcc23bcfd474c08a20e4c0171203608ccb8a97d78bc29ea675b281460bd797393730370581835dc0193ce50b1bba9f74123b9f7151bc85c3cc151362244b2c78c438f6a4e087ac58736c2feca8929dbed71a91a47a6ce4154cd7358a5efca629b63dfc7c1ac81d9832270b2c8b778ce3bc96c5bd790ca3b6cc3659034c8074158659b305d22b1d06fe634074c3cfe379b2067cd2553bcc0538194339a9de6295b979d1d9bb09cea7eacca3557d592021cf8fcab6af9d1d9b45d360154a90657766401f4df12b8323bd", Some("5fe4e5bf3fbad420a598f589fcbcf7688a8aa46d1bc39943291341f88a773f121705e89c44b54ee8d8d9007fa328bd92e619cbebf9137b2881493b8d028ab2a03fb73669583d2e1505b9005d3df10a52f88c9600019b208c5717be38a9c2283f9279f58aec78560f34bee7be46cb3840dc25582cca22db1c3e45c643bf0d2fa3f82401d486a8f5ddbb12742e8d5e205202385cf24f15460098a93bc3f88706e34175e9abfed76fd7a7ff3be46b48410ac03ac21052a252f012a32c96958e453212fd2995785aed4743f6b68781019126333cf22c0b653ad7e50a1522051449853d54cdcd2abf18cb21544bb5c0e2cb0fc0008e9df35859494eb2d63ac09de727")),
+	("ea37555bdc984e9cfcef98c226d8affd9f15a8776a6f450a1a013a8eda49acd690025e9f7046796f94a49c240b9f9b19de3b93d0134f68d0c21a1e74cb768441d63a772d75ecc13a409a2e7963fd568dacd1cec7cb8da3543fbee0ab9d908112caeea41118c51c22948e888fb4321cfb0806b92e82f2c10f692c850eb225560ef919b9e90950975f7e5d97a88c4945f53d339534dc2b04da07ea1d66485652e4325f7fcdf77c053ed5e0aaa74aa62a2b6e072751dd1c5ac5e11e1bf637f7ace716fc5fdc7ac97676866142b1098e51cfdc2ceb8da993382b3843056d9e2d3dfd5b4b74a9babada0886c1ab5704543d0f42d0eea4d6955138dc98e3e814faeddcb1b2cea", "ad1a02e985e3a3168743c19e23a17a7dd674ec21aa103c96df6949aa3b972ad2f12846d77273d98464fdd167f396f59b6b91e15a34d471511dc0581a87b8e818929cdfd100c4c34d50cb87fd84cba35d868197b01ee7827e1fe791927396283d4949ef0358bb554f34bbd4aeddf6543f7db5f7d7447b09933d3fef019da129e6f040fb4f88edf709f7eaed66826e7066ff97a8dd0b714a914f20687cace35c2ad4d7571672d88b7568cfce8ca72902babe806a64d8d9fde8826a773ac00d0e70c4017ad14b945149874b72105693875ed1b4510944688767d25ec5ef1029b52ee6561b484bc2fcc02326201c818ea0ec672a86bbe0d4ca137fa64db84ffd4e3e", "bff4b2f3ed9fd1ca5c9d7d1105f7ed6676276c527ef1a27ad06e3a5fdf76e5a9d1531bc6430cd9ff36d724b14e36f1a578f57b5721f10b6060cfb142b4b6780fa0b42a8b1a96e83c2534049b94539d2dbd59ad00ad12bf429f2d8caf1bb807d3c04f48ed1d9581bb3484368b19c75c4e38be2c681769c89f9b6124c95d7c11c03cc79da9a25e9ebd59b114692bab73dd27bd5b17c41cb6c41d8a5b5bc784e0049526480c00e3d1d442c88f9b17e3d03e144eed179e8d393c2650a4522bc3fa82983788950f4894dec7639b103b5a95c7778d60928391e01471ed20f8119bdadf1be3e7cdee66b177a1cd6e778b11a2576f02a1d0be64f6a7f24d4bc10df9fd5e", "b385f669874043f4d0556998f4b8a5145a1324fd395b6e70b74f6f8415bad15d409d961e0e900002b623ab31d7a22efb6fb88deb2bbfc22c9cb7bb501c453a7daf3e9c317ef10848bc70f2bf8cef07e9f270472ad31efa3d053c813e0786d06b8a24be5326fbdcc6ff39e99ca2f019f315e8d0ac1f4e58e6d1a08d6280c3a7b5662f308fa18cb326c88648485ccd485c460ded048b173e6a0bded98d3b92b23c06c411be51f468b6ad40242dbd918c48f3cba1da1c8c0d2428a9909296d1932ebd152a488a4fdfa81dd5e80b7ab362b7b214dc2bbc38674400504b3816d2a644cf9ce146b34f3ea5efecc469d64bcf9db366744dcda7c9bc983b466fbec3e43a", None),
+	("5e9401dd868171b8506ec0052c8aa2ba7af763723976142f95cdab56e65a6f44ff8a0d1f867b645474c404ad3603a83e1f534fc10745c3cfb0b81700b20d5b19578ce58f9da86e07d437e10337c859f2192ae50530add464e0f5c68da3fd8ca58f9c4555bb70fe0a512bc371c656af69a308ada5abc7720ed0263124f0631278c200779008e6dd6285b09677e266ed80cb5f90d1319d5652baa51d5c51841d920526da8b4844f1f1a6860e66fe0e8abcae2ce7ca82dc1231d19d0a0de61fdb9934494661d81271d65d7fb3315da8590741f55d640925f219b627eb64e62955d4bc71db17fc0f24dce4718f44bd2273c3f1dc16984a994de55fff708afe16d20f4de754d3621dd98837f7c06696724f826a38ac3caeb57de067f0be082530e9be5057be1c2f5883b69d8b4c69db9dae7aec8e2d580de74354a784225209d7d64bbcd2f162986f05d19fa41b9b70d07def790a0952674f082d7ead84cc3474f2f6eb7c33eb94b0cf4507c04a809837ad6dd92bfe2b1136040277b6dea46b71ee141bc7d88e", "38d7d4c25adeae161ef6560ef6cd7557948e19d724bb1548eff14aaa0996a7e549ea380c1c74a7f2cb0c0da1343ed9d74873e48d1e71157f3a374b972a12bd1136e1502b100124a8a152c45520eea52d0ae080b9b465766857576d4ffa1e8a24474e5a754ea3db7695cfb7050221cea9d0724cbfbd11ab55d2a4cda20d2cea1c1ff9b3c8400d6f8c5726946939650fa2314fdb6d68e5be65df5d77c601bde98d7a6d23f5083f1a5dd81acddff9d0d9acaa703be07a14263e73c16b12a65432057c45a19ad9c1c81f2603a761c441cfba6e906e8295e6586611e2190eaf40cf69da74de74b92902dafd40ecf1d3ab4c6b746b6e46a2f8c4c067c53bcedcbc7cbc6888a4459eac2bb8520aa55db9b505454d8dc91dd6a22f0ee1c73418a2c5a8fcbc42376d867a7274e5495763bac816b104f9d4d301ee0df1855ad981823dec27d134374b37de3772f819b58f68b7653e4a331275f55b6ce45f72f375866876735766c11cec66c205207f874c944144b1eb4075a69708292fa883bf30d96172a2", "d9ba1deb4d33000a145384d9ea9ae1118b12d83d999a3f85369f1c31759f162a5b8e8aee2efd10b07f6fdaf1866f52efa5dae7f7f0ae69e47f2ff2f03366fb7588b91066737427957069c07a9a67f5b85dd2b8b55dd1108eb3d2493fa776320d2c7e243726b185e5248b611be6ef0aab3c255b18632b9649c38a68475aff3deafe9dbd15dea111477dd8768f0aa361f4c69a0356540bda0b11771d21c72f4b1945e55b239d7f37f3dde4d8470dd5d91c4bbb8dd1238fc9854c6747268395e75973cb0026159900cc7127a5be3dfff2fafe1a93043a32123fd34eede762cd27b16981f53f0165dcdb981e09b6c381f6c07cf48ee25c76d1c831e99ee0e4de8f1946fd38ecb69fb30ac76b1129c5cf54f4e766ff332b65b2cbbc8069e2effa2d84bdab0c54ebba84046182c819dfd3eadd3355a5bcde574d29e73819aad2d72d570770f444b038e51948552acdab4a30ff82e78a409992b6300c3e095afc57df0506286e18dbd929d7ea631abbbd76d2a384de79a7f1b4a8dd48e8e33b734a9e4d", "cc5a10ddb3ea2c1a3d2b0631c5dfb522942b2ff19881f13bcd4a967522077fb823a0e9b32cd9bc85dea5b39419d146c4bbd85f9f966d63689b890f793d9909ea8ee8d807dee5724eda693df83490a82a806323979a2ff3a5ede1b87135bbc0286f70fee82d3276b9c50594254bb6865e32f5c68e29ba3cba6f5ab4efa3ca4321a49137a5339a097dc8c279528885b11f10921e8626fe74b1b396c7106a6482a688740eba37276e289fca07a3975d60b6b8de80b2c66acac35794eb1d3b990362f0be450b65c5a31d9a481d6bb87455719c3abc59d1b9dae783dbba6dd53ae374de23f9bf96dbdde37c8bdcd8f9ebe07008a1b85b094d3441aea4ee0e122ac194106480ae4faeee51d6affe4275e862b40155022eb19c9bad6fd411f1c2e5c8a4354401e8fb2249f34a4854a68ee944af3a7c55f56aa7fe9a379f9cbe9011a7b7f63620bb9dedd5bfefbeeb3f82ee499e513479739abfc9bce06dfa3b7b66ec9840da098d4e053b65099fb5ae9e97afa6c93c85af81ae2a02610177812d2514e9", Some("23c6f287a97212912dd36c426a842e8bf340228c49f4df931886f1744d21d8906ee58d00b09a0fe5b5af12bfd2492101b35fc674793f57ffde7f6b8c277859a0d9dbdb4b2b9ff7b7bee1c0b1cfbb3d2afc26c1f56cf226f0559d2a3e5ebd98c2bb29acb7abdaf977ff644c6aea9cb550ee0db75c06cae2271b824fc6dd58ae5e5af0903b97fd5b6e01d31adb3776b4563abff6c154cdcea0395b49e9f2c97c88f3683cddc2d340801e078c58866bfd9d6712789840b89563bf5eb24ce1ec58614e2514e6ef3346716394333ec2fee0e799e5751ef40441addecef2b693300ef09f2b2816c6e0d15a360106c83c97bd138a72c3ba4fcbf96fd548554cfb92bbcec27bc646e13fc779094f01f468fa7ad188d457937472c5680f797e8a4650eb403c07733a2ebfc10d89f062826a0d1ba014361a65269a4756b80e5953d37e0ce01d477f375f4385671bad1982063ca054468d950a43ee37547a305c00b8f85be0151ad2c6a14459943519a2e8609e1c08ef3db46677441f43408a40d8a46b048a")),
+	("779ba33a240f193f0901a8cf0366c694a2e9953179ff51d816884ba7623f25c8e44cc32829b0a6373b524653ae4aabbef89e1d7348542a231b7dcd012c72a0e587127a8fde498b93f53ced2105aaafb38f15d3ffda1854e3c82aafe8153bf885d825a59d4ae579192a207b88a823f7c499d543a8e14ce23685416daaef9cac975296d078df0b432c4169380e7f20349ef24ac3b7aad7753761fe302f52febbd4ee89976b8d9b1254f4657675b322171764067c0c804245063c547b58a012d6083ca908e214fa5b32aede112e7c9db98535a927c08b39ba986adbb6ec07bd4513eb14bb116c5cbdf789436de425d8769c5bd4059bf62acd9ecaedb172305d102e157f7eff59e53b58c52765a06917671e3bef2b2f6f7f777322f96e28b9c6fdcb37f5f3d1b0a306e8d874db123d4ecdc2041e3c1e1f278f9dbe71b1050c0e233cab9cd32618858f0eba79f7184bd43d33221e0f694d766470fc7b3cb2ff21b20097368944d30755d72a48e151454ea4a1bc162c26a1b57caab4ea49262e9eba33c85f53cc", "15542e26a531984d8cfd825924feebd0637d4da5802eb2daad17d89c830e3699e323a0ce9ac67aa05275a8c16b76262538c4068244a868f1c665cc6bd0808e538edf4f705949440a66da2a17644357dc6558f2c1648ac70eb1282ab0bfe7cc9405403bc2e3f6ba0ff708c8eb18b8a311bf610e47ca8a0bf690783b66eaa3ffcca4085b693b5a6641681546a80376cba957f9a1c5d1ce6a3e69a219d5bb2d437e7b99c5edb29d99471e54af3cd73e8a2cfaea8088b039cda890b78ec56e4671b8c86d5eb1dacb990f800fa4043f3db5a19b95500138b33b11f35f3fb3f1519d02893a924257c0d75316e82c9df65c8422ab80169f28e7aa776e209ebbbb9de8e04f12971b783e547fecbd92939458696aa8a9fc732963a4413ad218dfd11d9c4c4241e4604104124b24cc11f80945ad28a79f2c915fc840f9b0f98e0d1d3fe65748ee6d5e54213fb83703bc4f29fc0251f3c65b6c3bd5930909c82406fd29f010ce204dd32095bc2e26ce6732a3880d806b6cc5d0a1023dc0f8115fb03da609f2", "89f2cdcb1bbbebe159d265b4907e3ee741d494ab1a13046f9114f840d46bbd561b1308ad8574fdaa9ce643cd7baa06fb85bb8975f1fb3fe7fb969f536ecef7f6d6000c7900070d57a6cc4f175648d03cfc8f75937f1f1b2c6a23623dfa21dc70b99cd518eba783686376d4fff69b3f3c416563d89705aea9064ca678efd0d172a29f19210ef17f857be070ad52a4e67828a508b6d35ff2d9de01ff65bb6b656a671dcaaa98c55bd9dc22d97069c2b794b44800dc606ff584d09b478d3d0ba2fa906b986f0ea38a238d5bea4b8581bb193916c34424afe1d957fa6f64721d4f1d43a81b6b07614808a51aee21801ccf15845c38a56e853c439ca41e5dcc8355bca6ec5285c176de129d05cb03aa038aa0fbc4a7a828b0cc84324a6002c05e27a1a564cb57398316734e3e1b807878bccd16e107e235919ab25114c8e7b5d1893f23569d3b2cf5fb04043117223d90726a5f3ccc1cc99144b7cb4e53d6e48e2bd619cb92e8b75353919266a84bec7ded187fe1a918d474c73bfef29984c47201a4", "3fdd19a3e002757f91b24761481b17ccf5bb0cb08c67d59f210776028cab0db87e039f431bb30dba0698db4c656cbb21ac345c57aaa5388a54efcc0c0fd7aed62c627c9c93269b7c9d68fd96691210307846a358c5456edc37dae614246bd46ff6b5a07033cd5a53c451a7dd52ce6ae2c86fdbe03cac182a7e8dc7b88145bd2fa7ad24a43b08ea89bc41bfa41015af3874958a2286884e60f1310cf94e6d08be590d23c2c26560994b62c69f901c810af38d1c13e02698a86801c02582d34878d18af5d0e834ac6979c73f4f86cea053dda77e59dfc1558f41d69cf2292628767da3c8910f8f01ede7410977c4a79a6c7e1c118922d4fea2a79f76f26f49a2d052c3124307db3a5f22cbd71fbb5c1e3835be215815b87d339e7c9da26c761e0a309543c77696a556bd3229f72ba9c61aaf2fb6a59810126d2e2135bc74e156f017e403e323d5919fd0100ae39fd65541c10781c34c3f34f7e5d37cb139aed5848b7516f5aca4f82dd720535c4e322623c831362029eb7aace0eebd024efb874c", None),
+];
+
+#[test]
+fn bignum_arithmetic() {
+    for case in ARITHMETIC {
+        let (a, b) = (big(case.a), big(case.b));
+
+        assert_eq!(format!("{:x}", &a + &b), case.sum);
+        assert_eq!(format!("{:x}", &a - &b), case.difference);
+        assert_eq!(format!("{:x}", &a * &b), case.product);
+        assert_eq!(format!("{:x}", &a / &b), case.quotient);
+        assert_eq!(format!("{:x}", &a % &b), case.remainder);
+        assert_eq!(format!("{:x}", a.gcd(&b)), case.gcd);
+        assert_eq!(BigUint::from_be_bytes(&a.to_be_bytes()), a);
+    }
+}
+
+#[test]
+fn bignum_fixed_width_arithmetic() {
+    for case in ARITHMETIC {
+        let (Ok(a), Ok(b)) = (U4096::try_from(&big(case.a)), U4096::try_from(&big(case.b))) else {
+            continue;
+        };
+
+        let (low, high) = a.widening_mul(&b);
+        let product_bits = &(&BigUint::from(&high) << U4096::BITS) + &BigUint::from(&low);
+
+        assert_eq!(format!("{:x}", product_bits), case.product);
+        assert_eq!(
+            format!("{:x}", BigUint::from(&a.wrapping_sub(&b))),
+            case.difference
+        );
+
+        if let Some(s) = a.checked_add(&b) {
+            assert_eq!(format!("{:x}", BigUint::from(&s)), case.sum);
+        }
+    }
+}
+
+#[test]
+fn bignum_modular() {
+    for (base, exponent, modulus, result, inverse) in MODULAR {
+        let (base, exponent, modulus) = (big(base), big(exponent), big(modulus));
+
+        assert_eq!(format!("{:x}", base.modpow(&exponent, &modulus)), result);
+        assert_eq!(
+            base.modinv(&modulus).map(|i| format!("{:x}", i)).as_deref(),
+            inverse
+        );
+
+        if modulus.is_odd() {
+            let montgomery = Montgomery::new(&modulus).unwrap();
+
+            assert_eq!(format!("{:x}", montgomery.pow(&base, &exponent)), result);
+            assert_eq!(
+                format!("{:x}", montgomery.pow_vartime(&base, &exponent)),
+                result
+            );
+        }
+    }
+}
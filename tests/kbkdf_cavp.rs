@@ -0,0 +1,178 @@
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use vox_cryptography::block_ciphers::aes::{AESKey, AES};
+use vox_cryptography::cmac::Cmac;
+use vox_cryptography::hashes::{SHA1, SHA224, SHA256, SHA384, SHA512};
+use vox_cryptography::hmac::Hmac;
+use vox_cryptography::kdf::{CounterPosition, Kbkdf, KbkdfMode, Prf};
+
+// The response files from the CAVP KBKDF test vectors, https://csrc.nist.gov/projects/
+// cryptographic-algorithm-validation-program/key-derivation. The `.rsp` files of KDFFeedback
+// (zero IV, non-zero IV and no counter) go in tests/data/kbkdf/feedback and those of
+// KDFDblPipeline (with and without a counter) in tests/data/kbkdf/double_pipeline.
+const FEEDBACK_DIR: &str = "tests/data/kbkdf/feedback";
+const DOUBLE_PIPELINE_DIR: &str = "tests/data/kbkdf/double_pipeline";
+
+/// One COUNT of a response file, with the bracketed parameters of the section it is in.
+struct TestCase {
+    file: String,
+    section: HashMap<String, String>,
+    values: HashMap<String, String>,
+}
+
+impl TestCase {
+    fn bytes(&self, name: &str) -> Vec<u8> {
+        return hex::decode(self.values.get(name).map_or("", String::as_str)).unwrap();
+    }
+
+    fn describe(&self) -> String {
+        return format!(
+            "{} {:?} COUNT = {}",
+            self.file, self.section, self.values["COUNT"]
+        );
+    }
+}
+
+fn parse_responses(dir: &str) -> Vec<TestCase> {
+    let mut paths: Vec<_> = fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(dir))
+        .unwrap_or_else(|e| panic!("{dir} holds the CAVP response files: {e}"))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rsp"))
+        .collect();
+    paths.sort();
+
+    let mut cases = Vec::new();
+
+    for path in paths {
+        let file = path.file_name().unwrap().to_string_lossy().into_owned();
+        let mut section = HashMap::new();
+        let mut values: Option<HashMap<String, String>> = None;
+        let mut in_header = false;
+
+        for line in fs::read_to_string(&path).unwrap().lines() {
+            let line = line.trim();
+
+            if let Some(parameter) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                // A new run of parameters replaces all of the previous ones
+                if !in_header {
+                    section.clear();
+                    in_header = true;
+                }
+
+                let (name, value) = parameter.split_once('=').unwrap();
+                section.insert(name.trim().to_string(), value.trim().to_string());
+                continue;
+            }
+
+            // Intermediate values such as "Binary rep of i" and "instring" are not needed
+            let Some((name, value)) = line.split_once('=') else {
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+            in_header = false;
+
+            if name == "COUNT" {
+                values = Some(HashMap::new());
+            }
+
+            if let Some(current) = values.as_mut() {
+                current.insert(name.to_string(), value.to_string());
+            }
+
+            if name == "KO" {
+                cases.push(TestCase {
+                    file: file.clone(),
+                    section: section.clone(),
+                    values: values.take().unwrap(),
+                });
+            }
+        }
+    }
+
+    assert!(!cases.is_empty(), "no test cases in {dir}");
+
+    return cases;
+}
+
+fn derive<P: Prf>(prf: P, mode: KbkdfMode, case: &TestCase) -> Vec<u8> {
+    let mut kbkdf = Kbkdf::new(prf, mode);
+
+    kbkdf = match case.section.get("CTRLOCATION").map(String::as_str) {
+        None => kbkdf.without_counter(),
+        Some(location) => {
+            let position = match location {
+                "BEFORE_ITER" => CounterPosition::BeforeIteration,
+                "AFTER_ITER" => CounterPosition::MiddleFixed(0),
+                "AFTER_FIXED" => CounterPosition::AfterFixed,
+                _ => panic!("unknown counter location in {}", case.describe()),
+            };
+            let bits: usize = case.section["RLEN"]
+                .trim_end_matches("_BITS")
+                .parse()
+                .unwrap();
+
+            kbkdf
+                .with_counter_width(bits)
+                .with_counter_position(position)
+        }
+    };
+
+    if mode == KbkdfMode::Feedback {
+        kbkdf = kbkdf.with_iv(&case.bytes("IV"));
+    }
+
+    let length_bits: usize = case.values["L"].parse().unwrap();
+    let mut output = vec![0u8; length_bits / 8];
+    kbkdf
+        .derive_into(&case.bytes("FixedInputData"), &mut output)
+        .unwrap();
+
+    return output;
+}
+
+/// Runs every case with a PRF this crate implements, returning how many ran.
+fn run(dir: &str, mode: KbkdfMode) -> usize {
+    let mut ran = 0;
+
+    for case in parse_responses(dir) {
+        let key = case.bytes("KI");
+        let output = match case.section["PRF"].as_str() {
+            "CMAC_AES128" | "CMAC_AES192" | "CMAC_AES256" => derive(
+                Cmac::<AES>::new(AESKey::from_bytes(&key).unwrap()).unwrap(),
+                mode,
+                &case,
+            ),
+            "HMAC_SHA1" => derive(Hmac::<SHA1>::new(&key), mode, &case),
+            "HMAC_SHA224" => derive(Hmac::<SHA224>::new(&key), mode, &case),
+            "HMAC_SHA256" => derive(Hmac::<SHA256>::new(&key), mode, &case),
+            "HMAC_SHA384" => derive(Hmac::<SHA384>::new(&key), mode, &case),
+            "HMAC_SHA512" => derive(Hmac::<SHA512>::new(&key), mode, &case),
+            // CMAC over TDES
+            _ => continue,
+        };
+
+        assert_eq!(
+            hex::encode(output),
+            case.values["KO"].to_lowercase(),
+            "{}",
+            case.describe()
+        );
+        ran += 1;
+    }
+
+    return ran;
+}
+
+#[test]
+#[ignore = "needs the CAVP KDFFeedback response files in tests/data/kbkdf/feedback"]
+fn test_kbkdf_feedback_cavp() {
+    assert!(run(FEEDBACK_DIR, KbkdfMode::Feedback) > 0);
+}
+
+#[test]
+#[ignore = "needs the CAVP KDFDblPipeline response files in tests/data/kbkdf/double_pipeline"]
+fn test_kbkdf_double_pipeline_cavp() {
+    assert!(run(DOUBLE_PIPELINE_DIR, KbkdfMode::DoublePipeline) > 0);
+}
@@ -0,0 +1,120 @@
+use pretty_assertions::assert_eq;
+use vox_cryptography::error::VCryptoError;
+use vox_cryptography::openssl_enc::{EncCipher, EncKdf, OpenSslEnc, DEFAULT_PBKDF2_ITERATIONS};
+
+// Files written by `openssl enc -<cipher> -pass pass:swordfish -in plaintext.txt` with `-md md5`
+// or `-pbkdf2 [-iter N]`, Blowfish with `-provider legacy -provider default`
+const PASSWORD: &[u8] = b"swordfish";
+const PLAINTEXT: &[u8] = include_bytes!("data/enc/plaintext.txt");
+
+const FIXTURES: [(&[u8], &[u8], EncCipher, EncKdf); 8] = [
+    (
+        include_bytes!("data/enc/aes128cbc_md5.enc"),
+        PLAINTEXT,
+        EncCipher::Aes128Cbc,
+        EncKdf::BytesToKeyMd5,
+    ),
+    (
+        include_bytes!("data/enc/aes192cbc_pbkdf2_1000.enc"),
+        PLAINTEXT,
+        EncCipher::Aes192Cbc,
+        EncKdf::Pbkdf2 { iterations: 1000 },
+    ),
+    (
+        include_bytes!("data/enc/aes256cbc_md5.enc"),
+        PLAINTEXT,
+        EncCipher::Aes256Cbc,
+        EncKdf::BytesToKeyMd5,
+    ),
+    (
+        include_bytes!("data/enc/aes256cbc_pbkdf2.enc"),
+        PLAINTEXT,
+        EncCipher::Aes256Cbc,
+        EncKdf::Pbkdf2 {
+            iterations: DEFAULT_PBKDF2_ITERATIONS,
+        },
+    ),
+    (
+        include_bytes!("data/enc/aes256cbc_pbkdf2_1000_empty.enc"),
+        b"",
+        EncCipher::Aes256Cbc,
+        EncKdf::Pbkdf2 { iterations: 1000 },
+    ),
+    (
+        include_bytes!("data/enc/bfcbc_md5.enc"),
+        PLAINTEXT,
+        EncCipher::BlowfishCbc,
+        EncKdf::BytesToKeyMd5,
+    ),
+    (
+        include_bytes!("data/enc/bfcbc_pbkdf2_1000.enc"),
+        PLAINTEXT,
+        EncCipher::BlowfishCbc,
+        EncKdf::Pbkdf2 { iterations: 1000 },
+    ),
+    (
+        include_bytes!("data/enc/bfecb_md5.enc"),
+        PLAINTEXT,
+        EncCipher::BlowfishEcb,
+        EncKdf::BytesToKeyMd5,
+    ),
+];
+
+#[test]
+fn test_decrypt_openssl() {
+    for (data, plaintext, cipher, kdf) in FIXTURES {
+        let enc = OpenSslEnc::new(cipher, kdf);
+
+        assert_eq!(enc.decrypt(PASSWORD, data).unwrap(), plaintext);
+    }
+}
+
+#[test]
+fn test_encrypt_openssl() {
+    // Encrypting with the salt OpenSSL picked gives the same file
+    for (data, plaintext, cipher, kdf) in FIXTURES {
+        let salt = OpenSslEnc::salt(data).unwrap();
+        let enc = OpenSslEnc::new(cipher, kdf);
+
+        assert_eq!(
+            enc.encrypt_with_salt(PASSWORD, &salt, plaintext).unwrap(),
+            data
+        );
+    }
+}
+
+#[test]
+fn test_wrong_parameters() {
+    let (data, _, _, _) = FIXTURES[3];
+
+    for enc in [
+        OpenSslEnc::new(EncCipher::Aes256Cbc, EncKdf::BytesToKeyMd5),
+        OpenSslEnc::new(EncCipher::Aes256Cbc, EncKdf::Pbkdf2 { iterations: 1000 }),
+        OpenSslEnc::new(
+            EncCipher::Aes128Cbc,
+            EncKdf::Pbkdf2 {
+                iterations: DEFAULT_PBKDF2_ITERATIONS,
+            },
+        ),
+    ] {
+        assert_eq!(
+            enc.decrypt(PASSWORD, data),
+            Err(VCryptoError::InvalidPadding)
+        );
+    }
+
+    let enc = OpenSslEnc::new(
+        EncCipher::Aes256Cbc,
+        EncKdf::Pbkdf2 {
+            iterations: DEFAULT_PBKDF2_ITERATIONS,
+        },
+    );
+    assert_eq!(
+        enc.decrypt(b"swordfisk", data),
+        Err(VCryptoError::InvalidPadding)
+    );
+    assert_eq!(
+        enc.decrypt(PASSWORD, &data[16..]),
+        Err(VCryptoError::InvalidEncoding)
+    );
+}
@@ -0,0 +1,129 @@
+use pretty_assertions::assert_eq;
+use vox_cryptography::der::{oid, AlgorithmIdentifier};
+use vox_cryptography::error::VCryptoError;
+use vox_cryptography::pem::Pem;
+use vox_cryptography::pkcs8::{
+    EncryptedPrivateKeyInfo, Pbes2Cipher, Pbes2Params, Pbes2Prf, PrivateKeyInfo,
+    ENCRYPTED_PRIVATE_KEY_LABEL, PRIVATE_KEY_LABEL,
+};
+
+// Keys generated with `openssl genpkey` and encrypted with
+// `openssl pkcs8 -topk8 -v2 <cipher> -v2prf <prf> -iter <count> -passout pass:correct-horse`
+const PASSWORD: &[u8] = b"correct-horse";
+
+const EC_P256: &str = include_str!("data/pkcs8/ec_p256.pem");
+const ED25519: &str = include_str!("data/pkcs8/ed25519.pem");
+const RSA_2048: &str = include_str!("data/pkcs8/rsa_2048.pem");
+
+const ENCRYPTED: [(&str, &str, Pbes2Prf, Pbes2Cipher, u32); 4] = [
+    (
+        include_str!("data/pkcs8/ec_p256_aes128_sha1.pem"),
+        EC_P256,
+        Pbes2Prf::HmacWithSha1,
+        Pbes2Cipher::Aes128Cbc,
+        2048,
+    ),
+    (
+        include_str!("data/pkcs8/ec_p256_aes256_sha256.pem"),
+        EC_P256,
+        Pbes2Prf::HmacWithSha256,
+        Pbes2Cipher::Aes256Cbc,
+        2048,
+    ),
+    (
+        include_str!("data/pkcs8/ed25519_aes128_sha256.pem"),
+        ED25519,
+        Pbes2Prf::HmacWithSha256,
+        Pbes2Cipher::Aes128Cbc,
+        1000,
+    ),
+    (
+        include_str!("data/pkcs8/rsa_2048_aes256_sha256.pem"),
+        RSA_2048,
+        Pbes2Prf::HmacWithSha256,
+        Pbes2Cipher::Aes256Cbc,
+        2048,
+    ),
+];
+
+fn der(pem: &str, label: &str) -> Vec<u8> {
+    Pem::parse_with_label(pem, label).unwrap().into_contents()
+}
+
+#[test]
+fn test_private_key_info() {
+    for (pem, algorithm, private_key_len) in [
+        (
+            EC_P256,
+            AlgorithmIdentifier::with_parameters(
+                oid::EC_PUBLIC_KEY,
+                &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07],
+            ),
+            109,
+        ),
+        (ED25519, AlgorithmIdentifier::new(oid::ED25519), 34),
+        (
+            RSA_2048,
+            AlgorithmIdentifier::with_parameters(oid::RSA_ENCRYPTION, &[0x05, 0x00]),
+            1190,
+        ),
+    ] {
+        let der = der(pem, PRIVATE_KEY_LABEL);
+        let info = PrivateKeyInfo::from_der(&der).unwrap();
+
+        assert_eq!(info.algorithm(), algorithm);
+        assert_eq!(info.private_key().len(), private_key_len);
+        assert_eq!(info.public_key(), None);
+        assert_eq!(info.to_der(), der);
+        assert_eq!(Pem::new(PRIVATE_KEY_LABEL, &der).encode(), pem);
+    }
+}
+
+#[test]
+fn test_decrypt_openssl() {
+    for (encrypted_pem, pem, prf, cipher, iterations) in ENCRYPTED {
+        let der = der(encrypted_pem, ENCRYPTED_PRIVATE_KEY_LABEL);
+        let encrypted = EncryptedPrivateKeyInfo::from_der(&der).unwrap();
+        let params = Pbes2Params::from_algorithm_identifier(&encrypted.algorithm()).unwrap();
+
+        assert_eq!(params.prf(), prf);
+        assert_eq!(params.cipher(), cipher);
+        assert_eq!(params.iterations(), iterations);
+        assert_eq!(encrypted.to_der(), der);
+
+        let decrypted = encrypted.decrypt(PASSWORD).unwrap();
+        assert_eq!(decrypted, self::der(pem, PRIVATE_KEY_LABEL));
+        assert_eq!(
+            encrypted.decrypt(b"battery-staple"),
+            Err(VCryptoError::InvalidPadding)
+        );
+
+        // Encrypting again with the same salt and IV gives what OpenSSL wrote
+        let info = PrivateKeyInfo::from_der(&decrypted).unwrap();
+        let reencrypted = EncryptedPrivateKeyInfo::encrypt(&info, PASSWORD, &params);
+        assert_eq!(reencrypted, der);
+        assert_eq!(
+            Pem::new(ENCRYPTED_PRIVATE_KEY_LABEL, &reencrypted).encode(),
+            encrypted_pem
+        );
+    }
+}
+
+#[test]
+fn test_unsupported_schemes() {
+    // `-v2 aes-192-cbc` and `-scrypt`
+    for pem in [
+        include_str!("data/pkcs8/ec_p256_aes192.pem"),
+        include_str!("data/pkcs8/ec_p256_scrypt.pem"),
+    ] {
+        let der = der(pem, ENCRYPTED_PRIVATE_KEY_LABEL);
+        let encrypted = EncryptedPrivateKeyInfo::from_der(&der).unwrap();
+
+        assert_eq!(encrypted.algorithm().oid(), oid::PBES2);
+        assert_eq!(encrypted.to_der(), der);
+        assert_eq!(
+            encrypted.decrypt(PASSWORD),
+            Err(VCryptoError::UnknownAlgorithm)
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use vox_cryptography::bignum::BigUint;
+use vox_cryptography::hashes::{SHA1, SHA256, SHA384, SHA512};
+use vox_cryptography::random::HmacDrbg;
+use vox_cryptography::rsa::RsaPrivateKey;
+
+// A 2048 bit key and fixtures generated with OpenSSL 3.5
+const N: &str = "d78b37e50cb3c7d2cd34c62f12b6a1ac5fc494b93f3f6a2d2234ae5f7a7f636af029df6554cc85c6fd765c4bbd86a6894c4ca0a9f02b5c2c367564d6561d56cfcc126127cc0c61b810fd58aed85e96e9b7abefa54cdc0b47f26ca33c679ede004b35c8b55b6fda54aab82cd65a2d44174d6efc5350473a1d04df60eb51c0d141dea9849f508d60286bebb2d0639e3c6edb0d2dcc43ef4c127b86ffed1a8be3638e8daeebfb70e57a5a94423aee7a173a18f229a3da2b0a4b3e456c38537451cde909656891544b4d718c970866c98b26df726c5893444fb26b200ce5f686891cb95ed06b4ae6d2f61ce752e601836315d91a20fc1579fe184a8c3331a57e54f5";
+const D: &str = "2e5ffd483249f209d86dece00eea2d71cd6f19c29ba994ba1e3c70720984a40acb9e6ebaba1a8103f5dfc3eee5d9b536202cf27a6dba9e8bc2b1d25ecd4b65b4b2c717913cf543071bdcae7a7c0229fd2230039130aebd5c9efba1c96ad0031ad413bfd9c951110c1b6921f882916faf85d105c81523260d3d51a4642d044be137a013b3622400541500e6f23950ee71142d39c0bbe8315eadd537c546283a6536d7c7bd646d4d06e9aa4b89f186caf5a71691c923fec67755617aed239fe14a63105c8f31a2907ce4e5e7646ec8d8c8d03fa17349f5c5a1c7e2b0003a5ecf937862aea548be9517870f8d0345a445fca40d5e35e6e1b86722360e7348b4cc17";
+const P: &str = "f03f9acad17d80123f8329423be15049547056c083353fd89e1a2272797fc5d3d55c27d8324d9379f10642c2860aa6f203be680a2a6108f4eb0ca8fbb6879c7374caa600bfffdd6982a1d3886f0b14865f35f3241157a0f9ea103a08b639e546d2a935d0a6f7eae002ebe1b78b14d61b5cf32da460ecacf3cefc09b426f171e7";
+const Q: &str = "e5acf6e26ef827e865d0e2e549880a4b1d49a993945691c2def16f1386a63c4f01146ea0c009e2ad90d926003583a552d224276bc6935bb423012104286bd32b306e23dbb7d8125c59d678420260c31f2ba215e588813817aca2a3a718931ec2b3dccab0701a1e8f3fe5f6849220868d91f29147ccab5298fbd3d55575789ec3";
+
+const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+// openssl dgst -sha256 -sign key.pem
+const SIGNATURE_PKCS1V15_SHA256: &str = "c57cdbea89c66573f51c3be7cda5e899bc03e214a7cd41cf3c3caefd3e0cc30934a033758a7303beb8110dabc5760adfc3c0fd42fb29b505ea11687520e550be7a052232636278dadb2cdaea7286b0eca42d79873b8143a960e9fb56c4e1738cb2013b613f7c2d472fac52c7f99beac1213b2be4df14b92e6f8f0ede88e019b4c67c43686b5632abae2d7f01fa6143435f91b2acc029841c00b16ef7e564b59a8944c9bffb2cd0404dd1ad223d209cfc3f3105e99bc4771621318eeed40b387eb471c02b089de344577412d5cffb205e147ab874c95491e5812ce8fc5988046450133afbea35eff2fb4218e1f8cfe5b0f2bf4b9b6dc4e6310c77b83e1eee46b2";
+// openssl dgst -sha384 -sign key.pem -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:32
+const SIGNATURE_PSS_SHA384: &str = "57cea297842ac84c98bb67c55796abb2413ca33d7661d422ce31ebe7fb63667e5d10bceff298e46426f06679befe979321c5f83e3a3687a350486940796833001890fe980e91825533b3876949941695883a81ae8ec2ebcd8dbc41fe4dd2674aa2bc69d4ca8c6baa24cef559bc4677cffed944550608969f9ddc25bc001419a4ad99420701e912f4a521cd6bc3a9a6062158986286dc4a71de35bca50c86b13994b06668fc572020b124f105361a6b403d27e025fd4a43c366ab6a6af4ef494574a69f68c17d53a3ba6efc5fff8e0c00c1bfd88d11bc1519cdb0cfe7e507ced2aa2a03346df102aa1e404030567e12025bd3fb53a2ccef7c67a18363eed69f83";
+// openssl dgst -sha256 -sign key.pem -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:max
+const SIGNATURE_PSS_SHA256_MAX_SALT: &str = "6cd8a510bcc174fb75297645693243c60e4dba6417024f7e1ae59775f8a4bea4b93694d675e56df557fa18eeb09bc5da57624add85b73d40e28157e10039ced09695984b76823f9274ea1333b89fa2b004a83d493820bb026996a03934eab76bc37b077ac24cc85c5058e0e695c7c7f65b05db516c5f7998d90cd13984b8a7acaa774b2c22cdb93fbff77252f1ecc47d483e3baff401c99ce9a60c6ff9cbcea26c6c8c8765a071418e466f8e6a36a40174d0f96d3b2e7e32ab01fb056c19bf69fb56d18bb00347e0762adbf6ab178ad91e3e961709d44850a8d9d90228b40db10803b7e2ac5a3ff30168a4b9e8514013cdf8f24081bd0150c256511321cc592a";
+// openssl pkeyutl -encrypt -inkey key.pem -pkeyopt rsa_padding_mode:oaep
+const CIPHERTEXT_OAEP_SHA1: &str = "8b3d1f4e4cc8bc5921b85d0458767739c60bb53fc074be3dbeb9a809a051a3e29bd69137b88c42f248783df0a56d93622817988b065444656e217688aa1d38010c4ffd04387d6a4807f098f89aa16d8f791571fdd37d382acbc095c6c97949b3e49e84b0464dc5669d2963e2eb70dec7496323d8155f8fa9950b397d727ef60346bd16ae4a7011eef811cbabab888ad6ca7331c13f4a15fd619fa15d2bf59394a2cf2cc77cf5df8070e08133c35813cdbc0f3ff5b8cb99429642374b580136bc70c29e9f9d6d4da7f9ab097affd3c867b6eb21ab35299ee340a85abbdabbfedd6ca9230e234bca4e2817729c8a7e5f5816e9c659f3fa35f35b12cd4f827bcb7d";
+// As above with -pkeyopt rsa_oaep_md:sha512 -pkeyopt rsa_mgf1_md:sha512
+// -pkeyopt rsa_oaep_label:6c6162656c
+const CIPHERTEXT_OAEP_SHA512_LABEL: &str = "b2792093e84a49c681ccc0e23d48beb8b416effa99ea0dd847746c86f4823b0ad6b5c5080a624d9e462e42aee58db62c345cba1c5559a424b2538c47bb70f11b77f20dcde8cbcf973606463fe94c7e6aef589413557b33b85d09e8164075aa02d894b156e16304b7cce7213c43acc063b6ab7b23a4f1e166b7389e14e0c63cdc1f07fa963039248e027f55fe06a4a7dec8e0e132229251e29fb41aa8a2e8d5dc5096a81f070cd21f3faad11d464784a149ef51a00812eb1f97a19ce907b56a7acf95ef7b8bff74ca0809babf73000c94a0545dc8426f0dfb4bd070d8422d13399cf64ee59ed791e5b4fca5313d08d5a988065e9800c2d801efbfc9ab95501507";
+// openssl pkeyutl -encrypt -inkey key.pem -pkeyopt rsa_padding_mode:pkcs1
+const CIPHERTEXT_PKCS1V15: &str = "5f23c345bc9587c0d31a86e169c1b5b3ef1dcd799b22dbff4909f8e2d36707f48f0667895ea43567ed729b7e93e78102748da4f55b6828ab6f3d9d6014df423da149856695dfea22165b899aa262c9699422b54bac35c853c450bd58a2d95069c04f6000da8d247eb61518d4f10909b34bee789cfd34f9cc72fd132bf4c78effe7d2b66348600aee9d44b5a81d82e47cf974f4fb796778e526ad9e9e569d07aa81139a9fcd9745bd188d4296b5d4bb17cfafa732c0fecca1dd1f781391bb13f6c5cd8dd71c9300f20ddf46e297e55d7b07b4cce6f077847c8eca2388781c989d01cc74c1039874cf9ee7a3178569d40412abd45efc6408aee6874eddccdbbc8b";
+
+fn component(hex_string: &str) -> BigUint {
+    BigUint::from_be_bytes(&hex::decode(hex_string).unwrap())
+}
+
+fn key() -> RsaPrivateKey {
+    RsaPrivateKey::from_components(
+        component(N),
+        BigUint::from(65537u64),
+        component(D),
+        component(P),
+        component(Q),
+    )
+    .unwrap()
+}
+
+fn rng() -> HmacDrbg<SHA256> {
+    HmacDrbg::new(b"rsa interoperability tests", &[], &[])
+}
+
+#[test]
+fn rsa_pkcs1v15_signature() {
+    let key = key();
+    let signature = key.sign_pkcs1v15::<SHA256>(&mut rng(), MESSAGE).unwrap();
+
+    assert_eq!(hex::encode(&signature), SIGNATURE_PKCS1V15_SHA256);
+    assert!(key
+        .public_key()
+        .verify_pkcs1v15::<SHA256>(MESSAGE, &signature));
+}
+
+#[test]
+fn rsa_pss_signature() {
+    let public_key = key().public_key().clone();
+    let signature = hex::decode(SIGNATURE_PSS_SHA384).unwrap();
+
+    assert!(public_key.verify_pss::<SHA384>(MESSAGE, &signature, Some(32)));
+    assert!(public_key.verify_pss::<SHA384>(MESSAGE, &signature, None));
+    assert!(!public_key.verify_pss::<SHA384>(MESSAGE, &signature, Some(48)));
+
+    // The maximum salt length, 256 - 32 - 2 bytes
+    let signature = hex::decode(SIGNATURE_PSS_SHA256_MAX_SALT).unwrap();
+
+    assert!(public_key.verify_pss::<SHA256>(MESSAGE, &signature, Some(222)));
+    assert!(public_key.verify_pss::<SHA256>(MESSAGE, &signature, None));
+}
+
+#[test]
+fn rsa_oaep_decryption() {
+    let key = key();
+    let mut rng = rng();
+
+    let ciphertext = hex::decode(CIPHERTEXT_OAEP_SHA1).unwrap();
+    assert_eq!(
+        key.decrypt_oaep::<SHA1>(&mut rng, &ciphertext, &[])
+            .unwrap(),
+        MESSAGE
+    );
+
+    let ciphertext = hex::decode(CIPHERTEXT_OAEP_SHA512_LABEL).unwrap();
+    assert_eq!(
+        key.decrypt_oaep::<SHA512>(&mut rng, &ciphertext, b"label")
+            .unwrap(),
+        MESSAGE
+    );
+    assert!(key
+        .decrypt_oaep::<SHA512>(&mut rng, &ciphertext, &[])
+        .is_err());
+}
+
+#[test]
+fn rsa_pkcs1v15_decryption() {
+    let key = key();
+    let ciphertext = hex::decode(CIPHERTEXT_PKCS1V15).unwrap();
+
+    assert_eq!(
+        key.decrypt_pkcs1v15(&mut rng(), &ciphertext).unwrap(),
+        MESSAGE
+    );
+}
@@ -0,0 +1,70 @@
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use vox_cryptography::ed25519::{Signature, VerifyingKey};
+
+// The Ed25519 verification vectors from Wycheproof, https://github.com/C2SP/wycheproof, either
+// testvectors_v1/ed25519_test.json or the older testvectors/eddsa_test.json, checked in as
+// tests/data/wycheproof/ed25519_test.json.
+const ED25519_TEST: &str = "tests/data/wycheproof/ed25519_test.json";
+
+fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let Ok(public_key) = <[u8; 32]>::try_from(public_key) else {
+        return false;
+    };
+    let Ok(signature) = <[u8; 64]>::try_from(signature) else {
+        return false;
+    };
+
+    return match VerifyingKey::from_bytes(&public_key) {
+        Ok(verifying_key) => verifying_key.verify(message, &Signature::from_bytes(&signature)),
+        Err(_) => false,
+    };
+}
+
+fn hex_field(value: &Value, name: &str) -> Vec<u8> {
+    return hex::decode(value[name].as_str().unwrap()).unwrap();
+}
+
+#[test]
+#[ignore = "needs the Wycheproof Ed25519 vectors in tests/data/wycheproof/ed25519_test.json"]
+fn test_wycheproof_ed25519() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(ED25519_TEST);
+    let contents = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{} holds the Wycheproof vectors: {e}", path.display()));
+    let vectors: Value = serde_json::from_str(&contents).unwrap();
+    let mut cases = 0;
+
+    for group in vectors["testGroups"].as_array().unwrap() {
+        // Version 1 of the vectors renamed "key" to "publicKey"
+        let key = if group["publicKey"].is_object() {
+            &group["publicKey"]
+        } else {
+            &group["key"]
+        };
+        let public_key = hex_field(key, "pk");
+
+        for test in group["tests"].as_array().unwrap() {
+            let expected = match test["result"].as_str().unwrap() {
+                "valid" => true,
+                "invalid" => false,
+                result => panic!("tcId {} has result {result}", test["tcId"]),
+            };
+
+            assert_eq!(
+                verify(
+                    &public_key,
+                    &hex_field(test, "msg"),
+                    &hex_field(test, "sig")
+                ),
+                expected,
+                "tcId {}: {}",
+                test["tcId"],
+                test["comment"]
+            );
+            cases += 1;
+        }
+    }
+
+    assert_eq!(cases, vectors["numberOfTests"].as_u64().unwrap());
+}